@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-use crate::{cache, name_resolution, type_system::Check, visitor};
+use crate::{cache, name_resolution, type_system, type_system::Check, visitor};
 
 #[macro_export]
 macro_rules! force_match {
@@ -26,10 +26,12 @@ macro_rules! dispatch {
       ast::NodeKind::BindingStmt(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::IfExpr(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::LoopStmt(inner) => $target_fn(inner $(, $($args),* )?),
+      ast::NodeKind::ForInStmt(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::CallExpr(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::IntrinsicCall(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::BreakStmt(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::ContinueStmt(inner) => $target_fn(inner $(, $($args),* )?),
+      ast::NodeKind::DeferStmt(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::InlineExprStmt(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::Reference(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::AssignStmt(inner) => $target_fn(inner $(, $($args),* )?),
@@ -39,6 +41,8 @@ macro_rules! dispatch {
       ast::NodeKind::UnsafeExpr(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::StaticArrayValue(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::IndexingExpr(inner) => $target_fn(inner $(, $($args),* )?),
+      ast::NodeKind::SliceExpr(inner) => $target_fn(inner $(, $($args),* )?),
+      ast::NodeKind::InterpolationExpr(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::Enum(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::StructType(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::Prototype(inner) => $target_fn(inner $(, $($args),* )?),
@@ -51,15 +55,20 @@ macro_rules! dispatch {
       ast::NodeKind::Trait(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::ParenthesesExpr(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::Import(inner) => $target_fn(inner $(, $($args),* )?),
-      ast::NodeKind::SizeofIntrinsic(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::Range(inner) => $target_fn(inner $(, $($args),* )?),
       ast::NodeKind::UnimplementedExpr(inner) => $target_fn(inner $(, $($args),* )?),
+      ast::NodeKind::OptionalValue(inner) => $target_fn(inner $(, $($args),* )?),
+      ast::NodeKind::ResultValue(inner) => $target_fn(inner $(, $($args),* )?),
+      ast::NodeKind::TryExpr(inner) => $target_fn(inner $(, $($args),* )?),
     }
   };
 }
 
 #[derive(Debug, Clone)]
-pub struct UnimplementedExpr;
+pub struct UnimplementedExpr {
+  /// The position in the source file, reported if this expression is reached.
+  pub position: usize,
+}
 
 #[derive(Debug, Clone)]
 pub enum GenericConstraintKind {
@@ -97,20 +106,86 @@ pub enum IntSize {
   U16,
   U32,
   U64,
+  U128,
   Usize,
   I8,
   I16,
   I32,
   I64,
+  I128,
   Isize,
 }
 
+impl IntSize {
+  pub fn is_signed(&self) -> bool {
+    matches!(
+      self,
+      IntSize::I8 | IntSize::I16 | IntSize::I32 | IntSize::I64 | IntSize::I128 | IntSize::Isize
+    )
+  }
+
+  /// The bit-width of the integer type, given the bit-width of pointers
+  /// on the target, which dictates that of `Isize` and `Usize`.
+  pub fn bit_width(&self, pointer_width: u32) -> u32 {
+    match self {
+      IntSize::I8 | IntSize::U8 => 8,
+      IntSize::I16 | IntSize::U16 => 16,
+      IntSize::I32 | IntSize::U32 => 32,
+      IntSize::I64 | IntSize::U64 => 64,
+      IntSize::Isize | IntSize::Usize => pointer_width,
+      IntSize::I128 | IntSize::U128 => 128,
+    }
+  }
+
+  /// Determine whether an integer literal of the given magnitude fits
+  /// into this integer type.
+  pub fn fits(&self, magnitude: u128, is_negative: bool, pointer_width: u32) -> bool {
+    let bit_width = self.bit_width(pointer_width);
+
+    // Computed by shifting down from the maximum value, since `1 << 128`
+    // would overflow.
+    if !self.is_signed() {
+      return (!is_negative || magnitude == 0) && magnitude <= u128::MAX >> (128 - bit_width);
+    }
+
+    let max_positive = u128::MAX >> (129 - bit_width);
+
+    if is_negative {
+      magnitude <= max_positive + 1
+    } else {
+      magnitude <= max_positive
+    }
+  }
+
+  /// The name of the integer type, as written in source code.
+  pub fn name(&self) -> &'static str {
+    match self {
+      IntSize::U8 => "U8",
+      IntSize::U16 => "U16",
+      IntSize::U32 => "U32",
+      IntSize::U64 => "U64",
+      IntSize::U128 => "U128",
+      IntSize::Usize => "Usize",
+      IntSize::I8 => "I8",
+      IntSize::I16 => "I16",
+      IntSize::I32 => "Int",
+      IntSize::I64 => "I64",
+      IntSize::I128 => "I128",
+      IntSize::Isize => "Isize",
+    }
+  }
+}
+
 #[derive(PartialEq, Clone, Debug)]
 pub enum BasicType {
   Int(IntSize),
   Bool,
   Char,
   String,
+  /// An owned, growable UTF-8 string, which knows its length in bytes.
+  ///
+  /// Unlike `Str`, its buffer lives on the heap.
+  OwnedString,
   Null,
 }
 
@@ -120,14 +195,28 @@ pub enum Type {
   ///
   /// Its type and length are always known at compile-time.
   Array(Box<Type>, u32),
+  /// A view into a contiguous sequence of elements, whose length
+  /// is only known at runtime.
+  Slice(Box<Type>),
   Basic(BasicType),
   Pointer(Box<Type>),
+  /// A non-null pointer to an existing place, which may be read through
+  /// outside of unsafe blocks, but never written through.
   Reference(Box<Type>),
+  /// A reference that may also be written through. It may only be taken
+  /// of mutable places.
+  MutableReference(Box<Type>),
   Struct(StructType),
   /// A type that needs to be resolved.
   Stub(StubType),
   Function(FunctionType),
   This(ThisType),
+  /// A built-in type that either holds a value of its inner
+  /// type, or nothing at all.
+  Optional(Box<Type>),
+  /// A built-in type that either holds a value of its first inner
+  /// type (success), or an error of its second inner type.
+  Result(Box<Type>, Box<Type>),
   /// A meta type to be used during unification.
   Variable(usize),
   /// A meta type that represents the lack of a value.
@@ -148,6 +237,47 @@ pub enum Type {
 }
 
 impl Type {
+  /// The name of the type, as written in source code.
+  pub fn name(&self) -> String {
+    match self {
+      Type::Array(element_type, length) => format!("[{}, {}]", element_type.name(), length),
+      Type::Slice(element_type) => format!("[]{}", element_type.name()),
+      Type::Basic(BasicType::Int(size)) => size.name().to_string(),
+      Type::Basic(BasicType::Bool) => String::from("Bool"),
+      Type::Basic(BasicType::Char) => String::from("Char"),
+      Type::Basic(BasicType::String) => String::from("Str"),
+      Type::Basic(BasicType::OwnedString) => String::from("String"),
+      Type::Basic(BasicType::Null) => String::from("Null"),
+      Type::Pointer(pointee_type) => format!("*{}", pointee_type.name()),
+      Type::Reference(inner_type) => format!("&{}", inner_type.name()),
+      Type::MutableReference(inner_type) => format!("&mut {}", inner_type.name()),
+      Type::Struct(struct_type) => struct_type.name.clone(),
+      Type::Stub(stub_type) => stub_type.pattern.base_name.clone(),
+      Type::Function(function_type) => {
+        let mut names = function_type
+          .parameter_types
+          .iter()
+          .map(|parameter_type| parameter_type.name())
+          .collect::<Vec<_>>();
+
+        if names.is_empty() {
+          names.push(String::from("Unit"));
+        }
+
+        names.push(function_type.return_type.name());
+        names.join(" -> ")
+      }
+      Type::This(_) => String::from("This"),
+      Type::Optional(inner_type) => format!("?{}", inner_type.name()),
+      Type::Result(value_type, error_type) => {
+        format!("Result[{}, {}]", value_type.name(), error_type.name())
+      }
+      Type::Unit => String::from("Unit"),
+      // Meta types may not be written in source code.
+      Type::Variable(_) | Type::Error | Type::Never | Type::Any => String::from("_"),
+    }
+  }
+
   /// Determine whether the type is a unit type.
   ///
   /// This determination will not perform flattening.
@@ -162,6 +292,28 @@ impl Type {
     matches!(self, Type::Never)
   }
 
+  /// Determine whether values of this type are copied, instead of
+  /// being moved, when used by value.
+  ///
+  /// Structs are only copyable if they implement the built-in `Copy`
+  /// marker trait, and owned strings never are.
+  ///
+  /// This determination will perform flattening.
+  pub fn is_copyable(&self, cache: &cache::Cache) -> bool {
+    match self.flatten(cache) {
+      // Strings own their buffer, and copying a mutable reference would alias it.
+      Type::Basic(BasicType::OwnedString) | Type::MutableReference(_) => false,
+      Type::Struct(struct_type) => cache.copy_types.contains(&struct_type.cache_id),
+      Type::Array(element_type, _) | Type::Optional(element_type) => {
+        element_type.is_copyable(cache)
+      }
+      Type::Result(value_type, error_type) => {
+        value_type.is_copyable(cache) && error_type.is_copyable(cache)
+      }
+      _ => true,
+    }
+  }
+
   // TODO: Clarify comment.
   /// Determine whether the type is a meta type, implying that
   /// it is not lowerable.
@@ -230,6 +382,41 @@ impl Type {
     {
       return true;
     }
+    // Function types are compatible if their signatures are. Whether they are
+    // closures or plain function pointers (ex. extern callbacks) only concerns
+    // their representation, which is converted between during lowering.
+    else if let (Type::Function(function_type), Type::Function(other_function_type)) =
+      (self, other)
+    {
+      return function_type.is_variadic == other_function_type.is_variadic
+        && function_type.parameter_types.len() == other_function_type.parameter_types.len()
+        && function_type
+          .parameter_types
+          .iter()
+          .zip(other_function_type.parameter_types.iter())
+          .all(|(parameter_type, other_parameter_type)| parameter_type.is(other_parameter_type))
+        && function_type
+          .return_type
+          .is(other_function_type.return_type.as_ref());
+    }
+
+    match (self, other) {
+      // Static arrays coerce into slices of the same element type. The
+      // opposite doesn't hold, since the length of a slice isn't static.
+      (Type::Slice(element_type), Type::Array(other_element_type, _))
+      | (Type::Slice(element_type), Type::Slice(other_element_type)) => {
+        return element_type.is(other_element_type)
+      }
+      // Mutable references coerce into shared references, and references
+      // of either kind coerce into pointers. The opposite doesn't hold, since
+      // pointers may be null or dangling.
+      (Type::Reference(inner_type), Type::MutableReference(other_inner_type))
+      | (Type::Pointer(inner_type), Type::Reference(other_inner_type))
+      | (Type::Pointer(inner_type), Type::MutableReference(other_inner_type)) => {
+        return inner_type == other_inner_type
+      }
+      _ => {}
+    };
 
     // BUG: Is this actually true? What if we compare a Stub type with a Basic type (defined by the user)?
     // NOTE: Stub types will also work, because their target ids will be compared.
@@ -310,10 +497,12 @@ pub enum NodeKind {
   BindingStmt(BindingStmt),
   IfExpr(IfExpr),
   LoopStmt(LoopStmt),
+  ForInStmt(ForInStmt),
   CallExpr(CallExpr),
   IntrinsicCall(IntrinsicCall),
   BreakStmt(BreakStmt),
   ContinueStmt(ContinueStmt),
+  DeferStmt(DeferStmt),
   InlineExprStmt(InlineExprStmt),
   Reference(Reference),
   AssignStmt(AssignStmt),
@@ -323,6 +512,8 @@ pub enum NodeKind {
   UnsafeExpr(UnsafeExpr),
   StaticArrayValue(StaticArrayValue),
   IndexingExpr(IndexingExpr),
+  SliceExpr(SliceExpr),
+  InterpolationExpr(InterpolationExpr),
   Enum(Enum),
   StructType(StructType),
   Prototype(Prototype),
@@ -335,9 +526,11 @@ pub enum NodeKind {
   Trait(Trait),
   ParenthesesExpr(ParenthesesExpr),
   Import(Using),
-  SizeofIntrinsic(SizeofIntrinsic),
   Range(Range),
   UnimplementedExpr(UnimplementedExpr),
+  OptionalValue(OptionalValue),
+  ResultValue(ResultValue),
+  TryExpr(TryExpr),
 }
 
 impl NodeKind {
@@ -355,6 +548,7 @@ impl NodeKind {
         NodeKind::BlockExpr(block_expr) => map_children(&block_expr.statements).collect(),
         NodeKind::UnaryExpr(unary_expr) => vec![&unary_expr.expr.kind],
         NodeKind::UnsafeExpr(unsafe_expr) => vec![&unsafe_expr.0.kind],
+        NodeKind::DeferStmt(defer_stmt) => vec![&defer_stmt.body.kind],
         NodeKind::ParenthesesExpr(parentheses_expr) => vec![&parentheses_expr.expr.kind],
         NodeKind::CallExpr(call_expr) => vec![&call_expr.callee_expr.kind]
           .into_iter()
@@ -364,11 +558,36 @@ impl NodeKind {
         NodeKind::IfExpr(if_expr) => vec![&if_expr.condition.kind, &if_expr.then_expr.kind],
         // TODO: Missing condition.
         NodeKind::LoopStmt(loop_stmt) => map_children(&loop_stmt.body.statements).collect(),
+        NodeKind::ForInStmt(for_in_stmt) => vec![&for_in_stmt.iterable.kind]
+          .into_iter()
+          .chain(map_children(&for_in_stmt.body.statements))
+          .collect(),
         // TODO: Missing prototype.
         // NodeKind::Closure(closure) => map_children(&closure.body.statements).collect(),
         // TODO: Missing prototype.
         NodeKind::Function(function) => map_children(&function.body.statements).collect(),
         NodeKind::BindingStmt(binding_stmt) => vec![&binding_stmt.value.kind],
+        NodeKind::TryExpr(try_expr) => vec![&try_expr.expr.kind],
+        NodeKind::IndexingExpr(indexing_expr) => {
+          vec![
+            &indexing_expr.target_expr.kind,
+            &indexing_expr.index_expr.kind,
+          ]
+        }
+        NodeKind::SliceExpr(slice_expr) => vec![
+          &slice_expr.target_expr.kind,
+          &slice_expr.start_expr.kind,
+          &slice_expr.end_expr.kind,
+        ],
+        NodeKind::InterpolationExpr(interpolation_expr) => {
+          map_children(&interpolation_expr.holes).collect()
+        }
+        NodeKind::OptionalValue(optional_value) => optional_value
+          .value
+          .iter()
+          .map(|value| &value.kind)
+          .collect(),
+        NodeKind::ResultValue(result_value) => vec![&result_value.value.kind],
         // TODO: Implement all other nodes with visitable children.
         // REVIEW: Not all nodes can be processed like this: What about prototype, externs, and functions?
         _ => vec![],
@@ -441,22 +660,10 @@ impl NodeKind {
     !self.any(|node| !predicate(node))
   }
 
-  pub fn is_constant_expr(&self) -> bool {
-    let is_const_node = |node: &NodeKind| {
-      if let NodeKind::BindingStmt(binding_stmt) = node {
-        return binding_stmt.modifier == BindingModifier::ConstExpr;
-      }
-
-      matches!(
-        node,
-        NodeKind::Literal(_)
-          | NodeKind::ParenthesesExpr(_)
-          | NodeKind::UnaryExpr(_)
-          | NodeKind::SizeofIntrinsic(_)
-      )
-    };
-
-    self.all(is_const_node)
+  /// Determine whether this node is a constant expression, whose value
+  /// is known during type-checking (see `TypeContext::evaluate_constant`).
+  pub fn is_constant_expr(&self, cache: &cache::Cache) -> bool {
+    type_system::TypeContext::evaluate_constant(self, cache).is_some()
   }
 
   pub fn flatten<'a>(&'a self) -> &'a NodeKind {
@@ -512,12 +719,29 @@ pub struct Using {
   pub module_name: String,
 }
 
+#[derive(PartialEq, Clone, Debug)]
+pub enum CaptureMode {
+  /// The captured binding's value is copied into the closure's environment.
+  ByValue,
+  /// A pointer to the captured binding is stored in the closure's environment.
+  ByReference,
+}
+
+#[derive(Debug, Clone)]
+pub struct Capture {
+  pub name: String,
+  pub target_id: Option<cache::Id>,
+  pub mode: CaptureMode,
+}
+
 #[derive(Debug, Clone)]
 pub struct Closure {
-  pub captures: Vec<(String, Option<cache::Id>)>,
+  pub captures: Vec<Capture>,
   pub prototype: Prototype,
   pub body: BlockExpr,
   pub id: cache::Id,
+  /// The position in the source file, used for diagnostics.
+  pub position: usize,
 }
 
 #[derive(PartialEq, Clone, Debug)]
@@ -588,9 +812,33 @@ pub struct ContinueStmt;
 
 #[derive(Debug, Clone)]
 pub struct IndexingExpr {
-  pub name: String,
+  pub target_expr: Box<Node>,
   pub index_expr: Box<Node>,
-  pub target_id: Option<cache::Id>,
+  /// The position in the source file, reported if a bounds check fails.
+  pub position: usize,
+}
+
+/// Takes a slice of a static array or slice (ex. `array[1..3]`). The
+/// end is exclusive.
+#[derive(Debug, Clone)]
+pub struct SliceExpr {
+  pub target_expr: Box<Node>,
+  pub start_expr: Box<Node>,
+  pub end_expr: Box<Node>,
+  /// The position in the source file, reported if a bounds check fails.
+  pub position: usize,
+}
+
+/// A string literal with embedded expressions (ex. `"hello {name}"`),
+/// which evaluates to an owned string.
+#[derive(Debug, Clone)]
+pub struct InterpolationExpr {
+  /// The text surrounding each hole, with brace escapes resolved. There
+  /// is always one more piece than there are holes.
+  pub pieces: Vec<String>,
+  pub holes: Vec<Node>,
+  /// The position in the source file, reported if formatting fails.
+  pub position: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -603,9 +851,44 @@ pub struct StaticArrayValue {
 #[derive(Debug, Clone)]
 pub struct UnsafeExpr(pub Box<Node>);
 
+/// Defers the evaluation of its body until the enclosing block is exited,
+/// whether by falling through, or by `return`, `break` or `continue`.
+///
+/// Deferred bodies run in reverse order of declaration, interleaved
+/// with the destructors of the enclosing block's bindings.
+#[derive(Debug, Clone)]
+pub struct DeferStmt {
+  pub body: Box<Node>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OptionalValue {
+  /// The wrapped value, or `None` if this represents the `none` value.
+  pub value: Option<Box<Node>>,
+  /// Holds the inner type of the optional, in case it is a `none` value.
+  pub explicit_type: Option<Type>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ResultValue {
+  /// The wrapped value if this is an `ok` value, otherwise the error.
+  pub value: Box<Node>,
+  pub is_ok: bool,
+  /// The type of the side that isn't held. For `ok` values, that is the
+  /// error type, and for `err` values, that is the value type.
+  pub explicit_type: Type,
+}
+
+#[derive(Debug, Clone)]
+pub struct TryExpr {
+  pub expr: Box<Node>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Reference {
   pub pattern: Pattern,
+  /// The position in the source file, used for diagnostics.
+  pub position: usize,
   // REVIEW: Why not have the reference have a `Rc<>` to the target? This would remove dependence
   // ... on the cache, and would be filled during name resolution. We should note that the cache
   // ... isn't available during the `resolve()` name resolution step (that's not a such a big problem,
@@ -617,17 +900,14 @@ pub struct Reference {
 pub struct AssignStmt {
   pub assignee_expr: Box<Node>,
   pub value: Box<Node>,
+  /// The position of the `=` sign in the source file, used for diagnostics.
+  pub position: usize,
 }
 
-#[derive(Debug, Clone)]
-pub struct SizeofIntrinsic {
-  pub ty: Type,
-}
-
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Literal {
   Bool(bool),
-  Int(u64, IntSize),
+  Int(u128, IntSize),
   Char(char),
   String(String),
   Nullptr(Type),
@@ -640,6 +920,9 @@ pub struct Prototype {
   pub is_variadic: bool,
   pub is_extern: bool,
   pub accepts_instance: bool,
+  /// Whether the instance parameter was declared as `mut this`, which
+  /// allows the method to mutate the instance.
+  pub is_instance_mutable: bool,
   pub instance_type_id: Option<cache::Id>,
   pub this_parameter: Option<Parameter>,
 }
@@ -656,6 +939,8 @@ pub struct ExternFunction {
   pub prototype: Prototype,
   pub attributes: Vec<Attribute>,
   pub cache_id: cache::Id,
+  /// The position of the name in the source file, used for diagnostics.
+  pub position: usize,
 }
 
 impl visitor::Visitable for ExternFunction {
@@ -693,6 +978,8 @@ pub struct Function {
   pub attributes: Vec<Attribute>,
   pub cache_id: cache::Id,
   pub generics: Option<Generics>,
+  /// The position of the name in the source file, used for diagnostics.
+  pub position: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -724,6 +1011,8 @@ pub struct BindingStmt {
   pub modifier: BindingModifier,
   pub cache_id: cache::Id,
   pub ty: Type,
+  /// The position of the name in the source file, used for diagnostics.
+  pub position: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -740,6 +1029,18 @@ pub struct LoopStmt {
   pub body: BlockExpr,
 }
 
+/// Iterates over the elements of a static array or a slice, binding
+/// each of them (by value) in turn.
+#[derive(Debug, Clone)]
+pub struct ForInStmt {
+  pub element: Parameter,
+  pub iterable: Box<Node>,
+  pub body: BlockExpr,
+  /// The position of the element's name in the source file, used for
+  /// diagnostics.
+  pub position: usize,
+}
+
 #[derive(Debug, Clone)]
 pub struct InlineExprStmt {
   pub expr: Box<Node>,
@@ -751,15 +1052,179 @@ pub struct CallExpr {
   pub arguments: Vec<Node>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum IntrinsicKind {
   LengthOf,
+  Panic,
+  Print,
+  Println,
+  /// The ABI size of a type in bytes.
+  Sizeof,
+  /// The ABI alignment of a type in bytes.
+  Alignof,
+  /// The offset of a struct field in bytes.
+  Offsetof,
+  TypeName,
+  Assert,
+  Unreachable,
+  /// An assertion verified during type-checking.
+  StaticAssert,
+  /// Allocate a single value on the heap.
+  Alloc,
+  /// Allocate a contiguous block of values on the heap.
+  AllocArray,
+  Free,
+  /// Reallocate a block of values, preserving its contents.
+  Resize,
+  /// Copy a block of values, which must not overlap.
+  Copy,
+  /// Copy a block of values, which may overlap.
+  Move,
+  /// Create an owned string from a null-terminated `Str`.
+  ToString,
+  /// Borrow the null-terminated buffer of an owned string as a `Str`.
+  ToStr,
+  /// Append a string to an owned string in place, growing it if needed.
+  Append,
+  FreeString,
+}
+
+/// The registry of intrinsics, by their name as written after
+/// the `?` sigil.
+pub const INTRINSICS: [(&str, IntrinsicKind); 21] = [
+  ("length_of", IntrinsicKind::LengthOf),
+  ("panic", IntrinsicKind::Panic),
+  ("print", IntrinsicKind::Print),
+  ("println", IntrinsicKind::Println),
+  ("sizeof", IntrinsicKind::Sizeof),
+  ("alignof", IntrinsicKind::Alignof),
+  ("offsetof", IntrinsicKind::Offsetof),
+  ("type_name", IntrinsicKind::TypeName),
+  ("assert", IntrinsicKind::Assert),
+  ("unreachable", IntrinsicKind::Unreachable),
+  ("static_assert", IntrinsicKind::StaticAssert),
+  ("alloc", IntrinsicKind::Alloc),
+  ("alloc_array", IntrinsicKind::AllocArray),
+  ("free", IntrinsicKind::Free),
+  ("resize", IntrinsicKind::Resize),
+  ("copy", IntrinsicKind::Copy),
+  ("move", IntrinsicKind::Move),
+  ("to_string", IntrinsicKind::ToString),
+  ("to_str", IntrinsicKind::ToStr),
+  ("append", IntrinsicKind::Append),
+  ("free_string", IntrinsicKind::FreeString),
+];
+
+/// Describes how an intrinsic is called, and the types it
+/// accepts and yields.
+pub struct IntrinsicSignature {
+  /// The amount of types given within brackets (ex. `?sizeof[T]`).
+  pub type_argument_count: usize,
+  /// Whether a field name follows the types within brackets
+  /// (ex. `?offsetof[T, field]`).
+  pub takes_field_name: bool,
+  /// The types of the values given within parentheses, or `None` if
+  /// the intrinsic isn't called with parentheses (ex. `?sizeof[T]`).
+  pub parameter_types: Option<Vec<Type>>,
+  /// Whether values of any type may follow the parameters.
+  pub is_variadic: bool,
+  pub return_type: Type,
+}
+
+impl IntrinsicKind {
+  pub fn from_name(name: &str) -> Option<IntrinsicKind> {
+    INTRINSICS
+      .iter()
+      .find(|intrinsic| intrinsic.0 == name)
+      .map(|intrinsic| intrinsic.1)
+  }
+
+  pub fn name(&self) -> &'static str {
+    INTRINSICS
+      .iter()
+      .find(|intrinsic| intrinsic.1 == *self)
+      .unwrap()
+      .0
+  }
+
+  /// Determine the signature of the intrinsic, given the types within
+  /// its brackets. Those may be omitted (ex. during parsing), in which case
+  /// the any type takes their place.
+  pub fn signature(&self, type_arguments: &[Type]) -> IntrinsicSignature {
+    let usize_type = Type::Basic(BasicType::Int(IntSize::Usize));
+    let bool_type = Type::Basic(BasicType::Bool);
+    let string_type = Type::Basic(BasicType::String);
+    let owned_string_type = Type::Basic(BasicType::OwnedString);
+
+    let pointer_type = Type::Pointer(Box::new(
+      type_arguments.first().cloned().unwrap_or(Type::Any),
+    ));
+
+    let (type_argument_count, parameter_types, return_type) = match self {
+      // Cannot define array type directly. Use the any type for comparison.
+      IntrinsicKind::LengthOf => (
+        0,
+        Some(vec![Type::Any]),
+        Type::Basic(BasicType::Int(IntSize::I32)),
+      ),
+      IntrinsicKind::Panic => (0, Some(vec![string_type]), Type::Never),
+      // The values to format follow the format string, which is either a
+      // string literal or an interpolated string (verified during checking).
+      IntrinsicKind::Print | IntrinsicKind::Println => (0, Some(vec![Type::Any]), Type::Unit),
+      IntrinsicKind::Sizeof | IntrinsicKind::Alignof | IntrinsicKind::Offsetof => {
+        (1, None, usize_type)
+      }
+      IntrinsicKind::TypeName => (1, None, string_type),
+      IntrinsicKind::Assert | IntrinsicKind::StaticAssert => {
+        (0, Some(vec![bool_type, string_type]), Type::Unit)
+      }
+      IntrinsicKind::Unreachable => (0, Some(Vec::new()), Type::Never),
+      IntrinsicKind::Alloc => (1, Some(Vec::new()), pointer_type),
+      IntrinsicKind::AllocArray => (1, Some(vec![usize_type]), pointer_type),
+      IntrinsicKind::Free => (1, Some(vec![pointer_type]), Type::Unit),
+      IntrinsicKind::Resize => (
+        1,
+        Some(vec![pointer_type.clone(), usize_type]),
+        pointer_type,
+      ),
+      IntrinsicKind::Copy | IntrinsicKind::Move => (
+        1,
+        Some(vec![pointer_type.clone(), pointer_type, usize_type]),
+        Type::Unit,
+      ),
+      IntrinsicKind::ToString => (0, Some(vec![string_type]), owned_string_type),
+      IntrinsicKind::ToStr => (0, Some(vec![owned_string_type]), string_type),
+      IntrinsicKind::Append => (
+        0,
+        Some(vec![
+          Type::MutableReference(Box::new(owned_string_type.clone())),
+          owned_string_type,
+        ]),
+        Type::Unit,
+      ),
+      IntrinsicKind::FreeString => (0, Some(vec![owned_string_type]), Type::Unit),
+    };
+
+    IntrinsicSignature {
+      type_argument_count,
+      takes_field_name: matches!(self, IntrinsicKind::Offsetof),
+      parameter_types,
+      is_variadic: matches!(self, IntrinsicKind::Print | IntrinsicKind::Println),
+      return_type,
+    }
+  }
 }
 
 #[derive(Debug, Clone)]
 pub struct IntrinsicCall {
   pub kind: IntrinsicKind,
+  /// The types given within brackets (ex. `?sizeof[T]`).
+  pub type_arguments: Vec<Type>,
+  /// The field name given within brackets (ex. `?offsetof[T, field]`).
+  pub field_name: Option<String>,
   pub arguments: Vec<Node>,
+  /// The position in the source file, used for runtime diagnostics.
+  pub position: usize,
 }
 
 #[derive(PartialEq, Clone, Debug)]
@@ -767,6 +1232,8 @@ pub struct StructType {
   pub cache_id: cache::Id,
   pub name: String,
   pub fields: Vec<(String, Type)>,
+  /// The default values of the fields, in the same order as the fields.
+  pub field_defaults: Vec<Option<Literal>>,
 }
 
 #[derive(Debug, Clone)]
@@ -785,10 +1252,12 @@ pub enum OperatorKind {
   Xor,
   Not,
   AddressOf,
+  MutableAddressOf,
   Add,
   SubtractOrNegate,
   MultiplyOrDereference,
   Divide,
+  Remainder,
   LessThan,
   GreaterThan,
   LessThanOrEqual,
@@ -813,6 +1282,8 @@ pub struct UnaryExpr {
   ///
   /// Only available when the unary expression is a cast.
   pub cast_type: Option<Type>,
+  /// The position of the operator in the source file, used for diagnostics.
+  pub position: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -861,4 +1332,58 @@ mod tests {
 
     assert!(matches!(search_result, Some(NodeKind::BreakStmt(_))));
   }
+
+  #[test]
+  fn function_type_compatibility() {
+    let function_type = FunctionType {
+      parameter_types: vec![Type::Basic(BasicType::Bool)],
+      return_type: Box::new(Type::Unit),
+      is_variadic: false,
+      is_extern: false,
+    };
+
+    let callback_type = FunctionType {
+      is_extern: true,
+      ..function_type.clone()
+    };
+
+    let other_function_type = FunctionType {
+      parameter_types: Vec::new(),
+      ..function_type.clone()
+    };
+
+    // Closures and callbacks only differ in representation.
+    assert!(Type::Function(function_type.clone()).is(&Type::Function(callback_type)));
+    assert!(!Type::Function(function_type).is(&Type::Function(other_function_type)));
+  }
+
+  #[test]
+  fn slice_coercion() {
+    let int_type = Type::Basic(BasicType::Int(IntSize::I32));
+    let slice_type = Type::Slice(Box::new(int_type.clone()));
+    let array_type = Type::Array(Box::new(int_type), 3);
+
+    assert!(slice_type.is(&array_type));
+    assert!(slice_type.is(&slice_type));
+    assert!(!array_type.is(&slice_type));
+  }
+
+  #[test]
+  fn int_size_fits() {
+    assert!(IntSize::U8.fits(255, false, 64));
+    assert!(!IntSize::U8.fits(256, false, 64));
+    assert!(!IntSize::U8.fits(1, true, 64));
+    assert!(IntSize::I8.fits(128, true, 64));
+    assert!(!IntSize::I8.fits(128, false, 64));
+    assert!(IntSize::U64.fits(u64::MAX as u128, false, 64));
+    assert!(IntSize::U128.fits(u128::MAX, false, 64));
+    assert!(!IntSize::U128.fits(1, true, 64));
+    assert!(IntSize::I128.fits(1 << 127, true, 64));
+    assert!(!IntSize::I128.fits(1 << 127, false, 64));
+    assert!(IntSize::I128.fits(i128::MAX as u128, false, 64));
+    assert!(IntSize::Usize.fits(u32::MAX as u128 + 1, false, 64));
+    assert!(!IntSize::Usize.fits(u32::MAX as u128 + 1, false, 32));
+    assert!(IntSize::Isize.fits(1 << 31, true, 32));
+    assert!(!IntSize::Isize.fits(1 << 31, false, 32));
+  }
 }
@@ -4,6 +4,12 @@ pub type Id = usize;
 
 pub struct Cache {
   pub struct_impls: std::collections::HashMap<Id, Vec<(Id, String)>>,
+  /// The ids of the struct types that implement the built-in `Copy` marker
+  /// trait, whose values are therefore copied instead of moved.
+  pub copy_types: std::collections::HashSet<Id>,
+  /// The destructors of the struct types that implement the built-in `Drop`
+  /// trait, keyed by the struct type's id.
+  pub drop_methods: std::collections::HashMap<Id, Id>,
   // TODO: Update description with the generalization to allow for closure retrieval.
   /// A map of unique ids to their corresponding `NodeKind` construct.
   ///
@@ -13,6 +19,12 @@ pub struct Cache {
   pub symbols: std::collections::HashMap<Id, ast::NodeKind>,
   // REVIEW: Should this be here?
   pub main_function_id: Option<Id>,
+  /// The bit-width of pointers on the compilation target, which is also
+  /// the bit-width of the `Isize` and `Usize` integer types.
+  ///
+  /// Defaults to 64 bits, matching LLVM's default data layout. It is only
+  /// changed alongside the target (see `LlvmGenerator::set_target_triple`).
+  pub pointer_width: u32,
   id_counter: usize,
   _types: std::collections::HashMap<Id, ast::Type>,
 }
@@ -21,8 +33,11 @@ impl Cache {
   pub fn new() -> Self {
     Self {
       struct_impls: std::collections::HashMap::new(),
+      copy_types: std::collections::HashSet::new(),
+      drop_methods: std::collections::HashMap::new(),
       symbols: std::collections::HashMap::new(),
       main_function_id: None,
+      pointer_width: 64,
       id_counter: 0,
       _types: std::collections::HashMap::new(),
     }
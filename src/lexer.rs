@@ -11,7 +11,7 @@ pub enum TokenKind {
   Whitespace(char),
   Comment(String),
   String(String),
-  Int(u64),
+  Int(u128),
   Bool(bool),
   Char(char),
   Nullptr,
@@ -39,18 +39,25 @@ pub enum TokenKind {
   Impl,
   For,
   Trait,
+  Defer,
   TypeInt8,
   TypeInt16,
   TypeInt32,
   TypeInt64,
+  TypeInt128,
   TypeUint8,
   TypeUint16,
   TypeUint32,
   TypeUint64,
+  TypeUint128,
+  TypeIsize,
+  TypeUsize,
   TypeBool,
   TypeString,
+  TypeOwnedString,
   TypeThis,
   TypeUnit,
+  TypeResult,
   BraceL,
   BraceR,
   ParenthesesL,
@@ -63,6 +70,7 @@ pub enum TokenKind {
   Minus,
   Asterisk,
   Slash,
+  Percent,
   Bang,
   Equal,
   LessThan,
@@ -82,7 +90,11 @@ pub enum TokenKind {
   Using,
   DoubleColon,
   QuestionMark,
+  /// A question mark that immediately follows an expression, such
+  /// as in `foo()?`.
+  PostfixQuestionMark,
   Sizeof,
+  Alignof,
   Pipe,
   Const,
   Pass,
@@ -91,6 +103,10 @@ pub enum TokenKind {
   Elif,
   In,
   Indexof,
+  Some,
+  None,
+  Ok,
+  Err,
 }
 
 pub struct Lexer {
@@ -104,6 +120,8 @@ pub struct Lexer {
   seen_only_whitespace_this_line: bool,
   indent_level: usize,
   indent_counter: usize,
+  /// The last token that was lexed, if any.
+  previous_token: Option<TokenKind>,
 }
 
 impl Lexer {
@@ -121,6 +139,7 @@ impl Lexer {
       seen_only_whitespace_this_line: true,
       indent_level: 0,
       indent_counter: 0,
+      previous_token: None,
     }
   }
 
@@ -199,8 +218,8 @@ impl Lexer {
     })
   }
 
-  fn read_number(&mut self) -> Result<u64, codespan_reporting::diagnostic::Diagnostic<usize>> {
-    let number_result = self.read_while(is_digit).parse::<u64>();
+  fn read_number(&mut self) -> Result<u128, codespan_reporting::diagnostic::Diagnostic<usize>> {
+    let number_result = self.read_while(is_digit).parse::<u128>();
 
     if let Err(_) = number_result {
       return Err(
@@ -291,6 +310,25 @@ impl Lexer {
     Some(self.input[self.index + 1])
   }
 
+  /// Determine whether the previous token may end an expression, in which
+  /// case a question mark at the current position is a postfix operator.
+  ///
+  /// Whitespace in-between breaks the expression, as in `a ?b`.
+  fn is_postfix_position(&self) -> bool {
+    matches!(
+      self.previous_token,
+      Some(TokenKind::Identifier(_))
+        | Some(TokenKind::String(_))
+        | Some(TokenKind::Int(_))
+        | Some(TokenKind::Char(_))
+        | Some(TokenKind::Bool(_))
+        | Some(TokenKind::ParenthesesR)
+        | Some(TokenKind::BracketR)
+        | Some(TokenKind::BraceR)
+        | Some(TokenKind::PostfixQuestionMark)
+    )
+  }
+
   fn is_indent(&self) -> bool {
     self.seen_only_whitespace_this_line
       && (self.current_char == Some(' ') && self.peek_char() == Some(' '))
@@ -303,6 +341,16 @@ impl Lexer {
   /// digit, an [`Illegal`] token with the encountered character as its
   /// value will be returned.
   fn lex_token(&mut self) -> Result<TokenKind, codespan_reporting::diagnostic::Diagnostic<usize>> {
+    let token = self.lex_next_token()?;
+
+    self.previous_token = Some(token.clone());
+
+    Ok(token)
+  }
+
+  fn lex_next_token(
+    &mut self,
+  ) -> Result<TokenKind, codespan_reporting::diagnostic::Diagnostic<usize>> {
     if self.is_eof() {
       if self.indent_level > 0 {
         self.indent_level -= 1;
@@ -389,6 +437,7 @@ impl Lexer {
       '-' => TokenKind::Minus,
       '*' => TokenKind::Asterisk,
       '/' => TokenKind::Slash,
+      '%' => TokenKind::Percent,
       '!' => TokenKind::Bang,
       '=' if self.peek_char() == Some('=') => {
         self.read_char();
@@ -429,6 +478,7 @@ impl Lexer {
       '.' => TokenKind::Dot,
       '@' => TokenKind::At,
       '`' => TokenKind::Backtick,
+      '?' if self.is_postfix_position() => TokenKind::PostfixQuestionMark,
       '?' => TokenKind::QuestionMark,
       _ => {
         // NOTE: Identifiers will never start with a digit.
@@ -501,23 +551,31 @@ fn match_identifier(identifier: &str) -> Option<TokenKind> {
     "impl" => TokenKind::Impl,
     "for" => TokenKind::For,
     "trait" => TokenKind::Trait,
+    "defer" => TokenKind::Defer,
     "nullptr" => TokenKind::Nullptr,
     "I8" => TokenKind::TypeInt8,
     "I16" => TokenKind::TypeInt16,
     "Int" => TokenKind::TypeInt32,
     "I64" => TokenKind::TypeInt64,
+    "I128" => TokenKind::TypeInt128,
     "U8" => TokenKind::TypeUint8,
     "U16" => TokenKind::TypeUint16,
     "U32" => TokenKind::TypeUint32,
     "U64" => TokenKind::TypeUint64,
+    "U128" => TokenKind::TypeUint128,
+    "Isize" => TokenKind::TypeIsize,
+    "Usize" => TokenKind::TypeUsize,
     "Bool" => TokenKind::TypeBool,
     "Str" => TokenKind::TypeString,
+    "String" => TokenKind::TypeOwnedString,
     "This" => TokenKind::TypeThis,
     "Unit" => TokenKind::TypeUnit,
+    "Result" => TokenKind::TypeResult,
     "true" => TokenKind::Bool(true),
     "false" => TokenKind::Bool(false),
     "using" => TokenKind::Using,
     "sizeof" => TokenKind::Sizeof,
+    "alignof" => TokenKind::Alignof,
     "const" => TokenKind::Const,
     "var" => TokenKind::Var,
     "pass" => TokenKind::Pass,
@@ -525,6 +583,10 @@ fn match_identifier(identifier: &str) -> Option<TokenKind> {
     "elif" => TokenKind::Elif,
     "in" => TokenKind::In,
     "indexof" => TokenKind::Indexof,
+    "some" => TokenKind::Some,
+    "none" => TokenKind::None,
+    "ok" => TokenKind::Ok,
+    "err" => TokenKind::Err,
     _ => return None,
   })
 }
@@ -817,6 +879,48 @@ mod tests {
     assert_eq!(7, tokens_result.unwrap().len());
   }
 
+  #[test]
+  fn lex_postfix_question_mark() {
+    let mut lexer = Lexer::from_str("a? ?sizeof");
+
+    assert_eq!(
+      Ok(TokenKind::Identifier(String::from("a"))),
+      lexer.lex_token()
+    );
+
+    assert_eq!(Ok(TokenKind::PostfixQuestionMark), lexer.lex_token());
+    assert_eq!(Ok(TokenKind::Whitespace(' ')), lexer.lex_token());
+    assert_eq!(Ok(TokenKind::QuestionMark), lexer.lex_token());
+  }
+
+  #[test]
+  fn lex_postfix_question_mark_after_closing_tokens() {
+    let mut lexer = Lexer::from_str("(a)? ( ?");
+
+    assert_eq!(Ok(TokenKind::ParenthesesL), lexer.lex_token());
+
+    assert_eq!(
+      Ok(TokenKind::Identifier(String::from("a"))),
+      lexer.lex_token()
+    );
+
+    assert_eq!(Ok(TokenKind::ParenthesesR), lexer.lex_token());
+    assert_eq!(Ok(TokenKind::PostfixQuestionMark), lexer.lex_token());
+    assert_eq!(Ok(TokenKind::Whitespace(' ')), lexer.lex_token());
+    assert_eq!(Ok(TokenKind::ParenthesesL), lexer.lex_token());
+    assert_eq!(Ok(TokenKind::Whitespace(' ')), lexer.lex_token());
+    assert_eq!(Ok(TokenKind::QuestionMark), lexer.lex_token());
+  }
+
+  #[test]
+  fn lex_unimplemented_question_marks() {
+    let mut lexer = Lexer::from_str("???");
+
+    assert_eq!(Ok(TokenKind::QuestionMark), lexer.lex_token());
+    assert_eq!(Ok(TokenKind::QuestionMark), lexer.lex_token());
+    assert_eq!(Ok(TokenKind::QuestionMark), lexer.lex_token());
+  }
+
   // BUG: Need test to catch bug with comments messing up indentation.
 
   // TODO: Add tests for number-overflow cases.
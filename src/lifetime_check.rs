@@ -1,48 +1,2031 @@
-struct _BorrowCheckContext {
-  depth: usize,
-}
-
-impl _BorrowCheckContext {
-  fn _new() -> Self {
-    Self { depth: 0 }
-  }
-
-  // TODO: If we can't save a reference or some kind of identifier to a let-statement
-  // ... (to define its lifetime), consider adding an `Option<LifeTimeStruct>` field
-  // ... to the let-statement, just for this purpose.
-  // fn declare(&self, let_stmt: &ast::NodeKind::LetStmt) {
-  //   // TODO:
-  // }
-
-  // fn lifetime_check(&mut self, root_block: &ast::Block) {
-  //   // Reset the depth counter per-block.
-  //   self.depth = 0;
-
-  //   // TODO: Figure out how to determine depth via a linear manner? If not possible, opt for a different design/approach.
-
-  //   let block_queue = vec![root_block];
-
-  //   while let Some(block) = block_queue.pop() {
-  //     match statement {
-  //       ast::Node::LetStmt(let_stmt) => self.declare(let_stmt),
-  //       // TODO: Continue implementation.
-  //       _ => continue,
-  //     };
-  //   }
-  // }
-}
-
-// trait LifetimeCheck {
-//   fn lifetime(&self) {
-//     // TODO: Isn't this actually recursive for default implementations? Investigate.
-//     crate::dispatch!(self, BorrowCheck::lifetime);
-//   }
-
-//   fn lifetime_check(&self) {
-//     crate::dispatch!(self, BorrowCheck::borrow_check);
-//   }
-// }
-
-// impl BorrowCheck for ast::AssignStmt {
-//   fn lifetime_check(&self) {}
-// }
+use crate::{ast, cache};
+
+/// A reference taken to a place rooted at a binding or parameter of the
+/// function being checked.
+#[derive(Clone, Debug)]
+struct Borrow {
+  /// The binding or parameter whose value (or a part of it) is borrowed.
+  referent_id: cache::Id,
+  is_mutable: bool,
+  /// The binding that holds the reference, or `None` while the reference
+  /// is a temporary (ex. a call argument), which only lives until the end
+  /// of its statement.
+  holder_id: Option<cache::Id>,
+  /// The borrow expression as written in source code (ex. `&mut a.b`).
+  expr: String,
+  /// The position of the borrow expression in the source file, if known.
+  position: Option<usize>,
+}
+
+/// Where the value of a binding or parameter was moved to.
+#[derive(Clone, Debug)]
+struct Move {
+  /// A description of where the value was moved to (ex. `moved into `a``).
+  site: String,
+  /// The position of the moved place in the source file, if known.
+  position: Option<usize>,
+}
+
+/// The bindings and parameters whose values have been moved out, mapped to
+/// where they were moved to.
+type Moves = std::collections::HashMap<cache::Id, Move>;
+
+/// The moves along the control flow paths that leave a loop's body early.
+#[derive(Default)]
+struct LoopExits {
+  continues: Vec<Moves>,
+  breaks: Vec<Moves>,
+}
+
+pub struct LifetimeCheckContext {
+  pub diagnostics: Vec<codespan_reporting::diagnostic::Diagnostic<usize>>,
+  /// The file that the positions of diagnostic labels refer to.
+  pub file_id: usize,
+  /// The scope depth at which each binding or parameter of the current
+  /// function was declared. Bindings are removed once they go out of scope.
+  binding_depths: std::collections::HashMap<cache::Id, usize>,
+  /// The borrows that are currently alive.
+  borrows: Vec<Borrow>,
+  /// The values moved out along the current control flow path.
+  moves: Moves,
+  /// Whether the current control flow path has diverged (ex. after a
+  /// `return`), making the code that follows it unreachable.
+  diverged: bool,
+  loop_exits: Vec<LoopExits>,
+  /// The closures whose environments live on the stack of the function
+  /// that creates them. They are tracked as referents of depth one, so
+  /// that such closures may not be returned.
+  closure_environments: std::collections::HashSet<cache::Id>,
+  /// The elements of `for` loops. They are copied out of the iterable,
+  /// which keeps ownership of them.
+  loop_elements: std::collections::HashSet<cache::Id>,
+  /// The return type of the function being checked, which returned values
+  /// are coerced into.
+  return_type: ast::Type,
+  depth: usize,
+}
+
+impl LifetimeCheckContext {
+  /// Check the lifetimes of all references in the given AST, which must
+  /// have already been type-checked.
+  pub fn run(
+    ast: &[ast::Node],
+    cache: &cache::Cache,
+  ) -> Vec<codespan_reporting::diagnostic::Diagnostic<usize>> {
+    let mut context = LifetimeCheckContext::new();
+
+    for node in ast {
+      node.kind.lifetime_check(&mut context, cache);
+    }
+
+    context.diagnostics
+  }
+
+  pub fn new() -> Self {
+    Self {
+      diagnostics: Vec::new(),
+      file_id: 0,
+      binding_depths: std::collections::HashMap::new(),
+      borrows: Vec::new(),
+      moves: Moves::new(),
+      diverged: false,
+      loop_exits: Vec::new(),
+      closure_environments: std::collections::HashSet::new(),
+      loop_elements: std::collections::HashSet::new(),
+      return_type: ast::Type::Unit,
+      depth: 0,
+    }
+  }
+
+  fn referent_name(referent_id: &cache::Id, cache: &cache::Cache) -> String {
+    match cache.force_get(referent_id) {
+      ast::NodeKind::BindingStmt(binding_stmt) => binding_stmt.name.clone(),
+      ast::NodeKind::Parameter(parameter) => parameter.name.clone(),
+      _ => unreachable!(),
+    }
+  }
+
+  fn referent_type(referent_id: &cache::Id, cache: &cache::Cache) -> ast::Type {
+    match cache.force_get(referent_id) {
+      ast::NodeKind::BindingStmt(binding_stmt) => binding_stmt.ty.flatten(cache),
+      ast::NodeKind::Parameter(parameter) => parameter.ty.flatten(cache),
+      _ => unreachable!(),
+    }
+  }
+
+  /// Describe a place expression as written in source code.
+  fn describe_place(node: &ast::NodeKind) -> String {
+    match node {
+      ast::NodeKind::Reference(reference) => reference.pattern.base_name.clone(),
+      ast::NodeKind::MemberAccess(member_access) => format!(
+        "{}.{}",
+        LifetimeCheckContext::describe_place(&member_access.base_expr.kind),
+        member_access.member_name
+      ),
+      ast::NodeKind::IndexingExpr(indexing_expr) => format!(
+        "{}[..]",
+        LifetimeCheckContext::describe_place(&indexing_expr.target_expr.kind)
+      ),
+      ast::NodeKind::UnaryExpr(unary_expr) => {
+        format!(
+          "*{}",
+          LifetimeCheckContext::describe_place(&unary_expr.expr.kind)
+        )
+      }
+      ast::NodeKind::ParenthesesExpr(parentheses_expr) => {
+        LifetimeCheckContext::describe_place(&parentheses_expr.expr.kind)
+      }
+      _ => String::from("_"),
+    }
+  }
+
+  /// Determine the local binding or parameter that owns the place an
+  /// expression refers to.
+  ///
+  /// Places reached through pointers or references are owned elsewhere,
+  /// and therefore outlive the current function, so they yield `None`.
+  fn place_root(&self, node: &ast::NodeKind, cache: &cache::Cache) -> Option<cache::Id> {
+    match node.flatten() {
+      ast::NodeKind::Reference(reference) => {
+        let target_id = reference.pattern.target_id.unwrap();
+
+        if self.binding_depths.contains_key(&target_id) {
+          Some(target_id)
+        } else {
+          None
+        }
+      }
+      ast::NodeKind::MemberAccess(member_access) => {
+        if LifetimeCheckContext::is_indirect(member_access.base_expr.kind.infer_flatten_type(cache))
+        {
+          None
+        } else {
+          self.place_root(&member_access.base_expr.kind, cache)
+        }
+      }
+      ast::NodeKind::IndexingExpr(indexing_expr) => {
+        if LifetimeCheckContext::is_indirect(
+          indexing_expr.target_expr.kind.infer_flatten_type(cache),
+        ) {
+          None
+        } else {
+          self.place_root(&indexing_expr.target_expr.kind, cache)
+        }
+      }
+      _ => None,
+    }
+  }
+
+  /// Determine whether a place is reached through a reference, pointer or
+  /// slice, or is the element of a `for` loop, which is borrowed from its
+  /// iterable. Values may not be moved out of such places.
+  fn is_indirect_place(&self, node: &ast::NodeKind, cache: &cache::Cache) -> bool {
+    match node.flatten() {
+      ast::NodeKind::Reference(reference) => reference
+        .pattern
+        .target_id
+        .map_or(false, |target_id| self.loop_elements.contains(&target_id)),
+      ast::NodeKind::MemberAccess(member_access) => {
+        LifetimeCheckContext::is_indirect(member_access.base_expr.kind.infer_flatten_type(cache))
+          || self.is_indirect_place(&member_access.base_expr.kind, cache)
+      }
+      ast::NodeKind::IndexingExpr(indexing_expr) => {
+        LifetimeCheckContext::is_indirect(indexing_expr.target_expr.kind.infer_flatten_type(cache))
+          || self.is_indirect_place(&indexing_expr.target_expr.kind, cache)
+      }
+      ast::NodeKind::UnaryExpr(ast::UnaryExpr {
+        operator: ast::OperatorKind::MultiplyOrDereference,
+        ..
+      }) => true,
+      _ => false,
+    }
+  }
+
+  /// Determine whether values of the given type point to places owned
+  /// elsewhere.
+  fn is_indirect(ty: ast::Type) -> bool {
+    matches!(
+      ty,
+      ast::Type::Pointer(_)
+        | ast::Type::Reference(_)
+        | ast::Type::MutableReference(_)
+        | ast::Type::Slice(_)
+    )
+  }
+
+  /// Determine whether values of the given type may carry borrows.
+  fn is_borrowing(ty: ast::Type) -> bool {
+    matches!(
+      ty,
+      ast::Type::Reference(_) | ast::Type::MutableReference(_) | ast::Type::Slice(_)
+    )
+  }
+
+  /// Determine the borrows carried by the reference (or slice) through which
+  /// an indirect place is reached. Reborrowing such a place inherits them.
+  fn reborrow_origins(&self, place: &ast::NodeKind, cache: &cache::Cache) -> Vec<Borrow> {
+    let (base, is_through_base) = match place.flatten() {
+      ast::NodeKind::MemberAccess(member_access) => (
+        &member_access.base_expr.kind,
+        LifetimeCheckContext::is_indirect(member_access.base_expr.kind.infer_flatten_type(cache)),
+      ),
+      ast::NodeKind::IndexingExpr(indexing_expr) => (
+        &indexing_expr.target_expr.kind,
+        LifetimeCheckContext::is_indirect(indexing_expr.target_expr.kind.infer_flatten_type(cache)),
+      ),
+      ast::NodeKind::UnaryExpr(ast::UnaryExpr {
+        operator: ast::OperatorKind::MultiplyOrDereference,
+        expr,
+        ..
+      }) => (&expr.kind, true),
+      _ => return Vec::new(),
+    };
+
+    if is_through_base {
+      self.borrow_origins(base, cache)
+    } else {
+      self.reborrow_origins(base, cache)
+    }
+  }
+
+  /// Determine the borrows carried by a reference to the given place: a
+  /// borrow of its local root, or otherwise those it inherits as a reborrow.
+  fn place_origins(
+    &self,
+    place: &ast::NodeKind,
+    is_mutable: bool,
+    position: Option<usize>,
+    cache: &cache::Cache,
+  ) -> Vec<Borrow> {
+    match self.borrow_of(place, is_mutable, position, cache) {
+      Some(borrow) => vec![borrow],
+      None => self.reborrow_origins(place, cache),
+    }
+  }
+
+  /// Determine the borrows carried by a value once it is coerced into the
+  /// given type. Static arrays coerced into slices borrow their storage.
+  fn coerced_origins(
+    &self,
+    value: &ast::NodeKind,
+    target_type: &ast::Type,
+    cache: &cache::Cache,
+  ) -> Vec<Borrow> {
+    let is_array_to_slice = matches!(target_type.flatten(cache), ast::Type::Slice(_))
+      && matches!(value.infer_flatten_type(cache), ast::Type::Array(..));
+
+    if !is_array_to_slice {
+      return self.borrow_origins(value, cache);
+    }
+
+    let position = LifetimeCheckContext::place_position(value);
+
+    match self.borrow_of(value, false, position, cache) {
+      Some(borrow) => vec![Borrow {
+        expr: format!("{}[..]", LifetimeCheckContext::describe_place(value)),
+        ..borrow
+      }],
+      None => self.reborrow_origins(value, cache),
+    }
+  }
+
+  /// Create a temporary borrow of the given place, if it is rooted at a
+  /// local binding or parameter.
+  fn borrow_of(
+    &self,
+    place: &ast::NodeKind,
+    is_mutable: bool,
+    position: Option<usize>,
+    cache: &cache::Cache,
+  ) -> Option<Borrow> {
+    self.place_root(place, cache).map(|referent_id| Borrow {
+      referent_id,
+      is_mutable,
+      holder_id: None,
+      expr: format!(
+        "&{}{}",
+        if is_mutable { "mut " } else { "" },
+        LifetimeCheckContext::describe_place(place)
+      ),
+      position,
+    })
+  }
+
+  /// Determine the borrows of local places that the value of an expression
+  /// carries with it. The yielded borrows are always temporaries.
+  fn borrow_origins(&self, node: &ast::NodeKind, cache: &cache::Cache) -> Vec<Borrow> {
+    match node {
+      ast::NodeKind::UnaryExpr(unary_expr) => match unary_expr.operator {
+        ast::OperatorKind::AddressOf => self.place_origins(
+          &unary_expr.expr.kind,
+          false,
+          Some(unary_expr.position),
+          cache,
+        ),
+        ast::OperatorKind::MutableAddressOf => self.place_origins(
+          &unary_expr.expr.kind,
+          true,
+          Some(unary_expr.position),
+          cache,
+        ),
+        // Reading a reference out of another one copies it.
+        ast::OperatorKind::MultiplyOrDereference
+          if LifetimeCheckContext::is_borrowing(node.infer_flatten_type(cache)) =>
+        {
+          self.borrow_origins(&unary_expr.expr.kind, cache)
+        }
+        _ => Vec::new(),
+      },
+      // Slices of static arrays point into the array's storage, while
+      // ... slices of other slices point wherever those do.
+      ast::NodeKind::SliceExpr(slice_expr) => {
+        match slice_expr.target_expr.kind.infer_flatten_type(cache) {
+          ast::Type::Array(..) => {}
+          ast::Type::Slice(_) => return self.borrow_origins(&slice_expr.target_expr.kind, cache),
+          _ => return Vec::new(),
+        }
+
+        self
+          .place_root(&slice_expr.target_expr.kind, cache)
+          .map(|referent_id| {
+            vec![Borrow {
+              referent_id,
+              is_mutable: false,
+              holder_id: None,
+              expr: format!(
+                "{}[..]",
+                LifetimeCheckContext::describe_place(&slice_expr.target_expr.kind)
+              ),
+              position: Some(slice_expr.position),
+            }]
+          })
+          .unwrap_or_default()
+      }
+      // Copying a reference borrows the same places as the original.
+      ast::NodeKind::Reference(reference) => {
+        let target_id = reference.pattern.target_id;
+
+        self
+          .borrows
+          .iter()
+          .filter(|borrow| borrow.holder_id.is_some() && borrow.holder_id == target_id)
+          .map(|borrow| Borrow {
+            holder_id: None,
+            ..borrow.clone()
+          })
+          .collect()
+      }
+      ast::NodeKind::ParenthesesExpr(ast::ParenthesesExpr { expr })
+      | ast::NodeKind::UnsafeExpr(ast::UnsafeExpr(expr)) => self.borrow_origins(&expr.kind, cache),
+      ast::NodeKind::BlockExpr(block_expr) => block_expr
+        .yields
+        .as_ref()
+        .map(|yields| self.borrow_origins(&yields.kind, cache))
+        .unwrap_or_default(),
+      ast::NodeKind::IfExpr(if_expr) => {
+        let mut origins = self.borrow_origins(&if_expr.then_expr.kind, cache);
+
+        for (_, branch) in &if_expr.alternative_branches {
+          origins.extend(self.borrow_origins(&branch.kind, cache));
+        }
+
+        if let Some(else_expr) = &if_expr.else_expr {
+          origins.extend(self.borrow_origins(&else_expr.kind, cache));
+        }
+
+        origins
+      }
+      ast::NodeKind::StructValue(struct_value) => {
+        let field_types = match node.infer_flatten_type(cache) {
+          ast::Type::Struct(struct_type) => struct_type.fields,
+          _ => Vec::new(),
+        };
+
+        struct_value
+          .fields
+          .iter()
+          .enumerate()
+          .flat_map(|(index, field)| match field_types.get(index) {
+            Some((_, field_type)) => self.coerced_origins(&field.kind, field_type, cache),
+            None => self.borrow_origins(&field.kind, cache),
+          })
+          .collect()
+      }
+      // Capturing closures point to their environment.
+      ast::NodeKind::Closure(closure) if self.closure_environments.contains(&closure.id) => {
+        vec![Borrow {
+          referent_id: closure.id,
+          is_mutable: false,
+          holder_id: None,
+          expr: format!(
+            "closure capturing {}",
+            closure
+              .captures
+              .iter()
+              .map(|capture| format!("`{}`", capture.name))
+              .collect::<Vec<_>>()
+              .join(", ")
+          ),
+          position: None,
+        }]
+      }
+      // Without lifetime annotations, references returned by calls are
+      // ... conservatively assumed to borrow from any of their arguments,
+      // ... including the instance of methods.
+      ast::NodeKind::CallExpr(call_expr)
+        if LifetimeCheckContext::is_borrowing(node.infer_flatten_type(cache)) =>
+      {
+        let parameter_types = match call_expr.callee_expr.kind.infer_flatten_type(cache) {
+          ast::Type::Function(callee_type) => callee_type.parameter_types,
+          _ => Vec::new(),
+        };
+
+        // NOTE: Variadic arguments aren't coerced.
+        let mut origins = call_expr
+          .arguments
+          .iter()
+          .enumerate()
+          .flat_map(|(index, argument)| match parameter_types.get(index) {
+            Some(parameter_type) => self.coerced_origins(&argument.kind, parameter_type, cache),
+            None => self.borrow_origins(&argument.kind, cache),
+          })
+          .collect::<Vec<_>>();
+
+        if let ast::NodeKind::MemberAccess(member_access) = &call_expr.callee_expr.kind {
+          let base = &member_access.base_expr.kind;
+
+          if LifetimeCheckContext::is_indirect(base.infer_flatten_type(cache)) {
+            origins.extend(self.borrow_origins(base, cache));
+          } else {
+            origins.extend(self.place_origins(base, false, None, cache));
+          }
+        }
+
+        origins
+      }
+      _ => Vec::new(),
+    }
+  }
+
+  /// Register a new borrow, reporting any live borrow of the same place
+  /// that it conflicts with. Any number of shared borrows may coexist, but
+  /// a mutable borrow must be the only one.
+  fn borrow(&mut self, borrow: Borrow, cache: &cache::Cache) {
+    let conflict = self.borrows.iter().find(|existing| {
+      existing.referent_id == borrow.referent_id && (existing.is_mutable || borrow.is_mutable)
+    });
+
+    if let Some(conflict) = conflict {
+      let name = LifetimeCheckContext::referent_name(&borrow.referent_id, cache);
+      let kind_name = |is_mutable| if is_mutable { "mutable" } else { "shared" };
+
+      let message = if conflict.is_mutable && borrow.is_mutable {
+        format!(
+          "cannot borrow `{}` as mutable more than once at a time",
+          name
+        )
+      } else {
+        format!(
+          "cannot borrow `{}` as {} because it is also borrowed as {}",
+          name,
+          kind_name(borrow.is_mutable),
+          kind_name(conflict.is_mutable)
+        )
+      };
+
+      let notes = vec![
+        self.describe_borrow(conflict, cache),
+        format!("conflicting borrow: `{}`", borrow.expr),
+      ];
+
+      let labels = self
+        .label_borrow(
+          &borrow,
+          codespan_reporting::diagnostic::LabelStyle::Primary,
+          "conflicting borrow taken here",
+        )
+        .into_iter()
+        .chain(self.label_borrow(
+          conflict,
+          codespan_reporting::diagnostic::LabelStyle::Secondary,
+          "previous borrow taken here",
+        ))
+        .collect();
+
+      self.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message(message)
+          .with_labels(labels)
+          .with_notes(notes),
+      );
+    }
+
+    self.borrows.push(borrow);
+  }
+
+  /// Label a position in the source file. Labels span a single character,
+  /// since only the start of each expression's position is known.
+  fn label(
+    &self,
+    style: codespan_reporting::diagnostic::LabelStyle,
+    position: usize,
+    message: &str,
+  ) -> codespan_reporting::diagnostic::Label<usize> {
+    codespan_reporting::diagnostic::Label::new(style, self.file_id, position..position + 1)
+      .with_message(message)
+  }
+
+  /// Label the borrow expression of a borrow, if its position is known.
+  fn label_borrow(
+    &self,
+    borrow: &Borrow,
+    style: codespan_reporting::diagnostic::LabelStyle,
+    message: &str,
+  ) -> Option<codespan_reporting::diagnostic::Label<usize>> {
+    borrow
+      .position
+      .map(|position| self.label(style, position, message))
+  }
+
+  fn describe_borrow(&self, borrow: &Borrow, cache: &cache::Cache) -> String {
+    match &borrow.holder_id {
+      Some(holder_id) => format!(
+        "`{}` is borrowed here: `{}`, and held by `{}`",
+        LifetimeCheckContext::referent_name(&borrow.referent_id, cache),
+        borrow.expr,
+        LifetimeCheckContext::referent_name(holder_id, cache)
+      ),
+      None => format!(
+        "`{}` is borrowed here: `{}`, until the end of the statement",
+        LifetimeCheckContext::referent_name(&borrow.referent_id, cache),
+        borrow.expr
+      ),
+    }
+  }
+
+  /// End the lifetime of temporary borrows, which only live until the end
+  /// of the statement that created them.
+  fn end_statement(&mut self) {
+    self.borrows.retain(|borrow| borrow.holder_id.is_some());
+  }
+
+  /// Declare a binding or parameter at the current depth, and hand over
+  /// the borrows carried by its value (if any).
+  fn declare(&mut self, cache_id: cache::Id, origins: Vec<Borrow>) {
+    self.binding_depths.insert(cache_id, self.depth);
+
+    self
+      .borrows
+      .extend(origins.into_iter().map(|borrow| Borrow {
+        holder_id: Some(cache_id),
+        ..borrow
+      }));
+  }
+
+  /// Leave the current scope, ending the lifetimes of the bindings declared
+  /// within it, along with the borrows they hold.
+  fn leave_scope(&mut self) {
+    let depth = self.depth;
+    let binding_depths = &mut self.binding_depths;
+
+    binding_depths.retain(|_, binding_depth| *binding_depth < depth);
+    self.moves.retain(|id, _| binding_depths.contains_key(id));
+
+    self.borrows.retain(|borrow| match &borrow.holder_id {
+      Some(holder_id) => binding_depths.contains_key(holder_id),
+      None => true,
+    });
+
+    self.depth -= 1;
+  }
+
+  /// Check the body of a function or closure in a fresh state, since the
+  /// bindings of the enclosing function (if any) are out of reach.
+  fn check_function_body(
+    &mut self,
+    prototype: &ast::Prototype,
+    body: &ast::BlockExpr,
+    cache: &cache::Cache,
+  ) {
+    let binding_depths = std::mem::take(&mut self.binding_depths);
+    let borrows = std::mem::take(&mut self.borrows);
+    let moves = std::mem::take(&mut self.moves);
+    let diverged = std::mem::replace(&mut self.diverged, false);
+    let loop_exits = std::mem::take(&mut self.loop_exits);
+    let depth = self.depth;
+
+    let return_type = std::mem::replace(
+      &mut self.return_type,
+      prototype.return_type_annotation.flatten(cache),
+    );
+
+    self.depth = 1;
+
+    for parameter in prototype
+      .parameters
+      .iter()
+      .chain(prototype.this_parameter.iter())
+    {
+      self.declare(parameter.cache_id, Vec::new());
+    }
+
+    body.lifetime_check(self, cache);
+
+    // The value yielded by the body is returned, so it may not borrow
+    // from parameters either.
+    if let Some(yields) = &body.yields {
+      let origins = self.coerced_origins(&yields.kind, &self.return_type, cache);
+
+      self.check_escape(origins, 0, cache);
+    }
+
+    self.binding_depths = binding_depths;
+    self.borrows = borrows;
+    self.moves = moves;
+    self.diverged = diverged;
+    self.loop_exits = loop_exits;
+    self.return_type = return_type;
+    self.depth = depth;
+  }
+
+  /// Move the value of an expression out of the binding or parameter that
+  /// owns it, if the value is a place whose type isn't copyable.
+  fn consume(&mut self, value: &ast::NodeKind, site: String, cache: &cache::Cache) {
+    if self.diverged
+      || !matches!(
+        value.flatten(),
+        ast::NodeKind::Reference(_)
+          | ast::NodeKind::MemberAccess(_)
+          | ast::NodeKind::IndexingExpr(_)
+          | ast::NodeKind::UnaryExpr(ast::UnaryExpr {
+            operator: ast::OperatorKind::MultiplyOrDereference,
+            ..
+          })
+      )
+      || value.infer_flatten_type(cache).is_copyable(cache)
+    {
+      return;
+    }
+
+    let position = LifetimeCheckContext::place_position(value);
+
+    if self.is_indirect_place(value, cache) {
+      let place = LifetimeCheckContext::describe_place(value);
+
+      let labels = position
+        .map(|position| {
+          vec![self.label(
+            codespan_reporting::diagnostic::LabelStyle::Primary,
+            position,
+            "cannot move out of here",
+          )]
+        })
+        .unwrap_or_default();
+
+      self.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message(format!(
+            "cannot move out of borrowed or indirect place `{}`",
+            place
+          ))
+          .with_notes(vec![
+            format!("the value would be {}", site),
+            format!(
+              "`{}` has type `{}`, which doesn't implement `Copy`",
+              place,
+              value.infer_flatten_type(cache).name()
+            ),
+          ])
+          .with_labels(labels),
+      );
+
+      return;
+    }
+
+    // REVIEW: Partial moves aren't tracked, so moving out of a field or
+    // ... an element moves the entire binding.
+    if let Some(referent_id) = self.place_root(value, cache) {
+      self
+        .moves
+        .entry(referent_id)
+        .or_insert(Move { site, position });
+    }
+  }
+
+  /// The position of a place in the source file, if known.
+  fn place_position(node: &ast::NodeKind) -> Option<usize> {
+    match node.flatten() {
+      ast::NodeKind::Reference(reference) => Some(reference.position),
+      ast::NodeKind::MemberAccess(member_access) => {
+        LifetimeCheckContext::place_position(&member_access.base_expr.kind)
+      }
+      ast::NodeKind::IndexingExpr(indexing_expr) => Some(indexing_expr.position),
+      ast::NodeKind::UnaryExpr(unary_expr) => {
+        LifetimeCheckContext::place_position(&unary_expr.expr.kind)
+      }
+      _ => None,
+    }
+  }
+
+  /// Report a use of a binding or parameter whose value has been moved out.
+  fn check_use(&mut self, reference: &ast::Reference, cache: &cache::Cache) {
+    if self.diverged {
+      return;
+    }
+
+    let moved = match reference
+      .pattern
+      .target_id
+      .and_then(|target_id| self.moves.get(&target_id))
+    {
+      Some(moved) => moved,
+      None => return,
+    };
+
+    let name = &reference.pattern.base_name;
+
+    let ty = LifetimeCheckContext::referent_type(&reference.pattern.target_id.unwrap(), cache);
+
+    let notes = vec![
+      format!("`{}` was {}", name, moved.site),
+      format!(
+        "the value was moved because `{}` has type `{}`, which doesn't implement `Copy`",
+        name,
+        ty.name()
+      ),
+    ];
+
+    let mut labels = vec![self.label(
+      codespan_reporting::diagnostic::LabelStyle::Primary,
+      reference.position,
+      "value used here after move",
+    )];
+
+    if let Some(position) = moved.position {
+      labels.push(self.label(
+        codespan_reporting::diagnostic::LabelStyle::Secondary,
+        position,
+        "value moved here",
+      ));
+    }
+
+    self.diagnostics.push(
+      codespan_reporting::diagnostic::Diagnostic::error()
+        .with_message(format!("use of moved value `{}`", name))
+        .with_labels(labels)
+        .with_notes(notes),
+    );
+  }
+
+  /// Merge the moves of control flow paths that join together. A value is
+  /// considered moved if it was moved along any of them.
+  fn merge_moves(paths: Vec<Moves>) -> Moves {
+    let mut merged = Moves::new();
+
+    for path in paths {
+      for (referent_id, moved) in path {
+        merged.entry(referent_id).or_insert(moved);
+      }
+    }
+
+    merged
+  }
+
+  /// Check a single iteration of a loop's body, and collect the moves along
+  /// the paths that leave it early.
+  fn check_iteration(&mut self, check_body: &dyn Fn(&mut LifetimeCheckContext)) -> LoopExits {
+    self.loop_exits.push(LoopExits::default());
+    check_body(self);
+
+    self.loop_exits.pop().unwrap()
+  }
+
+  /// Check the body of a loop, whose moves carry over into its following
+  /// iterations.
+  ///
+  /// If the body moves out values declared outside of it, it is checked once
+  /// more, starting with those values moved, so that their uses are reported.
+  fn check_loop(&mut self, check_body: &dyn Fn(&mut LifetimeCheckContext)) {
+    let before = self.moves.clone();
+    let was_diverged = self.diverged;
+    let exits = self.check_iteration(check_body);
+    let mut next_iteration_paths = exits.continues;
+
+    if !self.diverged {
+      next_iteration_paths.push(std::mem::take(&mut self.moves));
+    }
+
+    let next_iteration = LifetimeCheckContext::merge_moves(next_iteration_paths);
+    let mut exit_paths = exits.breaks;
+
+    let carried = next_iteration
+      .iter()
+      .filter(|(referent_id, _)| {
+        !before.contains_key(referent_id) && self.binding_depths.contains_key(referent_id)
+      })
+      .map(|(referent_id, moved)| {
+        (
+          *referent_id,
+          Move {
+            site: format!("{}, in a previous iteration of the loop", moved.site),
+            position: moved.position,
+          },
+        )
+      })
+      .collect::<Moves>();
+
+    if !carried.is_empty() {
+      let diagnostic_count = self.diagnostics.len();
+
+      self.moves = LifetimeCheckContext::merge_moves(vec![before.clone(), carried]);
+      self.diverged = was_diverged;
+      exit_paths.extend(self.check_iteration(check_body).breaks);
+
+      // Diagnostics unrelated to the moves of the previous iteration would
+      // otherwise be reported twice.
+      for diagnostic in self.diagnostics.split_off(diagnostic_count) {
+        let is_repeated = self.diagnostics.iter().any(|existing| {
+          existing.message == diagnostic.message && existing.notes == diagnostic.notes
+        });
+
+        if !is_repeated {
+          self.diagnostics.push(diagnostic);
+        }
+      }
+    }
+
+    exit_paths.push(before);
+    exit_paths.push(next_iteration);
+    self.moves = LifetimeCheckContext::merge_moves(exit_paths);
+    self.diverged = was_diverged;
+  }
+
+  /// Check one of the branches of an `if` expression, starting from the
+  /// moves made before it. Its moves are collected if it doesn't diverge.
+  fn check_branch(
+    &mut self,
+    branch: &ast::NodeKind,
+    before: &Moves,
+    paths: &mut Vec<Moves>,
+    cache: &cache::Cache,
+  ) {
+    self.moves = before.clone();
+    self.diverged = false;
+    branch.lifetime_check(self, cache);
+
+    if !self.diverged {
+      paths.push(std::mem::take(&mut self.moves));
+    }
+  }
+
+  /// Report any of the borrows carried by a value that is about to leave the
+  /// scope of its referent: that is, referents declared at or deeper than the
+  /// given depth.
+  fn check_escape(&mut self, origins: Vec<Borrow>, min_depth: usize, cache: &cache::Cache) {
+    for borrow in origins {
+      let referent_depth = match self.binding_depths.get(&borrow.referent_id) {
+        Some(referent_depth) if *referent_depth >= min_depth => *referent_depth,
+        _ => continue,
+      };
+
+      if self.closure_environments.contains(&borrow.referent_id) {
+        self.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("cannot return a closure that captures local bindings")
+            .with_notes(vec![format!(
+              "the environment of the {} lives on the stack of the function that creates it",
+              borrow.expr
+            )]),
+        );
+
+        continue;
+      }
+
+      let name = LifetimeCheckContext::referent_name(&borrow.referent_id, cache);
+
+      let (message, note) = if min_depth == 0 {
+        (
+          format!("cannot return a reference to local `{}`", name),
+          format!("`{}` goes out of scope when the function returns", name),
+        )
+      } else {
+        (
+          format!("`{}` does not live long enough", name),
+          format!(
+            "`{}` goes out of scope at the end of its block (depth {}), while the reference is still in use",
+            name, referent_depth
+          ),
+        )
+      };
+
+      self.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message(message)
+          .with_notes(vec![self.describe_borrow(&borrow, cache), note]),
+      );
+    }
+  }
+}
+
+pub trait LifetimeCheck {
+  fn lifetime_check(&self, _context: &mut LifetimeCheckContext, _cache: &cache::Cache) {
+    //
+  }
+}
+
+impl LifetimeCheck for ast::NodeKind {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    crate::dispatch!(self, LifetimeCheck::lifetime_check, context, cache);
+  }
+}
+
+impl LifetimeCheck for ast::Function {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    context.check_function_body(&self.prototype, &self.body, cache);
+  }
+}
+
+impl LifetimeCheck for ast::Closure {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    // REVIEW: Captured bindings are not tracked, since they are either
+    // ... copied or captured through the closure's environment.
+    context.check_function_body(&self.prototype, &self.body, cache);
+
+    // The environment is allocated once per call of the enclosing function.
+    if !self.captures.is_empty() {
+      context.binding_depths.insert(self.id, 1);
+      context.closure_environments.insert(self.id);
+    }
+  }
+}
+
+impl LifetimeCheck for ast::StructImpl {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    for method in self.member_methods.iter().chain(self.static_methods.iter()) {
+      method.lifetime_check(context, cache);
+    }
+  }
+}
+
+impl LifetimeCheck for ast::BlockExpr {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    context.depth += 1;
+
+    for statement in &self.statements {
+      statement.kind.lifetime_check(context, cache);
+      context.end_statement();
+    }
+
+    if let Some(yields) = &self.yields {
+      yields.kind.lifetime_check(context, cache);
+
+      let depth = context.depth;
+      let origins = context.borrow_origins(&yields.kind, cache);
+
+      context.check_escape(origins, depth, cache);
+      context.end_statement();
+    }
+
+    context.leave_scope();
+  }
+}
+
+impl LifetimeCheck for ast::BindingStmt {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    self.value.kind.lifetime_check(context, cache);
+
+    context.consume(
+      &self.value.kind,
+      format!("moved into `{}`", self.name),
+      cache,
+    );
+
+    let origins = context.coerced_origins(&self.value.kind, &self.ty, cache);
+
+    context.declare(self.cache_id, origins);
+  }
+}
+
+impl LifetimeCheck for ast::ReturnStmt {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    if let Some(value) = &self.value {
+      value.kind.lifetime_check(context, cache);
+
+      let origins = context.coerced_origins(&value.kind, &context.return_type, cache);
+
+      context.check_escape(origins, 0, cache);
+    }
+
+    context.diverged = true;
+  }
+}
+
+impl LifetimeCheck for ast::AssignStmt {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    self.value.kind.lifetime_check(context, cache);
+
+    let assignee_type = self.assignee_expr.kind.infer_flatten_type(cache);
+    let origins = context.coerced_origins(&self.value.kind, &assignee_type, cache);
+
+    context.consume(
+      &self.value.kind,
+      format!(
+        "moved into `{}`",
+        LifetimeCheckContext::describe_place(&self.assignee_expr.kind)
+      ),
+      cache,
+    );
+
+    // Assigning to a binding (re-)initializes it, so it doesn't use its
+    // previous value, which may have been moved out.
+    let is_initialization = matches!(
+      self.assignee_expr.kind.flatten(),
+      ast::NodeKind::Reference(_)
+    );
+
+    if !is_initialization {
+      self.assignee_expr.kind.lifetime_check(context, cache);
+    }
+
+    // NOTE: Writes through shared references are rejected during type-checking,
+    // ... but places may not be mutated directly while they are borrowed either.
+    // REVIEW: Borrows written through pointers or references aren't tracked.
+    let referent_id = match context.place_root(&self.assignee_expr.kind, cache) {
+      Some(referent_id) => referent_id,
+      None => return,
+    };
+
+    // The value may not borrow bindings that go out of scope before the
+    // ... assignee does (ex. those declared in a nested block).
+    let referent_depth = context.binding_depths.get(&referent_id).copied();
+
+    if let Some(referent_depth) = referent_depth {
+      let escaping_origins = context.coerced_origins(&self.value.kind, &assignee_type, cache);
+
+      context.check_escape(escaping_origins, referent_depth + 1, cache);
+    }
+
+    if is_initialization && !context.diverged {
+      context.moves.remove(&referent_id);
+    }
+
+    // Temporary borrows (ex. `a = f(&a)`) end before the assignment occurs.
+    let conflict = context
+      .borrows
+      .iter()
+      .find(|borrow| borrow.holder_id.is_some() && borrow.referent_id == referent_id);
+
+    if let Some(conflict) = conflict {
+      let notes = vec![
+        context.describe_borrow(conflict, cache),
+        format!(
+          "`{}` is assigned to here, while the reference is still alive",
+          LifetimeCheckContext::describe_place(&self.assignee_expr.kind)
+        ),
+      ];
+
+      let labels = std::iter::once(context.label(
+        codespan_reporting::diagnostic::LabelStyle::Primary,
+        self.position,
+        "assigned to here",
+      ))
+      .chain(context.label_borrow(
+        conflict,
+        codespan_reporting::diagnostic::LabelStyle::Secondary,
+        "borrow taken here",
+      ))
+      .collect();
+
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message(format!(
+            "cannot assign to `{}` because it is borrowed",
+            LifetimeCheckContext::referent_name(&referent_id, cache)
+          ))
+          .with_labels(labels)
+          .with_notes(notes),
+      );
+    }
+
+    // Like bindings, the assignee holds the borrows carried by its new value,
+    // ... while a binding assigned to as a whole releases its previous ones.
+    if is_initialization {
+      context
+        .borrows
+        .retain(|borrow| borrow.holder_id != Some(referent_id));
+    }
+
+    context
+      .borrows
+      .extend(origins.into_iter().map(|borrow| Borrow {
+        holder_id: Some(referent_id),
+        ..borrow
+      }));
+  }
+}
+
+impl LifetimeCheck for ast::UnaryExpr {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    self.expr.kind.lifetime_check(context, cache);
+
+    let borrow = match self.operator {
+      ast::OperatorKind::AddressOf => {
+        context.borrow_of(&self.expr.kind, false, Some(self.position), cache)
+      }
+      ast::OperatorKind::MutableAddressOf => {
+        context.borrow_of(&self.expr.kind, true, Some(self.position), cache)
+      }
+      _ => None,
+    };
+
+    if let Some(borrow) = borrow {
+      context.borrow(borrow, cache);
+    }
+  }
+}
+
+impl LifetimeCheck for ast::ForInStmt {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    self.iterable.kind.lifetime_check(context, cache);
+
+    // The element lives for a single iteration, within the body's scope.
+    context.check_loop(&|context: &mut LifetimeCheckContext| {
+      context.depth += 1;
+      context.declare(self.element.cache_id, Vec::new());
+      context.loop_elements.insert(self.element.cache_id);
+      self.body.lifetime_check(context, cache);
+      context.leave_scope();
+    });
+  }
+}
+
+impl LifetimeCheck for ast::LoopStmt {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    // The condition is evaluated before every iteration.
+    context.check_loop(&|context: &mut LifetimeCheckContext| {
+      if let Some(condition) = &self.condition {
+        condition.kind.lifetime_check(context, cache);
+      }
+
+      self.body.lifetime_check(context, cache);
+    });
+  }
+}
+
+impl LifetimeCheck for ast::IfExpr {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    self.condition.kind.lifetime_check(context, cache);
+
+    let before = context.moves.clone();
+    let was_diverged = context.diverged;
+    let mut paths = Vec::new();
+
+    context.check_branch(&self.then_expr.kind, &before, &mut paths, cache);
+
+    // REVIEW: Moves within the conditions of alternative branches don't
+    // ... carry over into the branches that follow them.
+    for (condition, branch) in &self.alternative_branches {
+      context.moves = before.clone();
+      condition.kind.lifetime_check(context, cache);
+
+      let after_condition = context.moves.clone();
+
+      context.check_branch(&branch.kind, &after_condition, &mut paths, cache);
+    }
+
+    if let Some(else_expr) = &self.else_expr {
+      context.check_branch(&else_expr.kind, &before, &mut paths, cache);
+    } else {
+      paths.push(before.clone());
+    }
+
+    // The code that follows is unreachable if every branch diverges.
+    context.diverged = was_diverged || paths.is_empty();
+
+    context.moves = if paths.is_empty() {
+      before
+    } else {
+      LifetimeCheckContext::merge_moves(paths)
+    };
+  }
+}
+
+impl LifetimeCheck for ast::CallExpr {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    self.callee_expr.kind.lifetime_check(context, cache);
+
+    for argument in &self.arguments {
+      argument.kind.lifetime_check(context, cache);
+
+      context.consume(
+        &argument.kind,
+        format!(
+          "moved into a call to `{}`",
+          LifetimeCheckContext::describe_place(&self.callee_expr.kind)
+        ),
+        cache,
+      );
+    }
+  }
+}
+
+impl LifetimeCheck for ast::IntrinsicCall {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    for argument in &self.arguments {
+      argument.kind.lifetime_check(context, cache);
+
+      // Intrinsics otherwise only read their arguments.
+      if self.kind == ast::IntrinsicKind::FreeString {
+        context.consume(
+          &argument.kind,
+          String::from("moved into `?free_string`"),
+          cache,
+        );
+      }
+    }
+  }
+}
+
+impl LifetimeCheck for ast::InlineExprStmt {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    self.expr.kind.lifetime_check(context, cache);
+  }
+}
+
+impl LifetimeCheck for ast::BinaryExpr {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    self.left.kind.lifetime_check(context, cache);
+    self.right.kind.lifetime_check(context, cache);
+  }
+}
+
+impl LifetimeCheck for ast::ParenthesesExpr {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    self.expr.kind.lifetime_check(context, cache);
+  }
+}
+
+impl LifetimeCheck for ast::UnsafeExpr {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    self.0.kind.lifetime_check(context, cache);
+  }
+}
+
+impl LifetimeCheck for ast::MemberAccess {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    self.base_expr.kind.lifetime_check(context, cache);
+  }
+}
+
+impl LifetimeCheck for ast::IndexingExpr {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    self.target_expr.kind.lifetime_check(context, cache);
+    self.index_expr.kind.lifetime_check(context, cache);
+  }
+}
+
+impl LifetimeCheck for ast::SliceExpr {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    self.target_expr.kind.lifetime_check(context, cache);
+    self.start_expr.kind.lifetime_check(context, cache);
+    self.end_expr.kind.lifetime_check(context, cache);
+  }
+}
+
+impl LifetimeCheck for ast::InterpolationExpr {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    for hole in &self.holes {
+      hole.kind.lifetime_check(context, cache);
+    }
+  }
+}
+
+impl LifetimeCheck for ast::StaticArrayValue {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    for element in &self.elements {
+      element.kind.lifetime_check(context, cache);
+      context.consume(&element.kind, String::from("moved into an array"), cache);
+    }
+  }
+}
+
+impl LifetimeCheck for ast::StructValue {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    for field in &self.fields {
+      field.kind.lifetime_check(context, cache);
+
+      context.consume(
+        &field.kind,
+        format!("moved into a field of a `{}` value", self.struct_name),
+        cache,
+      );
+    }
+  }
+}
+
+impl LifetimeCheck for ast::OptionalValue {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    if let Some(value) = &self.value {
+      value.kind.lifetime_check(context, cache);
+      context.consume(
+        &value.kind,
+        String::from("moved into an optional value"),
+        cache,
+      );
+    }
+  }
+}
+
+impl LifetimeCheck for ast::ResultValue {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    self.value.kind.lifetime_check(context, cache);
+
+    context.consume(
+      &self.value.kind,
+      String::from("moved into a result value"),
+      cache,
+    );
+  }
+}
+
+impl LifetimeCheck for ast::TryExpr {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    self.expr.kind.lifetime_check(context, cache);
+  }
+}
+
+impl LifetimeCheck for ast::Literal {
+  //
+}
+
+impl LifetimeCheck for ast::ExternFunction {
+  //
+}
+
+impl LifetimeCheck for ast::ExternStatic {
+  //
+}
+
+impl LifetimeCheck for ast::BreakStmt {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, _cache: &cache::Cache) {
+    if !context.diverged {
+      let moves = context.moves.clone();
+
+      context.loop_exits.last_mut().unwrap().breaks.push(moves);
+    }
+
+    context.diverged = true;
+  }
+}
+
+impl LifetimeCheck for ast::ContinueStmt {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, _cache: &cache::Cache) {
+    if !context.diverged {
+      let moves = context.moves.clone();
+
+      context.loop_exits.last_mut().unwrap().continues.push(moves);
+    }
+
+    context.diverged = true;
+  }
+}
+
+impl LifetimeCheck for ast::DeferStmt {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    // REVIEW: The body only runs once its scope is exited, yet it is checked
+    // ... here, where it is declared. A value moved by the body will be reported
+    // ... if it is used afterwards within the same scope, even though that is sound.
+    self.body.kind.lifetime_check(context, cache);
+  }
+}
+
+impl LifetimeCheck for ast::Reference {
+  fn lifetime_check(&self, context: &mut LifetimeCheckContext, cache: &cache::Cache) {
+    context.check_use(self, cache);
+  }
+}
+
+impl LifetimeCheck for ast::Parameter {
+  //
+}
+
+impl LifetimeCheck for ast::Enum {
+  //
+}
+
+impl LifetimeCheck for ast::StructType {
+  //
+}
+
+impl LifetimeCheck for ast::Prototype {
+  //
+}
+
+impl LifetimeCheck for ast::Pattern {
+  //
+}
+
+impl LifetimeCheck for ast::TypeAlias {
+  //
+}
+
+impl LifetimeCheck for ast::Trait {
+  //
+}
+
+impl LifetimeCheck for ast::Import {
+  //
+}
+
+impl LifetimeCheck for ast::Range {
+  //
+}
+
+impl LifetimeCheck for ast::UnimplementedExpr {
+  //
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::name_resolution;
+
+  fn node(kind: ast::NodeKind) -> ast::Node {
+    ast::Node {
+      kind,
+      cached_type: None,
+    }
+  }
+
+  fn reference(name: &str, target_id: cache::Id) -> ast::NodeKind {
+    ast::NodeKind::Reference(ast::Reference {
+      pattern: ast::Pattern {
+        qualifier: None,
+        base_name: name.to_string(),
+        sub_name: None,
+        symbol_kind: name_resolution::SymbolKind::Definition,
+        target_id: Some(target_id),
+      },
+      position: 0,
+    })
+  }
+
+  fn address_of(operator: ast::OperatorKind, expr: ast::NodeKind) -> ast::NodeKind {
+    ast::NodeKind::UnaryExpr(ast::UnaryExpr {
+      operator,
+      expr: Box::new(node(expr)),
+      cast_type: None,
+      position: 0,
+    })
+  }
+
+  fn binding(
+    cache: &mut cache::Cache,
+    name: &str,
+    cache_id: cache::Id,
+    value: ast::NodeKind,
+  ) -> ast::Node {
+    let binding_stmt = ast::BindingStmt {
+      name: name.to_string(),
+      value: Box::new(node(value)),
+      modifier: ast::BindingModifier::Mutable,
+      cache_id,
+      ty: ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
+      position: 0,
+    };
+
+    cache
+      .symbols
+      .insert(cache_id, ast::NodeKind::BindingStmt(binding_stmt.clone()));
+
+    node(ast::NodeKind::BindingStmt(binding_stmt))
+  }
+
+  fn block(statements: Vec<ast::Node>, yields: Option<ast::NodeKind>) -> ast::BlockExpr {
+    ast::BlockExpr {
+      statements,
+      yields: yields.map(|yields| Box::new(node(yields))),
+      cache_id: 100,
+    }
+  }
+
+  fn literal() -> ast::NodeKind {
+    ast::NodeKind::Literal(ast::Literal::Int(1, ast::IntSize::I32))
+  }
+
+  #[test]
+  fn proper_initial_values() {
+    let context = LifetimeCheckContext::new();
+
+    assert!(context.diagnostics.is_empty());
+    assert!(context.binding_depths.is_empty());
+    assert!(context.borrows.is_empty());
+    assert_eq!(0, context.depth);
+  }
+
+  #[test]
+  fn shared_borrows_may_coexist() {
+    let mut context = LifetimeCheckContext::new();
+    let mut cache = cache::Cache::new();
+    let a = binding(&mut cache, "a", 0, literal());
+
+    let b = binding(
+      &mut cache,
+      "b",
+      1,
+      address_of(ast::OperatorKind::AddressOf, reference("a", 0)),
+    );
+
+    let c = binding(
+      &mut cache,
+      "c",
+      2,
+      address_of(ast::OperatorKind::AddressOf, reference("a", 0)),
+    );
+
+    block(vec![a, b, c], None).lifetime_check(&mut context, &cache);
+    assert!(context.diagnostics.is_empty());
+    assert!(context.borrows.is_empty());
+  }
+
+  #[test]
+  fn mutable_borrow_while_borrowed() {
+    let mut context = LifetimeCheckContext::new();
+    let mut cache = cache::Cache::new();
+    let a = binding(&mut cache, "a", 0, literal());
+
+    let b = binding(
+      &mut cache,
+      "b",
+      1,
+      address_of(ast::OperatorKind::AddressOf, reference("a", 0)),
+    );
+
+    let c = binding(
+      &mut cache,
+      "c",
+      2,
+      address_of(ast::OperatorKind::MutableAddressOf, reference("a", 0)),
+    );
+
+    block(vec![a, b, c], None).lifetime_check(&mut context, &cache);
+    assert_eq!(1, context.diagnostics.len());
+
+    assert_eq!(
+      "cannot borrow `a` as mutable because it is also borrowed as shared",
+      context.diagnostics[0].message
+    );
+
+    // The diagnostic points at both the existing and the conflicting borrow.
+    assert_eq!(2, context.diagnostics[0].notes.len());
+  }
+
+  #[test]
+  fn borrows_end_with_their_holder() {
+    let mut context = LifetimeCheckContext::new();
+    let mut cache = cache::Cache::new();
+    let a = binding(&mut cache, "a", 0, literal());
+
+    let b = binding(
+      &mut cache,
+      "b",
+      1,
+      address_of(ast::OperatorKind::MutableAddressOf, reference("a", 0)),
+    );
+
+    let inner_block = node(ast::NodeKind::BlockExpr(block(vec![b], None)));
+
+    let c = binding(
+      &mut cache,
+      "c",
+      2,
+      address_of(ast::OperatorKind::MutableAddressOf, reference("a", 0)),
+    );
+
+    block(vec![a, inner_block, c], None).lifetime_check(&mut context, &cache);
+    assert!(context.diagnostics.is_empty());
+  }
+
+  #[test]
+  fn assign_while_borrowed() {
+    let mut context = LifetimeCheckContext::new();
+    let mut cache = cache::Cache::new();
+    let a = binding(&mut cache, "a", 0, literal());
+
+    let b = binding(
+      &mut cache,
+      "b",
+      1,
+      address_of(ast::OperatorKind::AddressOf, reference("a", 0)),
+    );
+
+    let assign_stmt = node(ast::NodeKind::AssignStmt(ast::AssignStmt {
+      assignee_expr: Box::new(node(reference("a", 0))),
+      value: Box::new(node(literal())),
+      position: 0,
+    }));
+
+    block(vec![a, b, assign_stmt], None).lifetime_check(&mut context, &cache);
+    assert_eq!(1, context.diagnostics.len());
+
+    assert_eq!(
+      "cannot assign to `a` because it is borrowed",
+      context.diagnostics[0].message
+    );
+  }
+
+  #[test]
+  fn reference_outlives_referent() {
+    let mut context = LifetimeCheckContext::new();
+    let mut cache = cache::Cache::new();
+    let a = binding(&mut cache, "a", 0, literal());
+
+    let inner_block = block(
+      vec![a],
+      Some(address_of(ast::OperatorKind::AddressOf, reference("a", 0))),
+    );
+
+    let b = binding(&mut cache, "b", 1, ast::NodeKind::BlockExpr(inner_block));
+
+    block(vec![b], None).lifetime_check(&mut context, &cache);
+    assert_eq!(1, context.diagnostics.len());
+
+    assert_eq!(
+      "`a` does not live long enough",
+      context.diagnostics[0].message
+    );
+  }
+
+  #[test]
+  fn return_reference_to_local() {
+    let mut context = LifetimeCheckContext::new();
+    let mut cache = cache::Cache::new();
+    let a = binding(&mut cache, "a", 0, literal());
+
+    let return_stmt = node(ast::NodeKind::ReturnStmt(ast::ReturnStmt {
+      value: Some(Box::new(node(address_of(
+        ast::OperatorKind::AddressOf,
+        reference("a", 0),
+      )))),
+    }));
+
+    block(vec![a, return_stmt], None).lifetime_check(&mut context, &cache);
+    assert_eq!(1, context.diagnostics.len());
+
+    assert_eq!(
+      "cannot return a reference to local `a`",
+      context.diagnostics[0].message
+    );
+  }
+
+  #[test]
+  fn return_capturing_closure() {
+    let mut context = LifetimeCheckContext::new();
+    let mut cache = cache::Cache::new();
+    let a = binding(&mut cache, "a", 0, literal());
+
+    let closure = ast::NodeKind::Closure(ast::Closure {
+      captures: vec![ast::Capture {
+        name: "a".to_string(),
+        target_id: Some(0),
+        mode: ast::CaptureMode::ByValue,
+      }],
+      prototype: ast::Prototype {
+        parameters: Vec::new(),
+        return_type_annotation: ast::Type::Unit,
+        is_variadic: false,
+        is_extern: false,
+        accepts_instance: false,
+        is_instance_mutable: false,
+        instance_type_id: None,
+        this_parameter: None,
+      },
+      body: block(Vec::new(), None),
+      id: 2,
+      position: 0,
+    });
+
+    let f = binding(&mut cache, "f", 1, closure);
+
+    let return_stmt = node(ast::NodeKind::ReturnStmt(ast::ReturnStmt {
+      value: Some(Box::new(node(reference("f", 1)))),
+    }));
+
+    block(vec![a, f, return_stmt], None).lifetime_check(&mut context, &cache);
+    assert_eq!(1, context.diagnostics.len());
+
+    assert_eq!(
+      "cannot return a closure that captures local bindings",
+      context.diagnostics[0].message
+    );
+  }
+
+  fn string_parameter(
+    context: &mut LifetimeCheckContext,
+    cache: &mut cache::Cache,
+    name: &str,
+    cache_id: cache::Id,
+  ) {
+    cache.symbols.insert(
+      cache_id,
+      ast::NodeKind::Parameter(ast::Parameter {
+        name: name.to_string(),
+        ty: ast::Type::Basic(ast::BasicType::OwnedString),
+        position: 0,
+        cache_id,
+      }),
+    );
+
+    context.declare(cache_id, Vec::new());
+  }
+
+  #[test]
+  fn use_after_move() {
+    let mut context = LifetimeCheckContext::new();
+    let mut cache = cache::Cache::new();
+
+    string_parameter(&mut context, &mut cache, "s", 0);
+
+    let a = binding(&mut cache, "a", 1, reference("s", 0));
+    let b = binding(&mut cache, "b", 2, reference("s", 0));
+
+    block(vec![a, b], None).lifetime_check(&mut context, &cache);
+    assert_eq!(1, context.diagnostics.len());
+    assert_eq!("use of moved value `s`", context.diagnostics[0].message);
+
+    // The diagnostic points at the move site.
+    assert_eq!("`s` was moved into `a`", context.diagnostics[0].notes[0]);
+  }
+
+  #[test]
+  fn reinitialize_after_move() {
+    let mut context = LifetimeCheckContext::new();
+    let mut cache = cache::Cache::new();
+
+    string_parameter(&mut context, &mut cache, "s", 0);
+    string_parameter(&mut context, &mut cache, "t", 1);
+
+    let a = binding(&mut cache, "a", 2, reference("s", 0));
+
+    let assign_stmt = node(ast::NodeKind::AssignStmt(ast::AssignStmt {
+      assignee_expr: Box::new(node(reference("s", 0))),
+      value: Box::new(node(reference("t", 1))),
+      position: 0,
+    }));
+
+    let b = binding(&mut cache, "b", 3, reference("s", 0));
+
+    block(vec![a, assign_stmt, b], None).lifetime_check(&mut context, &cache);
+    assert!(context.diagnostics.is_empty());
+  }
+
+  #[test]
+  fn move_in_branch() {
+    let mut context = LifetimeCheckContext::new();
+    let mut cache = cache::Cache::new();
+
+    string_parameter(&mut context, &mut cache, "s", 0);
+
+    let a = binding(&mut cache, "a", 1, reference("s", 0));
+
+    // The value is moved along one of the paths, so it may have been moved.
+    let if_expr = node(ast::NodeKind::IfExpr(ast::IfExpr {
+      condition: Box::new(node(ast::NodeKind::Literal(ast::Literal::Bool(true)))),
+      then_expr: Box::new(node(ast::NodeKind::BlockExpr(block(vec![a], None)))),
+      alternative_branches: Vec::new(),
+      else_expr: None,
+    }));
+
+    let b = binding(&mut cache, "b", 2, reference("s", 0));
+
+    block(vec![if_expr, b], None).lifetime_check(&mut context, &cache);
+    assert_eq!(1, context.diagnostics.len());
+    assert_eq!("use of moved value `s`", context.diagnostics[0].message);
+  }
+
+  #[test]
+  fn move_in_diverging_branch() {
+    let mut context = LifetimeCheckContext::new();
+    let mut cache = cache::Cache::new();
+
+    string_parameter(&mut context, &mut cache, "s", 0);
+
+    let a = binding(&mut cache, "a", 1, reference("s", 0));
+    let return_stmt = node(ast::NodeKind::ReturnStmt(ast::ReturnStmt { value: None }));
+
+    let if_expr = node(ast::NodeKind::IfExpr(ast::IfExpr {
+      condition: Box::new(node(ast::NodeKind::Literal(ast::Literal::Bool(true)))),
+      then_expr: Box::new(node(ast::NodeKind::BlockExpr(block(
+        vec![a, return_stmt],
+        None,
+      )))),
+      alternative_branches: Vec::new(),
+      else_expr: None,
+    }));
+
+    let b = binding(&mut cache, "b", 2, reference("s", 0));
+
+    block(vec![if_expr, b], None).lifetime_check(&mut context, &cache);
+    assert!(context.diagnostics.is_empty());
+  }
+
+  #[test]
+  fn move_in_loop() {
+    let mut context = LifetimeCheckContext::new();
+    let mut cache = cache::Cache::new();
+
+    string_parameter(&mut context, &mut cache, "s", 0);
+
+    let a = binding(&mut cache, "a", 1, reference("s", 0));
+
+    let loop_stmt = ast::LoopStmt {
+      condition: None,
+      body: block(vec![a], None),
+    };
+
+    loop_stmt.lifetime_check(&mut context, &cache);
+    assert_eq!(1, context.diagnostics.len());
+    assert_eq!("use of moved value `s`", context.diagnostics[0].message);
+
+    assert_eq!(
+      "`s` was moved into `a`, in a previous iteration of the loop",
+      context.diagnostics[0].notes[0]
+    );
+  }
+
+  #[test]
+  fn use_after_move_labels() {
+    let (diagnostics, ast, cache) = crate::mock::tests::check_source(
+      "func f():\n  let s = ?to_string(\"s\")\n  let a = s\n  let b = s\n",
+    );
+
+    assert!(diagnostics.is_empty());
+
+    let diagnostics = LifetimeCheckContext::run(&ast, &cache);
+
+    assert_eq!(1, diagnostics.len());
+    assert_eq!("use of moved value `s`", diagnostics[0].message);
+
+    // The use and the move are both labeled.
+    let label_positions = diagnostics[0]
+      .labels
+      .iter()
+      .map(|label| label.range.start)
+      .collect::<Vec<_>>();
+
+    assert_eq!(vec![58, 46], label_positions);
+  }
+
+  #[test]
+  fn mutable_reference_is_moved() {
+    let (diagnostics, ast, cache) = crate::mock::tests::check_source(
+      "func f():\n  var a = 1\n  let r = &mut a\n  let b = r\n  let c = r\n",
+    );
+
+    assert!(diagnostics.is_empty());
+
+    let diagnostics = LifetimeCheckContext::run(&ast, &cache);
+
+    assert_eq!(1, diagnostics.len());
+    assert_eq!("use of moved value `r`", diagnostics[0].message);
+  }
+
+  #[test]
+  fn return_reborrow_of_local() {
+    for source_code in [
+      "func f() -> &Int:\n  let a = 1\n  let r = &a\n\n  return &*r\n",
+      "struct Local:\n  x: Int,\n\nfunc f() -> &Int:\n  let a = new Local { 1 }\n  let r = &a\n\n  return &r.x\n",
+    ] {
+      let (diagnostics, ast, cache) = crate::mock::tests::check_source(source_code);
+
+      assert!(diagnostics.is_empty());
+
+      let diagnostics = LifetimeCheckContext::run(&ast, &cache);
+
+      assert_eq!(1, diagnostics.len());
+
+      assert_eq!(
+        "cannot return a reference to local `a`",
+        diagnostics[0].message
+      );
+    }
+  }
+
+  #[test]
+  fn return_call_borrowing_local() {
+    let (diagnostics, ast, cache) = crate::mock::tests::check_source(
+      "func id(r: &Int) -> &Int:\n  return r\n\nfunc f() -> &Int:\n  let a = 1\n\n  return id(&a)\n",
+    );
+
+    assert!(diagnostics.is_empty());
+
+    let diagnostics = LifetimeCheckContext::run(&ast, &cache);
+
+    assert_eq!(1, diagnostics.len());
+
+    assert_eq!(
+      "cannot return a reference to local `a`",
+      diagnostics[0].message
+    );
+  }
+
+  #[test]
+  fn assign_reference_to_inner_binding() {
+    let (diagnostics, ast, cache) = crate::mock::tests::check_source(
+      "func f():\n  let a = 1\n  var r = &a\n\n  if true:\n    let b = 2\n    r = &b\n",
+    );
+
+    assert!(diagnostics.is_empty());
+
+    let diagnostics = LifetimeCheckContext::run(&ast, &cache);
+
+    assert_eq!(1, diagnostics.len());
+    assert_eq!("`b` does not live long enough", diagnostics[0].message);
+  }
+
+  #[test]
+  fn assign_hands_over_borrows() {
+    let (diagnostics, ast, cache) = crate::mock::tests::check_source(
+      "func f():\n  var a = 1\n  var b = 2\n  var r = &a\n  r = &b\n  a = 3\n  b = 4\n",
+    );
+
+    assert!(diagnostics.is_empty());
+
+    let diagnostics = LifetimeCheckContext::run(&ast, &cache);
+
+    assert_eq!(1, diagnostics.len());
+
+    assert_eq!(
+      "cannot assign to `b` because it is borrowed",
+      diagnostics[0].message
+    );
+
+    // The assignment and the borrow are both labeled.
+    let label_positions = diagnostics[0]
+      .labels
+      .iter()
+      .map(|label| label.range.start)
+      .collect::<Vec<_>>();
+
+    assert_eq!(vec![68, 53], label_positions);
+  }
+
+  #[test]
+  fn move_out_of_indirect_place() {
+    for (source_code, place) in [
+      (
+        "struct Wrapper:\n  file: String,\n\nfunc f(r: &Wrapper):\n  let s = r.file\n",
+        "r.file",
+      ),
+      ("func f(slice: []String):\n  let s = slice[0]\n", "slice[..]"),
+      ("func f(r: &String):\n  let s = *r\n", "*r"),
+      (
+        "func f():\n  let names = [?to_string(\"a\"), ?to_string(\"b\")]\n\n  for name in names:\n    let s = name\n",
+        "name",
+      ),
+    ] {
+      let (diagnostics, ast, cache) = crate::mock::tests::check_source(source_code);
+
+      assert!(diagnostics.is_empty());
+
+      let diagnostics = LifetimeCheckContext::run(&ast, &cache);
+
+      assert_eq!(1, diagnostics.len());
+
+      assert_eq!(
+        format!("cannot move out of borrowed or indirect place `{}`", place),
+        diagnostics[0].message
+      );
+    }
+  }
+
+  #[test]
+  fn copy_out_of_indirect_place() {
+    let (diagnostics, ast, cache) = crate::mock::tests::check_source(
+      "struct Wrapper:\n  id: Int,\n\nfunc f(r: &Wrapper, slice: []Int):\n  let a = r.id\n  let b = slice[0]\n\n  for number in slice:\n    let c = number\n",
+    );
+
+    assert!(diagnostics.is_empty());
+    assert!(LifetimeCheckContext::run(&ast, &cache).is_empty());
+  }
+
+  #[test]
+  fn return_local_array_as_slice() {
+    for source_code in [
+      "func f() -> []Int:\n  let a = [1, 2]\n\n  return a\n",
+      "func f() -> []Int:\n  let a = [1, 2]\n  let s: []Int = a\n\n  return s\n",
+    ] {
+      let (diagnostics, ast, cache) = crate::mock::tests::check_source(source_code);
+
+      assert!(diagnostics.is_empty());
+
+      let diagnostics = LifetimeCheckContext::run(&ast, &cache);
+
+      assert_eq!(1, diagnostics.len());
+      assert_eq!(
+        "cannot return a reference to local `a`",
+        diagnostics[0].message
+      );
+    }
+  }
+}
@@ -62,11 +62,27 @@ impl Lint for ast::UnimplementedExpr {
   //
 }
 
-impl Lint for ast::Range {
-  //
+impl Lint for ast::OptionalValue {
+  fn lint(&self, cache: &cache::Cache, context: &mut LintContext) {
+    if let Some(value) = &self.value {
+      value.lint(cache, context);
+    }
+  }
+}
+
+impl Lint for ast::ResultValue {
+  fn lint(&self, cache: &cache::Cache, context: &mut LintContext) {
+    self.value.lint(cache, context);
+  }
+}
+
+impl Lint for ast::TryExpr {
+  fn lint(&self, cache: &cache::Cache, context: &mut LintContext) {
+    self.expr.lint(cache, context);
+  }
 }
 
-impl Lint for ast::SizeofIntrinsic {
+impl Lint for ast::Range {
   //
 }
 
@@ -140,14 +156,27 @@ impl Lint for ast::UnaryExpr {
 
 impl Lint for ast::IndexingExpr {
   fn lint(&self, cache: &cache::Cache, context: &mut LintContext) {
-    context
-      .variable_references
-      .insert(self.target_id.unwrap(), true);
-
+    self.target_expr.lint(cache, context);
     self.index_expr.lint(cache, context);
   }
 }
 
+impl Lint for ast::SliceExpr {
+  fn lint(&self, cache: &cache::Cache, context: &mut LintContext) {
+    self.target_expr.lint(cache, context);
+    self.start_expr.lint(cache, context);
+    self.end_expr.lint(cache, context);
+  }
+}
+
+impl Lint for ast::InterpolationExpr {
+  fn lint(&self, cache: &cache::Cache, context: &mut LintContext) {
+    for hole in &self.holes {
+      hole.lint(cache, context);
+    }
+  }
+}
+
 impl Lint for ast::StaticArrayValue {
   fn lint(&self, cache: &cache::Cache, context: &mut LintContext) {
     for element in &self.elements {
@@ -293,6 +322,12 @@ impl Lint for ast::ReturnStmt {
   }
 }
 
+impl Lint for ast::DeferStmt {
+  fn lint(&self, cache: &cache::Cache, context: &mut LintContext) {
+    self.body.lint(cache, context);
+  }
+}
+
 impl Lint for ast::UnsafeExpr {
   fn lint(&self, cache: &cache::Cache, context: &mut LintContext) {
     self.0.lint(cache, context);
@@ -313,6 +348,14 @@ impl Lint for ast::Reference {
   }
 }
 
+impl Lint for ast::ForInStmt {
+  fn lint(&self, cache: &cache::Cache, context: &mut LintContext) {
+    context.lint_name_casing("variable", &self.element.name, convert_case::Case::Snake);
+    self.iterable.lint(cache, context);
+    self.body.lint(cache, context);
+  }
+}
+
 impl Lint for ast::LoopStmt {
   fn lint(&self, cache: &cache::Cache, context: &mut LintContext) {
     if let Some(condition) = &self.condition {
@@ -1,6 +1,6 @@
 use crate::{
   ast, cache, dispatch,
-  type_system::{Check, TypeContext},
+  type_system::{CastKind, Check, TypeContext},
 };
 
 use inkwell::{types::BasicType, values::BasicValue};
@@ -8,6 +8,9 @@ use std::convert::TryFrom;
 
 pub const MAIN_FUNCTION_NAME: &str = "main";
 
+/// The name of the method implicitly called whenever a struct value is created.
+pub const CONSTRUCTOR_NAME: &str = "init";
+
 pub trait Lower {
   fn lower<'a, 'ctx>(
     &self,
@@ -44,11 +47,16 @@ impl Lower for ast::NodeKind {
 impl Lower for ast::UnimplementedExpr {
   fn lower<'a, 'ctx>(
     &self,
-    _generator: &mut LlvmGenerator<'a, 'ctx>,
+    generator: &mut LlvmGenerator<'a, 'ctx>,
     _cache: &cache::Cache,
     _access: bool,
   ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
-    // TODO: Implement an alternative to panics, or remove.
+    let llvm_message = generator
+      .llvm_builder
+      .build_global_string_ptr("not implemented", "panic.message")
+      .as_pointer_value();
+
+    generator.build_panic(llvm_message, self.position);
 
     None
   }
@@ -58,16 +66,159 @@ impl Lower for ast::Range {
   // TODO: ?
 }
 
-impl Lower for ast::SizeofIntrinsic {
+impl Lower for ast::OptionalValue {
+  fn lower<'a, 'ctx>(
+    &self,
+    generator: &mut LlvmGenerator<'a, 'ctx>,
+    cache: &cache::Cache,
+    _access: bool,
+  ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
+    let llvm_optional_type = generator
+      .memoize_or_retrieve_type(&self.infer_type(cache), cache)
+      .into_struct_type();
+
+    // A zeroed optional has its flag unset, which represents `none`.
+    let value = match &self.value {
+      Some(value) => value,
+      None => return Some(llvm_optional_type.const_zero().as_basic_value_enum()),
+    };
+
+    let llvm_value = value.lower(generator, cache, true).unwrap();
+
+    generator.build_move(&value.kind, cache);
+
+    let llvm_flag = generator.llvm_context.bool_type().const_int(1, false);
+
+    let llvm_flagged_optional = generator
+      .llvm_builder
+      .build_insert_value(
+        llvm_optional_type.get_undef(),
+        llvm_flag,
+        0,
+        "optional.flag",
+      )
+      .unwrap();
+
+    let llvm_optional = generator
+      .llvm_builder
+      .build_insert_value(llvm_flagged_optional, llvm_value, 1, "optional.value")
+      .unwrap();
+
+    Some(llvm_optional.into_struct_value().as_basic_value_enum())
+  }
+}
+
+impl Lower for ast::ResultValue {
+  fn lower<'a, 'ctx>(
+    &self,
+    generator: &mut LlvmGenerator<'a, 'ctx>,
+    cache: &cache::Cache,
+    _access: bool,
+  ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
+    let llvm_result_type = generator
+      .memoize_or_retrieve_type(&self.infer_type(cache), cache)
+      .into_struct_type();
+
+    let llvm_value = self.value.lower(generator, cache, true).unwrap();
+
+    generator.build_move(&self.value.kind, cache);
+
+    // The flag is only set for `ok` values, which hold their value on the
+    // second field. Errors are held on the third field instead.
+    let llvm_flag = generator
+      .llvm_context
+      .bool_type()
+      .const_int(self.is_ok as u64, false);
+
+    let llvm_flagged_result = generator
+      .llvm_builder
+      .build_insert_value(llvm_result_type.get_undef(), llvm_flag, 0, "result.flag")
+      .unwrap();
+
+    let llvm_result = generator
+      .llvm_builder
+      .build_insert_value(
+        llvm_flagged_result,
+        llvm_value,
+        if self.is_ok { 1 } else { 2 },
+        "result.value",
+      )
+      .unwrap();
+
+    Some(llvm_result.into_struct_value().as_basic_value_enum())
+  }
+}
+
+impl Lower for ast::TryExpr {
   fn lower<'a, 'ctx>(
     &self,
     generator: &mut LlvmGenerator<'a, 'ctx>,
     cache: &cache::Cache,
     _access: bool,
   ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
-    let ty = generator.memoize_or_retrieve_type(&self.ty, cache);
+    let is_result = matches!(
+      self.expr.kind.infer_flatten_type(cache),
+      ast::Type::Result(..)
+    );
+
+    let llvm_value = self.expr.lower(generator, cache, false).unwrap();
+    let llvm_optional = generator.attempt_access(llvm_value).into_struct_value();
+    let llvm_current_function = generator.llvm_function_buffer.unwrap();
+
+    let llvm_flag = generator
+      .llvm_builder
+      .build_extract_value(llvm_optional, 0, "try.flag")
+      .unwrap()
+      .into_int_value();
+
+    let llvm_some_block = generator
+      .llvm_context
+      .append_basic_block(llvm_current_function, "try.some");
+
+    let llvm_none_block = generator
+      .llvm_context
+      .append_basic_block(llvm_current_function, "try.none");
+
+    generator
+      .llvm_builder
+      .build_conditional_branch(llvm_flag, llvm_some_block, llvm_none_block);
+
+    // Propagate `none` (or the error) by returning a zeroed optional (or result)
+    // of the function's return type.
+    generator.llvm_builder.position_at_end(llvm_none_block);
+
+    // NOTE: The type-checker ensures that the function's return type is an optional
+    // ... (or a result with the same error type).
+    let llvm_return_type = llvm_current_function
+      .get_type()
+      .get_return_type()
+      .unwrap()
+      .into_struct_type();
+
+    let llvm_return_value = if is_result {
+      let llvm_error = generator
+        .llvm_builder
+        .build_extract_value(llvm_optional, 2, "try.error")
+        .unwrap();
+
+      generator
+        .llvm_builder
+        .build_insert_value(llvm_return_type.const_zero(), llvm_error, 2, "try.result")
+        .unwrap()
+        .into_struct_value()
+    } else {
+      llvm_return_type.const_zero()
+    };
+
+    // Returning early exits every scope of the function, like `return` does.
+    generator.build_cleanups(0, cache);
+    generator.attempt_build_return(Some(llvm_return_value.as_basic_value_enum()));
 
-    Some(ty.size_of().unwrap().as_basic_value_enum())
+    generator.llvm_builder.position_at_end(llvm_some_block);
+
+    generator
+      .llvm_builder
+      .build_extract_value(llvm_optional, 1, "try.value")
   }
 }
 
@@ -113,15 +264,11 @@ impl Lower for ast::MemberAccess {
     cache: &cache::Cache,
     access: bool,
   ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
-    let llvm_struct = self
-      .base_expr
-      .lower(generator, cache, false)
-      .unwrap()
-      .into_pointer_value();
+    let llvm_struct = generator.lower_member_access_base(self, cache);
 
     // Flatten the type in case it is a `ThisType`.
     let llvm_struct_type = crate::force_match!(
-      self.base_expr.kind.infer_flatten_type(cache),
+      TypeContext::auto_deref(self.base_expr.kind.infer_flatten_type(cache), cache),
       ast::Type::Struct
     );
 
@@ -170,30 +317,72 @@ impl Lower for ast::Closure {
     // REVIEW: Closures don't have a unique id.
     // ... Don't we need to set the buffer unique id for closures as well?
 
-    let buffers = generator.copy_buffers();
-    // let mut modified_prototype = self.prototype.clone();
-
-    for (_index, _capture) in self.captures.iter().enumerate() {
-      // let capture_node = cache.force_get(&capture.1.unwrap());
-      // let capture_node_type = (&*capture_node).infer_type(cache);
-      // let computed_parameter_index = self.prototype.parameters.len() as usize + index;
+    let llvm_opaque_pointer_type = generator
+      .llvm_context
+      .i8_type()
+      .ptr_type(inkwell::AddressSpace::Generic);
 
-      // REVIEW: Is the parameter position correct?
-      // TODO: Re-implement, after parameters were made definitions.
-      // modified_prototype.parameters.push((
-      //   format!("capture.{}", capture.0),
-      //   capture_node_type,
-      //   computed_parameter_index as u32,
-      // ))
-    }
+    // Values captured by reference are the pointers to their bindings, while
+    // values captured by value are copies of their current values.
+    let llvm_captured_values = self
+      .captures
+      .iter()
+      .map(|capture| {
+        generator
+          .memoize_or_retrieve_value(
+            capture.target_id.unwrap(),
+            cache,
+            false,
+            capture.mode == ast::CaptureMode::ByValue,
+          )
+          .unwrap()
+      })
+      .collect::<Vec<_>>();
 
-    // FIXME: Use the modified prototype.
-    let llvm_function_type = generator.lower_prototype(
-      &self.prototype,
-      &TypeContext::infer_return_value_type(&self.body, cache),
-      cache,
+    let llvm_environment_type = generator.llvm_context.struct_type(
+      llvm_captured_values
+        .iter()
+        .map(|llvm_value| llvm_value.get_type())
+        .collect::<Vec<_>>()
+        .as_slice(),
+      false,
     );
 
+    // NOTE: The environment lives on the stack of the enclosing function. Capturing closures
+    // ... are prevented from outliving it by the lifetime checker.
+    let llvm_environment = if llvm_captured_values.is_empty() {
+      llvm_opaque_pointer_type.const_null()
+    } else {
+      let llvm_environment_alloca = generator
+        .llvm_builder
+        .build_alloca(llvm_environment_type, "closure.env");
+
+      for (index, llvm_captured_value) in llvm_captured_values.iter().enumerate() {
+        let llvm_field_gep = generator
+          .llvm_builder
+          .build_struct_gep(llvm_environment_alloca, index as u32, "closure.env.capture")
+          .unwrap();
+
+        generator
+          .llvm_builder
+          .build_store(llvm_field_gep, *llvm_captured_value);
+      }
+
+      generator
+        .llvm_builder
+        .build_pointer_cast(
+          llvm_environment_alloca,
+          llvm_opaque_pointer_type,
+          "closure.env.opaque",
+        )
+    };
+
+    let buffers = generator.copy_buffers();
+
+    let return_type = TypeContext::infer_function_return_type(&self.prototype, &self.body, cache);
+    let llvm_function_type = generator.lower_prototype(&self.prototype, &return_type, cache);
+
+    let llvm_function_type = generator.lower_closure_function_type(llvm_function_type);
     let llvm_function_name = generator.mangle_name(&String::from("closure"));
 
     assert!(generator
@@ -207,16 +396,6 @@ impl Lower for ast::Closure {
       Some(inkwell::module::Linkage::Private),
     );
 
-    // TODO: Use a zipper, along with a chain.
-    // for (i, llvm_parameter) in llvm_function.get_param_iter().enumerate() {
-    //   // TODO: Ensure safe access.
-    //   // FIXME: The llvm function's parameter count is longer than that of the prototypes. This is because of inserted captures. Fix this bug.
-    //   let parameter = &self.prototype.parameters[i];
-
-    //   parameter.lower(generator, cache);
-    //   llvm_parameter.set_name(format!("param.{}", parameter.0).as_str());
-    // }
-
     generator.llvm_function_buffer = Some(llvm_function);
 
     let llvm_entry_block = generator
@@ -225,17 +404,123 @@ impl Lower for ast::Closure {
 
     generator.llvm_builder.position_at_end(llvm_entry_block);
 
-    let yielded_result = self.body.lower(generator, cache, false);
+    // The drop flags of the enclosing function live on its own stack, so moving
+    // ... out of a captured binding must not clear them from within the closure.
+    let llvm_enclosing_drop_flags = std::mem::take(&mut generator.llvm_drop_flags);
+
+    // The outermost scope holds the parameters, which are owned by the closure.
+    generator.cleanup_scopes = vec![Vec::new()];
+    generator.loop_cleanup_depth = 0;
+
+    // A closure may be called after the bounds guards of the branch that
+    // ... creates it cease to hold, so its indexing is always checked.
+    generator.bound_checked_arrays.clear();
+
+    // The environment is always the first parameter, which shifts
+    // the positions of the prototype's own parameters by one.
+    for parameter in &self.prototype.parameters {
+      let llvm_parameter = llvm_function.get_nth_param(parameter.position + 1).unwrap();
+
+      llvm_parameter.set_name(format!("param.{}", parameter.name).as_str());
+
+      generator
+        .llvm_cached_values
+        .insert(parameter.cache_id, llvm_parameter);
+    }
+
+    let llvm_environment_parameter = llvm_function.get_first_param().unwrap();
+
+    llvm_environment_parameter.set_name("env");
+
+    // Within the closure's body, references to captured bindings must resolve
+    // to the environment instead of the enclosing function's values. Those are
+    // temporarily overridden, and restored once the body has been lowered.
+    let mut llvm_overridden_values = Vec::new();
+
+    if !self.captures.is_empty() {
+      let llvm_environment_pointer = generator.llvm_builder.build_pointer_cast(
+        llvm_environment_parameter.into_pointer_value(),
+        llvm_environment_type.ptr_type(inkwell::AddressSpace::Generic),
+        "env.cast",
+      );
+
+      for (index, capture) in self.captures.iter().enumerate() {
+        let capture_target_id = capture.target_id.unwrap();
+
+        let llvm_field_gep = generator
+          .llvm_builder
+          .build_struct_gep(llvm_environment_pointer, index as u32, "env.capture")
+          .unwrap();
+
+        // Parameters are values rather than pointers, so they must be loaded.
+        // Bindings captured by reference hold a pointer to the binding itself.
+        let is_parameter = matches!(
+          cache.force_get(&capture_target_id),
+          ast::NodeKind::Parameter(_)
+        );
+
+        let llvm_capture_value =
+          if is_parameter || capture.mode == ast::CaptureMode::ByReference {
+            generator.access(llvm_field_gep)
+          } else {
+            llvm_field_gep.as_basic_value_enum()
+          };
+
+        llvm_overridden_values.push((
+          capture_target_id,
+          generator
+            .llvm_cached_values
+            .insert(capture_target_id, llvm_capture_value),
+        ));
+      }
+    }
+
+    // The environment is always the first parameter.
+    generator.register_parameter_drops(&self.prototype.parameters, llvm_function, 1, cache);
+    generator.return_type_buffer = Some(return_type);
+
+    let yielded_result = generator.lower_block(&self.body, true, cache);
+
+    if generator.get_current_block().get_terminator().is_none() {
+      generator.build_cleanups(0, cache);
+    }
 
     generator.attempt_build_return(yielded_result);
 
     // FIXME: Might be missing the same check for never type as function.
 
-    let result = llvm_function.as_global_value().as_basic_value_enum();
+    for (capture_target_id, llvm_overridden_value) in llvm_overridden_values {
+      if let Some(llvm_overridden_value) = llvm_overridden_value {
+        generator
+          .llvm_cached_values
+          .insert(capture_target_id, llvm_overridden_value);
+      } else {
+        generator.llvm_cached_values.remove(&capture_target_id);
+      }
+    }
 
+    generator.llvm_drop_flags = llvm_enclosing_drop_flags;
     generator.restore_buffers(buffers);
 
-    Some(result)
+    // Closures are represented as a pair of their function and their environment.
+    let llvm_closure_type = generator.lower_closure_type(llvm_function_type);
+
+    let llvm_closure = generator
+      .llvm_builder
+      .build_insert_value(
+        llvm_closure_type.get_undef(),
+        llvm_function.as_global_value().as_pointer_value(),
+        0,
+        "closure.function",
+      )
+      .unwrap();
+
+    let llvm_closure = generator
+      .llvm_builder
+      .build_insert_value(llvm_closure, llvm_environment, 1, "closure")
+      .unwrap();
+
+    Some(llvm_closure.into_struct_value().as_basic_value_enum())
   }
 }
 
@@ -256,21 +541,24 @@ impl Lower for ast::IntrinsicCall {
   ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
     // REVIEW: No need to use the `access` parameter?
 
-    let llvm_arguments = self
-      .arguments
-      .iter()
-      .map(|node| node.kind.lower(generator, cache, true).unwrap().into())
-      .collect::<Vec<inkwell::values::BasicMetadataValueEnum<'ctx>>>();
-
     match self.kind {
       ast::IntrinsicKind::LengthOf => {
         let target_array = self.arguments.first().unwrap();
 
         let array_static_length = match target_array.kind.infer_flatten_type(cache) {
           ast::Type::Array(_, length) => length,
+          ast::Type::Slice(_) | ast::Type::Basic(ast::BasicType::OwnedString) => {
+            let (_, llvm_slice_length) = generator.lower_slice_parts(target_array, cache);
+
+            return Some(llvm_slice_length.as_basic_value_enum());
+          }
           _ => unreachable!(),
         };
 
+        // NOTE: The length of arrays is known statically, but the array is
+        // ... still evaluated, as it may have side-effects (ex. a call).
+        target_array.kind.lower(generator, cache, true);
+
         Some(
           generator
             .llvm_context
@@ -279,6 +567,252 @@ impl Lower for ast::IntrinsicCall {
             .as_basic_value_enum(),
         )
       }
+      ast::IntrinsicKind::Panic => {
+        let llvm_message = self.arguments[0]
+          .kind
+          .lower(generator, cache, true)
+          .unwrap()
+          .into_pointer_value();
+
+        generator.build_panic(llvm_message, self.position);
+
+        None
+      }
+      // NOTE: The format string is translated during lowering, thus
+      // ... the arguments of print intrinsics are lowered separately.
+      ast::IntrinsicKind::Print | ast::IntrinsicKind::Println => {
+        generator.build_print(
+          &self.arguments,
+          self.kind == ast::IntrinsicKind::Println,
+          cache,
+        );
+
+        None
+      }
+      ast::IntrinsicKind::Sizeof | ast::IntrinsicKind::Alignof | ast::IntrinsicKind::Offsetof => {
+        let llvm_type = generator.memoize_or_retrieve_type(&self.type_arguments[0], cache);
+
+        let value = match self.kind {
+          ast::IntrinsicKind::Sizeof => generator.llvm_target_data.get_abi_size(&llvm_type),
+          ast::IntrinsicKind::Alignof => {
+            generator.llvm_target_data.get_abi_alignment(&llvm_type) as u64
+          }
+          _ => {
+            let struct_type = match self.type_arguments[0].flatten(cache) {
+              ast::Type::Struct(struct_type) => struct_type,
+              _ => unreachable!(),
+            };
+
+            // NOTE: The field is verified to exist during type-checking.
+            let field_index = struct_type
+              .fields
+              .iter()
+              .position(|field| Some(&field.0) == self.field_name.as_ref())
+              .unwrap();
+
+            generator
+              .llvm_target_data
+              .offset_of_element(&llvm_type.into_struct_type(), field_index as u32)
+              .unwrap()
+          }
+        };
+
+        Some(
+          generator
+            .lower_int_type(&ast::IntSize::Usize)
+            .const_int(value, false)
+            .as_basic_value_enum(),
+        )
+      }
+      ast::IntrinsicKind::TypeName => Some(
+        generator
+          .llvm_builder
+          .build_global_string_ptr(self.type_arguments[0].name().as_str(), "type_name")
+          .as_basic_value_enum(),
+      ),
+      ast::IntrinsicKind::Assert => {
+        let llvm_condition = self.arguments[0]
+          .kind
+          .lower(generator, cache, true)
+          .unwrap()
+          .into_int_value();
+
+        let llvm_message = self.arguments[1]
+          .kind
+          .lower(generator, cache, true)
+          .unwrap()
+          .into_pointer_value();
+
+        generator.build_assert(llvm_condition, llvm_message, self.position);
+
+        None
+      }
+      ast::IntrinsicKind::Unreachable => {
+        generator.llvm_builder.build_unreachable();
+
+        None
+      }
+      // Static assertions are verified during type-checking.
+      ast::IntrinsicKind::StaticAssert => None,
+      ast::IntrinsicKind::Alloc => {
+        let llvm_type = generator.memoize_or_retrieve_type(&self.type_arguments[0], cache);
+
+        let llvm_size = generator
+          .lower_int_type(&ast::IntSize::Usize)
+          .const_int(generator.llvm_target_data.get_abi_size(&llvm_type), false);
+
+        Some(
+          generator
+            .build_allocation(llvm_type, llvm_size, "alloc")
+            .as_basic_value_enum(),
+        )
+      }
+      ast::IntrinsicKind::AllocArray => {
+        let llvm_type = generator.memoize_or_retrieve_type(&self.type_arguments[0], cache);
+
+        let llvm_count = self.arguments[0]
+          .kind
+          .lower(generator, cache, true)
+          .unwrap()
+          .into_int_value();
+
+        let llvm_size = generator.build_block_size(llvm_type, llvm_count, self.position);
+
+        Some(
+          generator
+            .build_allocation(llvm_type, llvm_size, "alloc_array")
+            .as_basic_value_enum(),
+        )
+      }
+      ast::IntrinsicKind::Free => {
+        let llvm_pointer = self.arguments[0]
+          .kind
+          .lower(generator, cache, true)
+          .unwrap()
+          .into_pointer_value();
+
+        generator.llvm_builder.build_free(llvm_pointer);
+
+        None
+      }
+      ast::IntrinsicKind::Resize => {
+        let llvm_type = generator.memoize_or_retrieve_type(&self.type_arguments[0], cache);
+
+        let llvm_pointer = self.arguments[0]
+          .kind
+          .lower(generator, cache, true)
+          .unwrap()
+          .into_pointer_value();
+
+        let llvm_count = self.arguments[1]
+          .kind
+          .lower(generator, cache, true)
+          .unwrap()
+          .into_int_value();
+
+        let llvm_size = generator.build_block_size(llvm_type, llvm_count, self.position);
+
+        Some(
+          generator
+            .build_resize(llvm_pointer, llvm_size)
+            .as_basic_value_enum(),
+        )
+      }
+      ast::IntrinsicKind::Copy | ast::IntrinsicKind::Move => {
+        let llvm_type = generator.memoize_or_retrieve_type(&self.type_arguments[0], cache);
+        let alignment = generator.llvm_target_data.get_abi_alignment(&llvm_type);
+
+        let llvm_destination = self.arguments[0]
+          .kind
+          .lower(generator, cache, true)
+          .unwrap()
+          .into_pointer_value();
+
+        let llvm_source = self.arguments[1]
+          .kind
+          .lower(generator, cache, true)
+          .unwrap()
+          .into_pointer_value();
+
+        let llvm_count = self.arguments[2]
+          .kind
+          .lower(generator, cache, true)
+          .unwrap()
+          .into_int_value();
+
+        let llvm_size = generator.build_block_size(llvm_type, llvm_count, self.position);
+
+        if self.kind == ast::IntrinsicKind::Copy {
+          generator
+            .llvm_builder
+            .build_memcpy(
+              llvm_destination,
+              alignment,
+              llvm_source,
+              alignment,
+              llvm_size,
+            )
+            .unwrap();
+        } else {
+          generator
+            .llvm_builder
+            .build_memmove(
+              llvm_destination,
+              alignment,
+              llvm_source,
+              alignment,
+              llvm_size,
+            )
+            .unwrap();
+        }
+
+        None
+      }
+      ast::IntrinsicKind::ToString => {
+        let llvm_str = self.arguments[0]
+          .kind
+          .lower(generator, cache, true)
+          .unwrap()
+          .into_pointer_value();
+
+        Some(generator.build_string_from_str(llvm_str))
+      }
+      ast::IntrinsicKind::ToStr => {
+        let (llvm_data, _) = generator.lower_slice_parts(&self.arguments[0], cache);
+
+        Some(llvm_data.as_basic_value_enum())
+      }
+      ast::IntrinsicKind::Append => {
+        let llvm_target = self.arguments[0]
+          .kind
+          .lower(generator, cache, true)
+          .unwrap()
+          .into_pointer_value();
+
+        let llvm_other = self.arguments[1]
+          .kind
+          .lower(generator, cache, true)
+          .unwrap();
+        let llvm_other = generator.attempt_access(llvm_other);
+        let llvm_append_function = generator.get_or_insert_string_append_function();
+
+        generator.llvm_builder.build_call(
+          llvm_append_function,
+          &[llvm_target.into(), llvm_other.into()],
+          "",
+        );
+
+        generator.build_free_if_temporary_string(&self.arguments[1], llvm_other, cache);
+
+        None
+      }
+      ast::IntrinsicKind::FreeString => {
+        let (llvm_data, _) = generator.lower_slice_parts(&self.arguments[0], cache);
+
+        generator.llvm_builder.build_free(llvm_data);
+
+        None
+      }
     }
   }
 }
@@ -316,19 +850,66 @@ impl Lower for ast::StructValue {
       format!("struct.{}.alloca", self.struct_name).as_str(),
     );
 
-    for (index, field) in self.fields.iter().enumerate() {
+    let struct_type = match cache.force_get(&self.target_id.unwrap()) {
+      ast::NodeKind::StructType(struct_type) => struct_type,
+      _ => unreachable!(),
+    };
+
+    for (index, field_type) in struct_type.fields.iter().map(|field| &field.1).enumerate() {
       let struct_field_gep = generator
         .llvm_builder
         // REVIEW: Is this conversion safe?
         .build_struct_gep(llvm_struct_alloca, index as u32, "struct.alloca.field.gep")
         .unwrap();
 
-      let llvm_field_value = field.lower(generator, cache, true).unwrap();
+      // Omitted trailing fields take their default values, which
+      // has been verified to exist during type-checking.
+      let llvm_field_value = if let Some(field) = self.fields.get(index) {
+        let llvm_field_value = generator.lower_coerced(field, field_type, cache).unwrap();
 
-      generator
-        .llvm_builder
-        // FIXME: For nested structs, they will return `None`.
-        .build_store(struct_field_gep, llvm_field_value);
+        generator.build_move(&field.kind, cache);
+
+        llvm_field_value
+      } else {
+        struct_type.field_defaults[index]
+          .as_ref()
+          .unwrap()
+          .lower(generator, cache, true)
+          .unwrap()
+      };
+
+      // Function-typed fields hold closures, so named functions must be wrapped.
+      let llvm_field_value = match field_type.flatten(cache) {
+        ast::Type::Function(function_type) if !function_type.is_extern => {
+          generator.attempt_wrap_in_closure(llvm_field_value)
+        }
+        _ => llvm_field_value,
+      };
+
+      generator
+        .llvm_builder
+        // FIXME: For nested structs, they will return `None`.
+        .build_store(struct_field_gep, llvm_field_value);
+    }
+
+    // Invoke the constructor, if any is defined.
+    let constructor_id = cache
+      .struct_impls
+      .get(&struct_type.cache_id)
+      .and_then(|methods| methods.iter().find(|method| method.1 == CONSTRUCTOR_NAME))
+      .map(|method| method.0);
+
+    if let Some(constructor_id) = constructor_id {
+      let llvm_constructor = generator
+        .memoize_or_retrieve_value(constructor_id, cache, false, false)
+        .unwrap()
+        .into_pointer_value();
+
+      generator.llvm_builder.build_call(
+        inkwell::values::CallableValue::try_from(llvm_constructor).unwrap(),
+        &[llvm_struct_alloca.into()],
+        "constructor.call",
+      );
     }
 
     Some(if access {
@@ -382,17 +963,46 @@ impl Lower for ast::AssignStmt {
     cache: &cache::Cache,
     _access: bool,
   ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
+    let assignee_type = self.assignee_expr.kind.infer_flatten_type(cache);
+
     // REVIEW: Is the `access` parameter necessary here? Or default to `false` instead?
-    let llvm_value = self.value.lower(generator, cache, true).unwrap();
+    let llvm_value = generator
+      .lower_coerced(&self.value, &assignee_type, cache)
+      .unwrap();
+
+    generator.build_move(&self.value.kind, cache);
 
     // NOTE: In the case that our target is a let-statement (through
     // a reference), memoization or retrieval will occur on the lowering
     // step of the reference. The assignee should also not be accessed here.
-    let llvm_assignee = self.assignee_expr.lower(generator, cache, false).unwrap();
+    let llvm_assignee = self
+      .assignee_expr
+      .lower(generator, cache, false)
+      .unwrap()
+      .into_pointer_value();
+
+    // The previous value of a binding which is dropped is overwritten, so it
+    // ... must be dropped first, unless it was moved out. The binding owns the
+    // ... new value afterwards.
+    if let ast::NodeKind::Reference(reference) = &self.assignee_expr.kind {
+      let target_id = reference.pattern.target_id.unwrap();
+
+      if let Some(llvm_drop_flag) = generator.llvm_drop_flags.get(&target_id).copied() {
+        let drop_method_id = generator.find_drop_method(&assignee_type, cache).unwrap();
+
+        generator.build_drop(llvm_assignee, llvm_drop_flag, drop_method_id, cache);
+        generator
+          .llvm_builder
+          .build_store(llvm_assignee, llvm_value);
+        generator.set_drop_flag(target_id, true);
+
+        return None;
+      }
+    }
 
     generator
       .llvm_builder
-      .build_store(llvm_assignee.into_pointer_value(), llvm_value);
+      .build_store(llvm_assignee, llvm_value);
 
     None
   }
@@ -402,12 +1012,15 @@ impl Lower for ast::ContinueStmt {
   fn lower<'a, 'ctx>(
     &self,
     generator: &mut LlvmGenerator<'a, 'ctx>,
-    _cache: &cache::Cache,
+    cache: &cache::Cache,
     _access: bool,
   ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
+    generator.build_cleanups(generator.loop_cleanup_depth, cache);
+
+    // NOTE: By this point, we assume that whether we're actually in a loop was handled by the type-checker.
     generator
       .llvm_builder
-      .build_unconditional_branch(generator.get_current_block());
+      .build_unconditional_branch(generator.current_continue_block.unwrap());
 
     None
   }
@@ -418,44 +1031,185 @@ impl Lower for ast::IndexingExpr {
     &self,
     generator: &mut LlvmGenerator<'a, 'ctx>,
     cache: &cache::Cache,
-    _access: bool,
+    access: bool,
   ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
     // TODO: Consider adding support for indexing strings (or better yet, generalized indexing implementation).
 
-    let llvm_index = self
-      .index_expr
-      .lower(generator, cache, false)
-      .unwrap()
-      .into_int_value();
+    let llvm_index_value = self.index_expr.lower(generator, cache, false).unwrap();
+    let llvm_index = generator.attempt_access(llvm_index_value).into_int_value();
+    let target_type = self.target_expr.kind.infer_flatten_type(cache);
 
-    // REVIEW: Opted not to use access rules. Ensure this is correct.
-    let llvm_target_array = generator
-      .memoize_or_retrieve_value(self.target_id.unwrap(), cache, false, false)
-      .unwrap();
+    let llvm_element_ptr = if matches!(target_type, ast::Type::Pointer(_)) {
+      // NOTE: The target is accessed, in order to retrieve the pointer
+      // ... itself instead of the location where it is stored.
+      let llvm_target_pointer = self
+        .target_expr
+        .lower(generator, cache, true)
+        .unwrap()
+        .into_pointer_value();
+
+      unsafe {
+        generator.llvm_builder.build_in_bounds_gep(
+          llvm_target_pointer,
+          &[llvm_index],
+          "pointer.index.gep",
+        )
+      }
+    } else if matches!(
+      target_type,
+      ast::Type::Slice(_) | ast::Type::Basic(ast::BasicType::OwnedString)
+    ) {
+      let (llvm_slice_pointer, llvm_slice_length) =
+        generator.lower_slice_parts(&self.target_expr, cache);
+
+      // The length of slices and strings is only known at runtime, so
+      // ... their indices are always checked.
+      generator.build_bounds_check(llvm_index, llvm_slice_length, self.position);
+
+      unsafe {
+        generator.llvm_builder.build_in_bounds_gep(
+          llvm_slice_pointer,
+          &[llvm_index],
+          "slice.index.gep",
+        )
+      }
+    } else {
+      let llvm_target_array = self.target_expr.lower(generator, cache, false).unwrap();
+
+      let array_length = match target_type {
+        ast::Type::Array(_, length) => length,
+        _ => unreachable!(),
+      };
+
+      // Constant indices are verified to be within bounds during type-checking.
+      if !self.index_expr.kind.is_constant_expr(cache) && !generator.is_bounds_guarded(self) {
+        let llvm_array_length = llvm_index.get_type().const_int(array_length as u64, false);
+
+        generator.build_bounds_check(llvm_index, llvm_array_length, self.position);
+      }
+
+      let llvm_target_array_ptr = generator.spill_if_value(llvm_target_array, "array.index.value");
 
-    // TODO: Need a way to handle possible segfaults (due to an index being out-of-bounds).
-    unsafe {
       // REVIEW: Figure out why there's a zero index (may want to look on `https://www.llvm.org/docs/GetElementPtr.html#why-is-the-extra-0-index-required`).
       let first_index = generator.llvm_context.i32_type().const_int(0, false);
 
-      let llvm_gep_ptr = generator.llvm_builder.build_in_bounds_gep(
-        llvm_target_array.into_pointer_value(),
-        &[first_index, llvm_index],
-        "array.index.gep",
+      unsafe {
+        generator.llvm_builder.build_in_bounds_gep(
+          llvm_target_array_ptr,
+          &[first_index, llvm_index],
+          "array.index.gep",
+        )
+      }
+    };
+
+    // The element's address is yielded when not accessed, which allows
+    // for chaining (ex. nested arrays), and assignment.
+    Some(if access {
+      generator.access(llvm_element_ptr)
+    } else {
+      llvm_element_ptr.as_basic_value_enum()
+    })
+  }
+}
+
+impl Lower for ast::InterpolationExpr {
+  fn lower<'a, 'ctx>(
+    &self,
+    generator: &mut LlvmGenerator<'a, 'ctx>,
+    cache: &cache::Cache,
+    _access: bool,
+  ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
+    Some(generator.build_interpolation(self, cache))
+  }
+}
+
+impl Lower for ast::SliceExpr {
+  fn lower<'a, 'ctx>(
+    &self,
+    generator: &mut LlvmGenerator<'a, 'ctx>,
+    cache: &cache::Cache,
+    _access: bool,
+  ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
+    let (llvm_target_pointer, llvm_target_length) =
+      generator.lower_slice_parts(&self.target_expr, cache);
+
+    let llvm_start_value = self.start_expr.lower(generator, cache, false).unwrap();
+    let llvm_start = generator.attempt_access(llvm_start_value).into_int_value();
+    let llvm_end_value = self.end_expr.lower(generator, cache, false).unwrap();
+    let llvm_end = generator.attempt_access(llvm_end_value).into_int_value();
+
+    let is_statically_verified = self.start_expr.kind.is_constant_expr(cache)
+      && self.end_expr.kind.is_constant_expr(cache)
+      && matches!(
+        self.target_expr.kind.infer_flatten_type(cache),
+        ast::Type::Array(..)
+      );
+
+    // Constant bounds on static arrays are verified during type-checking.
+    if !is_statically_verified {
+      let llvm_is_ordered = generator.llvm_builder.build_int_compare(
+        inkwell::IntPredicate::ULE,
+        llvm_start,
+        llvm_end,
+        "slice.is_ordered",
       );
 
-      let target_indexable_type = cache.force_get(&self.target_id.unwrap()).infer_type(cache);
+      let llvm_is_within_bounds = generator.llvm_builder.build_int_compare(
+        inkwell::IntPredicate::ULE,
+        llvm_end,
+        llvm_target_length,
+        "slice.is_within_bounds",
+      );
 
-      let target_indexable_size = match target_indexable_type {
-        ast::Type::Array(_, size) => size,
-        _ => unreachable!(),
-      };
+      let llvm_condition =
+        generator
+          .llvm_builder
+          .build_and(llvm_is_ordered, llvm_is_within_bounds, "slice.is_valid");
+
+      let llvm_message = generator
+        .llvm_builder
+        .build_global_string_ptr("slice out of bounds", "panic.message")
+        .as_pointer_value();
+
+      generator.build_assert(llvm_condition, llvm_message, self.position);
+    }
+
+    let llvm_pointer = unsafe {
+      generator
+        .llvm_builder
+        .build_in_bounds_gep(llvm_target_pointer, &[llvm_start], "slice.start")
+    };
+
+    let llvm_length = generator
+      .llvm_builder
+      .build_int_sub(llvm_end, llvm_start, "slice.length");
+
+    // Slices of strings are new strings, which own a copy of the bytes.
+    if matches!(
+      self.target_expr.kind.infer_flatten_type(cache),
+      ast::Type::Basic(ast::BasicType::OwnedString)
+    ) {
+      let llvm_from_bytes_function = generator.get_or_insert_string_from_bytes_function();
 
-      // FIXME: Need to verify proper static+dynamic indexing during type checking.
+      let llvm_string = generator
+        .llvm_builder
+        .build_call(
+          llvm_from_bytes_function,
+          &[llvm_pointer.into(), llvm_length.into()],
+          "string.slice",
+        )
+        .try_as_basic_value()
+        .left();
+
+      // The bytes have been copied, so a temporary target is no longer needed.
+      if LlvmGenerator::is_temporary_string(&self.target_expr, cache) {
+        generator.llvm_builder.build_free(llvm_target_pointer);
+      }
 
-      // REVIEW: Should we actually be de-referencing the pointer here?
-      Some(generator.access(llvm_gep_ptr))
+      return llvm_string;
     }
+
+    Some(generator.build_slice(llvm_pointer, llvm_length))
   }
 }
 
@@ -469,7 +1223,13 @@ impl Lower for ast::StaticArrayValue {
     let llvm_values = self
       .elements
       .iter()
-      .map(|element| element.lower(generator, cache, false).unwrap())
+      .map(|element| {
+        let llvm_value = element.lower(generator, cache, true).unwrap();
+
+        generator.build_move(&element.kind, cache);
+
+        llvm_value
+      })
       .collect::<Vec<_>>();
 
     let llvm_array_type = if llvm_values.is_empty() {
@@ -548,6 +1308,25 @@ impl Lower for ast::UnsafeExpr {
   }
 }
 
+impl Lower for ast::DeferStmt {
+  fn lower<'a, 'ctx>(
+    &self,
+    generator: &mut LlvmGenerator<'a, 'ctx>,
+    _cache: &cache::Cache,
+    _access: bool,
+  ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
+    // The body is lowered once per exit path of the enclosing scope, which is
+    // ... sound since the type-checker forbids declaring bindings within it.
+    generator
+      .cleanup_scopes
+      .last_mut()
+      .unwrap()
+      .push(Cleanup::Defer(self.body.as_ref().clone()));
+
+    None
+  }
+}
+
 impl Lower for ast::BinaryExpr {
   fn lower<'a, 'ctx>(
     &self,
@@ -555,20 +1334,129 @@ impl Lower for ast::BinaryExpr {
     cache: &cache::Cache,
     _access: bool,
   ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
-    let mut llvm_left_value = self.left.lower(generator, cache, false).unwrap();
-    let mut llvm_right_value = self.right.lower(generator, cache, false).unwrap();
+    let is_pointer_operand = matches!(
+      self.left.kind.infer_flatten_type(cache),
+      ast::Type::Pointer(_)
+    );
+
+    // Pointer arithmetic is performed in terms of elements, instead of bytes.
+    if is_pointer_operand
+      && matches!(
+        self.operator,
+        ast::OperatorKind::Add | ast::OperatorKind::SubtractOrNegate
+      )
+    {
+      // NOTE: Pointer operands are lowered with access rules, so that only
+      // ... the location they are stored at is accessed, and not their pointee.
+      let llvm_pointer = self
+        .left
+        .lower(generator, cache, true)
+        .unwrap()
+        .into_pointer_value();
+
+      if matches!(
+        self.right.kind.infer_flatten_type(cache),
+        ast::Type::Pointer(_)
+      ) {
+        let llvm_other_pointer = self
+          .right
+          .lower(generator, cache, true)
+          .unwrap()
+          .into_pointer_value();
+
+        // NOTE: The difference is always yielded as a 64-bit integer, which
+        // ... may differ from the width of `Isize` on the target.
+        let llvm_difference = generator.llvm_builder.build_ptr_diff(
+          llvm_pointer,
+          llvm_other_pointer,
+          "pointer.difference_op",
+        );
+
+        return Some(
+          generator
+            .llvm_builder
+            .build_int_cast(
+              llvm_difference,
+              generator.lower_int_type(&ast::IntSize::Isize),
+              "pointer.difference.cast",
+            )
+            .as_basic_value_enum(),
+        );
+      }
+
+      let llvm_offset_value = self.right.lower(generator, cache, false).unwrap();
+      let mut llvm_offset = generator.attempt_access(llvm_offset_value).into_int_value();
+
+      if self.operator == ast::OperatorKind::SubtractOrNegate {
+        llvm_offset = generator
+          .llvm_builder
+          .build_int_neg(llvm_offset, "pointer.offset.negate_op");
+      }
+
+      return Some(
+        unsafe {
+          generator
+            .llvm_builder
+            .build_gep(llvm_pointer, &[llvm_offset], "pointer.offset_op")
+        }
+        .as_basic_value_enum(),
+      );
+    }
+
+    if matches!(
+      self.left.kind.infer_flatten_type(cache),
+      ast::Type::Basic(ast::BasicType::OwnedString)
+    ) {
+      return Some(generator.lower_string_operation(self, cache));
+    }
+
+    let (llvm_left_value, llvm_right_value) = if is_pointer_operand {
+      // Pointers are compared by their addresses, which are unsigned.
+      let llvm_address_type = generator.lower_int_type(&ast::IntSize::Usize);
+
+      let mut lower_address = |operand: &ast::Node| {
+        let llvm_pointer = operand
+          .lower(generator, cache, true)
+          .unwrap()
+          .into_pointer_value();
+
+        generator
+          .llvm_builder
+          .build_ptr_to_int(llvm_pointer, llvm_address_type, "pointer.address")
+          .as_basic_value_enum()
+      };
 
-    // REVIEW: Is it okay to semi-force an access here? Why not instead make use of the `access` parameter?
-    // ... Maybe the operands should always be attempted to be accessed? What about strings? Will they ever be a
-    // ... binary expression's operand?
-    llvm_left_value = generator.attempt_access(llvm_left_value);
-    llvm_right_value = generator.attempt_access(llvm_right_value);
+      (lower_address(&self.left), lower_address(&self.right))
+    } else {
+      let llvm_left_value = self.left.lower(generator, cache, false).unwrap();
+      let llvm_right_value = self.right.lower(generator, cache, false).unwrap();
+
+      // REVIEW: Is it okay to semi-force an access here? Why not instead make use of the `access` parameter?
+      // ... Maybe the operands should always be attempted to be accessed? What about strings? Will they ever be a
+      // ... binary expression's operand?
+      (
+        generator.attempt_access(llvm_left_value),
+        generator.attempt_access(llvm_right_value),
+      )
+    };
 
     // NOTE: By this point, we assume that both values are of the same type.
     let is_int_values = llvm_left_value.is_int_value();
 
-    // TODO: Make use of.
-    // let is_signed = llvm_left_value.into_int_value().get_sign_extended_constant();
+    // LLVM integer types carry no signedness, so it must be determined from
+    // the operands' type instead.
+    let is_signed = matches!(
+      self.left.kind.infer_flatten_type(cache),
+      ast::Type::Basic(ast::BasicType::Int(size)) if size.is_signed()
+    );
+
+    let select_predicate = |signed_predicate, unsigned_predicate| {
+      if is_signed {
+        signed_predicate
+      } else {
+        unsigned_predicate
+      }
+    };
 
     let llvm_operation = match self.operator {
       ast::OperatorKind::Add if is_int_values => generator
@@ -620,8 +1508,7 @@ impl Lower for ast::BinaryExpr {
         )
         .as_basic_value_enum(),
       // BUG: Need to implement static checks for division by zero.
-      // TODO: Support for unsgined division?
-      ast::OperatorKind::Divide if is_int_values => generator
+      ast::OperatorKind::Divide if is_int_values && is_signed => generator
         .llvm_builder
         .build_int_signed_div(
           llvm_left_value.into_int_value(),
@@ -629,6 +1516,14 @@ impl Lower for ast::BinaryExpr {
           "int.divide_op",
         )
         .as_basic_value_enum(),
+      ast::OperatorKind::Divide if is_int_values => generator
+        .llvm_builder
+        .build_int_unsigned_div(
+          llvm_left_value.into_int_value(),
+          llvm_right_value.into_int_value(),
+          "int.divide_op",
+        )
+        .as_basic_value_enum(),
       ast::OperatorKind::Divide => generator
         .llvm_builder
         .build_float_div(
@@ -637,14 +1532,37 @@ impl Lower for ast::BinaryExpr {
           "float.divide_op",
         )
         .as_basic_value_enum(),
+      ast::OperatorKind::Remainder if is_int_values && is_signed => generator
+        .llvm_builder
+        .build_int_signed_rem(
+          llvm_left_value.into_int_value(),
+          llvm_right_value.into_int_value(),
+          "int.remainder_op",
+        )
+        .as_basic_value_enum(),
+      ast::OperatorKind::Remainder if is_int_values => generator
+        .llvm_builder
+        .build_int_unsigned_rem(
+          llvm_left_value.into_int_value(),
+          llvm_right_value.into_int_value(),
+          "int.remainder_op",
+        )
+        .as_basic_value_enum(),
+      ast::OperatorKind::Remainder => generator
+        .llvm_builder
+        .build_float_rem(
+          llvm_left_value.into_float_value(),
+          llvm_right_value.into_float_value(),
+          "float.remainder_op",
+        )
+        .as_basic_value_enum(),
       ast::OperatorKind::LessThan if is_int_values => generator
         .llvm_builder
         .build_int_compare(
-          // TODO: Support for unsigned?
-          inkwell::IntPredicate::SLT,
+          select_predicate(inkwell::IntPredicate::SLT, inkwell::IntPredicate::ULT),
           llvm_left_value.into_int_value(),
           llvm_right_value.into_int_value(),
-          "int.slt_op",
+          "int.lt_op",
         )
         .as_basic_value_enum(),
       ast::OperatorKind::LessThan => generator
@@ -659,11 +1577,10 @@ impl Lower for ast::BinaryExpr {
       ast::OperatorKind::GreaterThan if is_int_values => generator
         .llvm_builder
         .build_int_compare(
-          // TODO: Support for unsigned?
-          inkwell::IntPredicate::SGT,
+          select_predicate(inkwell::IntPredicate::SGT, inkwell::IntPredicate::UGT),
           llvm_left_value.into_int_value(),
           llvm_right_value.into_int_value(),
-          "int.sgt_op",
+          "int.gt_op",
         )
         .as_basic_value_enum(),
       ast::OperatorKind::GreaterThan => generator
@@ -678,10 +1595,10 @@ impl Lower for ast::BinaryExpr {
       ast::OperatorKind::LessThanOrEqual if is_int_values => generator
         .llvm_builder
         .build_int_compare(
-          inkwell::IntPredicate::SLE,
+          select_predicate(inkwell::IntPredicate::SLE, inkwell::IntPredicate::ULE),
           llvm_left_value.into_int_value(),
           llvm_right_value.into_int_value(),
-          "int.sltoe_op",
+          "int.ltoe_op",
         )
         .as_basic_value_enum(),
       ast::OperatorKind::LessThanOrEqual => generator
@@ -696,10 +1613,10 @@ impl Lower for ast::BinaryExpr {
       ast::OperatorKind::GreaterThanOrEqual if is_int_values => generator
         .llvm_builder
         .build_int_compare(
-          inkwell::IntPredicate::SGE,
+          select_predicate(inkwell::IntPredicate::SGE, inkwell::IntPredicate::UGE),
           llvm_left_value.into_int_value(),
           llvm_right_value.into_int_value(),
-          "int.sgtoe_op",
+          "int.gtoe_op",
         )
         .as_basic_value_enum(),
       ast::OperatorKind::GreaterThanOrEqual => generator
@@ -767,6 +1684,48 @@ impl Lower for ast::BinaryExpr {
           "nor_op",
         )
         .as_basic_value_enum(),
+      // The range check `index in length` holds when `0 <= index < length`.
+      // ... Negative values become large unsigned ones, so a single unsigned
+      // ... comparison suffices once both operands have the same width.
+      ast::OperatorKind::In => {
+        let is_right_signed = matches!(
+          self.right.kind.infer_flatten_type(cache),
+          ast::Type::Basic(ast::BasicType::Int(size)) if size.is_signed()
+        );
+
+        let llvm_left_int = llvm_left_value.into_int_value();
+        let llvm_right_int = llvm_right_value.into_int_value();
+
+        let llvm_wider_type = if llvm_left_int.get_type().get_bit_width()
+          >= llvm_right_int.get_type().get_bit_width()
+        {
+          llvm_left_int.get_type()
+        } else {
+          llvm_right_int.get_type()
+        };
+
+        let mut extend = |llvm_int: inkwell::values::IntValue<'ctx>, is_int_signed: bool| {
+          if llvm_int.get_type() == llvm_wider_type {
+            llvm_int
+          } else if is_int_signed {
+            generator
+              .llvm_builder
+              .build_int_s_extend(llvm_int, llvm_wider_type, "in.extend")
+          } else {
+            generator
+              .llvm_builder
+              .build_int_z_extend(llvm_int, llvm_wider_type, "in.extend")
+          }
+        };
+
+        let llvm_index = extend(llvm_left_int, is_signed);
+        let llvm_length = extend(llvm_right_int, is_right_signed);
+
+        generator
+          .llvm_builder
+          .build_int_compare(inkwell::IntPredicate::ULT, llvm_index, llvm_length, "in_op")
+          .as_basic_value_enum()
+      }
       // FIXME: Add the `xor` operator.
       // TODO: Support for when comparing equality of pointers/references.
       // TODO: Support for all operators.
@@ -802,20 +1761,14 @@ impl Lower for ast::LoopStmt {
     cache: &cache::Cache,
     _access: bool,
   ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
-    // FIXME: The condition needs to be re-lowered per iteration.
-    // ... It seems to be lowered per-iteration. Confirm this with unit tests.
-    // NOTE: At this point, the condition should be verified to be a boolean by the type-checker.
-    let llvm_condition = if let Some(condition) = &self.condition {
-      condition
-        .lower(generator, cache, false)
-        .unwrap()
-        .into_int_value()
-    } else {
-      generator.llvm_context.bool_type().const_int(1, false)
-    };
-
     let llvm_current_function = generator.llvm_function_buffer.unwrap();
 
+    // The condition is re-evaluated on each iteration, both when the body
+    // ... falls through and when a `continue` statement is reached.
+    let llvm_condition_block = generator
+      .llvm_context
+      .append_basic_block(llvm_current_function, "loop.condition");
+
     let llvm_then_block = generator
       .llvm_context
       .append_basic_block(llvm_current_function, "loop.then");
@@ -824,7 +1777,22 @@ impl Lower for ast::LoopStmt {
       .llvm_context
       .append_basic_block(llvm_current_function, "loop.after");
 
-    // Build the initial conditional jump to start the loop.
+    generator
+      .llvm_builder
+      .build_unconditional_branch(llvm_condition_block);
+
+    generator.llvm_builder.position_at_end(llvm_condition_block);
+
+    // NOTE: At this point, the condition should be verified to be a boolean by the type-checker.
+    let llvm_condition = if let Some(condition) = &self.condition {
+      condition
+        .lower(generator, cache, false)
+        .unwrap()
+        .into_int_value()
+    } else {
+      generator.llvm_context.bool_type().const_int(1, false)
+    };
+
     generator.llvm_builder.build_conditional_branch(
       llvm_condition,
       llvm_then_block,
@@ -832,45 +1800,157 @@ impl Lower for ast::LoopStmt {
     );
 
     generator.llvm_builder.position_at_end(llvm_then_block);
+
+    let previous_loop_block = generator.current_loop_block;
+    let previous_continue_block = generator.current_continue_block;
+    let previous_loop_cleanup_depth = generator.loop_cleanup_depth;
+
     generator.current_loop_block = Some(llvm_after_block);
+    generator.current_continue_block = Some(llvm_condition_block);
+    generator.loop_cleanup_depth = generator.cleanup_scopes.len();
     self.body.lower(generator, cache, false);
+    generator.current_loop_block = previous_loop_block;
+    generator.current_continue_block = previous_continue_block;
+    generator.loop_cleanup_depth = previous_loop_cleanup_depth;
 
-    // Fallthrough or loop if applicable.
+    // Loop, unless the body has already terminated.
     if generator.get_current_block().get_terminator().is_none() {
-      let llvm_condition_iter = if let Some(condition) = &self.condition {
-        condition
-          .lower(generator, cache, false)
-          .unwrap()
-          .into_int_value()
-      } else {
-        generator.llvm_context.bool_type().const_int(1, false)
-      };
-
-      // FIXME: Ensure this logic is correct (investigate).
-      generator.llvm_builder.build_conditional_branch(
-        llvm_condition_iter,
-        llvm_then_block,
-        llvm_after_block,
-      );
+      generator
+        .llvm_builder
+        .build_unconditional_branch(llvm_condition_block);
     }
 
     generator.llvm_builder.position_at_end(llvm_after_block);
-    generator.current_loop_block = None;
 
     None
   }
 }
 
-// fn test() -> i32 {
-//   let a = if true {
-//     return 0;
-//   };
-
-//   let a = ();
-
-//   return 1;
-// }
-
+impl Lower for ast::ForInStmt {
+  fn lower<'a, 'ctx>(
+    &self,
+    generator: &mut LlvmGenerator<'a, 'ctx>,
+    cache: &cache::Cache,
+    _access: bool,
+  ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
+    let (llvm_pointer, llvm_length) = generator.lower_slice_parts(&self.iterable, cache);
+    let llvm_current_function = generator.llvm_function_buffer.unwrap();
+    let llvm_index_type = generator.llvm_context.i32_type();
+
+    let llvm_index_alloca = generator
+      .llvm_builder
+      .build_alloca(llvm_index_type, "for.index");
+
+    generator
+      .llvm_builder
+      .build_store(llvm_index_alloca, llvm_index_type.const_int(0, false));
+
+    let llvm_condition_block = generator
+      .llvm_context
+      .append_basic_block(llvm_current_function, "for.condition");
+
+    let llvm_body_block = generator
+      .llvm_context
+      .append_basic_block(llvm_current_function, "for.body");
+
+    // Advancing to the next element is shared by the body's fall-through
+    // ... and any `continue` statements within it.
+    let llvm_next_block = generator
+      .llvm_context
+      .append_basic_block(llvm_current_function, "for.next");
+
+    let llvm_after_block = generator
+      .llvm_context
+      .append_basic_block(llvm_current_function, "for.after");
+
+    generator
+      .llvm_builder
+      .build_unconditional_branch(llvm_condition_block);
+
+    generator.llvm_builder.position_at_end(llvm_condition_block);
+
+    let llvm_index = generator.access(llvm_index_alloca).into_int_value();
+
+    let llvm_condition = generator.llvm_builder.build_int_compare(
+      inkwell::IntPredicate::ULT,
+      llvm_index,
+      llvm_length,
+      "for.is_within_bounds",
+    );
+
+    generator.llvm_builder.build_conditional_branch(
+      llvm_condition,
+      llvm_body_block,
+      llvm_after_block,
+    );
+
+    generator.llvm_builder.position_at_end(llvm_body_block);
+
+    // The index is within bounds by the loop's condition, so no bounds check is needed.
+    let llvm_element_ptr = unsafe {
+      generator
+        .llvm_builder
+        .build_in_bounds_gep(llvm_pointer, &[llvm_index], "for.element.gep")
+    };
+
+    let llvm_element = generator.access(llvm_element_ptr);
+
+    // NOTE: The element is a parameter node, whose values are used directly.
+    generator
+      .llvm_cached_values
+      .insert(self.element.cache_id, llvm_element);
+
+    let previous_loop_block = generator.current_loop_block;
+    let previous_continue_block = generator.current_continue_block;
+    let previous_loop_cleanup_depth = generator.loop_cleanup_depth;
+
+    generator.current_loop_block = Some(llvm_after_block);
+    generator.current_continue_block = Some(llvm_next_block);
+    generator.loop_cleanup_depth = generator.cleanup_scopes.len();
+    self.body.lower(generator, cache, false);
+    generator.current_loop_block = previous_loop_block;
+    generator.current_continue_block = previous_continue_block;
+    generator.loop_cleanup_depth = previous_loop_cleanup_depth;
+
+    // Fall through to the next element, unless the body has already terminated.
+    if generator.get_current_block().get_terminator().is_none() {
+      generator
+        .llvm_builder
+        .build_unconditional_branch(llvm_next_block);
+    }
+
+    generator.llvm_builder.position_at_end(llvm_next_block);
+
+    let llvm_next_index = generator.llvm_builder.build_int_add(
+      llvm_index,
+      llvm_index_type.const_int(1, false),
+      "for.index.next",
+    );
+
+    generator
+      .llvm_builder
+      .build_store(llvm_index_alloca, llvm_next_index);
+
+    generator
+      .llvm_builder
+      .build_unconditional_branch(llvm_condition_block);
+
+    generator.llvm_builder.position_at_end(llvm_after_block);
+
+    None
+  }
+}
+
+// fn test() -> i32 {
+//   let a = if true {
+//     return 0;
+//   };
+
+//   let a = ();
+
+//   return 1;
+// }
+
 impl Lower for ast::IfExpr {
   fn lower<'a, 'ctx>(
     &self,
@@ -948,8 +2028,22 @@ impl Lower for ast::IfExpr {
 
     generator.llvm_builder.position_at_end(llvm_then_block);
 
+    // Indexing within the `then` branch may skip runtime bounds checks, if
+    // the condition guards them.
+    // NOTE: Guards that are already in effect (ex. from an enclosing `if`)
+    // ... are left for the enclosing branch to remove.
+    let bounds_guards =
+      TypeContext::find_bounds_guards(&self.condition.kind, &self.then_expr.kind, cache)
+        .into_iter()
+        .filter(|bounds_guard| generator.bound_checked_arrays.insert(*bounds_guard))
+        .collect::<Vec<_>>();
+
     let llvm_then_block_value = self.then_expr.lower(generator, cache, false);
 
+    for bounds_guard in bounds_guards {
+      generator.bound_checked_arrays.remove(&bounds_guard);
+    }
+
     // FIXME: Is this correct? Or should we be using `get_current_block()` here? Or maybe this is just a special case to not leave the `then` block without a terminator? Investigate.
     // Fallthrough if applicable.
     if generator.get_current_block().get_terminator().is_none() {
@@ -1005,29 +2099,19 @@ impl Lower for ast::Literal {
   ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
     Some(match self {
       ast::Literal::Int(value, integer_kind) => {
-        let llvm_int_type = generator
-          .llvm_context
-          .custom_width_int_type(match integer_kind {
-            ast::IntSize::I8 | ast::IntSize::U8 => 8,
-            ast::IntSize::I16 | ast::IntSize::U16 => 16,
-            ast::IntSize::I32 | ast::IntSize::U32 => 32,
-            ast::IntSize::I64 | ast::IntSize::U64 => 64,
-            ast::IntSize::Isize | ast::IntSize::Usize => 128,
-          });
-
-        llvm_int_type
-          .const_int(
-            value.clone(),
-            match integer_kind {
-              ast::IntSize::I8
-              | ast::IntSize::I16
-              | ast::IntSize::I32
-              | ast::IntSize::I64
-              | ast::IntSize::Isize => true,
-              _ => false,
-            },
-          )
-          .as_basic_value_enum()
+        let llvm_int_type = generator.lower_int_type(integer_kind);
+
+        // NOTE: Values wider than 64 bits must be split into 64-bit words,
+        // least significant word first.
+        if llvm_int_type.get_bit_width() > 64 {
+          llvm_int_type
+            .const_int_arbitrary_precision(&[*value as u64, (*value >> 64) as u64])
+            .as_basic_value_enum()
+        } else {
+          llvm_int_type
+            .const_int(*value as u64, integer_kind.is_signed())
+            .as_basic_value_enum()
+        }
       }
       ast::Literal::Char(value) => generator
         .llvm_context
@@ -1059,7 +2143,15 @@ impl Lower for ast::Function {
     cache: &cache::Cache,
     _access: bool,
   ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
-    let return_type = TypeContext::infer_return_value_type(&self.body, cache);
+    // NOTE: Pointer-sized integers were sized by the type checker using the
+    // ... cache's pointer width, which must match that of the target.
+    assert_eq!(
+      cache.pointer_width,
+      generator.get_pointer_width(),
+      "the cache's pointer width does not match the target's; the target should be set before type-checking"
+    );
+
+    let return_type = TypeContext::infer_function_return_type(&self.prototype, &self.body, cache);
 
     let llvm_function_type = generator.lower_prototype(&self.prototype, &return_type, cache);
 
@@ -1126,7 +2218,20 @@ impl Lower for ast::Function {
 
     generator.llvm_builder.position_at_end(llvm_entry_block);
 
-    let yielded_result = self.body.lower(generator, cache, false);
+    // NOTE: The enclosing cleanup scopes (if any) belong to another function. Those
+    // ... are restored by the caller, since this is lowered through memoization.
+    // The outermost scope holds the parameters, which are owned by the function.
+    generator.cleanup_scopes = vec![Vec::new()];
+    generator.loop_cleanup_depth = 0;
+
+    generator.register_parameter_drops(&self.prototype.parameters, llvm_function, 0, cache);
+    generator.return_type_buffer = Some(return_type);
+
+    let yielded_result = generator.lower_block(&self.body, true, cache);
+
+    if generator.get_current_block().get_terminator().is_none() {
+      generator.build_cleanups(0, cache);
+    }
 
     // FIXME: Abstract this logic for use within `closure`, and possibly wherever else this is needed, guided by calls to `attempt_build_return`?
     // If a block was left for further processing, and it has no terminator,
@@ -1140,6 +2245,8 @@ impl Lower for ast::Function {
     }
 
     generator.llvm_function_buffer = None;
+    generator.return_type_buffer = None;
+    generator.cleanup_scopes.clear();
 
     Some(llvm_function.as_global_value().as_basic_value_enum())
   }
@@ -1159,13 +2266,19 @@ impl Lower for ast::ExternFunction {
       cache,
     );
 
-    // TODO: Need to handle if the function already exists.
-
-    let llvm_external_function = generator.llvm_module.add_function(
-      self.name.as_str(),
-      llvm_function_type,
-      Some(inkwell::module::Linkage::External),
-    );
+    // NOTE: The function may have already been declared (ex. by the panic
+    // ... routine, which relies on `abort` and `dprintf`).
+    // REVIEW: What if the existing declaration has a different signature?
+    let llvm_external_function = generator
+      .llvm_module
+      .get_function(self.name.as_str())
+      .unwrap_or_else(|| {
+        generator.llvm_module.add_function(
+          self.name.as_str(),
+          llvm_function_type,
+          Some(inkwell::module::Linkage::External),
+        )
+      });
 
     Some(
       llvm_external_function
@@ -1182,31 +2295,7 @@ impl Lower for ast::BlockExpr {
     cache: &cache::Cache,
     _access: bool,
   ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
-    for statement in &self.statements {
-      // FIXME: Some binding statements (such as let-statement) need to be manually
-      // ... cached in the generator, this is because not all calls to lower it are made
-      // ... using the `memoize_or_retrieve_value` helper function (such as this one!).
-      statement.lower(generator, cache, false);
-
-      // Do not continue lowering statements if the current block was terminated.
-      if generator.get_current_block().get_terminator().is_some() {
-        break;
-      }
-    }
-
-    // REVIEW: This syntax may replace if/else expressions?
-    // self.yields.as_ref().map(|value| {
-    //   generator
-    //     .lower_with_access_rules(&value.kind, cache)
-    //     // TODO: Why doesn't the one below unwrap?
-    //     .unwrap()
-    // })
-
-    if let Some(yields_value) = &self.yields {
-      generator.lower_with_access_rules(&yields_value.kind, cache)
-    } else {
-      None
-    }
+    generator.lower_block(self, false, cache)
   }
 }
 
@@ -1218,15 +2307,18 @@ impl Lower for ast::ReturnStmt {
     _access: bool,
   ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
     let llvm_return_value = if let Some(return_value) = &self.value {
-      Some(
-        generator
-          .lower_with_access_rules(&return_value.kind, cache)
-          .unwrap(),
-      )
+      let llvm_return_value = generator.lower_returned_value(return_value, cache).unwrap();
+
+      generator.build_move(&return_value.kind, cache);
+
+      Some(llvm_return_value)
     } else {
       None
     };
 
+    // The return value is lowered before any cleanups are run, because
+    // ... those may drop values that it depends upon.
+    generator.build_cleanups(0, cache);
     generator.attempt_build_return(llvm_return_value);
 
     None
@@ -1238,7 +2330,7 @@ impl Lower for ast::UnaryExpr {
     &self,
     generator: &mut LlvmGenerator<'a, 'ctx>,
     cache: &cache::Cache,
-    _access: bool,
+    access: bool,
   ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
     Some(match self.operator {
       ast::OperatorKind::Not => {
@@ -1268,46 +2360,125 @@ impl Lower for ast::UnaryExpr {
             .as_basic_value_enum()
         }
       }
-      ast::OperatorKind::AddressOf => {
-        // FIXME: Also, have to verify lifetimes. This isn't nearly started.
-        // FIXME: Cannot bind references to temporary values. Must only be to existing definitions. This should be enforced during type-checking.
-        // FIXME: The expression shouldn't be accessed in this case. Find out how to accomplish this.
+      // NOTE: References may only be taken of places, which is enforced
+      // ... during type-checking.
+      ast::OperatorKind::AddressOf | ast::OperatorKind::MutableAddressOf => {
+        let llvm_place = self.expr.lower(generator, cache, false).unwrap();
+
+        let is_parameter = match self.expr.kind.flatten() {
+          ast::NodeKind::Reference(reference) => matches!(
+            cache.force_get(&reference.pattern.target_id.unwrap()),
+            ast::NodeKind::Parameter(_)
+          ),
+          _ => false,
+        };
+
+        // Parameters are lowered to their values rather than to a location,
+        // so they must be spilled onto the stack in order to be referenced.
+        if is_parameter {
+          let llvm_parameter_ptr = generator
+            .llvm_builder
+            .build_alloca(llvm_place.get_type(), "param.spill");
 
-        // TODO: For the time being, this isn't being returned. This should be the return value.
-        self.expr.lower(generator, cache, false).unwrap()
+          generator
+            .llvm_builder
+            .build_store(llvm_parameter_ptr, llvm_place);
 
-        // TODO: Continue implementation (if necessary).
-        // todo!()
+          llvm_parameter_ptr.as_basic_value_enum()
+        } else {
+          generator
+            .spill_if_value(llvm_place, "ref.spill")
+            .as_basic_value_enum()
+        }
       }
+      // The pointer (or reference) itself is the place being dereferenced,
+      // which is only accessed if its value is requested.
       ast::OperatorKind::MultiplyOrDereference => {
-        let llvm_value = self.expr.lower(generator, cache, false).unwrap();
+        let llvm_pointer = self
+          .expr
+          .lower(generator, cache, true)
+          .unwrap()
+          .into_pointer_value();
 
-        // BUG: If the value is a reference to a let-statement, the pointer of
-        // ... the let-statement will be removed, but the actual pointer value will
-        // ... not be accessed. Perhaps will need to remove special treatment of let-statements.
-        generator.access(llvm_value.into_pointer_value())
+        if access {
+          generator.access(llvm_pointer)
+        } else {
+          llvm_pointer.as_basic_value_enum()
+        }
       }
       ast::OperatorKind::Cast => {
-        let llvm_value = self.expr.lower(generator, cache, false).unwrap();
-        let llvm_final_value = generator.attempt_access(llvm_value);
+        let cast_type = self.cast_type.as_ref().unwrap();
 
-        let llvm_to_type =
-          generator.memoize_or_retrieve_type(self.cast_type.as_ref().unwrap(), cache);
+        // NOTE: The cast has already been validated during type-checking.
+        let cast_kind = TypeContext::classify_cast(
+          &self.expr.kind.infer_flatten_type(cache),
+          &cast_type.flatten(cache),
+          cache,
+        )
+        .unwrap();
+
+        let llvm_to_type = generator.memoize_or_retrieve_type(cast_type, cache);
+
+        if cast_kind == CastKind::ArrayToPointer {
+          let llvm_array = self.expr.lower(generator, cache, false).unwrap();
 
-        if !llvm_value.is_int_value() {
-          // TODO: Implement for other cases.
-          todo!();
+          let llvm_array_ptr = generator.spill_if_value(llvm_array, "array.decay.value");
+
+          let first_index = generator.llvm_context.i32_type().const_int(0, false);
+
+          return Some(
+            unsafe {
+              generator.llvm_builder.build_in_bounds_gep(
+                llvm_array_ptr,
+                &[first_index, first_index],
+                "array.decay.gep",
+              )
+            }
+            .as_basic_value_enum(),
+          );
         }
 
-        generator.llvm_builder.build_cast(
-          // FIXME: Different instruction opcodes depending on whether the target type
-          // ... is bigger or smaller (extend vs. truncate). As well as from different
-          // ... types of values, such as when going from int to float, etc.
-          inkwell::values::InstructionOpcode::Trunc,
-          llvm_final_value,
-          llvm_to_type,
-          "cast_op",
-        )
+        // NOTE: Pointer values must not be accessed, as that would instead
+        // ... load the value they point to.
+        let llvm_final_value = if matches!(
+          cast_kind,
+          CastKind::PointerToPointer | CastKind::PointerToInt
+        ) {
+          self.expr.lower(generator, cache, true).unwrap()
+        } else {
+          let llvm_value = self.expr.lower(generator, cache, false).unwrap();
+
+          generator.attempt_access(llvm_value)
+        };
+
+        let opcode = match cast_kind {
+          CastKind::PointerToPointer => inkwell::values::InstructionOpcode::BitCast,
+          CastKind::PointerToInt => inkwell::values::InstructionOpcode::PtrToInt,
+          CastKind::IntToPointer => inkwell::values::InstructionOpcode::IntToPtr,
+          CastKind::Int { is_source_signed } => {
+            let from_bit_width = llvm_final_value.into_int_value().get_type().get_bit_width();
+            let to_bit_width = llvm_to_type.into_int_type().get_bit_width();
+
+            // Whether the value is sign-extended depends on the source type, and
+            // not on the target type.
+            if from_bit_width > to_bit_width {
+              inkwell::values::InstructionOpcode::Trunc
+            } else if from_bit_width < to_bit_width && is_source_signed {
+              inkwell::values::InstructionOpcode::SExt
+            } else if from_bit_width < to_bit_width {
+              inkwell::values::InstructionOpcode::ZExt
+            } else {
+              // Integers of the same bit-width only differ in signedness, which
+              // LLVM does not distinguish.
+              return Some(llvm_final_value);
+            }
+          }
+          CastKind::ArrayToPointer => unreachable!(),
+        };
+
+        generator
+          .llvm_builder
+          .build_cast(opcode, llvm_final_value, llvm_to_type, "cast_op")
       }
       _ => unreachable!(),
     })
@@ -1333,6 +2504,14 @@ impl Lower for ast::BindingStmt {
 
       let result = self.value.lower(generator, cache, false);
 
+      // Named functions bound to a binding become closures, unless they are externs.
+      let result = match &value_type {
+        ast::Type::Function(function_type) if !function_type.is_extern => {
+          result.map(|llvm_value| generator.attempt_wrap_in_closure(llvm_value))
+        }
+        _ => result,
+      };
+
       // REVIEW: Won't let-statements always have a value?
       if let Some(llvm_value) = result {
         generator
@@ -1343,7 +2522,10 @@ impl Lower for ast::BindingStmt {
       return result;
     }
 
-    let llvm_value_result = self.value.lower(generator, cache, true);
+    let binding_type = self.infer_type(cache).flatten(cache);
+    let llvm_value_result = generator.lower_coerced(&self.value, &binding_type, cache);
+
+    generator.build_move(&self.value.kind, cache);
 
     // FIXME: What about for other things that may be in the same situation (their values are unit)?
     // Do not proceed if the value will never evaluate.
@@ -1354,7 +2536,7 @@ impl Lower for ast::BindingStmt {
     }
 
     let llvm_value = llvm_value_result.unwrap();
-    let llvm_type = generator.memoize_or_retrieve_type(&value_type, cache);
+    let llvm_type = generator.memoize_or_retrieve_type(&binding_type, cache);
 
     let llvm_alloca = generator
       .llvm_builder
@@ -1362,6 +2544,10 @@ impl Lower for ast::BindingStmt {
 
     generator.llvm_builder.build_store(llvm_alloca, llvm_value);
 
+    if let Some(drop_method_id) = generator.find_drop_method(&binding_type, cache) {
+      generator.register_drop(self.cache_id, llvm_alloca, drop_method_id);
+    }
+
     let result = llvm_alloca.as_basic_value_enum();
 
     generator.llvm_cached_values.insert(self.cache_id, result);
@@ -1379,32 +2565,60 @@ impl Lower for ast::CallExpr {
     cache: &cache::Cache,
     _access: bool,
   ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
-    let mut llvm_arguments = self
-      .arguments
-      .iter()
-      .map(|node| node.kind.lower(generator, cache, true).unwrap().into())
-      .collect::<Vec<_>>();
+    let parameter_types = match self.callee_expr.kind.infer_flatten_type(cache) {
+      ast::Type::Function(callee_type) => callee_type.parameter_types,
+      _ => Vec::new(),
+    };
 
-    // Insert the instance pointer as the first argument, if applicable.
-    if let ast::NodeKind::MemberAccess(member_access) = &self.callee_expr.kind {
-      // FIXME: This will panic for zero-length vectors. Find another way to prepend elements.
-      llvm_arguments.insert(
-        0,
-        member_access
-          .base_expr
-          .lower(generator, cache, false)
-          .unwrap()
-          .into(),
-      );
+    let mut llvm_arguments = Vec::new();
+
+    for (index, argument) in self.arguments.iter().enumerate() {
+      let parameter_type = parameter_types.get(index).unwrap_or(&ast::Type::Any);
+
+      let llvm_argument = generator
+        .lower_coerced(argument, parameter_type, cache)
+        .unwrap();
+
+      generator.build_move(&argument.kind, cache);
+      llvm_arguments.push(llvm_argument.into());
     }
 
     // BUG: It seems that this is causing stack-overflow because results aren't cached? What's going on? Or maybe it's the parser?
     // REVIEW: Here we opted not to forward buffers. Ensure this is correct.
-    let llvm_target_callable = self
-      .callee_expr
-      .lower(generator, cache, false)
-      .unwrap()
-      .into_pointer_value();
+    let llvm_callee = self.callee_expr.lower(generator, cache, false).unwrap();
+    let llvm_callee = generator.attempt_access(llvm_callee);
+
+    // Closures are pairs of their function and their environment, the latter
+    // of which is passed as the first argument.
+    let llvm_target_callable = if llvm_callee.is_struct_value() {
+      let llvm_closure = llvm_callee.into_struct_value();
+
+      let llvm_environment = generator
+        .llvm_builder
+        .build_extract_value(llvm_closure, 1, "closure.env")
+        .unwrap();
+
+      llvm_arguments.insert(0, llvm_environment.into());
+
+      generator
+        .llvm_builder
+        .build_extract_value(llvm_closure, 0, "closure.function")
+        .unwrap()
+        .into_pointer_value()
+    } else {
+      // Insert the instance pointer as the first argument, if applicable.
+      // Function-typed fields are closures, so only methods may reach this point.
+      if let ast::NodeKind::MemberAccess(member_access) = &self.callee_expr.kind {
+        llvm_arguments.insert(
+          0,
+          generator
+            .lower_member_access_base(member_access, cache)
+            .into(),
+        );
+      }
+
+      llvm_callee.into_pointer_value()
+    };
 
     let llvm_call_value = generator.llvm_builder.build_call(
       inkwell::values::CallableValue::try_from(llvm_target_callable).unwrap(),
@@ -1426,9 +2640,11 @@ impl Lower for ast::BreakStmt {
   fn lower<'a, 'ctx>(
     &self,
     generator: &mut LlvmGenerator<'a, 'ctx>,
-    _cache: &cache::Cache,
+    cache: &cache::Cache,
     _access: bool,
   ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
+    generator.build_cleanups(generator.loop_cleanup_depth, cache);
+
     // NOTE: By this point, we assume that whether we're actually in a loop was handled by the type-checker.
     generator
       .llvm_builder
@@ -1445,15 +2661,60 @@ impl Lower for ast::InlineExprStmt {
     cache: &cache::Cache,
     access: bool,
   ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
+    let drop_method_id = if TypeContext::is_place(&self.expr.kind) {
+      None
+    } else {
+      generator.find_drop_method(&self.expr.kind.infer_type(cache), cache)
+    };
+
+    // A discarded value whose type implements `Drop` is a temporary, which
+    // ... is dropped right away, since nothing else owns it.
+    if let Some(drop_method_id) = drop_method_id {
+      let llvm_value = generator
+        .lower_with_access_rules(&self.expr.kind, cache)
+        .unwrap();
+
+      let llvm_temporary = generator
+        .llvm_builder
+        .build_alloca(llvm_value.get_type(), "drop.temporary");
+
+      generator
+        .llvm_builder
+        .build_store(llvm_temporary, llvm_value);
+      generator.build_drop_call(llvm_temporary, drop_method_id, cache);
+
+      return None;
+    }
+
     // REVIEW: Is it correct to pass the `access` parameter here?
     self.expr.lower(generator, cache, access)
   }
 }
 
+/// Work to be done when a scope is exited, either by falling through
+/// its end or by a `return`, `break` or `continue` statement.
+#[derive(Clone)]
+enum Cleanup<'ctx> {
+  /// Call the `drop` method of a binding or parameter, given a pointer to
+  /// its value, unless its drop flag was cleared because it was moved out.
+  Drop {
+    llvm_value: inkwell::values::PointerValue<'ctx>,
+    llvm_drop_flag: inkwell::values::PointerValue<'ctx>,
+    drop_method_id: cache::Id,
+  },
+  /// Lower the body of a `defer` statement.
+  Defer(ast::Node),
+}
+
 pub struct LlvmGeneratorBuffers<'ctx> {
   current_loop_block: Option<inkwell::basic_block::BasicBlock<'ctx>>,
+  current_continue_block: Option<inkwell::basic_block::BasicBlock<'ctx>>,
   llvm_current_block: Option<inkwell::basic_block::BasicBlock<'ctx>>,
   llvm_function_buffer: Option<inkwell::values::FunctionValue<'ctx>>,
+  return_type_buffer: Option<ast::Type>,
+  cleanup_scopes: Vec<Vec<Cleanup<'ctx>>>,
+  loop_cleanup_depth: usize,
+  bound_checked_arrays: std::collections::HashSet<(cache::Id, cache::Id)>,
 }
 
 pub struct LlvmGenerator<'a, 'ctx> {
@@ -1462,14 +2723,42 @@ pub struct LlvmGenerator<'a, 'ctx> {
   llvm_module: &'a inkwell::module::Module<'ctx>,
   pub(super) llvm_builder: inkwell::builder::Builder<'ctx>,
   pub(super) llvm_function_buffer: Option<inkwell::values::FunctionValue<'ctx>>,
+  /// The type of the values returned by the function being lowered (if any),
+  /// which returned values are coerced into.
+  return_type_buffer: Option<ast::Type>,
   // TODO: Shouldn't this be a vector instead?
   llvm_cached_values: std::collections::HashMap<cache::Id, inkwell::values::BasicValueEnum<'ctx>>,
   llvm_cached_types: std::collections::HashMap<cache::Id, inkwell::types::BasicTypeEnum<'ctx>>,
   /// The next fall-through block (if any).
   pub(super) current_loop_block: Option<inkwell::basic_block::BasicBlock<'ctx>>,
+  /// The block which begins the next iteration of the innermost loop (if any).
+  pub(super) current_continue_block: Option<inkwell::basic_block::BasicBlock<'ctx>>,
   panic_function_cache: Option<inkwell::values::FunctionValue<'ctx>>,
   print_function_cache: Option<inkwell::values::FunctionValue<'ctx>>,
   mangle_counter: usize,
+  /// Pairs of array and index bindings, for which indexing is known to be
+  /// in bounds, because of an enclosing bounds guard.
+  bound_checked_arrays: std::collections::HashSet<(cache::Id, cache::Id)>,
+  /// The data layout of the target, which determines the size of pointer-sized
+  /// integers and the results of `?sizeof` and `?alignof`.
+  llvm_target_data: inkwell::targets::TargetData,
+  /// The positions at which each line of the source file begins, used to
+  /// report source locations on panics. Empty if the source is unknown.
+  source_line_starts: Vec<usize>,
+  /// Tables of variant names, used to print enum values, keyed by the
+  /// enum's cache id.
+  enum_names_cache: std::collections::HashMap<cache::Id, inkwell::values::GlobalValue<'ctx>>,
+  /// Thunks which wrap named functions into closures, keyed by the function's cache id.
+  thunks_cache: std::collections::HashMap<cache::Id, inkwell::values::FunctionValue<'ctx>>,
+  /// The cleanups of each scope of the function being lowered, innermost last.
+  /// Each scope's cleanups run in reverse order of registration.
+  cleanup_scopes: Vec<Vec<Cleanup<'ctx>>>,
+  /// The number of cleanup scopes enclosing the innermost loop. Scopes past
+  /// it are exited by `break` and `continue` statements.
+  loop_cleanup_depth: usize,
+  /// Flags of bindings and parameters which are dropped once their scope is exited,
+  /// keyed by their cache ids. A flag is cleared once its value is moved out.
+  llvm_drop_flags: std::collections::HashMap<cache::Id, inkwell::values::PointerValue<'ctx>>,
 }
 
 impl<'a, 'ctx> LlvmGenerator<'a, 'ctx> {
@@ -1483,15 +2772,114 @@ impl<'a, 'ctx> LlvmGenerator<'a, 'ctx> {
       llvm_module,
       llvm_builder: llvm_context.create_builder(),
       llvm_function_buffer: None,
+      return_type_buffer: None,
       llvm_cached_values: std::collections::HashMap::new(),
       llvm_cached_types: std::collections::HashMap::new(),
       current_loop_block: None,
+      current_continue_block: None,
       panic_function_cache: None,
       print_function_cache: None,
       mangle_counter: 0,
+      bound_checked_arrays: std::collections::HashSet::new(),
+      // Until a target is set, use the module's data layout (which defaults to
+      // LLVM's default data layout).
+      llvm_target_data: inkwell::targets::TargetData::create(
+        llvm_module.get_data_layout().as_str().to_str().unwrap(),
+      ),
+      source_line_starts: Vec::new(),
+      enum_names_cache: std::collections::HashMap::new(),
+      thunks_cache: std::collections::HashMap::new(),
+      cleanup_scopes: Vec::new(),
+      loop_cleanup_depth: 0,
+      llvm_drop_flags: std::collections::HashMap::new(),
     }
   }
 
+  /// Set the source code of the module being lowered, so that
+  /// panics may report the line and column at which they occurred.
+  pub fn set_source(&mut self, source_code: &str) {
+    self.source_line_starts = std::iter::once(0)
+      .chain(
+        source_code
+          .chars()
+          .enumerate()
+          .filter(|(_, character)| *character == '\n')
+          .map(|(index, _)| index + 1),
+      )
+      .collect();
+  }
+
+  /// Resolve a position in the source file into a `module:line:column`
+  /// location. Both line and column are one-based.
+  fn locate(&self, position: usize) -> String {
+    // NOTE: Without the source code, the line and column cannot be determined.
+    if self.source_line_starts.is_empty() {
+      return format!("{}:?:?", self.module_name);
+    }
+
+    let line_index = match self.source_line_starts.binary_search(&position) {
+      Ok(index) => index,
+      Err(index) => index - 1,
+    };
+
+    format!(
+      "{}:{}:{}",
+      self.module_name,
+      line_index + 1,
+      position - self.source_line_starts[line_index] + 1
+    )
+  }
+
+  /// Set the target for which code is generated, given its target triple
+  /// (for example, `x86_64-unknown-linux-gnu`).
+  ///
+  /// This also sets the module's triple and data layout, as well as the
+  /// cache's `pointer_width`, which the type checker relies on to size
+  /// `Isize` and `Usize`. Both are derived from the same target data, so
+  /// this must be called before type-checking.
+  pub fn set_target_triple(
+    &mut self,
+    target_triple: &str,
+    cache: &mut cache::Cache,
+  ) -> Result<(), codespan_reporting::diagnostic::Diagnostic<usize>> {
+    inkwell::targets::Target::initialize_all(&inkwell::targets::InitializationConfig::default());
+
+    let llvm_target_triple = inkwell::targets::TargetTriple::create(target_triple);
+
+    let llvm_target_machine = inkwell::targets::Target::from_triple(&llvm_target_triple)
+      .ok()
+      .and_then(|llvm_target| {
+        llvm_target.create_target_machine(
+          &llvm_target_triple,
+          "generic",
+          "",
+          inkwell::OptimizationLevel::Default,
+          inkwell::targets::RelocMode::Default,
+          inkwell::targets::CodeModel::Default,
+        )
+      })
+      .ok_or_else(|| {
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message(format!("unsupported target triple `{}`", target_triple))
+      })?;
+
+    self.llvm_target_data = llvm_target_machine.get_target_data();
+    self.llvm_module.set_triple(&llvm_target_triple);
+
+    self
+      .llvm_module
+      .set_data_layout(&self.llvm_target_data.get_data_layout());
+
+    cache.pointer_width = self.get_pointer_width();
+
+    Ok(())
+  }
+
+  /// Retrieve the bit-width of pointers on the current target.
+  pub fn get_pointer_width(&self) -> u32 {
+    self.llvm_target_data.get_pointer_byte_size(None) * 8
+  }
+
   /// Lower a node while applying the access rules.
   fn lower_with_access_rules(
     &mut self,
@@ -1533,6 +2921,34 @@ impl<'a, 'ctx> LlvmGenerator<'a, 'ctx> {
     self.attempt_access(llvm_value)
   }
 
+  /// Lower the base expression of a member access into a pointer to its struct.
+  ///
+  /// Pointers and references to structs are de-referenced automatically, which means that
+  /// the innermost pointer itself is yielded instead of the location it is stored at.
+  fn lower_member_access_base(
+    &mut self,
+    member_access: &ast::MemberAccess,
+    cache: &cache::Cache,
+  ) -> inkwell::values::PointerValue<'ctx> {
+    let depth = TypeContext::auto_deref_depth(
+      &member_access.base_expr.kind.infer_flatten_type(cache),
+      cache,
+    );
+
+    let mut llvm_pointer = member_access
+      .base_expr
+      .lower(self, cache, depth > 0)
+      .unwrap()
+      .into_pointer_value();
+
+    // Load the pointers in between, down to the innermost one.
+    for _ in 1..depth {
+      llvm_pointer = self.access(llvm_pointer).into_pointer_value();
+    }
+
+    llvm_pointer
+  }
+
   fn is_callable(llvm_value: inkwell::values::BasicValueEnum<'ctx>) -> bool {
     inkwell::values::CallableValue::try_from(llvm_value.into_pointer_value()).is_ok()
   }
@@ -1540,14 +2956,24 @@ impl<'a, 'ctx> LlvmGenerator<'a, 'ctx> {
   fn copy_buffers(&self) -> LlvmGeneratorBuffers<'ctx> {
     LlvmGeneratorBuffers {
       current_loop_block: self.current_loop_block,
+      current_continue_block: self.current_continue_block,
       llvm_current_block: self.llvm_builder.get_insert_block(),
       llvm_function_buffer: self.llvm_function_buffer,
+      return_type_buffer: self.return_type_buffer.clone(),
+      cleanup_scopes: self.cleanup_scopes.clone(),
+      loop_cleanup_depth: self.loop_cleanup_depth,
+      bound_checked_arrays: self.bound_checked_arrays.clone(),
     }
   }
 
   fn restore_buffers(&mut self, buffers: LlvmGeneratorBuffers<'ctx>) {
     self.current_loop_block = buffers.current_loop_block;
+    self.current_continue_block = buffers.current_continue_block;
     self.llvm_function_buffer = buffers.llvm_function_buffer;
+    self.return_type_buffer = buffers.return_type_buffer;
+    self.cleanup_scopes = buffers.cleanup_scopes;
+    self.loop_cleanup_depth = buffers.loop_cleanup_depth;
+    self.bound_checked_arrays = buffers.bound_checked_arrays;
 
     if let Some(llvm_current_block) = buffers.llvm_current_block {
       self.llvm_builder.position_at_end(llvm_current_block);
@@ -1579,303 +3005,3111 @@ impl<'a, 'ctx> LlvmGenerator<'a, 'ctx> {
       .as_basic_value_enum()
   }
 
-  fn attempt_access(
-    &mut self,
-    llvm_value: inkwell::values::BasicValueEnum<'ctx>,
-  ) -> inkwell::values::BasicValueEnum<'ctx> {
-    if llvm_value.is_pointer_value() && !LlvmGenerator::is_callable(llvm_value) {
-      self.access(llvm_value.into_pointer_value())
-    } else {
-      llvm_value
+  /// Determine whether an indexing expression is guarded by an enclosing
+  /// bounds check, in which case it may not go out of bounds.
+  fn is_bounds_guarded(&self, indexing_expr: &ast::IndexingExpr) -> bool {
+    match (
+      indexing_expr.target_expr.kind.flatten(),
+      indexing_expr.index_expr.kind.flatten(),
+    ) {
+      (ast::NodeKind::Reference(array_reference), ast::NodeKind::Reference(index_reference)) => {
+        self.bound_checked_arrays.contains(&(
+          array_reference.pattern.target_id.unwrap(),
+          index_reference.pattern.target_id.unwrap(),
+        ))
+      }
+      _ => false,
     }
   }
 
-  fn find_type_cache_id(&self, ty: &ast::Type, cache: &cache::Cache) -> Option<cache::Id> {
-    Some(match ty.flatten(cache) {
-      ast::Type::Struct(struct_type) => struct_type.cache_id.clone(),
-      // REVIEW: Any more?
-      _ => return None,
-    })
+  /// Insert a runtime check that panics if the index is not within
+  /// the bounds of an array or slice of the given length.
+  fn build_bounds_check(
+    &mut self,
+    llvm_index: inkwell::values::IntValue<'ctx>,
+    llvm_length: inkwell::values::IntValue<'ctx>,
+    position: usize,
+  ) {
+    let llvm_current_function = self.llvm_function_buffer.unwrap();
+
+    // NOTE: Indices are unsigned, thus a single comparison suffices.
+    let llvm_is_out_of_bounds = self.llvm_builder.build_int_compare(
+      inkwell::IntPredicate::UGE,
+      llvm_index,
+      llvm_length,
+      "bounds_check.is_out_of_bounds",
+    );
+
+    let llvm_fail_block = self
+      .llvm_context
+      .append_basic_block(llvm_current_function, "bounds_check.fail");
+
+    let llvm_ok_block = self
+      .llvm_context
+      .append_basic_block(llvm_current_function, "bounds_check.ok");
+
+    self.llvm_builder.build_conditional_branch(
+      llvm_is_out_of_bounds,
+      llvm_fail_block,
+      llvm_ok_block,
+    );
+
+    self.llvm_builder.position_at_end(llvm_fail_block);
+
+    let llvm_message = self
+      .llvm_builder
+      .build_global_string_ptr("index out of bounds", "panic.message")
+      .as_pointer_value();
+
+    self.build_panic(llvm_message, position);
+    self.llvm_builder.position_at_end(llvm_ok_block);
   }
 
-  // REVIEW: Ensure that this function is tail-recursive.
-  fn lower_type(
+  /// Insert a runtime check that panics with the given message if
+  /// the condition does not hold.
+  fn build_assert(
     &mut self,
-    ty: &ast::Type,
-    cache: &cache::Cache,
-  ) -> inkwell::types::BasicTypeEnum<'ctx> {
-    match ty {
-      ast::Type::Basic(primitive_type) => match primitive_type {
-        ast::BasicType::Bool => self.llvm_context.bool_type().as_basic_type_enum(),
-        ast::BasicType::Int(size) => {
-          // REVIEW: Should we handle unsigned integers here?
-
-          let llvm_int_type = self.llvm_context.custom_width_int_type(match size {
-            ast::IntSize::I8 | ast::IntSize::U8 => 8,
-            ast::IntSize::I16 | ast::IntSize::U16 => 16,
-            ast::IntSize::I32 | ast::IntSize::U32 => 32,
-            ast::IntSize::I64 | ast::IntSize::U64 => 64,
-            ast::IntSize::Isize | ast::IntSize::Usize => 128,
-          });
-
-          llvm_int_type.as_basic_type_enum()
-        }
-        ast::BasicType::Char => self.llvm_context.i8_type().as_basic_type_enum(),
-        ast::BasicType::String => self
-          .llvm_context
-          .i8_type()
-          .ptr_type(inkwell::AddressSpace::Generic)
-          .as_basic_type_enum(),
-        // NOTE: The null primitive type is never lowered, only the nullptr value.
-        ast::BasicType::Null => unreachable!(),
-      },
-      ast::Type::Array(element_type, size) => self
-        .lower_type(&element_type, cache)
-        .array_type(size.clone())
-        .as_basic_type_enum(),
-      ast::Type::Pointer(pointee_type) => self
-        .lower_type(&pointee_type, cache)
-        .ptr_type(inkwell::AddressSpace::Generic)
-        .as_basic_type_enum(),
-      // REVIEW: Is this redundant (because of `StubType`)?
-      ast::Type::Struct(struct_type) => {
-        let llvm_field_types = struct_type
-          .fields
-          .iter()
-          .map(|field| self.lower_type(&field.1, cache))
-          .collect::<Vec<_>>();
+    llvm_condition: inkwell::values::IntValue<'ctx>,
+    llvm_message: inkwell::values::PointerValue<'ctx>,
+    position: usize,
+  ) {
+    let llvm_current_function = self.llvm_function_buffer.unwrap();
 
-        let name = self.mangle_name(&format!("struct.{}", struct_type.name));
-        let llvm_struct_type = self.llvm_context.opaque_struct_type(name.as_str());
+    let llvm_fail_block = self
+      .llvm_context
+      .append_basic_block(llvm_current_function, "assert.fail");
 
-        llvm_struct_type.set_body(llvm_field_types.as_slice(), false);
+    let llvm_ok_block = self
+      .llvm_context
+      .append_basic_block(llvm_current_function, "assert.ok");
 
-        llvm_struct_type.as_basic_type_enum()
-      }
-      // REVIEW: Why not resolve the type if it is a stub type, then proceed to lower it?
-      ast::Type::Stub(stub_type) => {
-        self.memoize_or_retrieve_type_by_binding(stub_type.pattern.target_id.unwrap(), cache)
-      }
-      ast::Type::Function(callable_type) => self
-        .lower_callable_type(callable_type, cache)
-        .ptr_type(inkwell::AddressSpace::Generic)
-        .as_basic_type_enum(),
-      // TODO: Implement.
-      ast::Type::Reference(_reference_type) => todo!(),
-      // TODO: Implement.
-      ast::Type::This(this_type) => {
-        self.memoize_or_retrieve_type_by_binding(this_type.target_id.unwrap(), cache)
-      }
-      // FIXME: Will never be able to treat void type as we expect here, because it is only usable to create void return types!
-      // FIXME: Should be lowering to the void type instead, but not allowed by return type!
-      // FIXME: What about when a resolved function type is encountered? Wouldn't it need to be lowered here?
-      // REVIEW: Consider lowering the unit type as void? Only in case we actually use this, otherwise no. (This also serves as a bug catcher).
-      // ast::Type::Unit => self
-      //   .llvm_context
-      //   .bool_type()
-      //   .ptr_type(inkwell::AddressSpace::Generic)
-      //   .as_basic_type_enum(),
-      // Meta types are never to be lowered.
-      ast::Type::Unit
-      | ast::Type::Error
-      | ast::Type::Variable(_)
-      | ast::Type::Never
-      | ast::Type::Any => {
-        unreachable!()
-      }
-    }
+    self
+      .llvm_builder
+      .build_conditional_branch(llvm_condition, llvm_ok_block, llvm_fail_block);
+
+    self.llvm_builder.position_at_end(llvm_fail_block);
+    self.build_panic(llvm_message, position);
+    self.llvm_builder.position_at_end(llvm_ok_block);
   }
 
-  fn lower_callable_type(
+  /// Build a slice value out of a pointer to its first element and its length.
+  fn build_slice(
     &mut self,
-    function_type: &ast::FunctionType,
-    cache: &cache::Cache,
-  ) -> inkwell::types::FunctionType<'ctx> {
-    let llvm_parameter_types = function_type
-      .parameter_types
-      .iter()
-      .map(|parameter_type| self.lower_type(&parameter_type, cache).into())
-      .collect::<Vec<_>>();
+    llvm_pointer: inkwell::values::PointerValue<'ctx>,
+    llvm_length: inkwell::values::IntValue<'ctx>,
+  ) -> inkwell::values::BasicValueEnum<'ctx> {
+    let llvm_slice_type = self.llvm_context.struct_type(
+      &[
+        llvm_pointer.get_type().as_basic_type_enum(),
+        llvm_length.get_type().as_basic_type_enum(),
+      ],
+      false,
+    );
 
-    let llvm_return_type = self.lower_type(&function_type.return_type, cache);
+    let llvm_slice = self
+      .llvm_builder
+      .build_insert_value(
+        llvm_slice_type.get_undef(),
+        llvm_pointer,
+        0,
+        "slice.pointer",
+      )
+      .unwrap();
 
-    llvm_return_type.fn_type(llvm_parameter_types.as_slice(), function_type.is_variadic)
+    self
+      .llvm_builder
+      .build_insert_value(llvm_slice, llvm_length, 1, "slice.length")
+      .unwrap()
+      .into_struct_value()
+      .as_basic_value_enum()
   }
 
-  /// Returns a new LLVM function type based on the given prototype.
+  /// Lower an array, slice or string into a pointer to its first element
+  /// and its length.
   ///
-  /// The return value is required because the prototype's return type is
-  /// merely an annotation, and is unreliable. Assumes the return type to be
-  /// flat.
-  fn lower_prototype(
+  /// The length of static arrays is known, so it is yielded as a constant.
+  fn lower_slice_parts(
     &mut self,
-    prototype: &ast::Prototype,
-    return_type: &ast::Type,
+    node: &ast::Node,
     cache: &cache::Cache,
-  ) -> inkwell::types::FunctionType<'ctx> {
-    let mut llvm_parameter_types = prototype
-      .parameters
-      .iter()
-      .map(|parameter| self.memoize_or_retrieve_type(&parameter.ty, cache).into())
-      .collect::<Vec<_>>();
+  ) -> (
+    inkwell::values::PointerValue<'ctx>,
+    inkwell::values::IntValue<'ctx>,
+  ) {
+    match node.kind.infer_flatten_type(cache) {
+      ast::Type::Array(_, length) => {
+        let llvm_array = node.lower(self, cache, false).unwrap();
+        let llvm_array_ptr = self.spill_if_value(llvm_array, "array.value");
+        let llvm_first_index = self.llvm_context.i32_type().const_int(0, false);
+
+        let llvm_pointer = unsafe {
+          self.llvm_builder.build_in_bounds_gep(
+            llvm_array_ptr,
+            &[llvm_first_index, llvm_first_index],
+            "array.decay",
+          )
+        };
 
-    if prototype.accepts_instance {
-      let llvm_instance_type =
-        self.memoize_or_retrieve_type_by_binding(prototype.instance_type_id.unwrap(), cache);
+        (
+          llvm_pointer,
+          self.llvm_context.i32_type().const_int(length as u64, false),
+        )
+      }
+      // The layout of strings begins with that of byte slices.
+      ast::Type::Slice(_) | ast::Type::Basic(ast::BasicType::OwnedString) => {
+        let llvm_slice = self
+          .attempt_access(node.lower(self, cache, true).unwrap())
+          .into_struct_value();
 
-      // FIXME: This will panic for zero-length vectors. Find another way to prepend elements.
-      llvm_parameter_types.insert(
-        0,
-        llvm_instance_type
-          .ptr_type(inkwell::AddressSpace::Generic)
-          .into(),
-      );
-    }
+        let llvm_pointer = self
+          .llvm_builder
+          .build_extract_value(llvm_slice, 0, "slice.pointer")
+          .unwrap()
+          .into_pointer_value();
 
-    if !return_type.is_a_never() && !return_type.is_a_unit() {
-      self
-        .memoize_or_retrieve_type(return_type, cache)
-        .fn_type(llvm_parameter_types.as_slice(), prototype.is_variadic)
-        .ptr_type(inkwell::AddressSpace::Generic)
-        .into()
-    } else {
-      self
-        .llvm_context
-        .void_type()
-        .fn_type(llvm_parameter_types.as_slice(), prototype.is_variadic)
-        .ptr_type(inkwell::AddressSpace::Generic)
-        .as_basic_type_enum()
+        let llvm_length = self
+          .llvm_builder
+          .build_extract_value(llvm_slice, 1, "slice.length")
+          .unwrap()
+          .into_int_value();
+
+        (llvm_pointer, llvm_length)
+      }
+      _ => unreachable!(),
     }
-    .into_pointer_type()
-    .get_element_type()
-    .into_function_type()
   }
 
-  // TODO: Ensure that there isn't any possible recursion problems going on.
-  fn memoize_or_retrieve_type(
+  /// Determine whether a value of the given node is coerced from a static
+  /// array into a slice, when used as a value of the given type.
+  fn coerces_array_to_slice(
+    node: &ast::NodeKind,
+    target_type: &ast::Type,
+    cache: &cache::Cache,
+  ) -> bool {
+    matches!(target_type.flatten(cache), ast::Type::Slice(_))
+      && matches!(node.infer_flatten_type(cache), ast::Type::Array(_, _))
+  }
+
+  /// Determine whether values of the given type are closures, rather than
+  /// plain function pointers (which is the case for extern callbacks).
+  fn is_closure_type(ty: &ast::Type, cache: &cache::Cache) -> bool {
+    matches!(
+      ty.flatten(cache),
+      ast::Type::Function(ast::FunctionType {
+        is_extern: false,
+        ..
+      })
+    )
+  }
+
+  /// Lower a value that is about to be stored or passed as a value of
+  /// the given type, coercing static arrays into slices and named functions
+  /// into closures if applicable.
+  fn lower_coerced(
     &mut self,
-    ty: &ast::Type,
+    node: &ast::Node,
+    target_type: &ast::Type,
     cache: &cache::Cache,
-  ) -> inkwell::types::BasicTypeEnum<'ctx> {
-    if let Some(cache_id) = self.find_type_cache_id(ty, cache) {
-      // REVIEW: Isn't this indirectly recursive? Will it cause problems?
-      return self.memoize_or_retrieve_type_by_binding(cache_id, cache);
+  ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
+    // Named functions (including externs) are wrapped wherever closures are
+    // ... expected, while callbacks for externs are kept as plain function pointers.
+    if LlvmGenerator::is_closure_type(target_type, cache) {
+      return node
+        .lower(self, cache, true)
+        .map(|llvm_value| self.attempt_wrap_in_closure(llvm_value));
+    } else if !LlvmGenerator::coerces_array_to_slice(&node.kind, target_type, cache) {
+      return node.lower(self, cache, true);
     }
 
-    self.lower_type(ty, cache)
+    let (llvm_pointer, llvm_length) = self.lower_slice_parts(node, cache);
+
+    Some(self.build_slice(llvm_pointer, llvm_length))
   }
 
-  fn memoize_or_retrieve_type_by_binding(
+  /// Lower a block, yielding the value of its last expression (if any). The
+  /// value yielded by the body of a function is returned by it, and thus
+  /// coerced like returned values.
+  fn lower_block(
     &mut self,
-    cache_id: cache::Id,
+    block: &ast::BlockExpr,
+    is_function_body: bool,
     cache: &cache::Cache,
-  ) -> inkwell::types::BasicTypeEnum<'ctx> {
-    if let Some(existing_definition) = self.llvm_cached_types.get(&cache_id) {
-      return existing_definition.clone();
+  ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
+    self.cleanup_scopes.push(Vec::new());
+
+    for statement in &block.statements {
+      // FIXME: Some binding statements (such as let-statement) need to be manually
+      // ... cached in the generator, this is because not all calls to lower it are made
+      // ... using the `memoize_or_retrieve_value` helper function (such as this one!).
+      statement.lower(self, cache, false);
+
+      // Do not continue lowering statements if the current block was terminated.
+      if self.get_current_block().get_terminator().is_some() {
+        break;
+      }
     }
 
-    // REVIEW: Consider making a separate map for types in the cache.
+    // REVIEW: This syntax may replace if/else expressions?
+    // self.yields.as_ref().map(|value| {
+    //   self
+    //     .lower_with_access_rules(&value.kind, cache)
+    //     // TODO: Why doesn't the one below unwrap?
+    //     .unwrap()
+    // })
 
-    let node = cache.force_get(&cache_id);
+    let llvm_yielded_value = if let Some(yields_value) = &block.yields {
+      let llvm_yielded_value = if is_function_body {
+        self.lower_returned_value(yields_value, cache)
+      } else {
+        self.lower_with_access_rules(&yields_value.kind, cache)
+      };
 
-    // REVIEW: Why not perform type-flattening here instead?
-    let ty = match &node {
-      ast::NodeKind::StructType(struct_type) => ast::Type::Struct(struct_type.clone()),
-      ast::NodeKind::TypeAlias(type_alias) => type_alias.ty.clone(),
-      // REVIEW: Any more?
-      _ => unreachable!(),
+      // The yielded value leaves the block, so it must not be dropped with it.
+      self.build_move(&yields_value.kind, cache);
+
+      llvm_yielded_value
+    } else {
+      None
     };
 
-    let llvm_type = self.lower_type(&ty, cache);
+    // Exiting the block by falling through its end. Other exits (such as `return`)
+    // ... build the cleanups themselves, before terminating the block.
+    if self.get_current_block().get_terminator().is_none() {
+      self.build_cleanups(self.cleanup_scopes.len() - 1, cache);
+    }
 
-    self.llvm_cached_types.insert(cache_id, llvm_type);
+    self.cleanup_scopes.pop();
 
-    llvm_type
+    llvm_yielded_value
   }
 
-  fn get_current_block(&self) -> inkwell::basic_block::BasicBlock<'ctx> {
-    self.llvm_builder.get_insert_block().unwrap()
+  /// Lower a value that is about to be returned from the function being
+  /// lowered, or yielded by its body, coercing static arrays into slices
+  /// if applicable.
+  fn lower_returned_value(
+    &mut self,
+    node: &ast::Node,
+    cache: &cache::Cache,
+  ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
+    match self.return_type_buffer.clone() {
+      Some(return_type)
+        if LlvmGenerator::coerces_array_to_slice(&node.kind, &return_type, cache)
+          || LlvmGenerator::is_closure_type(&return_type, cache) =>
+      {
+        self.lower_coerced(node, &return_type, cache)
+      }
+      _ => self.lower_with_access_rules(&node.kind, cache),
+    }
   }
 
-  fn attempt_build_return(&mut self, return_value: Option<inkwell::values::BasicValueEnum<'ctx>>) {
-    // REVIEW: Consider mixing this with the function's terminator check, for void functions?
-    // Only build a single return instruction per block.
-    if self.get_current_block().get_terminator().is_some() {
-      return;
-    } else if let Some(return_value) = return_value {
-      self.llvm_builder.build_return(Some(&return_value));
-    } else {
-      self.llvm_builder.build_return(None);
+  /// Compute the size in bytes of a block of values of the given type,
+  /// inserting a runtime check that panics if it overflows a pointer-sized
+  /// integer.
+  ///
+  /// The check is omitted if the count is a constant which fits.
+  fn build_block_size(
+    &mut self,
+    llvm_type: inkwell::types::BasicTypeEnum<'ctx>,
+    llvm_count: inkwell::values::IntValue<'ctx>,
+    position: usize,
+  ) -> inkwell::values::IntValue<'ctx> {
+    let element_size = self.llvm_target_data.get_abi_size(&llvm_type);
+
+    let llvm_element_size = self
+      .lower_int_type(&ast::IntSize::Usize)
+      .const_int(element_size, false);
+
+    let maximum_size = match self.get_pointer_width() {
+      width if width >= 64 => u64::MAX,
+      width => (1 << width) - 1,
+    };
+
+    // NOTE: Blocks of zero-sized values never overflow.
+    if let Some(maximum_count) = maximum_size.checked_div(element_size) {
+      if !matches!(llvm_count.get_zero_extended_constant(), Some(count) if count <= maximum_count) {
+        let llvm_fits = self.llvm_builder.build_int_compare(
+          inkwell::IntPredicate::ULE,
+          llvm_count,
+          self
+            .lower_int_type(&ast::IntSize::Usize)
+            .const_int(maximum_count, false),
+          "block_size.fits",
+        );
+
+        let llvm_message = self
+          .llvm_builder
+          .build_global_string_ptr("allocation size overflows", "panic.message")
+          .as_pointer_value();
+
+        self.build_assert(llvm_fits, llvm_message, position);
+      }
     }
+
+    self
+      .llvm_builder
+      .build_int_mul(llvm_count, llvm_element_size, "block.size")
   }
 
-  // REVIEW: Shouldn't this be used for any and all lowering? Possible problems with closures and/or structs if not?
-  /// Attempt to retrieve an existing definition, otherwise proceed to
-  /// lowering it and memoizing it under the current module.
+  /// Allocate a heap block of the given size in bytes, yielding a pointer
+  /// to values of the given type.
   ///
-  /// If specified, any modified buffers during the process will be kept,
-  /// otherwise they will all be restored.
-  fn memoize_or_retrieve_value(
+  /// The builder for `malloc` accepts 32-bit sizes only, so it is declared
+  /// and called directly with a pointer-sized size, reusing any existing
+  /// declaration.
+  fn build_allocation(
     &mut self,
-    cache_id: cache::Id,
+    llvm_type: inkwell::types::BasicTypeEnum<'ctx>,
+    llvm_size: inkwell::values::IntValue<'ctx>,
+    name: &str,
+  ) -> inkwell::values::PointerValue<'ctx> {
+    let llvm_byte_pointer_type = self
+      .llvm_context
+      .i8_type()
+      .ptr_type(inkwell::AddressSpace::Generic);
+
+    let llvm_malloc_function = self.llvm_module.get_function("malloc").unwrap_or_else(|| {
+      self.llvm_module.add_function(
+        "malloc",
+        llvm_byte_pointer_type.fn_type(&[self.lower_int_type(&ast::IntSize::Usize).into()], false),
+        Some(inkwell::module::Linkage::External),
+      )
+    });
+
+    let llvm_pointer = self
+      .llvm_builder
+      .build_call(llvm_malloc_function, &[llvm_size.into()], name)
+      .try_as_basic_value()
+      .left()
+      .unwrap()
+      .into_pointer_value();
+
+    self.llvm_builder.build_pointer_cast(
+      llvm_pointer,
+      llvm_type.ptr_type(inkwell::AddressSpace::Generic),
+      format!("{}.cast", name).as_str(),
+    )
+  }
+
+  /// Reallocate a heap block to the given size in bytes, yielding a
+  /// pointer of the same type as the given one.
+  ///
+  /// There is no builder for `realloc`, so it is declared and called
+  /// directly, reusing any existing declaration.
+  fn build_resize(
+    &mut self,
+    llvm_pointer: inkwell::values::PointerValue<'ctx>,
+    llvm_size: inkwell::values::IntValue<'ctx>,
+  ) -> inkwell::values::PointerValue<'ctx> {
+    let llvm_byte_pointer_type = self
+      .llvm_context
+      .i8_type()
+      .ptr_type(inkwell::AddressSpace::Generic);
+
+    let llvm_realloc_function = self.llvm_module.get_function("realloc").unwrap_or_else(|| {
+      self.llvm_module.add_function(
+        "realloc",
+        llvm_byte_pointer_type.fn_type(
+          &[
+            llvm_byte_pointer_type.into(),
+            self.lower_int_type(&ast::IntSize::Usize).into(),
+          ],
+          false,
+        ),
+        Some(inkwell::module::Linkage::External),
+      )
+    });
+
+    let llvm_byte_pointer =
+      self
+        .llvm_builder
+        .build_pointer_cast(llvm_pointer, llvm_byte_pointer_type, "");
+
+    let llvm_new_pointer = self
+      .llvm_builder
+      .build_call(
+        llvm_realloc_function,
+        &[llvm_byte_pointer.into(), llvm_size.into()],
+        "",
+      )
+      .try_as_basic_value()
+      .left()
+      .unwrap()
+      .into_pointer_value();
+
+    self
+      .llvm_builder
+      .build_pointer_cast(llvm_new_pointer, llvm_pointer.get_type(), "resize")
+  }
+
+  /// Insert a call to the panic routine with the given message, and the
+  /// location of the given position. The current block is terminated,
+  /// since the panic routine never returns.
+  fn build_panic(&mut self, llvm_message: inkwell::values::PointerValue<'ctx>, position: usize) {
+    let llvm_panic_function = self.get_or_insert_panic_function();
+    let location = self.locate(position);
+
+    let llvm_location = self
+      .llvm_builder
+      .build_global_string_ptr(location.as_str(), "panic.location")
+      .as_pointer_value();
+
+    self.llvm_builder.build_call(
+      llvm_panic_function,
+      &[llvm_message.into(), llvm_location.into()],
+      "",
+    );
+
+    self.llvm_builder.build_unreachable();
+  }
+
+  /// Retrieve the panic routine, defining it if it hasn't been already.
+  ///
+  /// The panic routine accepts a message and a location (both strings),
+  /// writes them to the standard error stream, then aborts the program. It
+  /// never returns.
+  fn get_or_insert_panic_function(&mut self) -> inkwell::values::FunctionValue<'ctx> {
+    if let Some(llvm_panic_function) = self.panic_function_cache {
+      return llvm_panic_function;
+    }
+
+    let llvm_noreturn_attribute = self.llvm_context.create_enum_attribute(
+      inkwell::attributes::Attribute::get_named_enum_kind_id("noreturn"),
+      0,
+    );
+
+    let llvm_string_type = self
+      .llvm_context
+      .i8_type()
+      .ptr_type(inkwell::AddressSpace::Generic);
+
+    let llvm_abort_function = self.llvm_module.get_function("abort").unwrap_or_else(|| {
+      self.llvm_module.add_function(
+        "abort",
+        self.llvm_context.void_type().fn_type(&[], false),
+        Some(inkwell::module::Linkage::External),
+      )
+    });
+
+    // NOTE: The `dprintf` function is used (instead of `fprintf`), since
+    // ... the name of the standard error stream symbol is platform-specific.
+    let llvm_dprintf_function = self.llvm_module.get_function("dprintf").unwrap_or_else(|| {
+      self.llvm_module.add_function(
+        "dprintf",
+        self.llvm_context.i32_type().fn_type(
+          &[self.llvm_context.i32_type().into(), llvm_string_type.into()],
+          true,
+        ),
+        Some(inkwell::module::Linkage::External),
+      )
+    });
+
+    let llvm_panic_function_name = self.mangle_name(&String::from("panic"));
+
+    let llvm_panic_function = self.llvm_module.add_function(
+      llvm_panic_function_name.as_str(),
+      self
+        .llvm_context
+        .void_type()
+        .fn_type(&[llvm_string_type.into(), llvm_string_type.into()], false),
+      Some(inkwell::module::Linkage::Private),
+    );
+
+    llvm_abort_function.add_attribute(
+      inkwell::attributes::AttributeLoc::Function,
+      llvm_noreturn_attribute,
+    );
+
+    llvm_panic_function.add_attribute(
+      inkwell::attributes::AttributeLoc::Function,
+      llvm_noreturn_attribute,
+    );
+
+    let buffers = self.copy_buffers();
+
+    let llvm_entry_block = self
+      .llvm_context
+      .append_basic_block(llvm_panic_function, "panic.entry");
+
+    self.llvm_builder.position_at_end(llvm_entry_block);
+
+    let llvm_format = self
+      .llvm_builder
+      .build_global_string_ptr("panic at %s: %s\n", "panic.format")
+      .as_pointer_value();
+
+    // The file descriptor of the standard error stream.
+    let llvm_stderr_fd = self.llvm_context.i32_type().const_int(2, false);
+
+    self.llvm_builder.build_call(
+      llvm_dprintf_function,
+      &[
+        llvm_stderr_fd.into(),
+        llvm_format.into(),
+        llvm_panic_function.get_nth_param(1).unwrap().into(),
+        llvm_panic_function.get_nth_param(0).unwrap().into(),
+      ],
+      "",
+    );
+
+    self.llvm_builder.build_call(llvm_abort_function, &[], "");
+    self.llvm_builder.build_unreachable();
+    self.restore_buffers(buffers);
+    self.panic_function_cache = Some(llvm_panic_function);
+
+    llvm_panic_function
+  }
+
+  /// Lower the type of owned strings: a pointer to their null-terminated
+  /// buffer, followed by their length and capacity in bytes.
+  ///
+  /// The capacity accounts for the null terminator, which isn't part of the length.
+  fn lower_owned_string_type(&self) -> inkwell::types::StructType<'ctx> {
+    self.llvm_context.struct_type(
+      &[
+        self
+          .llvm_context
+          .i8_type()
+          .ptr_type(inkwell::AddressSpace::Generic)
+          .as_basic_type_enum(),
+        self.llvm_context.i32_type().as_basic_type_enum(),
+        self.llvm_context.i32_type().as_basic_type_enum(),
+      ],
+      false,
+    )
+  }
+
+  /// Build an owned string value out of its buffer, length and capacity.
+  fn build_string(
+    &mut self,
+    llvm_data: inkwell::values::PointerValue<'ctx>,
+    llvm_length: inkwell::values::IntValue<'ctx>,
+    llvm_capacity: inkwell::values::IntValue<'ctx>,
+  ) -> inkwell::values::BasicValueEnum<'ctx> {
+    let llvm_string_type = self.lower_owned_string_type();
+
+    let llvm_string = self
+      .llvm_builder
+      .build_insert_value(llvm_string_type.get_undef(), llvm_data, 0, "string.data")
+      .unwrap();
+
+    let llvm_string = self
+      .llvm_builder
+      .build_insert_value(llvm_string, llvm_length, 1, "string.length")
+      .unwrap();
+
+    self
+      .llvm_builder
+      .build_insert_value(llvm_string, llvm_capacity, 2, "string.capacity")
+      .unwrap()
+      .into_struct_value()
+      .as_basic_value_enum()
+  }
+
+  /// Split an owned string value into its buffer, length and capacity.
+  fn build_string_parts(
+    &mut self,
+    llvm_string: inkwell::values::StructValue<'ctx>,
+  ) -> (
+    inkwell::values::PointerValue<'ctx>,
+    inkwell::values::IntValue<'ctx>,
+    inkwell::values::IntValue<'ctx>,
+  ) {
+    let llvm_data = self
+      .llvm_builder
+      .build_extract_value(llvm_string, 0, "string.data")
+      .unwrap()
+      .into_pointer_value();
+
+    let llvm_length = self
+      .llvm_builder
+      .build_extract_value(llvm_string, 1, "string.length")
+      .unwrap()
+      .into_int_value();
+
+    let llvm_capacity = self
+      .llvm_builder
+      .build_extract_value(llvm_string, 2, "string.capacity")
+      .unwrap()
+      .into_int_value();
+
+    (llvm_data, llvm_length, llvm_capacity)
+  }
+
+  /// Create an owned string out of a copy of a null-terminated `Str`.
+  fn build_string_from_str(
+    &mut self,
+    llvm_str: inkwell::values::PointerValue<'ctx>,
+  ) -> inkwell::values::BasicValueEnum<'ctx> {
+    let llvm_length_of_str_function = self.get_or_insert_string_length_of_str_function();
+
+    let llvm_length = self
+      .llvm_builder
+      .build_call(
+        llvm_length_of_str_function,
+        &[llvm_str.into()],
+        "string.length",
+      )
+      .try_as_basic_value()
+      .left()
+      .unwrap()
+      .into_int_value();
+
+    let llvm_from_bytes_function = self.get_or_insert_string_from_bytes_function();
+
+    self
+      .llvm_builder
+      .build_call(
+        llvm_from_bytes_function,
+        &[llvm_str.into(), llvm_length.into()],
+        "string.from_str",
+      )
+      .try_as_basic_value()
+      .left()
+      .unwrap()
+  }
+
+  /// Determine whether a string-typed node yields a new owned string,
+  /// which no binding owns, and must thus be freed once it has been used.
+  fn is_temporary_string(node: &ast::Node, cache: &cache::Cache) -> bool {
+    matches!(
+      node.kind.infer_flatten_type(cache),
+      ast::Type::Basic(ast::BasicType::OwnedString)
+    ) && matches!(
+      node.kind.flatten(),
+      ast::NodeKind::CallExpr(_)
+        | ast::NodeKind::BinaryExpr(_)
+        | ast::NodeKind::IntrinsicCall(_)
+        | ast::NodeKind::SliceExpr(_)
+        | ast::NodeKind::InterpolationExpr(_)
+    )
+  }
+
+  /// Free the buffer of the given owned string, if its node yields a
+  /// temporary string.
+  fn build_free_if_temporary_string(
+    &mut self,
+    node: &ast::Node,
+    llvm_string: inkwell::values::BasicValueEnum<'ctx>,
     cache: &cache::Cache,
-    forward_buffers: bool,
-    apply_access_rules: bool,
-  ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
-    let node = cache.force_get(&cache_id);
+  ) {
+    if LlvmGenerator::is_temporary_string(node, cache) {
+      let llvm_data = self
+        .llvm_builder
+        .build_extract_value(llvm_string.into_struct_value(), 0, "string.data")
+        .unwrap()
+        .into_pointer_value();
+
+      self.llvm_builder.build_free(llvm_data);
+    }
+  }
+
+  /// Lower a binary expression on owned strings, which are either
+  /// concatenated or compared by the string runtime.
+  fn lower_string_operation(
+    &mut self,
+    binary_expr: &ast::BinaryExpr,
+    cache: &cache::Cache,
+  ) -> inkwell::values::BasicValueEnum<'ctx> {
+    let llvm_left_value = binary_expr.left.lower(self, cache, true).unwrap();
+    let llvm_left = self.attempt_access(llvm_left_value);
+    let llvm_right_value = binary_expr.right.lower(self, cache, true).unwrap();
+    let llvm_right = self.attempt_access(llvm_right_value);
+
+    // Comparisons are made against the order, which is negative if the left
+    // ... string sorts first, zero if both are equal, and positive otherwise.
+    let llvm_predicate = match binary_expr.operator {
+      ast::OperatorKind::Add => None,
+      ast::OperatorKind::LessThan => Some(inkwell::IntPredicate::SLT),
+      ast::OperatorKind::GreaterThan => Some(inkwell::IntPredicate::SGT),
+      ast::OperatorKind::LessThanOrEqual => Some(inkwell::IntPredicate::SLE),
+      ast::OperatorKind::GreaterThanOrEqual => Some(inkwell::IntPredicate::SGE),
+      ast::OperatorKind::Equality => Some(inkwell::IntPredicate::EQ),
+      // NOTE: Strings may only be concatenated or compared, as verified by the type-checker.
+      ast::OperatorKind::And
+      | ast::OperatorKind::Or
+      | ast::OperatorKind::Nand
+      | ast::OperatorKind::Nor
+      | ast::OperatorKind::Xor
+      | ast::OperatorKind::Not
+      | ast::OperatorKind::AddressOf
+      | ast::OperatorKind::MutableAddressOf
+      | ast::OperatorKind::SubtractOrNegate
+      | ast::OperatorKind::MultiplyOrDereference
+      | ast::OperatorKind::Divide
+      | ast::OperatorKind::Remainder
+      | ast::OperatorKind::Cast
+      | ast::OperatorKind::In => unreachable!(),
+    };
+
+    let llvm_result = if let Some(llvm_predicate) = llvm_predicate {
+      let llvm_compare_function = self.get_or_insert_string_compare_function();
+
+      let llvm_order = self
+        .llvm_builder
+        .build_call(
+          llvm_compare_function,
+          &[llvm_left.into(), llvm_right.into()],
+          "string.order",
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+      self
+        .llvm_builder
+        .build_int_compare(
+          llvm_predicate,
+          llvm_order,
+          self.llvm_context.i32_type().const_zero(),
+          "string.compare_op",
+        )
+        .as_basic_value_enum()
+    } else {
+      let llvm_concat_function = self.get_or_insert_string_concat_function();
+
+      self
+        .llvm_builder
+        .build_call(
+          llvm_concat_function,
+          &[llvm_left.into(), llvm_right.into()],
+          "string.concat_op",
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+    };
+
+    // Both concatenation and comparisons copy or read the operands, which
+    // ... are no longer needed afterwards.
+    self.build_free_if_temporary_string(&binary_expr.left, llvm_left, cache);
+    self.build_free_if_temporary_string(&binary_expr.right, llvm_right, cache);
+
+    llvm_result
+  }
+
+  /// Retrieve a function of the string runtime, defining it if it
+  /// hasn't been already. The body is built within the function's entry block.
+  fn get_or_insert_string_function(
+    &mut self,
+    name: &str,
+    llvm_function_type: inkwell::types::FunctionType<'ctx>,
+    build_body: fn(&mut Self, inkwell::values::FunctionValue<'ctx>),
+  ) -> inkwell::values::FunctionValue<'ctx> {
+    // NOTE: Names containing a dot cannot collide with user definitions.
+    let llvm_function_name = format!("string.{}", name);
+
+    if let Some(llvm_function) = self.llvm_module.get_function(llvm_function_name.as_str()) {
+      return llvm_function;
+    }
+
+    let llvm_function = self.llvm_module.add_function(
+      llvm_function_name.as_str(),
+      llvm_function_type,
+      Some(inkwell::module::Linkage::Private),
+    );
+
+    let buffers = self.copy_buffers();
+
+    let llvm_entry_block = self
+      .llvm_context
+      .append_basic_block(llvm_function, "string.entry");
+
+    self.llvm_builder.position_at_end(llvm_entry_block);
+    self.llvm_function_buffer = Some(llvm_function);
+    build_body(self, llvm_function);
+    self.restore_buffers(buffers);
+
+    llvm_function
+  }
+
+  /// Retrieve the runtime function that creates an owned string out of
+  /// a copy of the given bytes and their length.
+  fn get_or_insert_string_from_bytes_function(&mut self) -> inkwell::values::FunctionValue<'ctx> {
+    let llvm_function_type = self.lower_owned_string_type().fn_type(
+      &[
+        self
+          .llvm_context
+          .i8_type()
+          .ptr_type(inkwell::AddressSpace::Generic)
+          .into(),
+        self.llvm_context.i32_type().into(),
+      ],
+      false,
+    );
+
+    self.get_or_insert_string_function(
+      "from_bytes",
+      llvm_function_type,
+      |generator, llvm_function| {
+        let llvm_bytes = llvm_function.get_nth_param(0).unwrap().into_pointer_value();
+        let llvm_length = llvm_function.get_nth_param(1).unwrap().into_int_value();
+        let llvm_data = generator.build_string_buffer(llvm_length);
+
+        generator
+          .llvm_builder
+          .build_memcpy(llvm_data, 1, llvm_bytes, 1, llvm_length)
+          .unwrap();
+
+        let llvm_string = generator.build_string_with_terminator(llvm_data, llvm_length);
+
+        generator.llvm_builder.build_return(Some(&llvm_string));
+      },
+    )
+  }
+
+  /// Retrieve the runtime function that creates an owned string out of
+  /// two strings, one after the other.
+  fn get_or_insert_string_concat_function(&mut self) -> inkwell::values::FunctionValue<'ctx> {
+    let llvm_string_type = self.lower_owned_string_type();
+
+    let llvm_function_type =
+      llvm_string_type.fn_type(&[llvm_string_type.into(), llvm_string_type.into()], false);
+
+    self.get_or_insert_string_function("concat", llvm_function_type, |generator, llvm_function| {
+      let (llvm_left_data, llvm_left_length, _) =
+        generator.build_string_parts(llvm_function.get_nth_param(0).unwrap().into_struct_value());
+
+      let (llvm_right_data, llvm_right_length, _) =
+        generator.build_string_parts(llvm_function.get_nth_param(1).unwrap().into_struct_value());
+
+      let llvm_length =
+        generator
+          .llvm_builder
+          .build_int_add(llvm_left_length, llvm_right_length, "string.length");
+
+      let llvm_data = generator.build_string_buffer(llvm_length);
+
+      generator
+        .llvm_builder
+        .build_memcpy(llvm_data, 1, llvm_left_data, 1, llvm_left_length)
+        .unwrap();
+
+      let llvm_tail = unsafe {
+        generator
+          .llvm_builder
+          .build_in_bounds_gep(llvm_data, &[llvm_left_length], "string.tail")
+      };
+
+      generator
+        .llvm_builder
+        .build_memcpy(llvm_tail, 1, llvm_right_data, 1, llvm_right_length)
+        .unwrap();
+
+      let llvm_string = generator.build_string_with_terminator(llvm_data, llvm_length);
+
+      generator.llvm_builder.build_return(Some(&llvm_string));
+    })
+  }
+
+  /// Retrieve the runtime function that yields the length of a
+  /// null-terminated `Str`, by scanning it for its terminator.
+  fn get_or_insert_string_length_of_str_function(
+    &mut self,
+  ) -> inkwell::values::FunctionValue<'ctx> {
+    let llvm_function_type = self.llvm_context.i32_type().fn_type(
+      &[self
+        .llvm_context
+        .i8_type()
+        .ptr_type(inkwell::AddressSpace::Generic)
+        .into()],
+      false,
+    );
+
+    // REVIEW: A `Str` longer than `U32` allows wraps around.
+    self.get_or_insert_string_function(
+      "length_of_str",
+      llvm_function_type,
+      |generator, llvm_function| {
+        let llvm_i32_type = generator.llvm_context.i32_type();
+        let llvm_str = llvm_function.get_nth_param(0).unwrap().into_pointer_value();
+        let llvm_entry_block = generator.get_current_block();
+
+        let llvm_scan_block = generator
+          .llvm_context
+          .append_basic_block(llvm_function, "string.scan");
+
+        let llvm_done_block = generator
+          .llvm_context
+          .append_basic_block(llvm_function, "string.scan_done");
+
+        generator
+          .llvm_builder
+          .build_unconditional_branch(llvm_scan_block);
+
+        generator.llvm_builder.position_at_end(llvm_scan_block);
+
+        let llvm_index = generator
+          .llvm_builder
+          .build_phi(llvm_i32_type, "string.index");
+
+        let llvm_byte_ptr = unsafe {
+          generator.llvm_builder.build_in_bounds_gep(
+            llvm_str,
+            &[llvm_index.as_basic_value().into_int_value()],
+            "string.byte.gep",
+          )
+        };
+
+        let llvm_byte = generator
+          .llvm_builder
+          .build_load(llvm_byte_ptr, "string.byte")
+          .into_int_value();
+
+        let llvm_is_terminator = generator.llvm_builder.build_int_compare(
+          inkwell::IntPredicate::EQ,
+          llvm_byte,
+          generator.llvm_context.i8_type().const_zero(),
+          "string.is_terminator",
+        );
+
+        let llvm_next_index = generator.llvm_builder.build_int_add(
+          llvm_index.as_basic_value().into_int_value(),
+          llvm_i32_type.const_int(1, false),
+          "string.next_index",
+        );
+
+        generator.llvm_builder.build_conditional_branch(
+          llvm_is_terminator,
+          llvm_done_block,
+          llvm_scan_block,
+        );
+
+        llvm_index.add_incoming(&[
+          (&llvm_i32_type.const_zero(), llvm_entry_block),
+          (&llvm_next_index, llvm_scan_block),
+        ]);
+
+        generator.llvm_builder.position_at_end(llvm_done_block);
+        generator
+          .llvm_builder
+          .build_return(Some(&llvm_index.as_basic_value()));
+      },
+    )
+  }
+
+  /// Retrieve the runtime function that orders two strings by their bytes.
+  ///
+  /// The result is negative if the first string sorts first, zero if both
+  /// strings are equal, and positive otherwise. A string sorts before any
+  /// longer string it is a prefix of.
+  fn get_or_insert_string_compare_function(&mut self) -> inkwell::values::FunctionValue<'ctx> {
+    let llvm_string_type = self.lower_owned_string_type();
+    let llvm_i32_type = self.llvm_context.i32_type();
+
+    let llvm_function_type =
+      llvm_i32_type.fn_type(&[llvm_string_type.into(), llvm_string_type.into()], false);
+
+    self.get_or_insert_string_function("compare", llvm_function_type, |generator, llvm_function| {
+      let llvm_i32_type = generator.llvm_context.i32_type();
+      let llvm_entry_block = generator.get_current_block();
+
+      let (llvm_left_data, llvm_left_length, _) =
+        generator.build_string_parts(llvm_function.get_nth_param(0).unwrap().into_struct_value());
+
+      let (llvm_right_data, llvm_right_length, _) =
+        generator.build_string_parts(llvm_function.get_nth_param(1).unwrap().into_struct_value());
+
+      let llvm_is_shorter = generator.llvm_builder.build_int_compare(
+        inkwell::IntPredicate::ULT,
+        llvm_left_length,
+        llvm_right_length,
+        "string.is_shorter",
+      );
+
+      let llvm_is_longer = generator.llvm_builder.build_int_compare(
+        inkwell::IntPredicate::UGT,
+        llvm_left_length,
+        llvm_right_length,
+        "string.is_longer",
+      );
+
+      let llvm_common_length = generator
+        .llvm_builder
+        .build_select(
+          llvm_is_shorter,
+          llvm_left_length,
+          llvm_right_length,
+          "string.common_length",
+        )
+        .into_int_value();
+
+      // If the common bytes are equal, the shorter string sorts first.
+      let llvm_length_order = generator.llvm_builder.build_int_sub(
+        generator
+          .llvm_builder
+          .build_int_z_extend(llvm_is_longer, llvm_i32_type, ""),
+        generator
+          .llvm_builder
+          .build_int_z_extend(llvm_is_shorter, llvm_i32_type, ""),
+        "string.length_order",
+      );
+
+      let llvm_loop_block = generator
+        .llvm_context
+        .append_basic_block(llvm_function, "string.compare");
+
+      let llvm_bytes_block = generator
+        .llvm_context
+        .append_basic_block(llvm_function, "string.compare_bytes");
+
+      let llvm_differ_block = generator
+        .llvm_context
+        .append_basic_block(llvm_function, "string.bytes_differ");
+
+      let llvm_common_equal_block = generator
+        .llvm_context
+        .append_basic_block(llvm_function, "string.common_equal");
+
+      generator
+        .llvm_builder
+        .build_unconditional_branch(llvm_loop_block);
+
+      generator.llvm_builder.position_at_end(llvm_loop_block);
+
+      let llvm_index = generator
+        .llvm_builder
+        .build_phi(llvm_i32_type, "string.index");
+
+      let llvm_index_value = llvm_index.as_basic_value().into_int_value();
+
+      let llvm_is_done = generator.llvm_builder.build_int_compare(
+        inkwell::IntPredicate::EQ,
+        llvm_index_value,
+        llvm_common_length,
+        "string.is_done",
+      );
+
+      generator.llvm_builder.build_conditional_branch(
+        llvm_is_done,
+        llvm_common_equal_block,
+        llvm_bytes_block,
+      );
+
+      generator.llvm_builder.position_at_end(llvm_bytes_block);
+
+      let llvm_left_byte_ptr = unsafe {
+        generator.llvm_builder.build_in_bounds_gep(
+          llvm_left_data,
+          &[llvm_index_value],
+          "string.left_byte.gep",
+        )
+      };
+
+      let llvm_left_byte = generator
+        .llvm_builder
+        .build_load(llvm_left_byte_ptr, "string.left_byte")
+        .into_int_value();
+
+      let llvm_right_byte_ptr = unsafe {
+        generator.llvm_builder.build_in_bounds_gep(
+          llvm_right_data,
+          &[llvm_index_value],
+          "string.right_byte.gep",
+        )
+      };
+
+      let llvm_right_byte = generator
+        .llvm_builder
+        .build_load(llvm_right_byte_ptr, "string.right_byte")
+        .into_int_value();
+
+      let llvm_is_byte_equal = generator.llvm_builder.build_int_compare(
+        inkwell::IntPredicate::EQ,
+        llvm_left_byte,
+        llvm_right_byte,
+        "string.is_byte_equal",
+      );
+
+      let llvm_next_index = generator.llvm_builder.build_int_add(
+        llvm_index_value,
+        llvm_i32_type.const_int(1, false),
+        "string.next_index",
+      );
+
+      generator.llvm_builder.build_conditional_branch(
+        llvm_is_byte_equal,
+        llvm_loop_block,
+        llvm_differ_block,
+      );
+
+      llvm_index.add_incoming(&[
+        (&llvm_i32_type.const_zero(), llvm_entry_block),
+        (&llvm_next_index, llvm_bytes_block),
+      ]);
+
+      // Bytes are compared as unsigned values.
+      generator.llvm_builder.position_at_end(llvm_differ_block);
+
+      let llvm_bytes_order = generator.llvm_builder.build_int_sub(
+        generator
+          .llvm_builder
+          .build_int_z_extend(llvm_left_byte, llvm_i32_type, ""),
+        generator
+          .llvm_builder
+          .build_int_z_extend(llvm_right_byte, llvm_i32_type, ""),
+        "string.bytes_order",
+      );
+
+      generator.llvm_builder.build_return(Some(&llvm_bytes_order));
+      generator
+        .llvm_builder
+        .position_at_end(llvm_common_equal_block);
+      generator
+        .llvm_builder
+        .build_return(Some(&llvm_length_order));
+    })
+  }
+
+  /// Retrieve the runtime function that appends a string to the string
+  /// behind the given pointer, in place.
+  ///
+  /// The buffer grows to at least twice its capacity whenever it runs out of space.
+  fn get_or_insert_string_append_function(&mut self) -> inkwell::values::FunctionValue<'ctx> {
+    let llvm_string_type = self.lower_owned_string_type();
+
+    let llvm_function_type = self.llvm_context.void_type().fn_type(
+      &[
+        llvm_string_type
+          .ptr_type(inkwell::AddressSpace::Generic)
+          .into(),
+        llvm_string_type.into(),
+      ],
+      false,
+    );
+
+    self.get_or_insert_string_function("append", llvm_function_type, |generator, llvm_function| {
+      let llvm_i32_type = generator.llvm_context.i32_type();
+      let llvm_target = llvm_function.get_nth_param(0).unwrap().into_pointer_value();
+      let llvm_entry_block = generator.get_current_block();
+
+      let llvm_target_value = generator.access(llvm_target).into_struct_value();
+      let (llvm_data, llvm_length, llvm_capacity) = generator.build_string_parts(llvm_target_value);
+
+      let (llvm_other_data, llvm_other_length, _) =
+        generator.build_string_parts(llvm_function.get_nth_param(1).unwrap().into_struct_value());
+
+      let llvm_new_length =
+        generator
+          .llvm_builder
+          .build_int_add(llvm_length, llvm_other_length, "string.new_length");
+
+      let llvm_required_capacity = generator.llvm_builder.build_int_add(
+        llvm_new_length,
+        llvm_i32_type.const_int(1, false),
+        "string.required_capacity",
+      );
+
+      let llvm_is_full = generator.llvm_builder.build_int_compare(
+        inkwell::IntPredicate::UGT,
+        llvm_required_capacity,
+        llvm_capacity,
+        "string.is_full",
+      );
+
+      let llvm_grow_block = generator
+        .llvm_context
+        .append_basic_block(llvm_function, "string.grow");
+
+      let llvm_copy_block = generator
+        .llvm_context
+        .append_basic_block(llvm_function, "string.copy");
+
+      generator.llvm_builder.build_conditional_branch(
+        llvm_is_full,
+        llvm_grow_block,
+        llvm_copy_block,
+      );
+
+      generator.llvm_builder.position_at_end(llvm_grow_block);
+
+      let llvm_doubled_capacity = generator.llvm_builder.build_int_mul(
+        llvm_capacity,
+        llvm_i32_type.const_int(2, false),
+        "string.doubled_capacity",
+      );
+
+      let llvm_is_doubling_enough = generator.llvm_builder.build_int_compare(
+        inkwell::IntPredicate::UGE,
+        llvm_doubled_capacity,
+        llvm_required_capacity,
+        "string.is_doubling_enough",
+      );
+
+      let llvm_grown_capacity = generator
+        .llvm_builder
+        .build_select(
+          llvm_is_doubling_enough,
+          llvm_doubled_capacity,
+          llvm_required_capacity,
+          "string.grown_capacity",
+        )
+        .into_int_value();
+
+      let llvm_grown_size = generator.llvm_builder.build_int_z_extend(
+        llvm_grown_capacity,
+        generator.lower_int_type(&ast::IntSize::Usize),
+        "string.grown_size",
+      );
+
+      let llvm_grown_data = generator.build_resize(llvm_data, llvm_grown_size);
+
+      generator
+        .llvm_builder
+        .build_unconditional_branch(llvm_copy_block);
+
+      generator.llvm_builder.position_at_end(llvm_copy_block);
+
+      let llvm_final_data = generator
+        .llvm_builder
+        .build_phi(llvm_data.get_type(), "string.final_data");
+
+      llvm_final_data.add_incoming(&[
+        (&llvm_data, llvm_entry_block),
+        (&llvm_grown_data, llvm_grow_block),
+      ]);
+
+      let llvm_final_capacity = generator
+        .llvm_builder
+        .build_phi(llvm_i32_type, "string.final_capacity");
+
+      llvm_final_capacity.add_incoming(&[
+        (&llvm_capacity, llvm_entry_block),
+        (&llvm_grown_capacity, llvm_grow_block),
+      ]);
+
+      let llvm_final_data = llvm_final_data.as_basic_value().into_pointer_value();
+
+      // NOTE: A string appended to itself would otherwise be copied
+      // ... from its previous buffer, which may have been freed by now.
+      let llvm_usize_type = generator.lower_int_type(&ast::IntSize::Usize);
+
+      let llvm_is_self_append = generator.llvm_builder.build_int_compare(
+        inkwell::IntPredicate::EQ,
+        generator
+          .llvm_builder
+          .build_ptr_to_int(llvm_other_data, llvm_usize_type, ""),
+        generator
+          .llvm_builder
+          .build_ptr_to_int(llvm_data, llvm_usize_type, ""),
+        "string.is_self_append",
+      );
+
+      let llvm_source = generator
+        .llvm_builder
+        .build_select(
+          llvm_is_self_append,
+          llvm_final_data,
+          llvm_other_data,
+          "string.source",
+        )
+        .into_pointer_value();
+
+      let llvm_tail = unsafe {
+        generator
+          .llvm_builder
+          .build_in_bounds_gep(llvm_final_data, &[llvm_length], "string.tail")
+      };
+
+      generator
+        .llvm_builder
+        .build_memcpy(llvm_tail, 1, llvm_source, 1, llvm_other_length)
+        .unwrap();
+
+      let llvm_terminator = unsafe {
+        generator.llvm_builder.build_in_bounds_gep(
+          llvm_final_data,
+          &[llvm_new_length],
+          "string.terminator",
+        )
+      };
+
+      generator.llvm_builder.build_store(
+        llvm_terminator,
+        generator.llvm_context.i8_type().const_zero(),
+      );
+
+      let llvm_string = generator.build_string(
+        llvm_final_data,
+        llvm_new_length,
+        llvm_final_capacity.as_basic_value().into_int_value(),
+      );
+
+      generator.llvm_builder.build_store(llvm_target, llvm_string);
+      generator.llvm_builder.build_return(None);
+    })
+  }
+
+  /// Allocate the buffer of a string of the given length, with
+  /// room for its null terminator.
+  ///
+  /// String lengths and capacities are `U32` values, whereas the size
+  /// of the allocation is pointer-sized, which the capacity is extended to.
+  fn build_string_buffer(
+    &mut self,
+    llvm_length: inkwell::values::IntValue<'ctx>,
+  ) -> inkwell::values::PointerValue<'ctx> {
+    let llvm_capacity = self.llvm_builder.build_int_add(
+      llvm_length,
+      llvm_length.get_type().const_int(1, false),
+      "string.capacity",
+    );
+
+    let llvm_size = self.llvm_builder.build_int_z_extend_or_bit_cast(
+      llvm_capacity,
+      self.lower_int_type(&ast::IntSize::Usize),
+      "string.size",
+    );
+
+    self.build_allocation(
+      self.llvm_context.i8_type().as_basic_type_enum(),
+      llvm_size,
+      "string.buffer",
+    )
+  }
+
+  /// Null-terminate a buffer allocated by `build_string_buffer`, and build
+  /// the owned string value that owns it.
+  fn build_string_with_terminator(
+    &mut self,
+    llvm_data: inkwell::values::PointerValue<'ctx>,
+    llvm_length: inkwell::values::IntValue<'ctx>,
+  ) -> inkwell::values::BasicValueEnum<'ctx> {
+    let llvm_terminator = unsafe {
+      self
+        .llvm_builder
+        .build_in_bounds_gep(llvm_data, &[llvm_length], "string.terminator")
+    };
+
+    self
+      .llvm_builder
+      .build_store(llvm_terminator, self.llvm_context.i8_type().const_zero());
+
+    let llvm_capacity = self.llvm_builder.build_int_add(
+      llvm_length,
+      llvm_length.get_type().const_int(1, false),
+      "string.capacity",
+    );
+
+    self.build_string(llvm_data, llvm_length, llvm_capacity)
+  }
+
+  /// Insert a call to the print runtime, formatting each value based on its
+  /// type. The first argument is the format string, which is translated into
+  /// a `printf` format string.
+  fn build_print(&mut self, arguments: &[ast::Node], is_line: bool, cache: &cache::Cache) {
+    // NOTE: The format string is verified during type-checking. Interpolated
+    // ... format strings are printed directly, without building a string.
+    let (mut format, llvm_values, llvm_temporaries) =
+      match arguments.first().unwrap().kind.flatten() {
+        ast::NodeKind::Literal(ast::Literal::String(template)) => {
+          let pieces = TypeContext::split_format_string(template).unwrap();
+
+          self.build_format(&pieces, &arguments[1..], cache)
+        }
+        ast::NodeKind::InterpolationExpr(interpolation_expr) => {
+          self.build_format(&interpolation_expr.pieces, &interpolation_expr.holes, cache)
+        }
+        _ => unreachable!(),
+      };
+
+    if is_line {
+      format.push('\n');
+    }
+
+    let llvm_format = self
+      .llvm_builder
+      .build_global_string_ptr(format.as_str(), "print.format")
+      .as_pointer_value();
+
+    let llvm_print_function = self.get_or_insert_print_function();
+
+    let llvm_arguments =
+      std::iter::once(inkwell::values::BasicMetadataValueEnum::from(llvm_format))
+        .chain(llvm_values)
+        .collect::<Vec<inkwell::values::BasicMetadataValueEnum<'ctx>>>();
+
+    self
+      .llvm_builder
+      .build_call(llvm_print_function, llvm_arguments.as_slice(), "");
+
+    for llvm_temporary in llvm_temporaries {
+      self.llvm_builder.build_free(llvm_temporary);
+    }
+  }
+
+  /// Translate the pieces surrounding each value into a `printf` format
+  /// string, and lower the values to be formatted.
+  ///
+  /// The buffers of temporary strings among the values are yielded as well,
+  /// and must be freed once the values have been formatted.
+  fn build_format(
+    &mut self,
+    pieces: &[String],
+    values: &[ast::Node],
+    cache: &cache::Cache,
+  ) -> (
+    String,
+    Vec<inkwell::values::BasicMetadataValueEnum<'ctx>>,
+    Vec<inkwell::values::PointerValue<'ctx>>,
+  ) {
+    let mut format = pieces.first().unwrap().replace('%', "%%");
+    let mut llvm_values = Vec::new();
+    let mut llvm_temporaries = Vec::new();
+
+    for (value, piece) in values.iter().zip(pieces.iter().skip(1)) {
+      let (specifier, llvm_value) = self.lower_print_value(value, cache);
+
+      // NOTE: Owned strings are formatted through their buffer.
+      if LlvmGenerator::is_temporary_string(value, cache) {
+        if let inkwell::values::BasicMetadataValueEnum::PointerValue(llvm_data) = llvm_value {
+          llvm_temporaries.push(llvm_data);
+        }
+      }
+
+      format.push_str(specifier);
+      format.push_str(piece.replace('%', "%%").as_str());
+      llvm_values.push(llvm_value);
+    }
+
+    (format, llvm_values, llvm_temporaries)
+  }
+
+  /// Format the holes of an interpolated string into a new owned string.
+  ///
+  /// The runtime's `snprintf` is called twice: first to measure the
+  /// formatted length, then to write into a buffer of that length.
+  fn build_interpolation(
+    &mut self,
+    interpolation_expr: &ast::InterpolationExpr,
+    cache: &cache::Cache,
+  ) -> inkwell::values::BasicValueEnum<'ctx> {
+    let (format, llvm_values, llvm_temporaries) =
+      self.build_format(&interpolation_expr.pieces, &interpolation_expr.holes, cache);
+
+    let llvm_format = self
+      .llvm_builder
+      .build_global_string_ptr(format.as_str(), "interpolation.format")
+      .as_pointer_value();
+
+    let llvm_usize_type = self.lower_int_type(&ast::IntSize::Usize);
+    let llvm_buffer_type = llvm_format.get_type();
+
+    let llvm_snprintf_function = self
+      .llvm_module
+      .get_function("snprintf")
+      .unwrap_or_else(|| {
+        self.llvm_module.add_function(
+          "snprintf",
+          self.llvm_context.i32_type().fn_type(
+            &[
+              llvm_buffer_type.into(),
+              llvm_usize_type.into(),
+              llvm_buffer_type.into(),
+            ],
+            true,
+          ),
+          Some(inkwell::module::Linkage::External),
+        )
+      });
+
+    let llvm_measure_arguments: Vec<inkwell::values::BasicMetadataValueEnum<'ctx>> = vec![
+      llvm_buffer_type.const_null().into(),
+      llvm_usize_type.const_zero().into(),
+      llvm_format.into(),
+    ]
+    .into_iter()
+    .chain(llvm_values.iter().copied())
+    .collect();
+
+    // NOTE: The result is an `int`, which matches the width of string lengths.
+    let llvm_length = self
+      .llvm_builder
+      .build_call(
+        llvm_snprintf_function,
+        llvm_measure_arguments.as_slice(),
+        "interpolation.length",
+      )
+      .try_as_basic_value()
+      .left()
+      .unwrap()
+      .into_int_value();
+
+    // A negative result signals an encoding error, which cannot be recovered from.
+    let llvm_is_formatted = self.llvm_builder.build_int_compare(
+      inkwell::IntPredicate::SGE,
+      llvm_length,
+      llvm_length.get_type().const_zero(),
+      "interpolation.is_formatted",
+    );
+
+    let llvm_message = self
+      .llvm_builder
+      .build_global_string_ptr("string formatting failed", "panic.message")
+      .as_pointer_value();
+
+    self.build_assert(llvm_is_formatted, llvm_message, interpolation_expr.position);
+
+    let llvm_data = self.build_string_buffer(llvm_length);
+
+    let llvm_capacity = self.llvm_builder.build_int_add(
+      llvm_length,
+      llvm_length.get_type().const_int(1, false),
+      "string.capacity",
+    );
+
+    let llvm_write_arguments: Vec<inkwell::values::BasicMetadataValueEnum<'ctx>> = vec![
+      llvm_data.into(),
+      self
+        .llvm_builder
+        .build_int_z_extend_or_bit_cast(llvm_capacity, llvm_usize_type, "interpolation.size")
+        .into(),
+      llvm_format.into(),
+    ]
+    .into_iter()
+    .chain(llvm_values)
+    .collect();
+
+    // The terminator is written by `snprintf` itself.
+    self
+      .llvm_builder
+      .build_call(llvm_snprintf_function, llvm_write_arguments.as_slice(), "");
+
+    for llvm_temporary in llvm_temporaries {
+      self.llvm_builder.build_free(llvm_temporary);
+    }
+
+    self.build_string(llvm_data, llvm_length, llvm_capacity)
+  }
+
+  /// Lower a value to be printed, and determine its `printf` conversion
+  /// specifier. Values are converted where needed (ex. integers narrower
+  /// than 32 bits are extended, and booleans become strings).
+  fn lower_print_value(
+    &mut self,
+    value: &ast::Node,
+    cache: &cache::Cache,
+  ) -> (&'static str, inkwell::values::BasicMetadataValueEnum<'ctx>) {
+    let llvm_value = value.kind.lower(self, cache, true).unwrap();
+
+    match value.kind.infer_flatten_type(cache) {
+      ast::Type::Basic(ast::BasicType::Int(size)) => {
+        let llvm_int = llvm_value.into_int_value();
+        let is_wide = llvm_int.get_type().get_bit_width() > 32;
+
+        // Variadic arguments narrower than `int` must be promoted.
+        let llvm_int = if is_wide {
+          llvm_int
+        } else if size.is_signed() {
+          self.llvm_builder.build_int_s_extend_or_bit_cast(
+            llvm_int,
+            self.llvm_context.i32_type(),
+            "print.int.sext",
+          )
+        } else {
+          self.llvm_builder.build_int_z_extend_or_bit_cast(
+            llvm_int,
+            self.llvm_context.i32_type(),
+            "print.int.zext",
+          )
+        };
+
+        let specifier = match (is_wide, size.is_signed()) {
+          (false, true) => "%d",
+          (false, false) => "%u",
+          (true, true) => "%lld",
+          (true, false) => "%llu",
+        };
+
+        (specifier, llvm_int.into())
+      }
+      ast::Type::Basic(ast::BasicType::Bool) => {
+        let llvm_true = self
+          .llvm_builder
+          .build_global_string_ptr("true", "print.true")
+          .as_pointer_value();
+
+        let llvm_false = self
+          .llvm_builder
+          .build_global_string_ptr("false", "print.false")
+          .as_pointer_value();
+
+        let llvm_string = self.llvm_builder.build_select(
+          llvm_value.into_int_value(),
+          llvm_true,
+          llvm_false,
+          "print.bool",
+        );
+
+        ("%s", llvm_string.into())
+      }
+      ast::Type::Basic(ast::BasicType::Char) => {
+        let llvm_char = self.llvm_builder.build_int_z_extend(
+          llvm_value.into_int_value(),
+          self.llvm_context.i32_type(),
+          "print.char.zext",
+        );
+
+        ("%c", llvm_char.into())
+      }
+      ast::Type::Basic(ast::BasicType::String) => ("%s", llvm_value.into()),
+      // The buffers of strings are always null-terminated.
+      ast::Type::Basic(ast::BasicType::OwnedString) => {
+        let llvm_string = self.attempt_access(llvm_value).into_struct_value();
+
+        (
+          "%s",
+          self
+            .llvm_builder
+            .build_extract_value(llvm_string, 0, "print.string.data")
+            .unwrap()
+            .into(),
+        )
+      }
+      ast::Type::Pointer(_) => ("%p", llvm_value.into()),
+      ast::Type::Stub(stub_type) => {
+        let enum_ = match cache.force_get(&stub_type.pattern.target_id.unwrap()) {
+          ast::NodeKind::Enum(enum_) => enum_,
+          _ => unreachable!(),
+        };
+
+        // Values outside of the enum's range (ex. produced by casts) have
+        // ... no name, and are printed as a placeholder instead.
+        let llvm_fallback_name = self
+          .llvm_builder
+          .build_global_string_ptr("<invalid>", "print.enum.invalid")
+          .as_pointer_value();
+
+        if enum_.variants.is_empty() {
+          return ("%s", llvm_fallback_name.into());
+        }
+
+        let llvm_enum_names = self.get_or_insert_enum_names(enum_);
+        let llvm_variant_value = llvm_value.into_int_value();
+        let first_index = self.llvm_context.i32_type().const_int(0, false);
+
+        let llvm_is_in_range = self.llvm_builder.build_int_compare(
+          inkwell::IntPredicate::ULT,
+          llvm_variant_value,
+          llvm_variant_value
+            .get_type()
+            .const_int(enum_.variants.len() as u64, false),
+          "print.enum.is_in_range",
+        );
+
+        // The index is clamped, so that the table is never read out of bounds.
+        let llvm_index = self.llvm_builder.build_select(
+          llvm_is_in_range,
+          llvm_variant_value,
+          llvm_variant_value.get_type().const_zero(),
+          "print.enum.index",
+        );
+
+        let llvm_name_ptr = unsafe {
+          self.llvm_builder.build_in_bounds_gep(
+            llvm_enum_names.as_pointer_value(),
+            &[first_index, llvm_index.into_int_value()],
+            "print.enum.gep",
+          )
+        };
+
+        let llvm_name = self
+          .llvm_builder
+          .build_load(llvm_name_ptr, "print.enum.name");
+
+        (
+          "%s",
+          self
+            .llvm_builder
+            .build_select(
+              llvm_is_in_range,
+              llvm_name,
+              llvm_fallback_name.as_basic_value_enum(),
+              "print.enum.checked_name",
+            )
+            .into(),
+        )
+      }
+      _ => unreachable!(),
+    }
+  }
+
+  /// Retrieve the print runtime, declaring it if it hasn't been already.
+  ///
+  /// NOTE: No formatting routines are generated. Instead, the print runtime is
+  /// the C standard library's `printf` function, given a format string derived
+  /// from the static types of the printed values. Programs using the print
+  /// intrinsics must therefore be linked against libc.
+  fn get_or_insert_print_function(&mut self) -> inkwell::values::FunctionValue<'ctx> {
+    if let Some(llvm_print_function) = self.print_function_cache {
+      return llvm_print_function;
+    }
+
+    let llvm_string_type = self
+      .llvm_context
+      .i8_type()
+      .ptr_type(inkwell::AddressSpace::Generic);
+
+    // NOTE: The function may have already been declared as an extern.
+    let llvm_print_function = self.llvm_module.get_function("printf").unwrap_or_else(|| {
+      self.llvm_module.add_function(
+        "printf",
+        self
+          .llvm_context
+          .i32_type()
+          .fn_type(&[llvm_string_type.into()], true),
+        Some(inkwell::module::Linkage::External),
+      )
+    });
+
+    self.print_function_cache = Some(llvm_print_function);
+
+    llvm_print_function
+  }
+
+  /// Retrieve the table of variant names of an enum, indexed by the
+  /// variant's value, defining it if it hasn't been already.
+  fn get_or_insert_enum_names(&mut self, enum_: &ast::Enum) -> inkwell::values::GlobalValue<'ctx> {
+    if let Some(llvm_enum_names) = self.enum_names_cache.get(&enum_.cache_id) {
+      return *llvm_enum_names;
+    }
+
+    let llvm_string_type = self
+      .llvm_context
+      .i8_type()
+      .ptr_type(inkwell::AddressSpace::Generic);
+
+    let llvm_names = enum_
+      .variants
+      .iter()
+      .map(|variant| {
+        self
+          .llvm_builder
+          .build_global_string_ptr(variant.0.as_str(), "enum.variant_name")
+          .as_pointer_value()
+      })
+      .collect::<Vec<_>>();
+
+    let llvm_enum_names_name = self.mangle_name(&format!("enum.{}.names", enum_.name));
+
+    let llvm_enum_names = self.llvm_module.add_global(
+      llvm_string_type.array_type(llvm_names.len() as u32),
+      None,
+      llvm_enum_names_name.as_str(),
+    );
+
+    llvm_enum_names.set_initializer(&llvm_string_type.const_array(llvm_names.as_slice()));
+    llvm_enum_names.set_constant(true);
+    llvm_enum_names.set_linkage(inkwell::module::Linkage::Private);
+    self
+      .enum_names_cache
+      .insert(enum_.cache_id, llvm_enum_names);
+
+    llvm_enum_names
+  }
+
+  /// Retrieve the location of a value, spilling it onto the stack if it
+  /// does not already live in memory (ex. array literals or call results).
+  fn spill_if_value(
+    &mut self,
+    llvm_value: inkwell::values::BasicValueEnum<'ctx>,
+    name: &str,
+  ) -> inkwell::values::PointerValue<'ctx> {
+    if llvm_value.is_pointer_value() {
+      return llvm_value.into_pointer_value();
+    }
+
+    let llvm_value_ptr = self.llvm_builder.build_alloca(llvm_value.get_type(), name);
+
+    self.llvm_builder.build_store(llvm_value_ptr, llvm_value);
+
+    llvm_value_ptr
+  }
+
+  fn attempt_access(
+    &mut self,
+    llvm_value: inkwell::values::BasicValueEnum<'ctx>,
+  ) -> inkwell::values::BasicValueEnum<'ctx> {
+    if llvm_value.is_pointer_value() && !LlvmGenerator::is_callable(llvm_value) {
+      self.access(llvm_value.into_pointer_value())
+    } else {
+      llvm_value
+    }
+  }
+
+  fn find_type_cache_id(&self, ty: &ast::Type, cache: &cache::Cache) -> Option<cache::Id> {
+    Some(match ty.flatten(cache) {
+      ast::Type::Struct(struct_type) => struct_type.cache_id.clone(),
+      // REVIEW: Any more?
+      _ => return None,
+    })
+  }
+
+  // REVIEW: Ensure that this function is tail-recursive.
+  /// Lower an integer type. Signedness is not part of LLVM integer types,
+  /// and pointer-sized integers take their bit-width from the target's data layout.
+  fn lower_int_type(&self, size: &ast::IntSize) -> inkwell::types::IntType<'ctx> {
+    match size {
+      ast::IntSize::I8 | ast::IntSize::U8 => self.llvm_context.i8_type(),
+      ast::IntSize::I16 | ast::IntSize::U16 => self.llvm_context.i16_type(),
+      ast::IntSize::I32 | ast::IntSize::U32 => self.llvm_context.i32_type(),
+      ast::IntSize::I64 | ast::IntSize::U64 => self.llvm_context.i64_type(),
+      ast::IntSize::I128 | ast::IntSize::U128 => self.llvm_context.i128_type(),
+      ast::IntSize::Isize | ast::IntSize::Usize => self
+        .llvm_target_data
+        .ptr_sized_int_type_in_context(self.llvm_context, None),
+    }
+  }
+
+  fn lower_type(
+    &mut self,
+    ty: &ast::Type,
+    cache: &cache::Cache,
+  ) -> inkwell::types::BasicTypeEnum<'ctx> {
+    match ty {
+      ast::Type::Basic(primitive_type) => match primitive_type {
+        ast::BasicType::Bool => self.llvm_context.bool_type().as_basic_type_enum(),
+        ast::BasicType::Int(size) => self.lower_int_type(size).as_basic_type_enum(),
+        ast::BasicType::Char => self.llvm_context.i8_type().as_basic_type_enum(),
+        ast::BasicType::String => self
+          .llvm_context
+          .i8_type()
+          .ptr_type(inkwell::AddressSpace::Generic)
+          .as_basic_type_enum(),
+        ast::BasicType::OwnedString => self.lower_owned_string_type().as_basic_type_enum(),
+        // NOTE: The null primitive type is never lowered, only the nullptr value.
+        ast::BasicType::Null => unreachable!(),
+      },
+      ast::Type::Array(element_type, size) => self
+        .lower_type(&element_type, cache)
+        .array_type(size.clone())
+        .as_basic_type_enum(),
+      ast::Type::Pointer(pointee_type) => self
+        .lower_type(&pointee_type, cache)
+        .ptr_type(inkwell::AddressSpace::Generic)
+        .as_basic_type_enum(),
+      // REVIEW: Is this redundant (because of `StubType`)?
+      ast::Type::Struct(struct_type) => {
+        let llvm_field_types = struct_type
+          .fields
+          .iter()
+          .map(|field| self.lower_type(&field.1, cache))
+          .collect::<Vec<_>>();
+
+        let name = self.mangle_name(&format!("struct.{}", struct_type.name));
+        let llvm_struct_type = self.llvm_context.opaque_struct_type(name.as_str());
+
+        llvm_struct_type.set_body(llvm_field_types.as_slice(), false);
+
+        llvm_struct_type.as_basic_type_enum()
+      }
+      // REVIEW: Why not resolve the type if it is a stub type, then proceed to lower it?
+      ast::Type::Stub(stub_type) => {
+        self.memoize_or_retrieve_type_by_binding(stub_type.pattern.target_id.unwrap(), cache)
+      }
+      // Extern function types are plain function pointers, which
+      // may be handed over to foreign code as callbacks.
+      ast::Type::Function(callable_type) if callable_type.is_extern => self
+        .lower_callable_type(callable_type, cache)
+        .ptr_type(inkwell::AddressSpace::Generic)
+        .as_basic_type_enum(),
+      // Otherwise, function-typed values are closures.
+      ast::Type::Function(callable_type) => {
+        let llvm_function_type = self.lower_callable_type(callable_type, cache);
+        let llvm_closure_function_type = self.lower_closure_function_type(llvm_function_type);
+
+        self
+          .lower_closure_type(llvm_closure_function_type)
+          .as_basic_type_enum()
+      }
+      ast::Type::Optional(value_type) => {
+        let llvm_value_type = self.lower_type(&value_type, cache);
+
+        self
+          .llvm_context
+          .struct_type(
+            &[
+              self.llvm_context.bool_type().as_basic_type_enum(),
+              llvm_value_type,
+            ],
+            false,
+          )
+          .as_basic_type_enum()
+      }
+      // Results are laid out as their flag, followed by both their value
+      // and their error, of which only one is set.
+      ast::Type::Result(value_type, error_type) => {
+        let llvm_value_type = self.lower_type(&value_type, cache);
+        let llvm_error_type = self.lower_type(&error_type, cache);
+
+        self
+          .llvm_context
+          .struct_type(
+            &[
+              self.llvm_context.bool_type().as_basic_type_enum(),
+              llvm_value_type,
+              llvm_error_type,
+            ],
+            false,
+          )
+          .as_basic_type_enum()
+      }
+      ast::Type::Slice(element_type) => {
+        let llvm_element_ptr_type = self
+          .lower_type(&element_type, cache)
+          .ptr_type(inkwell::AddressSpace::Generic);
+
+        self
+          .llvm_context
+          .struct_type(
+            &[
+              llvm_element_ptr_type.as_basic_type_enum(),
+              self.llvm_context.i32_type().as_basic_type_enum(),
+            ],
+            false,
+          )
+          .as_basic_type_enum()
+      }
+      // References are lowered as plain pointers; their guarantees are
+      // only enforced during type-checking.
+      ast::Type::Reference(inner_type) | ast::Type::MutableReference(inner_type) => self
+        .lower_type(&inner_type, cache)
+        .ptr_type(inkwell::AddressSpace::Generic)
+        .as_basic_type_enum(),
+      // TODO: Implement.
+      ast::Type::This(this_type) => {
+        self.memoize_or_retrieve_type_by_binding(this_type.target_id.unwrap(), cache)
+      }
+      // FIXME: Will never be able to treat void type as we expect here, because it is only usable to create void return types!
+      // FIXME: Should be lowering to the void type instead, but not allowed by return type!
+      // FIXME: What about when a resolved function type is encountered? Wouldn't it need to be lowered here?
+      // REVIEW: Consider lowering the unit type as void? Only in case we actually use this, otherwise no. (This also serves as a bug catcher).
+      // ast::Type::Unit => self
+      //   .llvm_context
+      //   .bool_type()
+      //   .ptr_type(inkwell::AddressSpace::Generic)
+      //   .as_basic_type_enum(),
+      // Meta types are never to be lowered.
+      ast::Type::Unit
+      | ast::Type::Error
+      | ast::Type::Variable(_)
+      | ast::Type::Never
+      | ast::Type::Any => {
+        unreachable!()
+      }
+    }
+  }
+
+  fn lower_callable_type(
+    &mut self,
+    function_type: &ast::FunctionType,
+    cache: &cache::Cache,
+  ) -> inkwell::types::FunctionType<'ctx> {
+    let llvm_parameter_types = function_type
+      .parameter_types
+      .iter()
+      .map(|parameter_type| self.memoize_or_retrieve_type(&parameter_type, cache).into())
+      .collect::<Vec<_>>();
+
+    let return_type = function_type.return_type.flatten(cache);
+
+    if return_type.is_a_unit() || return_type.is_a_never() {
+      return self
+        .llvm_context
+        .void_type()
+        .fn_type(llvm_parameter_types.as_slice(), function_type.is_variadic);
+    }
+
+    self
+      .memoize_or_retrieve_type(&return_type, cache)
+      .fn_type(llvm_parameter_types.as_slice(), function_type.is_variadic)
+  }
+
+  /// Returns the LLVM function type used by closures, which accepts
+  /// an opaque pointer to its environment as its first parameter.
+  fn lower_closure_function_type(
+    &self,
+    llvm_function_type: inkwell::types::FunctionType<'ctx>,
+  ) -> inkwell::types::FunctionType<'ctx> {
+    let mut llvm_parameter_types = vec![self
+      .llvm_context
+      .i8_type()
+      .ptr_type(inkwell::AddressSpace::Generic)
+      .into()];
+
+    llvm_parameter_types.extend(
+      llvm_function_type
+        .get_param_types()
+        .into_iter()
+        .map(|llvm_parameter_type| llvm_parameter_type.into()),
+    );
+
+    match llvm_function_type.get_return_type() {
+      Some(llvm_return_type) => llvm_return_type.fn_type(
+        llvm_parameter_types.as_slice(),
+        llvm_function_type.is_var_arg(),
+      ),
+      None => self.llvm_context.void_type().fn_type(
+        llvm_parameter_types.as_slice(),
+        llvm_function_type.is_var_arg(),
+      ),
+    }
+  }
+
+  /// Wrap a plain function pointer into a closure with an empty environment, if
+  /// applicable. This is done through a thunk which discards the environment, and
+  /// forwards its arguments to the function.
+  ///
+  /// Any other value is returned as-is.
+  fn attempt_wrap_in_closure(
+    &mut self,
+    llvm_value: inkwell::values::BasicValueEnum<'ctx>,
+  ) -> inkwell::values::BasicValueEnum<'ctx> {
+    if !llvm_value.is_pointer_value() || !LlvmGenerator::is_callable(llvm_value) {
+      return llvm_value;
+    }
+
+    // Thunks are memoized per function, so that each function is only wrapped once.
+    let cache_id = self
+      .llvm_cached_values
+      .iter()
+      .find(|(_, llvm_cached_value)| **llvm_cached_value == llvm_value)
+      .map(|(cache_id, _)| *cache_id);
+
+    let llvm_thunk = match cache_id.and_then(|cache_id| self.thunks_cache.get(&cache_id)) {
+      Some(llvm_thunk) => *llvm_thunk,
+      None => {
+        let llvm_thunk = self.build_thunk(llvm_value.into_pointer_value());
+
+        if let Some(cache_id) = cache_id {
+          self.thunks_cache.insert(cache_id, llvm_thunk);
+        }
+
+        llvm_thunk
+      }
+    };
+
+    let llvm_closure_type = self.lower_closure_type(llvm_thunk.get_type());
+
+    let llvm_closure = self
+      .llvm_builder
+      .build_insert_value(
+        llvm_closure_type.get_undef(),
+        llvm_thunk.as_global_value().as_pointer_value(),
+        0,
+        "closure.function",
+      )
+      .unwrap();
+
+    let llvm_closure = self
+      .llvm_builder
+      .build_insert_value(
+        llvm_closure,
+        self
+          .llvm_context
+          .i8_type()
+          .ptr_type(inkwell::AddressSpace::Generic)
+          .const_null(),
+        1,
+        "closure",
+      )
+      .unwrap();
+
+    llvm_closure.into_struct_value().as_basic_value_enum()
+  }
+
+  /// Build a thunk for the given function pointer, which accepts (and discards) an
+  /// environment pointer as its first parameter.
+  fn build_thunk(
+    &mut self,
+    llvm_function_pointer: inkwell::values::PointerValue<'ctx>,
+  ) -> inkwell::values::FunctionValue<'ctx> {
+    let llvm_function_type = llvm_function_pointer
+      .get_type()
+      .get_element_type()
+      .into_function_type();
+
+    let llvm_thunk_type = self.lower_closure_function_type(llvm_function_type);
+    let llvm_thunk_name = self.mangle_name(&String::from("thunk"));
+
+    let llvm_thunk = self.llvm_module.add_function(
+      llvm_thunk_name.as_str(),
+      llvm_thunk_type,
+      Some(inkwell::module::Linkage::Private),
+    );
+
+    let buffers = self.copy_buffers();
+
+    let llvm_entry_block = self
+      .llvm_context
+      .append_basic_block(llvm_thunk, "thunk.entry");
+
+    self.llvm_builder.position_at_end(llvm_entry_block);
+
+    // Skip the environment parameter.
+    let llvm_arguments = llvm_thunk
+      .get_param_iter()
+      .skip(1)
+      .map(|llvm_parameter| llvm_parameter.into())
+      .collect::<Vec<_>>();
+
+    let llvm_call_value = self.llvm_builder.build_call(
+      inkwell::values::CallableValue::try_from(llvm_function_pointer).unwrap(),
+      llvm_arguments.as_slice(),
+      "thunk.call",
+    );
+
+    self.attempt_build_return(llvm_call_value.try_as_basic_value().left());
+
+    self.restore_buffers(buffers);
+
+    llvm_thunk
+  }
+
+  /// Returns the LLVM type of a closure value, which is a pair of the
+  /// given closure function type's pointer, and its environment pointer.
+  fn lower_closure_type(
+    &self,
+    llvm_closure_function_type: inkwell::types::FunctionType<'ctx>,
+  ) -> inkwell::types::StructType<'ctx> {
+    self.llvm_context.struct_type(
+      &[
+        llvm_closure_function_type
+          .ptr_type(inkwell::AddressSpace::Generic)
+          .as_basic_type_enum(),
+        self
+          .llvm_context
+          .i8_type()
+          .ptr_type(inkwell::AddressSpace::Generic)
+          .as_basic_type_enum(),
+      ],
+      false,
+    )
+  }
+
+  /// Returns a new LLVM function type based on the given prototype.
+  ///
+  /// The return value is required because the prototype's return type is
+  /// merely an annotation, and is unreliable. Assumes the return type to be
+  /// flat.
+  fn lower_prototype(
+    &mut self,
+    prototype: &ast::Prototype,
+    return_type: &ast::Type,
+    cache: &cache::Cache,
+  ) -> inkwell::types::FunctionType<'ctx> {
+    let mut llvm_parameter_types = prototype
+      .parameters
+      .iter()
+      .map(|parameter| self.memoize_or_retrieve_type(&parameter.ty, cache).into())
+      .collect::<Vec<_>>();
+
+    if prototype.accepts_instance {
+      let llvm_instance_type =
+        self.memoize_or_retrieve_type_by_binding(prototype.instance_type_id.unwrap(), cache);
+
+      // FIXME: This will panic for zero-length vectors. Find another way to prepend elements.
+      llvm_parameter_types.insert(
+        0,
+        llvm_instance_type
+          .ptr_type(inkwell::AddressSpace::Generic)
+          .into(),
+      );
+    }
+
+    if !return_type.is_a_never() && !return_type.is_a_unit() {
+      self
+        .memoize_or_retrieve_type(return_type, cache)
+        .fn_type(llvm_parameter_types.as_slice(), prototype.is_variadic)
+        .ptr_type(inkwell::AddressSpace::Generic)
+        .into()
+    } else {
+      self
+        .llvm_context
+        .void_type()
+        .fn_type(llvm_parameter_types.as_slice(), prototype.is_variadic)
+        .ptr_type(inkwell::AddressSpace::Generic)
+        .as_basic_type_enum()
+    }
+    .into_pointer_type()
+    .get_element_type()
+    .into_function_type()
+  }
+
+  // TODO: Ensure that there isn't any possible recursion problems going on.
+  fn memoize_or_retrieve_type(
+    &mut self,
+    ty: &ast::Type,
+    cache: &cache::Cache,
+  ) -> inkwell::types::BasicTypeEnum<'ctx> {
+    if let Some(cache_id) = self.find_type_cache_id(ty, cache) {
+      // REVIEW: Isn't this indirectly recursive? Will it cause problems?
+      return self.memoize_or_retrieve_type_by_binding(cache_id, cache);
+    }
+
+    self.lower_type(ty, cache)
+  }
+
+  fn memoize_or_retrieve_type_by_binding(
+    &mut self,
+    cache_id: cache::Id,
+    cache: &cache::Cache,
+  ) -> inkwell::types::BasicTypeEnum<'ctx> {
+    if let Some(existing_definition) = self.llvm_cached_types.get(&cache_id) {
+      return existing_definition.clone();
+    }
+
+    // REVIEW: Consider making a separate map for types in the cache.
+
+    let node = cache.force_get(&cache_id);
+
+    // REVIEW: Why not perform type-flattening here instead?
+    let ty = match &node {
+      ast::NodeKind::StructType(struct_type) => ast::Type::Struct(struct_type.clone()),
+      ast::NodeKind::TypeAlias(type_alias) => type_alias.ty.clone(),
+      // Enums are lowered as their underlying integer type.
+      ast::NodeKind::Enum(enum_) => ast::Type::Basic(enum_.ty.clone()),
+      // REVIEW: Any more?
+      _ => unreachable!(),
+    };
+
+    let llvm_type = self.lower_type(&ty, cache);
+
+    self.llvm_cached_types.insert(cache_id, llvm_type);
+
+    llvm_type
+  }
+
+  /// Find the `drop` method of the given type, if it implements the `Drop` trait.
+  ///
+  /// NOTE: Only the value itself is dropped. Values holding other `Drop`
+  /// ... values are rejected by the type checker, as no drop glue is generated.
+  fn find_drop_method(&self, ty: &ast::Type, cache: &cache::Cache) -> Option<cache::Id> {
+    self
+      .find_type_cache_id(ty, cache)
+      .and_then(|type_id| cache.drop_methods.get(&type_id).copied())
+  }
+
+  /// Register the value of a binding or parameter to be dropped once the
+  /// innermost scope is exited, unless it is moved out before then.
+  fn register_drop(
+    &mut self,
+    cache_id: cache::Id,
+    llvm_value: inkwell::values::PointerValue<'ctx>,
+    drop_method_id: cache::Id,
+  ) {
+    let llvm_bool_type = self.llvm_context.bool_type();
+
+    let llvm_drop_flag = self.llvm_builder.build_alloca(llvm_bool_type, "drop.flag");
+
+    self
+      .llvm_builder
+      .build_store(llvm_drop_flag, llvm_bool_type.const_int(1, false));
+
+    self.llvm_drop_flags.insert(cache_id, llvm_drop_flag);
+
+    self.cleanup_scopes.last_mut().unwrap().push(Cleanup::Drop {
+      llvm_value,
+      llvm_drop_flag,
+      drop_method_id,
+    });
+  }
+
+  /// Register the parameters of the function being lowered whose types
+  /// implement `Drop`, to be dropped once the function returns. The LLVM
+  /// parameters are offset by the given amount (ex. by a closure's environment).
+  fn register_parameter_drops(
+    &mut self,
+    parameters: &[ast::Parameter],
+    llvm_function: inkwell::values::FunctionValue<'ctx>,
+    offset: u32,
+    cache: &cache::Cache,
+  ) {
+    for parameter in parameters {
+      if let Some(drop_method_id) = self.find_drop_method(&parameter.ty, cache) {
+        let llvm_parameter = llvm_function
+          .get_nth_param(parameter.position + offset)
+          .unwrap();
+
+        // Parameters are values, so they must be spilled for the `drop`
+        // ... method to accept them as its instance.
+        let llvm_parameter_alloca = self
+          .llvm_builder
+          .build_alloca(llvm_parameter.get_type(), "param.drop");
+
+        self
+          .llvm_builder
+          .build_store(llvm_parameter_alloca, llvm_parameter);
+
+        self.register_drop(parameter.cache_id, llvm_parameter_alloca, drop_method_id);
+      }
+    }
+  }
+
+  /// Set or clear the drop flag of a binding or parameter, if it has one.
+  fn set_drop_flag(&mut self, cache_id: cache::Id, is_set: bool) {
+    if let Some(llvm_drop_flag) = self.llvm_drop_flags.get(&cache_id).copied() {
+      self.llvm_builder.build_store(
+        llvm_drop_flag,
+        self
+          .llvm_context
+          .bool_type()
+          .const_int(is_set as u64, false),
+      );
+    }
+  }
+
+  /// Clear the drop flag of the binding or parameter that a value is moved
+  /// out of (if any), so that it is no longer dropped once its scope is exited.
+  ///
+  /// Like the lifetime checker, moving out of a field or an element moves the
+  /// entire binding. Values of copyable types are copied instead.
+  fn build_move(&mut self, value: &ast::NodeKind, cache: &cache::Cache) {
+    if value.infer_flatten_type(cache).is_copyable(cache) {
+      return;
+    }
+
+    match value.flatten() {
+      ast::NodeKind::Reference(reference) => {
+        self.set_drop_flag(reference.pattern.target_id.unwrap(), false);
+      }
+      ast::NodeKind::MemberAccess(member_access)
+        if TypeContext::auto_deref_depth(
+          &member_access.base_expr.kind.infer_flatten_type(cache),
+          cache,
+        ) == 0 =>
+      {
+        self.build_move(&member_access.base_expr.kind, cache);
+      }
+      ast::NodeKind::IndexingExpr(indexing_expr)
+        if matches!(
+          indexing_expr.target_expr.kind.infer_flatten_type(cache),
+          ast::Type::Array(..)
+        ) =>
+      {
+        self.build_move(&indexing_expr.target_expr.kind, cache);
+      }
+      _ => {}
+    }
+  }
+
+  /// Call the `drop` method on the given value, if its drop flag is set.
+  fn build_drop(
+    &mut self,
+    llvm_value: inkwell::values::PointerValue<'ctx>,
+    llvm_drop_flag: inkwell::values::PointerValue<'ctx>,
+    drop_method_id: cache::Id,
+    cache: &cache::Cache,
+  ) {
+    let llvm_current_function = self.llvm_function_buffer.unwrap();
+    let llvm_should_drop = self.access(llvm_drop_flag).into_int_value();
+
+    let llvm_then_block = self
+      .llvm_context
+      .append_basic_block(llvm_current_function, "drop.then");
+
+    let llvm_after_block = self
+      .llvm_context
+      .append_basic_block(llvm_current_function, "drop.after");
+
+    self
+      .llvm_builder
+      .build_conditional_branch(llvm_should_drop, llvm_then_block, llvm_after_block);
+
+    self.llvm_builder.position_at_end(llvm_then_block);
+    self.build_drop_call(llvm_value, drop_method_id, cache);
+
+    self
+      .llvm_builder
+      .build_unconditional_branch(llvm_after_block);
+    self.llvm_builder.position_at_end(llvm_after_block);
+  }
+
+  /// Call the `drop` method on the given value unconditionally.
+  fn build_drop_call(
+    &mut self,
+    llvm_value: inkwell::values::PointerValue<'ctx>,
+    drop_method_id: cache::Id,
+    cache: &cache::Cache,
+  ) {
+    let llvm_drop_method = self
+      .memoize_or_retrieve_value(drop_method_id, cache, false, false)
+      .unwrap()
+      .into_pointer_value();
+
+    // NOTE: The `drop` method is an instance method, which accepts its
+    // ... instance as a pointer.
+    self.llvm_builder.build_call(
+      inkwell::values::CallableValue::try_from(llvm_drop_method).unwrap(),
+      &[llvm_value.into()],
+      "",
+    );
+  }
+
+  /// Build the cleanups of all the scopes past the given depth, innermost first.
+  ///
+  /// The scopes themselves are kept, since cleanups are built once per exit path.
+  fn build_cleanups(&mut self, depth: usize, cache: &cache::Cache) {
+    let cleanups = self.cleanup_scopes[depth..]
+      .iter()
+      .rev()
+      .flat_map(|scope| scope.iter().rev().cloned())
+      .collect::<Vec<_>>();
+
+    for cleanup in cleanups {
+      // A deferred body may terminate the current block (ex. by panicking).
+      if self.get_current_block().get_terminator().is_some() {
+        break;
+      }
+
+      match cleanup {
+        Cleanup::Drop {
+          llvm_value,
+          llvm_drop_flag,
+          drop_method_id,
+        } => self.build_drop(llvm_value, llvm_drop_flag, drop_method_id, cache),
+        Cleanup::Defer(body) => {
+          body.lower(self, cache, false);
+        }
+      }
+    }
+  }
+
+  fn get_current_block(&self) -> inkwell::basic_block::BasicBlock<'ctx> {
+    self.llvm_builder.get_insert_block().unwrap()
+  }
+
+  fn attempt_build_return(&mut self, return_value: Option<inkwell::values::BasicValueEnum<'ctx>>) {
+    // REVIEW: Consider mixing this with the function's terminator check, for void functions?
+    // Only build a single return instruction per block.
+    if self.get_current_block().get_terminator().is_some() {
+      return;
+    } else if let Some(return_value) = return_value {
+      self.llvm_builder.build_return(Some(&return_value));
+    } else {
+      self.llvm_builder.build_return(None);
+    }
+  }
+
+  // REVIEW: Shouldn't this be used for any and all lowering? Possible problems with closures and/or structs if not?
+  /// Attempt to retrieve an existing definition, otherwise proceed to
+  /// lowering it and memoizing it under the current module.
+  ///
+  /// If specified, any modified buffers during the process will be kept,
+  /// otherwise they will all be restored.
+  fn memoize_or_retrieve_value(
+    &mut self,
+    cache_id: cache::Id,
+    cache: &cache::Cache,
+    forward_buffers: bool,
+    apply_access_rules: bool,
+  ) -> Option<inkwell::values::BasicValueEnum<'ctx>> {
+    let node = cache.force_get(&cache_id);
+
+    // BUG: If a definition is lowered elsewhere without the use of this function,
+    // ... there will not be any call to `lower_with_access_rules` for it. This means
+    // ... that those definitions will be cached as `PointerValue`s instead of possibly
+    // ... needing to be lowered.
+    // If the definition has been cached previously, simply retrieve it.
+    if let Some(existing_definition) = self.llvm_cached_values.get(&cache_id) {
+      // NOTE: The underlying LLVM value is not copied, but rather the reference to it.
+      let existing_value_cloned = existing_definition.clone();
+
+      return Some(if apply_access_rules {
+        self.apply_access_rules(node, existing_value_cloned, cache)
+      } else {
+        existing_value_cloned
+      });
+    }
+
+    let buffers = self.copy_buffers();
+    let llvm_value_result = node.lower(self, cache, false);
+
+    let result = if let Some(llvm_value) = llvm_value_result {
+      // Cache the value without applying access rules.
+      // This way, access rules may be chosen to be applied upon cache retrieval.
+      self.llvm_cached_values.insert(cache_id, llvm_value);
+
+      Some(if apply_access_rules {
+        self.apply_access_rules(node, llvm_value, cache)
+      } else {
+        llvm_value
+      })
+    } else {
+      None
+    };
+
+    // Restore buffers after processing, if requested.
+    if !forward_buffers {
+      self.restore_buffers(buffers);
+    }
+
+    result
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::mock::tests::{ComparableMock, Mock};
+
+  // TODO: Test mocking helpers themselves (in their own file).
+
+  #[test]
+  fn proper_initial_values() {
+    // TODO:
+  }
+
+  fn lower_string_binary_expr(operator: &str, file_name: &str) {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+
+    let (diagnostics, ast, cache) = crate::mock::tests::check_source(&format!(
+      "func f():\n  let a = ?to_string(\"a\") {} ?to_string(\"b\")\n",
+      operator
+    ));
+
+    assert!(diagnostics.is_empty());
+
+    let mut binary_exprs = Vec::new();
+
+    ast.first().unwrap().kind.traverse(|node| {
+      if let ast::NodeKind::BinaryExpr(_) = node {
+        binary_exprs.push(node);
+      }
+
+      true
+    });
+
+    Mock::new(&llvm_context, &llvm_module)
+      .with_cache(cache)
+      .function()
+      .lower(binary_exprs.first().unwrap(), true)
+      .compare_module_with_file(file_name);
+  }
+
+  fn lower_int_binary_expr(size: ast::IntSize, operator: ast::OperatorKind, file_name: &str) {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+    let a_cache_id: cache::Id = 0;
+    let b_cache_id: cache::Id = a_cache_id + 1;
+
+    let binding_stmt = |name: &str, cache_id| {
+      ast::NodeKind::BindingStmt(ast::BindingStmt {
+        name: name.to_string(),
+        value: Mock::boxed_node(ast::NodeKind::Literal(ast::Literal::Int(1, size.clone()))),
+        modifier: ast::BindingModifier::Immutable,
+        cache_id,
+        ty: ast::Type::Basic(ast::BasicType::Int(size.clone())),
+        position: 0,
+      })
+    };
+
+    let binary_expr = ast::NodeKind::BinaryExpr(ast::BinaryExpr {
+      left: Mock::reference(a_cache_id),
+      right: Mock::reference(b_cache_id),
+      operator,
+    });
+
+    Mock::new(&llvm_context, &llvm_module)
+      .cache(binding_stmt("a", a_cache_id), a_cache_id)
+      .cache(binding_stmt("b", b_cache_id), b_cache_id)
+      .function()
+      .lower_cache(a_cache_id, false)
+      .lower_cache(b_cache_id, false)
+      .lower(&binary_expr, false)
+      .compare_with_file(file_name);
+  }
+
+  fn lower_int_cast(from_size: ast::IntSize, to_size: ast::IntSize, file_name: &str) {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+    let cache_id: cache::Id = 0;
+
+    let binding_stmt = ast::NodeKind::BindingStmt(ast::BindingStmt {
+      name: "a".to_string(),
+      value: Mock::boxed_node(ast::NodeKind::Literal(ast::Literal::Int(
+        1,
+        from_size.clone(),
+      ))),
+      modifier: ast::BindingModifier::Immutable,
+      cache_id,
+      ty: ast::Type::Basic(ast::BasicType::Int(from_size)),
+      position: 0,
+    });
+
+    let cast_expr = ast::NodeKind::UnaryExpr(ast::UnaryExpr {
+      operator: ast::OperatorKind::Cast,
+      expr: Mock::reference(cache_id),
+      cast_type: Some(ast::Type::Basic(ast::BasicType::Int(to_size))),
+      position: 0,
+    });
+
+    Mock::new(&llvm_context, &llvm_module)
+      .cache(binding_stmt, cache_id)
+      .function()
+      .lower_cache(cache_id, false)
+      .lower(&cast_expr, false)
+      .compare_with_file(file_name);
+  }
+
+  fn int_pointer_binding_stmt(cache_id: cache::Id) -> ast::NodeKind {
+    let int_type = ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32));
+
+    ast::NodeKind::BindingStmt(ast::BindingStmt {
+      name: "a".to_string(),
+      value: Mock::boxed_node(ast::NodeKind::Literal(ast::Literal::Nullptr(
+        int_type.clone(),
+      ))),
+      modifier: ast::BindingModifier::Immutable,
+      cache_id,
+      ty: ast::Type::Pointer(Box::new(int_type)),
+      position: 0,
+    })
+  }
+
+  #[test]
+  fn target_pointer_width() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+    let mut generator = LlvmGenerator::new(&llvm_context, &llvm_module);
+    let mut cache = cache::Cache::new();
+
+    assert_eq!(64, generator.get_pointer_width());
+    assert_eq!(cache.pointer_width, generator.get_pointer_width());
+
+    assert!(generator
+      .set_target_triple("i686-unknown-linux-gnu", &mut cache)
+      .is_ok());
+
+    assert_eq!(32, generator.get_pointer_width());
+    assert_eq!(32, cache.pointer_width);
+
+    assert_eq!(
+      32,
+      generator
+        .lower_int_type(&ast::IntSize::Usize)
+        .get_bit_width()
+    );
+
+    assert!(generator
+      .set_target_triple("unknown-target", &mut cache)
+      .is_err());
+
+    assert_eq!(32, cache.pointer_width);
+  }
+
+  #[test]
+  fn lower_pointer_to_int_cast() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+    let cache_id: cache::Id = 0;
+    let binding_stmt = int_pointer_binding_stmt(cache_id);
+
+    let cast_expr = ast::NodeKind::UnaryExpr(ast::UnaryExpr {
+      operator: ast::OperatorKind::Cast,
+      expr: Mock::reference(cache_id),
+      cast_type: Some(ast::Type::Basic(ast::BasicType::Int(ast::IntSize::Usize))),
+      position: 0,
+    });
+
+    Mock::new(&llvm_context, &llvm_module)
+      .cache(binding_stmt, cache_id)
+      .function()
+      .lower_cache(cache_id, false)
+      .lower(&cast_expr, false)
+      .compare_with_file("pointer_to_int_cast");
+  }
+
+  #[test]
+  fn lower_print_intrinsic() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+
+    let print_call = ast::NodeKind::IntrinsicCall(ast::IntrinsicCall {
+      kind: ast::IntrinsicKind::Println,
+      type_arguments: Vec::new(),
+      field_name: None,
+      arguments: vec![
+        ast::Node {
+          kind: ast::NodeKind::Literal(ast::Literal::String(String::from("{} = {}%"))),
+          cached_type: None,
+        },
+        ast::Node {
+          kind: ast::NodeKind::Literal(ast::Literal::Bool(true)),
+          cached_type: None,
+        },
+        ast::Node {
+          kind: ast::NodeKind::Literal(ast::Literal::Int(42, ast::IntSize::I32)),
+          cached_type: None,
+        },
+      ],
+      position: 0,
+    });
+
+    Mock::new(&llvm_context, &llvm_module)
+      .function()
+      .lower(&print_call, false)
+      .compare_with_file("print_intrinsic");
+  }
+
+  #[test]
+  fn lower_assert_intrinsic() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+
+    let assert_call = ast::NodeKind::IntrinsicCall(ast::IntrinsicCall {
+      kind: ast::IntrinsicKind::Assert,
+      type_arguments: Vec::new(),
+      field_name: None,
+      arguments: vec![
+        ast::Node {
+          kind: ast::NodeKind::Literal(ast::Literal::Bool(true)),
+          cached_type: None,
+        },
+        ast::Node {
+          kind: ast::NodeKind::Literal(ast::Literal::String(String::from("oops"))),
+          cached_type: None,
+        },
+      ],
+      position: 0,
+    });
+
+    Mock::new(&llvm_context, &llvm_module)
+      .function()
+      .lower(&assert_call, false)
+      .compare_with_file("assert_intrinsic");
+  }
+
+  #[test]
+  fn lower_free_intrinsic() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+    let int_type = ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32));
+
+    let free_call = ast::NodeKind::IntrinsicCall(ast::IntrinsicCall {
+      kind: ast::IntrinsicKind::Free,
+      type_arguments: vec![int_type.clone()],
+      field_name: None,
+      arguments: vec![ast::Node {
+        kind: ast::NodeKind::Literal(ast::Literal::Nullptr(int_type)),
+        cached_type: None,
+      }],
+      position: 0,
+    });
+
+    Mock::new(&llvm_context, &llvm_module)
+      .function()
+      .lower(&free_call, false)
+      .compare_with_file("free_intrinsic");
+  }
+
+  #[test]
+  fn lower_slice_expr() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+
+    let int_node = |value: u128| ast::Node {
+      kind: ast::NodeKind::Literal(ast::Literal::Int(value, ast::IntSize::U32)),
+      cached_type: None,
+    };
+
+    let slice_expr = ast::NodeKind::SliceExpr(ast::SliceExpr {
+      target_expr: Box::new(ast::Node {
+        kind: ast::NodeKind::StaticArrayValue(ast::StaticArrayValue {
+          elements: vec![int_node(1)],
+          explicit_type: None,
+        }),
+        cached_type: None,
+      }),
+      start_expr: Box::new(int_node(0)),
+      end_expr: Box::new(int_node(1)),
+      position: 0,
+    });
+
+    Mock::new(&llvm_context, &llvm_module)
+      .function()
+      .lower(&slice_expr, false)
+      .compare_with_file("slice_expr");
+  }
+
+  #[test]
+  fn lower_copy_intrinsic() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+    let int_type = ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32));
+
+    let null_pointer = || ast::Node {
+      kind: ast::NodeKind::Literal(ast::Literal::Nullptr(int_type.clone())),
+      cached_type: None,
+    };
+
+    let copy_call = ast::NodeKind::IntrinsicCall(ast::IntrinsicCall {
+      kind: ast::IntrinsicKind::Copy,
+      type_arguments: vec![int_type.clone()],
+      field_name: None,
+      arguments: vec![
+        null_pointer(),
+        null_pointer(),
+        ast::Node {
+          kind: ast::NodeKind::Literal(ast::Literal::Int(4, ast::IntSize::Usize)),
+          cached_type: None,
+        },
+      ],
+      position: 0,
+    });
+
+    Mock::new(&llvm_context, &llvm_module)
+      .function()
+      .lower(&copy_call, false)
+      .compare_with_file("copy_intrinsic");
+  }
+
+  #[test]
+  fn lower_copy_intrinsic_overflow_check() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+    let int_type = ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32));
+    let usize_type = ast::Type::Basic(ast::BasicType::Int(ast::IntSize::Usize));
+
+    let null_pointer = |ty: &ast::Type| ast::Node {
+      kind: ast::NodeKind::Literal(ast::Literal::Nullptr(ty.clone())),
+      cached_type: None,
+    };
+
+    let copy_call = ast::NodeKind::IntrinsicCall(ast::IntrinsicCall {
+      kind: ast::IntrinsicKind::Copy,
+      type_arguments: vec![int_type.clone()],
+      field_name: None,
+      arguments: vec![
+        null_pointer(&int_type),
+        null_pointer(&int_type),
+        ast::Node {
+          kind: ast::NodeKind::UnaryExpr(ast::UnaryExpr {
+            operator: ast::OperatorKind::MultiplyOrDereference,
+            expr: Box::new(null_pointer(&usize_type)),
+            cast_type: None,
+            position: 0,
+          }),
+          cached_type: None,
+        },
+      ],
+      position: 0,
+    });
+
+    Mock::new(&llvm_context, &llvm_module)
+      .function()
+      .lower(&copy_call, false)
+      .compare_with_file("copy_intrinsic_overflow_check");
+  }
+
+  #[test]
+  fn lower_unimplemented_expr() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+
+    let unimplemented_expr =
+      ast::NodeKind::UnimplementedExpr(ast::UnimplementedExpr { position: 0 });
+
+    Mock::new(&llvm_context, &llvm_module)
+      .function()
+      .lower(&unimplemented_expr, false)
+      .compare_with_file("unimplemented_expr");
+  }
+
+  #[test]
+  fn locate() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+    let mut generator = LlvmGenerator::new(&llvm_context, &llvm_module);
+
+    assert_eq!("unnamed:?:?", generator.locate(3));
+    generator.set_source("func\n  ???\n");
+    assert_eq!("unnamed:1:1", generator.locate(0));
+    assert_eq!("unnamed:1:5", generator.locate(4));
+    assert_eq!("unnamed:2:3", generator.locate(7));
+  }
+
+  #[test]
+  fn lower_dynamic_array_indexing() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+    let array_id: cache::Id = 0;
+    let index_id: cache::Id = 1;
+
+    let array_binding_stmt = ast::NodeKind::BindingStmt(ast::BindingStmt {
+      name: "a".to_string(),
+      value: Mock::boxed_node(ast::NodeKind::StaticArrayValue(ast::StaticArrayValue {
+        elements: vec![ast::Node {
+          kind: ast::NodeKind::Literal(ast::Literal::Int(1, ast::IntSize::I32)),
+          cached_type: None,
+        }],
+        explicit_type: None,
+      })),
+      modifier: ast::BindingModifier::Immutable,
+      cache_id: array_id,
+      ty: ast::Type::Array(
+        Box::new(ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32))),
+        1,
+      ),
+      position: 0,
+    });
+
+    let index_binding_stmt = ast::NodeKind::BindingStmt(ast::BindingStmt {
+      name: "i".to_string(),
+      value: Mock::boxed_node(ast::NodeKind::Literal(ast::Literal::Int(
+        0,
+        ast::IntSize::U32,
+      ))),
+      modifier: ast::BindingModifier::Immutable,
+      cache_id: index_id,
+      ty: ast::Type::Basic(ast::BasicType::Int(ast::IntSize::U32)),
+      position: 0,
+    });
+
+    let indexing_expr = ast::NodeKind::IndexingExpr(ast::IndexingExpr {
+      target_expr: Mock::reference(array_id),
+      index_expr: Mock::reference(index_id),
+      position: 0,
+    });
+
+    Mock::new(&llvm_context, &llvm_module)
+      .cache(array_binding_stmt, array_id)
+      .cache(index_binding_stmt, index_id)
+      .function()
+      .lower_cache(array_id, false)
+      .lower_cache(index_id, false)
+      .lower(&indexing_expr, true)
+      .compare_with_file("array_indexing_bounds_check");
+  }
+
+  #[test]
+  fn lower_nested_array_indexing() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+    let cache_id: cache::Id = 0;
+    let int_type = ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32));
+
+    let array_value = |element: ast::NodeKind| {
+      Mock::boxed_node(ast::NodeKind::StaticArrayValue(ast::StaticArrayValue {
+        elements: vec![*Mock::boxed_node(element)],
+        explicit_type: None,
+      }))
+    };
+
+    let binding_stmt = ast::NodeKind::BindingStmt(ast::BindingStmt {
+      name: "a".to_string(),
+      value: array_value(
+        array_value(ast::NodeKind::Literal(ast::Literal::Int(
+          1,
+          ast::IntSize::I32,
+        )))
+        .kind,
+      ),
+      modifier: ast::BindingModifier::Immutable,
+      cache_id,
+      ty: ast::Type::Array(Box::new(ast::Type::Array(Box::new(int_type), 1)), 1),
+      position: 0,
+    });
+
+    let indexing_expr = |target_expr: Box<ast::Node>| ast::IndexingExpr {
+      target_expr,
+      index_expr: Mock::boxed_node(ast::NodeKind::Literal(ast::Literal::Int(
+        0,
+        ast::IntSize::I32,
+      ))),
+      position: 0,
+    };
 
-    // BUG: If a definition is lowered elsewhere without the use of this function,
-    // ... there will not be any call to `lower_with_access_rules` for it. This means
-    // ... that those definitions will be cached as `PointerValue`s instead of possibly
-    // ... needing to be lowered.
-    // If the definition has been cached previously, simply retrieve it.
-    if let Some(existing_definition) = self.llvm_cached_values.get(&cache_id) {
-      // NOTE: The underlying LLVM value is not copied, but rather the reference to it.
-      let existing_value_cloned = existing_definition.clone();
+    let nested_indexing_expr = ast::NodeKind::IndexingExpr(indexing_expr(Mock::boxed_node(
+      ast::NodeKind::IndexingExpr(indexing_expr(Mock::reference(cache_id))),
+    )));
 
-      return Some(if apply_access_rules {
-        self.apply_access_rules(node, existing_value_cloned, cache)
-      } else {
-        existing_value_cloned
-      });
-    }
+    Mock::new(&llvm_context, &llvm_module)
+      .cache(binding_stmt, cache_id)
+      .function()
+      .lower_cache(cache_id, false)
+      .lower(&nested_indexing_expr, true)
+      .compare_with_file("nested_array_indexing");
+  }
 
-    let buffers = self.copy_buffers();
-    let llvm_value_result = node.lower(self, cache, false);
+  #[test]
+  fn lower_pointer_indexing() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+    let cache_id: cache::Id = 0;
+    let binding_stmt = int_pointer_binding_stmt(cache_id);
 
-    let result = if let Some(llvm_value) = llvm_value_result {
-      // Cache the value without applying access rules.
-      // This way, access rules may be chosen to be applied upon cache retrieval.
-      self.llvm_cached_values.insert(cache_id, llvm_value);
+    let indexing_expr = ast::NodeKind::IndexingExpr(ast::IndexingExpr {
+      target_expr: Mock::reference(cache_id),
+      index_expr: Mock::boxed_node(ast::NodeKind::Literal(ast::Literal::Int(
+        1,
+        ast::IntSize::I32,
+      ))),
+      position: 0,
+    });
 
-      Some(if apply_access_rules {
-        self.apply_access_rules(node, llvm_value, cache)
-      } else {
-        llvm_value
-      })
-    } else {
-      None
-    };
+    Mock::new(&llvm_context, &llvm_module)
+      .cache(binding_stmt, cache_id)
+      .function()
+      .lower_cache(cache_id, false)
+      .lower(&indexing_expr, true)
+      .compare_with_file("pointer_indexing");
+  }
 
-    // Restore buffers after processing, if requested.
-    if !forward_buffers {
-      self.restore_buffers(buffers);
-    }
+  fn lower_pointer_binary_expr(operator: ast::OperatorKind, file_name: &str) {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+    let cache_id: cache::Id = 0;
+    let binding_stmt = int_pointer_binding_stmt(cache_id);
 
-    result
+    let binary_expr = ast::NodeKind::BinaryExpr(ast::BinaryExpr {
+      left: Mock::reference(cache_id),
+      right: Mock::reference(cache_id),
+      operator,
+    });
+
+    Mock::new(&llvm_context, &llvm_module)
+      .cache(binding_stmt, cache_id)
+      .function()
+      .lower_cache(cache_id, false)
+      .lower(&binary_expr, false)
+      .compare_with_file(file_name);
   }
-}
 
-#[cfg(test)]
-mod tests {
-  use super::*;
-  use crate::mock::tests::{ComparableMock, Mock};
+  #[test]
+  fn lower_pointer_difference() {
+    lower_pointer_binary_expr(ast::OperatorKind::SubtractOrNegate, "pointer_difference");
+  }
 
-  // TODO: Test mocking helpers themselves (in their own file).
+  #[test]
+  fn lower_pointer_comparison() {
+    lower_pointer_binary_expr(ast::OperatorKind::LessThan, "pointer_less_than");
+    lower_pointer_binary_expr(ast::OperatorKind::Equality, "pointer_equality");
+  }
 
   #[test]
-  fn proper_initial_values() {
-    // TODO:
+  fn lower_pointer_offset() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+    let cache_id: cache::Id = 0;
+    let binding_stmt = int_pointer_binding_stmt(cache_id);
+
+    let binary_expr = ast::NodeKind::BinaryExpr(ast::BinaryExpr {
+      left: Mock::reference(cache_id),
+      right: Mock::boxed_node(ast::NodeKind::Literal(ast::Literal::Int(
+        2,
+        ast::IntSize::I32,
+      ))),
+      operator: ast::OperatorKind::Add,
+    });
+
+    Mock::new(&llvm_context, &llvm_module)
+      .cache(binding_stmt, cache_id)
+      .function()
+      .lower_cache(cache_id, false)
+      .lower(&binary_expr, false)
+      .compare_with_file("pointer_offset");
   }
 
   #[test]
@@ -1899,6 +6133,7 @@ mod tests {
 
     Mock::new(&llvm_context, &llvm_module)
       .function()
+      .with_loop()
       .lower(&node, false)
       .compare_with_file("continue_stmt");
   }
@@ -1914,6 +6149,7 @@ mod tests {
       modifier: ast::BindingModifier::Immutable,
       cache_id: 0,
       ty: ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
+      position: 0,
     });
 
     Mock::new(&llvm_context, &llvm_module)
@@ -1935,6 +6171,7 @@ mod tests {
       modifier: ast::BindingModifier::Immutable,
       cache_id: a_cache_id,
       ty: ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
+      position: 0,
     });
 
     let binding_stmt_b = ast::NodeKind::BindingStmt(ast::BindingStmt {
@@ -1943,6 +6180,7 @@ mod tests {
       modifier: ast::BindingModifier::Immutable,
       cache_id: a_cache_id + 1,
       ty: ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
+      position: 0,
     });
 
     Mock::new(&llvm_context, &llvm_module)
@@ -1966,6 +6204,7 @@ mod tests {
       cache_id: 0,
       // FIXME: Wrong type.
       ty: ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
+      position: 0,
     });
 
     Mock::new(&llvm_context, &llvm_module)
@@ -1988,6 +6227,7 @@ mod tests {
       cache_id: a_cache_id,
       // FIXME: Wrong type.
       ty: ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
+      position: 0,
     });
 
     let binding_stmt_b = ast::NodeKind::BindingStmt(ast::BindingStmt {
@@ -1997,6 +6237,7 @@ mod tests {
       cache_id: a_cache_id + 1,
       // FIXME: Wrong type.
       ty: ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
+      position: 0,
     });
 
     Mock::new(&llvm_context, &llvm_module)
@@ -2021,6 +6262,7 @@ mod tests {
       cache_id: 0,
       // FIXME: Wrong type.
       ty: ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
+      position: 0,
     });
 
     Mock::new(&llvm_context, &llvm_module)
@@ -2042,6 +6284,7 @@ mod tests {
       cache_id,
       // FIXME: Wrong type.
       ty: ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
+      position: 0,
     });
 
     let assign_stmt = ast::NodeKind::AssignStmt(ast::AssignStmt {
@@ -2050,6 +6293,7 @@ mod tests {
         2,
         ast::IntSize::I32,
       ))),
+      position: 0,
     });
 
     Mock::new(&llvm_context, &llvm_module)
@@ -2075,6 +6319,7 @@ mod tests {
       cache_id: a_cache_id,
       // FIXME: Wrong type.
       ty: ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
+      position: 0,
     });
 
     let binding_stmt_b = ast::NodeKind::BindingStmt(ast::BindingStmt {
@@ -2084,11 +6329,13 @@ mod tests {
       cache_id: b_cache_id,
       // FIXME: Wrong type.
       ty: ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
+      position: 0,
     });
 
     let assign_stmt = ast::NodeKind::AssignStmt(ast::AssignStmt {
       assignee_expr: Mock::reference(b_cache_id),
       value: Mock::reference(a_cache_id),
+      position: 0,
     });
 
     Mock::new(&llvm_context, &llvm_module)
@@ -2100,6 +6347,62 @@ mod tests {
       .compare_with_file("assign_stmt_ptr_to_ptr");
   }
 
+  #[test]
+  fn lower_assign_through_mutable_reference() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+    let a_cache_id: cache::Id = 0;
+    let b_cache_id: cache::Id = a_cache_id + 1;
+
+    let binding_stmt_a = ast::NodeKind::BindingStmt(ast::BindingStmt {
+      name: "a".to_string(),
+      value: Mock::boxed_node(Mock::literal_int()),
+      modifier: ast::BindingModifier::Mutable,
+      cache_id: a_cache_id,
+      ty: ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
+      position: 0,
+    });
+
+    let binding_stmt_b = ast::NodeKind::BindingStmt(ast::BindingStmt {
+      name: "b".to_string(),
+      value: Mock::boxed_node(ast::NodeKind::UnaryExpr(ast::UnaryExpr {
+        operator: ast::OperatorKind::MutableAddressOf,
+        expr: Mock::reference(a_cache_id),
+        cast_type: None,
+        position: 0,
+      })),
+      modifier: ast::BindingModifier::Immutable,
+      cache_id: b_cache_id,
+      ty: ast::Type::MutableReference(Box::new(ast::Type::Basic(ast::BasicType::Int(
+        ast::IntSize::I32,
+      )))),
+      position: 0,
+    });
+
+    let assign_stmt = ast::NodeKind::AssignStmt(ast::AssignStmt {
+      assignee_expr: Mock::boxed_node(ast::NodeKind::UnaryExpr(ast::UnaryExpr {
+        operator: ast::OperatorKind::MultiplyOrDereference,
+        expr: Mock::reference(b_cache_id),
+        cast_type: None,
+        position: 0,
+      })),
+      value: Mock::boxed_node(ast::NodeKind::Literal(ast::Literal::Int(
+        2,
+        ast::IntSize::I32,
+      ))),
+      position: 0,
+    });
+
+    Mock::new(&llvm_context, &llvm_module)
+      .cache(binding_stmt_a, a_cache_id)
+      .cache(binding_stmt_b, b_cache_id)
+      .function()
+      .lower_cache(a_cache_id, false)
+      .lower_cache(b_cache_id, false)
+      .lower(&assign_stmt, false)
+      .compare_with_file("assign_stmt_through_reference");
+  }
+
   #[test]
   fn lower_enum() {
     let llvm_context = inkwell::context::Context::create();
@@ -2155,6 +6458,7 @@ mod tests {
       prototype: Mock::prototype_simple(true),
       attributes: Vec::new(),
       cache_id: 0,
+      position: 0,
     });
 
     Mock::new(&llvm_context, &llvm_module)
@@ -2197,4 +6501,263 @@ mod tests {
       .lower(&if_expr, false)
       .compare_with_file("if_expr_simple");
   }
+
+  #[test]
+  fn lower_int_divide() {
+    lower_int_binary_expr(
+      ast::IntSize::I8,
+      ast::OperatorKind::Divide,
+      "int_divide_signed",
+    );
+    lower_int_binary_expr(
+      ast::IntSize::U8,
+      ast::OperatorKind::Divide,
+      "int_divide_unsigned",
+    );
+  }
+
+  #[test]
+  fn lower_int_remainder() {
+    lower_int_binary_expr(
+      ast::IntSize::I8,
+      ast::OperatorKind::Remainder,
+      "int_remainder_signed",
+    );
+
+    lower_int_binary_expr(
+      ast::IntSize::U8,
+      ast::OperatorKind::Remainder,
+      "int_remainder_unsigned",
+    );
+  }
+
+  #[test]
+  fn lower_int_compare() {
+    lower_int_binary_expr(
+      ast::IntSize::I8,
+      ast::OperatorKind::LessThan,
+      "int_less_than_signed",
+    );
+
+    lower_int_binary_expr(
+      ast::IntSize::U8,
+      ast::OperatorKind::LessThan,
+      "int_less_than_unsigned",
+    );
+
+    lower_int_binary_expr(
+      ast::IntSize::I8,
+      ast::OperatorKind::GreaterThan,
+      "int_greater_than_signed",
+    );
+
+    lower_int_binary_expr(
+      ast::IntSize::U8,
+      ast::OperatorKind::GreaterThan,
+      "int_greater_than_unsigned",
+    );
+  }
+
+  #[test]
+  fn lower_int_cast() {
+    lower_int_cast(ast::IntSize::I8, ast::IntSize::I32, "int_cast_sign_extend");
+    lower_int_cast(ast::IntSize::U8, ast::IntSize::I32, "int_cast_zero_extend");
+    lower_int_cast(ast::IntSize::I32, ast::IntSize::U8, "int_cast_truncate");
+    lower_int_cast(
+      ast::IntSize::I64,
+      ast::IntSize::I128,
+      "int_cast_sign_extend_wide",
+    );
+  }
+
+  #[test]
+  fn lower_interpolation_expr() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+
+    let (diagnostics, ast, cache) = crate::mock::tests::check_source(
+      "func name() -> String:\n  return ?to_string(\"b\")\n\nfunc f():\n  let a = \"name: {name()}\"\n",
+    );
+
+    assert!(diagnostics.is_empty());
+
+    let mut interpolation_exprs = Vec::new();
+
+    ast.last().unwrap().kind.traverse(|node| {
+      if let ast::NodeKind::InterpolationExpr(_) = node {
+        interpolation_exprs.push(node);
+      }
+
+      true
+    });
+
+    Mock::new(&llvm_context, &llvm_module)
+      .with_cache(cache)
+      .function()
+      .lower(interpolation_exprs.first().unwrap(), true)
+      .compare_with_file("interpolation_expr");
+  }
+
+  #[test]
+  fn lower_drop_binding() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+
+    let (diagnostics, ast, cache) = crate::mock::tests::check_source(
+      "struct R:\n  x: Int,\n\nimpl Drop for R:\n  func drop(this):\n    pass\n\nfunc f():\n  if true:\n    let a = new R { 1 }\n    let b = a\n",
+    );
+
+    assert!(diagnostics.is_empty());
+
+    let mut block_exprs = Vec::new();
+
+    ast.last().unwrap().kind.traverse(|node| {
+      if let ast::NodeKind::BlockExpr(_) = node {
+        block_exprs.push(node);
+      }
+
+      true
+    });
+
+    // The drop flag of `a` is cleared once it is moved into `b`, so only `b` is dropped.
+    Mock::new(&llvm_context, &llvm_module)
+      .with_cache(cache)
+      .function()
+      .lower(block_exprs.first().unwrap(), false)
+      .compare_with_file("drop_binding");
+  }
+
+  #[test]
+  fn lower_return_array_as_slice() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+
+    let (diagnostics, ast, cache) = crate::mock::tests::check_source(
+      "extern static table: [Int, 2]\n\nfunc f() -> []Int:\n  return table\n",
+    );
+
+    assert!(diagnostics.is_empty());
+
+    // The slice points into the static array itself, rather than into a copy of it.
+    Mock::new(&llvm_context, &llvm_module)
+      .with_cache(cache)
+      .module()
+      .lower(&ast.last().unwrap().kind, false)
+      .compare_with_file("return_array_as_slice");
+  }
+
+  #[test]
+  fn lower_indirect_call() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+
+    let (diagnostics, ast, cache) = crate::mock::tests::check_source(
+      "func apply(callback: Int -> Int) -> Int:\n  return callback(1)\n",
+    );
+
+    assert!(diagnostics.is_empty());
+
+    // Function-typed parameters are closures, whose environment is passed first.
+    Mock::new(&llvm_context, &llvm_module)
+      .with_cache(cache)
+      .module()
+      .lower(&ast.last().unwrap().kind, false)
+      .compare_with_file("indirect_call");
+  }
+
+  #[test]
+  fn lower_closure_capture() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+
+    let (diagnostics, ast, cache) = crate::mock::tests::check_source(
+      "func f():\n  let a = 1\n  let g = func () -> Int:\n    return a\n",
+    );
+
+    assert!(diagnostics.is_empty());
+
+    // The captured value is copied into an environment on the stack of `f`,
+    // ... which the closure reads it back from.
+    Mock::new(&llvm_context, &llvm_module)
+      .with_cache(cache)
+      .module()
+      .lower(&ast.last().unwrap().kind, false)
+      .compare_with_file("closure_capture");
+  }
+
+  #[test]
+  fn lower_static_method_call() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+
+    let (diagnostics, ast, cache) = crate::mock::tests::check_source(
+      "struct Point:\n  x: Int,\n\nimpl Point:\n  static func origin() -> Point:\n    return new Point{0}\n\nfunc f():\n  let a = Point::origin()\n",
+    );
+
+    assert!(diagnostics.is_empty());
+
+    Mock::new(&llvm_context, &llvm_module)
+      .with_cache(cache)
+      .module()
+      .lower(&ast.last().unwrap().kind, false)
+      .compare_with_file("static_method_call");
+  }
+
+  #[test]
+  fn lower_implicit_constructor() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+
+    let (diagnostics, ast, cache) = crate::mock::tests::check_source(
+      "struct Point:\n  x: Int,\n\nimpl Point:\n  func init(this):\n    pass\n\nfunc f():\n  let a = new Point{0}\n",
+    );
+
+    assert!(diagnostics.is_empty());
+
+    // The constructor is called on the struct value once its fields are initialized.
+    Mock::new(&llvm_context, &llvm_module)
+      .with_cache(cache)
+      .module()
+      .lower(&ast.last().unwrap().kind, false)
+      .compare_with_file("implicit_constructor");
+  }
+
+  #[test]
+  fn lower_defer_continue() {
+    let llvm_context = inkwell::context::Context::create();
+    let llvm_module = llvm_context.create_module("test");
+
+    let (diagnostics, ast, cache) = crate::mock::tests::check_source(
+      "func g():\n  pass\n\nfunc f():\n  loop:\n    defer: g()\n    continue\n",
+    );
+
+    assert!(diagnostics.is_empty());
+
+    let mut loop_stmts = Vec::new();
+
+    ast.last().unwrap().kind.traverse(|node| {
+      if let ast::NodeKind::LoopStmt(_) = node {
+        loop_stmts.push(node);
+      }
+
+      true
+    });
+
+    // The deferred call runs before continuing to the next iteration.
+    Mock::new(&llvm_context, &llvm_module)
+      .with_cache(cache)
+      .function()
+      .lower(loop_stmts.first().unwrap(), false)
+      .compare_with_file("defer_continue");
+  }
+
+  #[test]
+  fn lower_string_concat() {
+    lower_string_binary_expr("+", "string_concat");
+  }
+
+  #[test]
+  fn lower_string_compare() {
+    lower_string_binary_expr("<", "string_compare");
+  }
 }
@@ -3,6 +3,60 @@ pub mod tests {
   use crate::{ast, cache, lowering::LlvmGenerator};
   use crate::{lowering::Lower, name_resolution};
 
+  /// Lex, parse and resolve the names of the given source code, as a single
+  /// module. Panics if the source code is not syntactically valid, or if any
+  /// of its names cannot be resolved.
+  pub fn resolve_source(source_code: &str) -> (Vec<ast::Node>, cache::Cache) {
+    let tokens = crate::lexer::Lexer::from_str(source_code)
+      .lex_all()
+      .unwrap()
+      .into_iter()
+      .filter(|token| {
+        !matches!(
+          token.0,
+          crate::lexer::TokenKind::Whitespace(_) | crate::lexer::TokenKind::Comment(_)
+        )
+      })
+      .collect();
+
+    let mut cache = cache::Cache::new();
+    let mut substitutions = Vec::new();
+
+    let ast = crate::parser::Parser::new(tokens, &mut cache, &mut substitutions)
+      .parse_all()
+      .unwrap();
+
+    let qualifier = name_resolution::Qualifier {
+      package_name: String::from("test"),
+      module_name: String::from("test"),
+    };
+
+    let mut ast_map = std::collections::BTreeMap::new();
+
+    ast_map.insert(qualifier.clone(), ast);
+
+    assert!(name_resolution::NameResolver::new(qualifier.clone())
+      .run(&mut ast_map, &mut cache)
+      .is_empty());
+
+    (ast_map.remove(&qualifier).unwrap(), cache)
+  }
+
+  /// Type-check the given source code, yielding the resulting diagnostics,
+  /// along with the checked AST and its cache.
+  pub fn check_source(
+    source_code: &str,
+  ) -> (
+    Vec<codespan_reporting::diagnostic::Diagnostic<usize>>,
+    Vec<ast::Node>,
+    cache::Cache,
+  ) {
+    let (mut ast, mut cache) = resolve_source(source_code);
+    let (diagnostics, _) = crate::type_system::TypeContext::run(&mut ast, &mut cache);
+
+    (diagnostics, ast, cache)
+  }
+
   pub trait ComparableMock: ToString {
     fn compare_with(&self, expected: &str) {
       Mock::compare(&self.to_string(), expected);
@@ -33,9 +87,21 @@ pub mod tests {
       }
     }
 
+    /// Lower within a loop, whose next iteration and exit are separate
+    /// (empty) blocks, so that `break` and `continue` can be told apart.
     pub fn with_loop(&mut self) -> &mut Self {
-      self.mock.generator.current_loop_block =
-        Some(self.mock.generator.llvm_builder.get_insert_block().unwrap());
+      let next_block = self
+        .mock
+        .context
+        .append_basic_block(self.function, "loop.next");
+
+      let after_block = self
+        .mock
+        .context
+        .append_basic_block(self.function, "loop.after");
+
+      self.mock.generator.current_continue_block = Some(next_block);
+      self.mock.generator.current_loop_block = Some(after_block);
 
       self
     }
@@ -55,6 +121,12 @@ pub mod tests {
 
       self
     }
+
+    /// Compare the whole module, including any runtime definitions that
+    /// lowering has introduced, instead of only the function.
+    pub fn compare_module_with_file(&self, file_name: &str) {
+      Mock::compare_with_file(&self.mock.module.print_to_string().to_string(), file_name);
+    }
   }
 
   impl<'a, 'ctx> ThenMock<'a, 'ctx> for FunctionMock<'a, 'ctx> {
@@ -130,6 +202,7 @@ pub mod tests {
           symbol_kind: name_resolution::SymbolKind::Definition,
           target_id: Some(cache_id),
         },
+        position: 0,
       }))
     }
 
@@ -140,6 +213,7 @@ pub mod tests {
         is_variadic: false,
         is_extern,
         accepts_instance: false,
+        is_instance_mutable: false,
         instance_type_id: None,
         this_parameter: None,
       }
@@ -189,6 +263,14 @@ pub mod tests {
       }
     }
 
+    /// Use the cache of checked source code, such as the one yielded
+    /// by `check_source`.
+    pub fn with_cache(mut self, cache: cache::Cache) -> Self {
+      self.cache = cache;
+
+      self
+    }
+
     pub fn function(&'a mut self) -> FunctionMock<'a, 'ctx> {
       let function =
         self
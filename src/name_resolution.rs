@@ -1,5 +1,25 @@
 use crate::{ast, cache, lowering};
 
+/// The name of the built-in marker trait implemented by plain data structs,
+/// whose values may be copied freely instead of being moved.
+pub const COPY_TRAIT_NAME: &str = "Copy";
+
+/// The name of the built-in trait implemented by structs that need to run
+/// cleanup code (their destructor) once they go out of scope.
+pub const DROP_TRAIT_NAME: &str = "Drop";
+
+/// The name of the destructor method required by the `Drop` trait.
+pub const DROP_METHOD_NAME: &str = "drop";
+
+/// Determine whether a resolved trait pattern refers to the built-in trait
+/// with the given name. A trait declared by the user under the same name
+/// shadows the built-in one, so only unresolved, unqualified patterns match.
+pub fn is_builtin_trait(trait_pattern: &ast::Pattern, name: &str) -> bool {
+  trait_pattern.target_id.is_none()
+    && trait_pattern.qualifier.is_none()
+    && trait_pattern.base_name == name
+}
+
 #[derive(Hash, PartialEq, Eq, Clone, Debug)]
 pub enum SymbolKind {
   Definition,
@@ -33,10 +53,18 @@ impl Resolve for ast::Type {
     match self {
       ast::Type::Stub(stub_type) => stub_type.resolve(resolver, cache),
       ast::Type::This(this_type) => this_type.resolve(resolver, cache),
-      ast::Type::Pointer(pointee_type) => pointee_type.resolve(resolver, cache),
+      ast::Type::Pointer(pointee_type)
+      | ast::Type::Reference(pointee_type)
+      | ast::Type::MutableReference(pointee_type) => pointee_type.resolve(resolver, cache),
       ast::Type::Array(element_type, _) => element_type.resolve(resolver, cache),
+      ast::Type::Slice(element_type) => element_type.resolve(resolver, cache),
       ast::Type::Struct(struct_type) => struct_type.resolve(resolver, cache),
       ast::Type::Function(function_type) => function_type.resolve(resolver, cache),
+      ast::Type::Optional(value_type) => value_type.resolve(resolver, cache),
+      ast::Type::Result(value_type, error_type) => {
+        value_type.resolve(resolver, cache);
+        error_type.resolve(resolver, cache);
+      }
       // REVIEW: Are there any other types that may need to be resolved?
       _ => {}
     };
@@ -59,16 +87,49 @@ impl Resolve for ast::UnimplementedExpr {
   //
 }
 
-impl Resolve for ast::Range {
-  // REVIEW: Will the constant expressions ever need to declare or resolve?
+impl Resolve for ast::OptionalValue {
+  fn declare(&self, resolver: &mut NameResolver) {
+    if let Some(value) = &self.value {
+      value.kind.declare(resolver);
+    }
+  }
+
+  fn resolve(&mut self, resolver: &mut NameResolver, cache: &mut cache::Cache) {
+    if let Some(value) = &mut self.value {
+      value.kind.resolve(resolver, cache);
+    }
+
+    if let Some(explicit_type) = &mut self.explicit_type {
+      explicit_type.resolve(resolver, cache);
+    }
+  }
 }
 
-impl Resolve for ast::SizeofIntrinsic {
+impl Resolve for ast::ResultValue {
+  fn declare(&self, resolver: &mut NameResolver) {
+    self.value.kind.declare(resolver);
+  }
+
   fn resolve(&mut self, resolver: &mut NameResolver, cache: &mut cache::Cache) {
-    self.ty.resolve(resolver, cache);
+    self.value.kind.resolve(resolver, cache);
+    self.explicit_type.resolve(resolver, cache);
+  }
+}
+
+impl Resolve for ast::TryExpr {
+  fn declare(&self, resolver: &mut NameResolver) {
+    self.expr.kind.declare(resolver);
+  }
+
+  fn resolve(&mut self, resolver: &mut NameResolver, cache: &mut cache::Cache) {
+    self.expr.kind.resolve(resolver, cache);
   }
 }
 
+impl Resolve for ast::Range {
+  // REVIEW: Will the constant expressions ever need to declare or resolve?
+}
+
 impl Resolve for ast::Using {
   //
 }
@@ -84,9 +145,31 @@ impl Resolve for ast::ParenthesesExpr {
 }
 
 impl Resolve for ast::Trait {
-  fn declare(&self, _resolver: &mut NameResolver) {
-    // REVIEW: Is there a need to declare symbol here?
-    // resolver.declare_symbol((self.name.clone(), SymbolKind::Type), self.cache_id);
+  fn declare(&self, resolver: &mut NameResolver) {
+    resolver.declare_symbol(
+      Symbol {
+        base_name: self.name.clone(),
+        sub_name: None,
+        kind: SymbolKind::Type,
+      },
+      self.cache_id,
+    );
+  }
+
+  fn resolve(&mut self, resolver: &mut NameResolver, cache: &mut cache::Cache) {
+    // NOTE: The instance parameter is left unresolved, since a trait
+    // ... isn't bound to any particular struct type.
+    for (_, prototype) in &mut self.methods {
+      for parameter in &mut prototype.parameters {
+        parameter.ty.resolve(resolver, cache);
+      }
+
+      prototype.return_type_annotation.resolve(resolver, cache);
+    }
+
+    cache
+      .symbols
+      .insert(self.cache_id, ast::NodeKind::Trait(self.clone()));
   }
 }
 
@@ -128,7 +211,39 @@ impl Resolve for ast::StructImpl {
     self.target_struct_pattern.resolve(resolver, cache);
 
     if let Some(trait_pattern) = &mut self.trait_pattern {
-      trait_pattern.resolve(resolver, cache);
+      let is_declared = trait_pattern.qualifier.is_some()
+        || resolver
+          .local_lookup(&Symbol {
+            base_name: trait_pattern.base_name.clone(),
+            sub_name: trait_pattern.sub_name.clone(),
+            kind: trait_pattern.symbol_kind.clone(),
+          })
+          .is_some();
+
+      // The built-in `Copy` and `Drop` traits are never declared, and are
+      // shadowed by user traits of the same name.
+      if is_declared {
+        trait_pattern.resolve(resolver, cache);
+      } else if trait_pattern.base_name == COPY_TRAIT_NAME {
+        if let Some(struct_type_id) = self.target_struct_pattern.target_id {
+          cache.copy_types.insert(struct_type_id);
+        }
+      } else if trait_pattern.base_name == DROP_TRAIT_NAME {
+        let drop_method = self
+          .member_methods
+          .iter()
+          .find(|method| method.name == DROP_METHOD_NAME);
+
+        if let (Some(struct_type_id), Some(drop_method)) =
+          (self.target_struct_pattern.target_id, drop_method)
+        {
+          cache
+            .drop_methods
+            .insert(struct_type_id, drop_method.cache_id);
+        }
+      } else {
+        trait_pattern.resolve(resolver, cache);
+      }
     }
 
     // REVIEW: We can't unwrap here because the lookup might have failed.
@@ -171,7 +286,6 @@ impl Resolve for ast::MemberAccess {
 
 impl Resolve for ast::Closure {
   fn declare(&self, resolver: &mut NameResolver) {
-    // TODO: Here, captures should be force-declared.
     // FIXME: The body is resolved within a virtual environment. This means that declarations from here may not be accessible. To solve this, perhaps we may virtualize all but the last scope (this declaration's body's scope).
 
     self.prototype.declare(resolver);
@@ -179,16 +293,29 @@ impl Resolve for ast::Closure {
   }
 
   fn resolve(&mut self, resolver: &mut NameResolver, cache: &mut cache::Cache) {
-    // FIXME: Continue implementation.
+    // The closure's own parameters are never captured.
+    resolver.closure_frames.push(ClosureFrame {
+      local_ids: self
+        .prototype
+        .parameters
+        .iter()
+        .map(|parameter| parameter.cache_id)
+        .collect(),
+      captures: Vec::new(),
+    });
 
-    for (_index, capture) in self.captures.iter_mut().enumerate() {
-      capture.1 = resolver.local_lookup_or_error(&Symbol {
-        base_name: capture.0.clone(),
+    // Captures that were explicitly listed are registered as if
+    // they were referenced from within the body.
+    for capture in &self.captures {
+      let symbol = Symbol {
+        base_name: capture.name.clone(),
         sub_name: None,
         kind: SymbolKind::Definition,
-      });
+      };
 
-      // FIXME: Anything else needs to be done here?
+      if let Some(target_id) = resolver.local_lookup_or_error(&symbol) {
+        resolver.capture(&symbol, target_id, cache);
+      }
     }
 
     // Cache the existing relative scopes, and create a new, empty
@@ -204,6 +331,7 @@ impl Resolve for ast::Closure {
     // ... before or after the return type is possibly inferred?
     resolver.relative_scopes = relative_scopes_buffer;
 
+    self.captures = resolver.closure_frames.pop().unwrap().captures;
     self.prototype.resolve(resolver, cache);
 
     cache
@@ -272,6 +400,10 @@ impl Resolve for ast::IntrinsicCall {
   }
 
   fn resolve(&mut self, resolver: &mut NameResolver, cache: &mut cache::Cache) {
+    for type_argument in &mut self.type_arguments {
+      type_argument.resolve(resolver, cache);
+    }
+
     for argument in &mut self.arguments {
       argument.kind.resolve(resolver, cache);
     }
@@ -437,17 +569,41 @@ impl Resolve for ast::ContinueStmt {
 
 impl Resolve for ast::IndexingExpr {
   fn declare(&self, resolver: &mut NameResolver) {
+    self.target_expr.kind.declare(resolver);
     self.index_expr.kind.declare(resolver);
   }
 
   fn resolve(&mut self, resolver: &mut NameResolver, cache: &mut cache::Cache) {
+    self.target_expr.kind.resolve(resolver, cache);
     self.index_expr.kind.resolve(resolver, cache);
+  }
+}
 
-    self.target_id = resolver.local_lookup_or_error(&Symbol {
-      base_name: self.name.clone(),
-      sub_name: None,
-      kind: SymbolKind::Definition,
-    });
+impl Resolve for ast::SliceExpr {
+  fn declare(&self, resolver: &mut NameResolver) {
+    self.target_expr.kind.declare(resolver);
+    self.start_expr.kind.declare(resolver);
+    self.end_expr.kind.declare(resolver);
+  }
+
+  fn resolve(&mut self, resolver: &mut NameResolver, cache: &mut cache::Cache) {
+    self.target_expr.kind.resolve(resolver, cache);
+    self.start_expr.kind.resolve(resolver, cache);
+    self.end_expr.kind.resolve(resolver, cache);
+  }
+}
+
+impl Resolve for ast::InterpolationExpr {
+  fn declare(&self, resolver: &mut NameResolver) {
+    for hole in &self.holes {
+      hole.kind.declare(resolver);
+    }
+  }
+
+  fn resolve(&mut self, resolver: &mut NameResolver, cache: &mut cache::Cache) {
+    for hole in self.holes.iter_mut() {
+      hole.kind.resolve(resolver, cache);
+    }
   }
 }
 
@@ -461,6 +617,16 @@ impl Resolve for ast::StaticArrayValue {
   }
 }
 
+impl Resolve for ast::DeferStmt {
+  fn declare(&self, resolver: &mut NameResolver) {
+    self.body.kind.declare(resolver);
+  }
+
+  fn resolve(&mut self, resolver: &mut NameResolver, cache: &mut cache::Cache) {
+    self.body.kind.resolve(resolver, cache);
+  }
+}
+
 impl Resolve for ast::UnsafeExpr {
   fn declare(&self, resolver: &mut NameResolver) {
     self.0.kind.declare(resolver);
@@ -499,6 +665,17 @@ impl Resolve for ast::Parameter {
 impl Resolve for ast::Reference {
   fn resolve(&mut self, resolver: &mut NameResolver, cache: &mut cache::Cache) {
     self.pattern.resolve(resolver, cache);
+
+    // Qualified references always point to global entities, which are never captured.
+    if let (Some(target_id), None) = (self.pattern.target_id, &self.pattern.qualifier) {
+      let symbol = Symbol {
+        base_name: self.pattern.base_name.clone(),
+        sub_name: self.pattern.sub_name.clone(),
+        kind: self.pattern.symbol_kind.clone(),
+      };
+
+      resolver.capture(&symbol, target_id, cache);
+    }
   }
 }
 
@@ -524,6 +701,28 @@ impl Resolve for ast::LoopStmt {
   }
 }
 
+impl Resolve for ast::ForInStmt {
+  fn declare(&self, resolver: &mut NameResolver) {
+    self.iterable.kind.declare(resolver);
+
+    // The element is only visible within the body.
+    resolver.push_scope();
+    self.element.declare(resolver);
+    self.body.declare(resolver);
+    resolver.force_pop_scope();
+  }
+
+  fn resolve(&mut self, resolver: &mut NameResolver, cache: &mut cache::Cache) {
+    if let Some(closure_frame) = resolver.closure_frames.last_mut() {
+      closure_frame.local_ids.insert(self.element.cache_id);
+    }
+
+    self.iterable.kind.resolve(resolver, cache);
+    self.element.resolve(resolver, cache);
+    self.body.resolve(resolver, cache);
+  }
+}
+
 impl Resolve for ast::IfExpr {
   fn declare(&self, resolver: &mut NameResolver) {
     self.condition.kind.declare(resolver);
@@ -562,6 +761,10 @@ impl Resolve for ast::BindingStmt {
     // BUG: The problem seems to be occurring only when using let-statements. Investigate.
     // ... On the second iteration of the resolve step only! During cached nodes resolution.
 
+    if let Some(closure_frame) = resolver.closure_frames.last_mut() {
+      closure_frame.local_ids.insert(self.cache_id);
+    }
+
     self.value.kind.resolve(resolver, cache);
 
     // REVIEW: Annotated type is not being resolved.
@@ -771,6 +974,14 @@ pub struct Qualifier {
   pub module_name: String,
 }
 
+/// Keeps track of the bindings declared within a closure that is being
+/// resolved, as well as the bindings it captures from its environment.
+#[derive(Clone)]
+struct ClosureFrame {
+  local_ids: std::collections::HashSet<cache::Id>,
+  captures: Vec<ast::Capture>,
+}
+
 #[derive(Clone)]
 pub struct NameResolver {
   diagnostics: Vec<codespan_reporting::diagnostic::Diagnostic<usize>>,
@@ -788,6 +999,8 @@ pub struct NameResolver {
   /// The unique id of the current block's scope. Used in the resolve step.
   current_block_cache_id: Option<cache::Id>,
   current_struct_type_id: Option<cache::Id>,
+  /// The stack of closures currently being resolved, from outermost to innermost.
+  closure_frames: Vec<ClosureFrame>,
 }
 
 impl NameResolver {
@@ -800,6 +1013,7 @@ impl NameResolver {
       scope_map: std::collections::HashMap::new(),
       current_block_cache_id: None,
       current_struct_type_id: None,
+      closure_frames: Vec::new(),
     };
 
     result.create_module(initial_module_qualifier);
@@ -988,15 +1202,67 @@ impl NameResolver {
       return Some(cache_id.clone());
     }
 
-    // TODO: Include sub-name if available.
+    let name = if let Some(sub_name) = &symbol.sub_name {
+      format!("{}::{}", symbol.base_name, sub_name)
+    } else {
+      symbol.base_name.clone()
+    };
+
     self.diagnostics.push(
       codespan_reporting::diagnostic::Diagnostic::error()
-        .with_message(format!("undefined reference to `{}`", symbol.base_name)),
+        .with_message(format!("undefined reference to `{}`", name)),
     );
 
     None
   }
 
+  /// Register a resolved symbol as a capture of every closure being resolved
+  /// that does not itself declare it.
+  ///
+  /// Global symbols are never captured. Mutable bindings are captured by
+  /// reference, so that changes are visible on both sides; everything else
+  /// is captured by value.
+  fn capture(&mut self, symbol: &Symbol, target_id: cache::Id, cache: &cache::Cache) {
+    if self.closure_frames.is_empty() || symbol.kind != SymbolKind::Definition {
+      return;
+    }
+
+    // REVISE: Unsafe unwrap.
+    if self.lookup(self.current_scope_qualifier.clone().unwrap(), symbol) == Some(target_id) {
+      return;
+    }
+
+    let mode = match cache.symbols.get(&target_id) {
+      Some(ast::NodeKind::BindingStmt(binding_stmt))
+        if binding_stmt.modifier == ast::BindingModifier::Mutable =>
+      {
+        ast::CaptureMode::ByReference
+      }
+      _ => ast::CaptureMode::ByValue,
+    };
+
+    // Nested closures must also capture the binding, in order to
+    // forward it, unless they are the ones declaring it.
+    for closure_frame in self.closure_frames.iter_mut().rev() {
+      if closure_frame.local_ids.contains(&target_id) {
+        break;
+      }
+
+      let is_captured = closure_frame
+        .captures
+        .iter()
+        .any(|capture| capture.target_id == Some(target_id));
+
+      if !is_captured {
+        closure_frame.captures.push(ast::Capture {
+          name: symbol.base_name.clone(),
+          target_id: Some(target_id),
+          mode: mode.clone(),
+        });
+      }
+    }
+  }
+
   fn current_scope_contains(&mut self, key: &Symbol) -> bool {
     self.get_current_scope().contains_key(key)
   }
@@ -1130,4 +1396,30 @@ mod tests {
     assert!(name_resolver.relative_scopes.is_empty());
     assert_eq!(1, name_resolver.scope_map.len());
   }
+
+  #[test]
+  fn capture() {
+    let mut name_resolver = NameResolver::new(mock_qualifier());
+    let cache = cache::Cache::new();
+    let symbol = mock_symbol();
+
+    name_resolver.closure_frames.push(ClosureFrame {
+      local_ids: std::collections::HashSet::new(),
+      captures: Vec::new(),
+    });
+
+    // Global symbols are never captured.
+    name_resolver.bind(symbol.clone(), 0);
+    name_resolver.capture(&symbol, 0, &cache);
+    assert!(name_resolver.closure_frames.last().unwrap().captures.is_empty());
+
+    name_resolver.capture(&symbol, 1, &cache);
+    name_resolver.capture(&symbol, 1, &cache);
+
+    let captures = &name_resolver.closure_frames.last().unwrap().captures;
+
+    assert_eq!(1, captures.len());
+    assert_eq!(Some(1), captures[0].target_id);
+    assert_eq!(ast::CaptureMode::ByValue, captures[0].mode);
+  }
 }
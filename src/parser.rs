@@ -2,39 +2,117 @@ use crate::{ast, cache, lexer, name_resolution};
 
 pub const THIS_IDENTIFIER: &str = "this";
 
-// TODO: Add more test cases for larger numbers than `0`. Also, is there a need for a panic here? If so, consider using `unreachable!()`. Additionally, should `unreachabe!()` panics even be reported on the documentation?
 /// Determine the minimum bit-size in which a number can fit.
-fn minimum_int_size_of(number: &u64) -> ast::IntSize {
-  let log2_result = f64::log2(*number as f64 + 1_f64);
-  let minimum_bit_size = f64::floor(log2_result) as u64;
+///
+/// Numbers that do not fit into a signed 128-bit integer are given the
+/// unsigned 128-bit type.
+fn minimum_int_size_of(number: &u128) -> ast::IntSize {
+  let minimum_bit_size = 128 - number.leading_zeros();
 
-  if minimum_bit_size <= 8 {
+  if minimum_bit_size < 8 {
     ast::IntSize::I8
-  } else if minimum_bit_size <= 16 {
+  } else if minimum_bit_size < 16 {
     ast::IntSize::I16
-  } else if minimum_bit_size <= 32 {
+  } else if minimum_bit_size < 32 {
     ast::IntSize::I32
-  } else if minimum_bit_size <= 64 {
+  } else if minimum_bit_size < 64 {
     ast::IntSize::I64
+  } else if minimum_bit_size < 128 {
+    ast::IntSize::I128
   } else {
-    panic!("expected minimum bit-size to be smaller than 64");
+    ast::IntSize::U128
   }
 }
 
+/// Determine whether a string literal contains at least one interpolation
+/// hole (ex. `{name}`).
+///
+/// Empty placeholders (`{}`) and brace escapes (`{{`) are not considered
+/// holes, so that format strings given to the print intrinsics remain
+/// plain string literals. Their escapes are thus left unresolved, to be
+/// resolved by the print intrinsics instead.
+fn is_interpolation(value: &str) -> bool {
+  let mut chars = value.chars().peekable();
+
+  while let Some(character) = chars.next() {
+    if character != '{' {
+      continue;
+    }
+
+    match chars.peek() {
+      Some('{') => {
+        chars.next();
+      }
+      Some('}') | None => {}
+      Some(_) => return true,
+    }
+  }
+
+  false
+}
+
+/// Split an interpolated string into its text pieces and the source of
+/// each of its holes, resolving any brace escapes in the process.
+///
+/// There is always one more piece than there are holes.
+fn split_interpolation(value: &str) -> Result<(Vec<String>, Vec<String>), &'static str> {
+  let mut pieces = Vec::new();
+  let mut holes = Vec::new();
+  let mut buffer = String::new();
+  let mut chars = value.chars().peekable();
+
+  while let Some(character) = chars.next() {
+    match character {
+      '{' if chars.peek() == Some(&'{') => {
+        chars.next();
+        buffer.push('{');
+      }
+      '}' if chars.peek() == Some(&'}') => {
+        chars.next();
+        buffer.push('}');
+      }
+      '{' => {
+        let mut hole = String::new();
+
+        loop {
+          match chars.next() {
+            Some('}') => break,
+            Some(character) => hole.push(character),
+            None => return Err("unterminated interpolation hole"),
+          }
+        }
+
+        if hole.trim().is_empty() {
+          return Err("interpolation holes cannot be empty; use `{{` to write a literal brace");
+        }
+
+        pieces.push(std::mem::take(&mut buffer));
+        holes.push(hole);
+      }
+      '}' => return Err("unmatched `}` in string; use `}}` to write a literal brace"),
+      _ => buffer.push(character),
+    }
+  }
+
+  pieces.push(buffer);
+
+  Ok((pieces, holes))
+}
+
 fn get_token_precedence(token: &lexer::TokenKind) -> usize {
   // FIXME: What about the `not` operator, and others?
   match token {
-    lexer::TokenKind::Plus
-    | lexer::TokenKind::Minus
-    | lexer::TokenKind::Equality
-    | lexer::TokenKind::LessThan
-    | lexer::TokenKind::GreaterThan
-    | lexer::TokenKind::And
+    lexer::TokenKind::And
     | lexer::TokenKind::Or
     | lexer::TokenKind::Nand
     | lexer::TokenKind::Nor
     | lexer::TokenKind::Xor => 1,
-    lexer::TokenKind::Asterisk | lexer::TokenKind::Slash => 2,
+    lexer::TokenKind::Equality
+    | lexer::TokenKind::LessThan
+    | lexer::TokenKind::GreaterThan
+    | lexer::TokenKind::In => 2,
+    lexer::TokenKind::Plus | lexer::TokenKind::Minus => 3,
+    lexer::TokenKind::Asterisk | lexer::TokenKind::Slash | lexer::TokenKind::Percent => 4,
     _ => 0,
   }
 }
@@ -45,6 +123,9 @@ pub struct Parser<'a> {
   tokens: Vec<lexer::Token>,
   index: usize,
   cache: &'a mut cache::Cache,
+  /// The id of the source file being parsed, which labels of diagnostics
+  /// refer to.
+  pub file_id: usize,
   // TODO: This shouldn't be present here. Instantiate unspecified types to `None` or a special value.
   substitutions: &'a mut Vec<ast::Type>,
 }
@@ -59,6 +140,7 @@ impl<'a> Parser<'a> {
       tokens,
       index: 0,
       cache,
+      file_id: 0,
       substitutions: substitution,
     }
   }
@@ -74,6 +156,7 @@ impl<'a> Parser<'a> {
         | lexer::TokenKind::Minus
         | lexer::TokenKind::Asterisk
         | lexer::TokenKind::Slash
+        | lexer::TokenKind::Percent
         | lexer::TokenKind::LessThan
         | lexer::TokenKind::GreaterThan
         | lexer::TokenKind::And
@@ -152,6 +235,19 @@ impl<'a> Parser<'a> {
     }
   }
 
+  /// Retrieve the position of the current token in the source file.
+  ///
+  /// If the end-of-file has been reached, the position of the last
+  /// token (if any) will be returned instead.
+  fn get_position(&self) -> usize {
+    self
+      .tokens
+      .get(self.index)
+      .or_else(|| self.tokens.last())
+      .map(|token| token.1)
+      .unwrap_or(0)
+  }
+
   /// Compare the current token to the given one for equality.
   ///
   /// If `EOF` has been reached, `false` will always be returned. This is
@@ -211,40 +307,50 @@ impl<'a> Parser<'a> {
     self.tokens.is_empty() || self.index >= self.tokens.len() - 1
   }
 
+  /// %name ('::' %name)*
+  ///
+  /// Two segments refer to a static entity of a local symbol (such as
+  /// `Person::create`), while three or more segments are qualified by a
+  /// package and module (such as `package::module::Person::create`).
   fn parse_pattern(
     &mut self,
     symbol_kind: name_resolution::SymbolKind,
   ) -> ParserResult<ast::Pattern> {
-    let starting_name = self.parse_name()?;
+    let start_position = self.get_position();
+    let mut segments = vec![self.parse_name()?];
 
-    let qualifier = if self.is(&lexer::TokenKind::DoubleColon) {
+    while self.is(&lexer::TokenKind::DoubleColon) {
       self.skip()?;
+      segments.push(self.parse_name()?);
+    }
+
+    let qualifier = if segments.len() > 2 {
+      let package_name = segments.remove(0);
 
       Some(name_resolution::Qualifier {
-        package_name: starting_name.clone(),
-        module_name: self.parse_name()?,
+        package_name,
+        module_name: segments.remove(0),
       })
     } else {
       None
     };
 
-    let base_name = if qualifier.is_none() {
-      starting_name
-    } else {
-      self.skip_past(&lexer::TokenKind::DoubleColon)?;
-
-      self.parse_name()?
-    };
-
-    let sub_name = if self.is(&lexer::TokenKind::DoubleColon) {
-      self.skip_past(&lexer::TokenKind::DoubleColon)?;
+    if segments.len() > 2 {
+      // The last token is the path's last segment.
+      let end_position = self.tokens[self.index - 1].1 + segments.last().unwrap().len();
 
-      Some(self.parse_name()?)
-    } else {
-      None
-    };
+      return Err(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("path has too many segments")
+          .with_labels(vec![codespan_reporting::diagnostic::Label::primary(
+            self.file_id,
+            start_position..end_position,
+          )]),
+      );
+    }
 
-    // TODO: Add support for static sub-entities.
+    let base_name = segments.remove(0);
+    let sub_name = segments.pop();
 
     Ok(ast::Pattern {
       qualifier,
@@ -275,9 +381,11 @@ impl<'a> Parser<'a> {
         ast::NodeKind::BindingStmt(self.parse_binding_stmt()?)
       }
       lexer::TokenKind::Loop => ast::NodeKind::LoopStmt(self.parse_loop_stmt()?),
+      lexer::TokenKind::For => ast::NodeKind::ForInStmt(self.parse_for_in_stmt()?),
       lexer::TokenKind::Break => ast::NodeKind::BreakStmt(self.parse_break_stmt()?),
       lexer::TokenKind::Continue => ast::NodeKind::ContinueStmt(self.parse_continue_stmt()?),
       lexer::TokenKind::Unsafe => ast::NodeKind::UnsafeExpr(self.parse_unsafe_expr()?),
+      lexer::TokenKind::Defer => ast::NodeKind::DeferStmt(self.parse_defer_stmt()?),
       _ => {
         let expr = self.parse_expr()?;
 
@@ -355,18 +463,21 @@ impl<'a> Parser<'a> {
     })
   }
 
-  /// {U8 | U16 | U32 | U64 | I8 | I16 | Int | I64}
+  /// {U8 | U16 | U32 | U64 | U128 | Usize | I8 | I16 | Int | I64 | I128 | Isize}
   fn parse_int_type(&mut self) -> ParserResult<ast::Type> {
     let size = match self.get_token()? {
       lexer::TokenKind::TypeInt8 => ast::IntSize::I8,
       lexer::TokenKind::TypeInt16 => ast::IntSize::I16,
       lexer::TokenKind::TypeInt32 => ast::IntSize::I32,
       lexer::TokenKind::TypeInt64 => ast::IntSize::I64,
+      lexer::TokenKind::TypeInt128 => ast::IntSize::I128,
       lexer::TokenKind::TypeUint8 => ast::IntSize::U8,
       lexer::TokenKind::TypeUint16 => ast::IntSize::U16,
       lexer::TokenKind::TypeUint32 => ast::IntSize::U32,
       lexer::TokenKind::TypeUint64 => ast::IntSize::U64,
-      // TODO: Add unsigned type tokens.
+      lexer::TokenKind::TypeUint128 => ast::IntSize::U128,
+      lexer::TokenKind::TypeIsize => ast::IntSize::Isize,
+      lexer::TokenKind::TypeUsize => ast::IntSize::Usize,
       _ => return Err(self.expected("integer type")),
     };
 
@@ -389,10 +500,17 @@ impl<'a> Parser<'a> {
     Ok(ast::Type::This(ast::ThisType { target_id: None }))
   }
 
-  /// '[' %type, 0-9+ ']'
+  /// '[' %type, 0-9+ ']' | '[' ']' %type
   fn parse_array_type(&mut self) -> ParserResult<ast::Type> {
     self.skip_past(&lexer::TokenKind::BracketL)?;
 
+    // A slice type (ex. `[]Int`).
+    if self.is(&lexer::TokenKind::BracketR) {
+      self.skip()?;
+
+      return Ok(ast::Type::Slice(Box::new(self.parse_non_function_type()?)));
+    }
+
     let element_type = self.parse_type()?;
 
     self.skip_past(&lexer::TokenKind::Comma)?;
@@ -409,8 +527,19 @@ impl<'a> Parser<'a> {
   }
 
   fn parse_type(&mut self) -> ParserResult<ast::Type> {
+    let ty = self.parse_non_function_type()?;
+
+    // Upgrade to a function type, if applicable.
+    if self.is(&lexer::TokenKind::Arrow) {
+      return self.parse_function_type(ty);
+    }
+
+    Ok(ty)
+  }
+
+  fn parse_non_function_type(&mut self) -> ParserResult<ast::Type> {
     // TODO: Support for more types.
-    let mut ty = match self.get_token()? {
+    match self.get_token()? {
       // TODO: Other types as well.
       // TODO: Parse function types.
       // lexer::TokenKind::Fn => self.parse_callable_type(),
@@ -418,10 +547,14 @@ impl<'a> Parser<'a> {
       | lexer::TokenKind::TypeInt16
       | lexer::TokenKind::TypeInt32
       | lexer::TokenKind::TypeInt64
+      | lexer::TokenKind::TypeInt128
       | lexer::TokenKind::TypeUint8
       | lexer::TokenKind::TypeUint16
       | lexer::TokenKind::TypeUint32
-      | lexer::TokenKind::TypeUint64 => self.parse_int_type(),
+      | lexer::TokenKind::TypeUint64
+      | lexer::TokenKind::TypeUint128
+      | lexer::TokenKind::TypeIsize
+      | lexer::TokenKind::TypeUsize => self.parse_int_type(),
       lexer::TokenKind::TypeBool => self.parse_bool_type(),
       lexer::TokenKind::Identifier(_) => self.parse_stub_type(),
       lexer::TokenKind::BracketL => self.parse_array_type(),
@@ -429,23 +562,69 @@ impl<'a> Parser<'a> {
       lexer::TokenKind::Asterisk => {
         self.skip()?;
 
-        Ok(ast::Type::Pointer(Box::new(self.parse_type()?)))
+        Ok(ast::Type::Pointer(Box::new(self.parse_non_function_type()?)))
       }
       lexer::TokenKind::TypeString => {
         self.skip()?;
 
         Ok(ast::Type::Basic(ast::BasicType::String))
       }
+      lexer::TokenKind::TypeOwnedString => {
+        self.skip()?;
+
+        Ok(ast::Type::Basic(ast::BasicType::OwnedString))
+      }
       lexer::TokenKind::TypeThis => self.parse_this_type(),
-      _ => return Err(self.expected("type")),
-    }?;
+      lexer::TokenKind::QuestionMark => self.parse_optional_type(),
+      lexer::TokenKind::TypeResult => self.parse_result_type(),
+      lexer::TokenKind::Ampersand => self.parse_reference_type(),
+      _ => Err(self.expected("type")),
+    }
+  }
 
-    // Upgrade to a function type, if applicable.
-    if self.is(&lexer::TokenKind::Arrow) {
-      ty = self.parse_function_type(ty)?;
+  /// '&' ['mut'] %type
+  fn parse_reference_type(&mut self) -> ParserResult<ast::Type> {
+    self.skip_past(&lexer::TokenKind::Ampersand)?;
+
+    let is_mutable = self.is(&lexer::TokenKind::Mut);
+
+    if is_mutable {
+      self.skip()?;
     }
 
-    Ok(ty)
+    let inner_type = Box::new(self.parse_non_function_type()?);
+
+    Ok(if is_mutable {
+      ast::Type::MutableReference(inner_type)
+    } else {
+      ast::Type::Reference(inner_type)
+    })
+  }
+
+  /// '?' %type
+  fn parse_optional_type(&mut self) -> ParserResult<ast::Type> {
+    self.skip_past(&lexer::TokenKind::QuestionMark)?;
+
+    Ok(ast::Type::Optional(Box::new(self.parse_non_function_type()?)))
+  }
+
+  /// 'Result' '[' %type ',' %type ']'
+  fn parse_result_type(&mut self) -> ParserResult<ast::Type> {
+    self.skip_past(&lexer::TokenKind::TypeResult)?;
+    self.skip_past(&lexer::TokenKind::BracketL)?;
+
+    let value_type = self.parse_type()?;
+
+    self.skip_past(&lexer::TokenKind::Comma)?;
+
+    let error_type = self.parse_type()?;
+
+    self.skip_past(&lexer::TokenKind::BracketR)?;
+
+    Ok(ast::Type::Result(
+      Box::new(value_type),
+      Box::new(error_type),
+    ))
   }
 
   fn parse_unit_type(&mut self) -> ParserResult<ast::Type> {
@@ -454,15 +633,17 @@ impl<'a> Parser<'a> {
     Ok(ast::Type::Unit)
   }
 
-  /// fn '(' (%type (','))* ')' ('[' %type ']')
+  /// %type ('->' %type)+
   fn parse_function_type(&mut self, first_type: ast::Type) -> ParserResult<ast::Type> {
     self.skip_past(&lexer::TokenKind::Arrow)?;
 
-    let mut parameter_types = vec![first_type, self.parse_type()?];
+    // The types in between arrows must not be upgraded to function types
+    // themselves, otherwise `A -> B -> C` would be parsed as `A -> (B -> C)`.
+    let mut parameter_types = vec![first_type, self.parse_non_function_type()?];
 
     while self.is(&lexer::TokenKind::Arrow) {
       self.skip()?;
-      parameter_types.push(self.parse_type()?);
+      parameter_types.push(self.parse_non_function_type()?);
     }
 
     let return_type = parameter_types.remove(parameter_types.len() - 1);
@@ -513,9 +694,17 @@ impl<'a> Parser<'a> {
     let mut is_variadic = false;
     let mut parameter_index_counter = 0;
     let mut accepts_instance = false;
+    let mut is_instance_mutable = false;
     let mut this_parameter = None;
 
-    if self.is(&lexer::TokenKind::Identifier(THIS_IDENTIFIER.to_string())) {
+    let this_token = lexer::TokenKind::Identifier(THIS_IDENTIFIER.to_string());
+
+    if self.is(&lexer::TokenKind::Mut) && self.peek_is(&this_token) {
+      self.skip()?;
+      is_instance_mutable = true;
+    }
+
+    if self.is(&this_token) {
       self.skip()?;
       parameter_index_counter += 1;
       accepts_instance = true;
@@ -566,6 +755,7 @@ impl<'a> Parser<'a> {
       return_type_annotation,
       is_variadic,
       accepts_instance,
+      is_instance_mutable,
       instance_type_id: None,
       this_parameter,
       is_extern,
@@ -581,6 +771,7 @@ impl<'a> Parser<'a> {
     // TODO: Support for visibility.
     self.skip_past(&lexer::TokenKind::Func)?;
 
+    let position = self.get_position();
     let name = self.parse_name()?;
 
     let generics = if self.is(&lexer::TokenKind::LessThan) {
@@ -603,6 +794,7 @@ impl<'a> Parser<'a> {
       attributes,
       cache_id: self.cache.create_id(),
       generics,
+      position,
     })
   }
 
@@ -616,14 +808,24 @@ impl<'a> Parser<'a> {
     self.skip_past(&lexer::TokenKind::Extern)?;
     self.skip_past(&lexer::TokenKind::Func)?;
 
+    let position = self.get_position();
     let name = self.parse_name()?;
-    let prototype = self.parse_prototype(true)?;
+    let mut prototype = self.parse_prototype(true)?;
+
+    // Function-typed parameters of externs are callbacks, which are plain
+    // function pointers instead of closures.
+    for parameter in prototype.parameters.iter_mut() {
+      if let ast::Type::Function(function_type) = &mut parameter.ty {
+        function_type.is_extern = true;
+      }
+    }
 
     Ok(ast::ExternFunction {
       name,
       prototype,
       attributes,
       cache_id: self.cache.create_id(),
+      position,
     })
   }
 
@@ -783,6 +985,7 @@ impl<'a> Parser<'a> {
 
     self.skip()?;
 
+    let position = self.get_position();
     let name = self.parse_name()?;
 
     let ty = if self.is(&lexer::TokenKind::Colon) {
@@ -805,6 +1008,7 @@ impl<'a> Parser<'a> {
       modifier,
       cache_id: self.cache.create_id(),
       ty,
+      position,
     })
   }
 
@@ -865,6 +1069,35 @@ impl<'a> Parser<'a> {
     Ok(ast::LoopStmt { condition, body })
   }
 
+  /// for %name in %expr %block
+  fn parse_for_in_stmt(&mut self) -> ParserResult<ast::ForInStmt> {
+    self.skip_past(&lexer::TokenKind::For)?;
+
+    let position = self.get_position();
+    let element_name = self.parse_name()?;
+
+    self.skip_past(&lexer::TokenKind::In)?;
+
+    let iterable = self.parse_expr()?;
+
+    self.skip_past(&lexer::TokenKind::Colon)?;
+
+    let body = self.parse_block_expr()?;
+
+    Ok(ast::ForInStmt {
+      // The element's type is inferred from the iterable.
+      element: ast::Parameter {
+        name: element_name,
+        ty: self.create_type_variable(),
+        position: 0,
+        cache_id: self.cache.create_id(),
+      },
+      iterable: Box::new(iterable),
+      body,
+      position,
+    })
+  }
+
   /// break
   fn parse_break_stmt(&mut self) -> ParserResult<ast::BreakStmt> {
     self.skip_past(&lexer::TokenKind::Break)?;
@@ -889,6 +1122,16 @@ impl<'a> Parser<'a> {
     Ok(ast::UnsafeExpr(Box::new(self.parse_expr()?)))
   }
 
+  /// defer ':' %expr
+  fn parse_defer_stmt(&mut self) -> ParserResult<ast::DeferStmt> {
+    self.skip_past(&lexer::TokenKind::Defer)?;
+    self.skip_past(&lexer::TokenKind::Colon)?;
+
+    Ok(ast::DeferStmt {
+      body: Box::new(self.parse_expr()?),
+    })
+  }
+
   /// {true | false}
   fn parse_bool_literal(&mut self) -> ParserResult<ast::Literal> {
     // REVISE: There shouldn't be a need to clone the token here.
@@ -913,7 +1156,7 @@ impl<'a> Parser<'a> {
 
         // REVIEW: Deal with unsigned integers here?
         // Default size to 32 bit-width.
-        let size = if minimum_size < ast::IntSize::I32 {
+        let size = if matches!(minimum_size, ast::IntSize::I8 | ast::IntSize::I16) {
           ast::IntSize::I32
         } else {
           minimum_size
@@ -937,6 +1180,57 @@ impl<'a> Parser<'a> {
     Ok(result)
   }
 
+  /// '"' ([^"{]* '{' %expr '}')* [^"]* '"'
+  fn parse_interpolation_expr(&mut self) -> ParserResult<ast::InterpolationExpr> {
+    let value = match self.get_token()? {
+      lexer::TokenKind::String(value) => value.clone(),
+      _ => return Err(self.expected("string literal")),
+    };
+
+    let position = self.get_position();
+
+    self.skip()?;
+
+    let (pieces, hole_sources) = split_interpolation(&value).map_err(|message| {
+      codespan_reporting::diagnostic::Diagnostic::error().with_message(message)
+    })?;
+
+    let mut holes = Vec::new();
+
+    for hole_source in hole_sources {
+      // REVISE: Token positions are all mapped to the string literal itself,
+      // ... since holes are lexed separately from the rest of the source file.
+      let mut tokens: Vec<lexer::Token> = lexer::Lexer::from_str(hole_source.trim())
+        .lex_all()?
+        .into_iter()
+        .filter(|token| {
+          !matches!(
+            token.0,
+            lexer::TokenKind::Whitespace(_) | lexer::TokenKind::Comment(_)
+          )
+        })
+        .map(|token| (token.0, position))
+        .collect();
+
+      tokens.push((lexer::TokenKind::EOF, position));
+
+      let mut hole_parser = Parser::new(tokens, &mut *self.cache, &mut *self.substitutions);
+      let hole = hole_parser.parse_expr()?;
+
+      if !hole_parser.is_eof() {
+        return Err(hole_parser.expected("end of interpolation hole"));
+      }
+
+      holes.push(hole);
+    }
+
+    Ok(ast::InterpolationExpr {
+      pieces,
+      holes,
+      position,
+    })
+  }
+
   /// '[' (%expr (','))* ']'
   fn parse_array_value(&mut self) -> ParserResult<ast::StaticArrayValue> {
     let mut elements = Vec::new();
@@ -968,44 +1262,59 @@ impl<'a> Parser<'a> {
     })
   }
 
-  /// %name '[' %expr ']'
-  fn parse_array_indexing(&mut self) -> ParserResult<ast::IndexingExpr> {
-    // TODO: Work with a pattern instead.
-    let name = self.parse_name()?;
+  /// %expr '[' %expr ('..' %expr) ']'
+  fn parse_indexing_expr(&mut self, target_expr: ast::Node) -> ParserResult<ast::NodeKind> {
+    let position = self.get_position();
 
     self.skip_past(&lexer::TokenKind::BracketL)?;
 
-    let index = Box::new(self.parse_expr()?);
+    // NOTE: Integer literals followed by an ellipsis would otherwise be
+    // ... parsed as a range, which may only have literal bounds.
+    let index_expr = if matches!(self.get_token()?, lexer::TokenKind::Int(_))
+      && self.peek_is(&lexer::TokenKind::ShortEllipsis)
+    {
+      ast::Node {
+        kind: ast::NodeKind::Literal(self.parse_int_literal()?),
+        cached_type: None,
+      }
+    } else {
+      self.parse_expr()?
+    };
 
-    self.skip_past(&lexer::TokenKind::BracketR)?;
+    // Promote the indexing expression to a slice expression, if applicable.
+    if self.is(&lexer::TokenKind::ShortEllipsis) {
+      self.skip()?;
 
-    Ok(ast::IndexingExpr {
-      name,
-      index_expr: index,
-      target_id: None,
-    })
-  }
+      let end_expr = self.parse_expr()?;
 
-  fn parse_nullptr_literal(&mut self) -> ParserResult<ast::Literal> {
-    self.skip_past(&lexer::TokenKind::Nullptr)?;
-    self.skip_past(&lexer::TokenKind::BracketL)?;
+      self.skip_past(&lexer::TokenKind::BracketR)?;
 
-    let ty = self.parse_type()?;
+      return Ok(ast::NodeKind::SliceExpr(ast::SliceExpr {
+        target_expr: Box::new(target_expr),
+        start_expr: Box::new(index_expr),
+        end_expr: Box::new(end_expr),
+        position,
+      }));
+    }
 
     self.skip_past(&lexer::TokenKind::BracketR)?;
 
-    Ok(ast::Literal::Nullptr(ty))
+    Ok(ast::NodeKind::IndexingExpr(ast::IndexingExpr {
+      target_expr: Box::new(target_expr),
+      index_expr: Box::new(index_expr),
+      position,
+    }))
   }
 
-  fn parse_sizeof_intrinsic(&mut self) -> ParserResult<ast::SizeofIntrinsic> {
-    self.skip_past(&lexer::TokenKind::Sizeof)?;
+  fn parse_nullptr_literal(&mut self) -> ParserResult<ast::Literal> {
+    self.skip_past(&lexer::TokenKind::Nullptr)?;
     self.skip_past(&lexer::TokenKind::BracketL)?;
 
     let ty = self.parse_type()?;
 
     self.skip_past(&lexer::TokenKind::BracketR)?;
 
-    Ok(ast::SizeofIntrinsic { ty })
+    Ok(ast::Literal::Nullptr(ty))
   }
 
   fn parse_literal(&mut self) -> ParserResult<ast::Literal> {
@@ -1030,32 +1339,6 @@ impl<'a> Parser<'a> {
     Ok(result)
   }
 
-  // TODO: Retire this function. Remove it once its no longer needed.
-  fn after_pattern_is(&self, token: &lexer::TokenKind) -> bool {
-    let mut index = self.index;
-    let mut delimiter_switch = false;
-    let is_past_eof_at = |index: usize| index >= self.tokens.len();
-
-    // REVISE: This is hacky code. Fix up.
-    // REVIEW: Ensure this works as expected with the modifications done.
-    while !is_past_eof_at(index)
-      && match self.tokens.get(index).unwrap().0 {
-        lexer::TokenKind::Dot | lexer::TokenKind::Colon if delimiter_switch => true,
-        lexer::TokenKind::Identifier(_) if !delimiter_switch => true,
-        _ => false,
-      }
-    {
-      delimiter_switch = !delimiter_switch;
-      index += 1;
-    }
-
-    if let Some(token_at_index) = self.tokens.get(index) {
-      &token_at_index.0 == token
-    } else {
-      false
-    }
-  }
-
   // TODO: Make use-of, or dispose.
   fn is_chain(&self) -> bool {
     if self.is_eof() {
@@ -1064,18 +1347,84 @@ impl<'a> Parser<'a> {
 
     matches!(
       self.get_token().unwrap_or(&lexer::TokenKind::EOF),
-      lexer::TokenKind::Dot | lexer::TokenKind::ParenthesesL
+      lexer::TokenKind::Dot
+        | lexer::TokenKind::ParenthesesL
+        | lexer::TokenKind::BracketL
+        | lexer::TokenKind::PostfixQuestionMark
     )
   }
 
-  /// '?' {%name | sizeof}
-  fn parse_intrinsic(&mut self) -> ParserResult<ast::NodeKind> {
+  /// '?' %name ('[' %type (',' %type)* (',' %name)? ']')? ('(' (%expr (,))* ')')?
+  fn parse_intrinsic(&mut self) -> ParserResult<ast::IntrinsicCall> {
+    let position = self.get_position();
+
     self.skip_past(&lexer::TokenKind::QuestionMark)?;
 
-    Ok(match self.get_token()? {
-      lexer::TokenKind::Sizeof => ast::NodeKind::SizeofIntrinsic(self.parse_sizeof_intrinsic()?),
-      lexer::TokenKind::Identifier(_) => ast::NodeKind::IntrinsicCall(self.parse_intrinsic_call()?),
+    // NOTE: The names of the `sizeof` and `alignof` intrinsics are keywords.
+    let name = match self.get_token()? {
+      lexer::TokenKind::Sizeof => String::from("sizeof"),
+      lexer::TokenKind::Alignof => String::from("alignof"),
+      lexer::TokenKind::Identifier(name) => name.clone(),
       _ => return Err(self.expected("intrinsic")),
+    };
+
+    self.skip()?;
+
+    let kind = match ast::IntrinsicKind::from_name(name.as_str()) {
+      Some(kind) => kind,
+      None => {
+        return Err(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message(format!("unknown intrinsic `?{}`", name)),
+        )
+      }
+    };
+
+    let signature = kind.signature(&[]);
+    let mut type_arguments = Vec::new();
+    let mut field_name = None;
+
+    if signature.type_argument_count > 0 {
+      self.skip_past(&lexer::TokenKind::BracketL)?;
+
+      for index in 0..signature.type_argument_count {
+        if index != 0 {
+          self.skip_past(&lexer::TokenKind::Comma)?;
+        }
+
+        type_arguments.push(self.parse_type()?);
+      }
+
+      if signature.takes_field_name {
+        self.skip_past(&lexer::TokenKind::Comma)?;
+        field_name = Some(self.parse_name()?);
+      }
+
+      self.skip_past(&lexer::TokenKind::BracketR)?;
+    }
+
+    let mut arguments = Vec::new();
+
+    if signature.parameter_types.is_some() {
+      self.skip_past(&lexer::TokenKind::ParenthesesL)?;
+
+      while self.until(&lexer::TokenKind::ParenthesesR)? {
+        arguments.push(self.parse_expr()?);
+
+        if !self.is(&lexer::TokenKind::ParenthesesR) {
+          self.skip_past(&lexer::TokenKind::Comma)?;
+        }
+      }
+
+      self.skip_past(&lexer::TokenKind::ParenthesesR)?;
+    }
+
+    Ok(ast::IntrinsicCall {
+      kind,
+      type_arguments,
+      field_name,
+      arguments,
+      position,
     })
   }
 
@@ -1092,9 +1441,8 @@ impl<'a> Parser<'a> {
         ast::NodeKind::Closure(self.parse_closure()?)
       }
       lexer::TokenKind::If => ast::NodeKind::IfExpr(self.parse_if_expr()?),
-      // REVISE: Change this syntax to the same treatment as call expressions (check afterwards).
-      lexer::TokenKind::Identifier(_) if self.after_pattern_is(&lexer::TokenKind::BracketL) => {
-        ast::NodeKind::IndexingExpr(self.parse_array_indexing()?)
+      lexer::TokenKind::String(value) if is_interpolation(value) => {
+        ast::NodeKind::InterpolationExpr(self.parse_interpolation_expr()?)
       }
       lexer::TokenKind::Identifier(_) => ast::NodeKind::Reference(self.parse_reference()?),
       lexer::TokenKind::BracketL => ast::NodeKind::StaticArrayValue(self.parse_array_value()?),
@@ -1104,7 +1452,13 @@ impl<'a> Parser<'a> {
       lexer::TokenKind::QuestionMark if self.peek_is(&lexer::TokenKind::QuestionMark) => {
         ast::NodeKind::UnimplementedExpr(self.parse_unimplemented_expr()?)
       }
-      lexer::TokenKind::QuestionMark => self.parse_intrinsic()?,
+      lexer::TokenKind::QuestionMark => ast::NodeKind::IntrinsicCall(self.parse_intrinsic()?),
+      lexer::TokenKind::Some | lexer::TokenKind::None => {
+        ast::NodeKind::OptionalValue(self.parse_optional_value()?)
+      }
+      lexer::TokenKind::Ok | lexer::TokenKind::Err => {
+        ast::NodeKind::ResultValue(self.parse_result_value()?)
+      }
       lexer::TokenKind::ParenthesesL => {
         ast::NodeKind::ParenthesesExpr(self.parse_parentheses_expr()?)
       }
@@ -1123,6 +1477,8 @@ impl<'a> Parser<'a> {
       let kind = match self.get_token()? {
         lexer::TokenKind::ParenthesesL => ast::NodeKind::CallExpr(self.parse_call_expr(node)?),
         lexer::TokenKind::Dot => ast::NodeKind::MemberAccess(self.parse_member_access(node)?),
+        lexer::TokenKind::BracketL => self.parse_indexing_expr(node)?,
+        lexer::TokenKind::PostfixQuestionMark => ast::NodeKind::TryExpr(self.parse_try_expr(node)?),
         _ => unreachable!(),
       };
 
@@ -1146,6 +1502,73 @@ impl<'a> Parser<'a> {
     })
   }
 
+  /// %expr '?'
+  fn parse_try_expr(&mut self, expr: ast::Node) -> ParserResult<ast::TryExpr> {
+    self.skip_past(&lexer::TokenKind::PostfixQuestionMark)?;
+
+    Ok(ast::TryExpr {
+      expr: Box::new(expr),
+    })
+  }
+
+  /// {some '(' %expr ')' | none '[' %type ']'}
+  fn parse_optional_value(&mut self) -> ParserResult<ast::OptionalValue> {
+    if self.is(&lexer::TokenKind::None) {
+      self.skip()?;
+      self.skip_past(&lexer::TokenKind::BracketL)?;
+
+      let explicit_type = self.parse_type()?;
+
+      self.skip_past(&lexer::TokenKind::BracketR)?;
+
+      return Ok(ast::OptionalValue {
+        value: None,
+        explicit_type: Some(explicit_type),
+      });
+    }
+
+    self.skip_past(&lexer::TokenKind::Some)?;
+    self.skip_past(&lexer::TokenKind::ParenthesesL)?;
+
+    let value = self.parse_expr()?;
+
+    self.skip_past(&lexer::TokenKind::ParenthesesR)?;
+
+    Ok(ast::OptionalValue {
+      value: Some(Box::new(value)),
+      explicit_type: None,
+    })
+  }
+
+  /// {ok | err} '[' %type ']' '(' %expr ')'
+  fn parse_result_value(&mut self) -> ParserResult<ast::ResultValue> {
+    let is_ok = self.is(&lexer::TokenKind::Ok);
+
+    if !is_ok && !self.is(&lexer::TokenKind::Err) {
+      return Err(self.expected("result value"));
+    }
+
+    self.skip()?;
+
+    // The type of the side that isn't held can't be inferred from the value.
+    self.skip_past(&lexer::TokenKind::BracketL)?;
+
+    let explicit_type = self.parse_type()?;
+
+    self.skip_past(&lexer::TokenKind::BracketR)?;
+    self.skip_past(&lexer::TokenKind::ParenthesesL)?;
+
+    let value = self.parse_expr()?;
+
+    self.skip_past(&lexer::TokenKind::ParenthesesR)?;
+
+    Ok(ast::ResultValue {
+      value: Box::new(value),
+      is_ok,
+      explicit_type,
+    })
+  }
+
   /// {'+' | '-' | '*' | '/'}
   fn parse_operator(&mut self) -> ParserResult<ast::OperatorKind> {
     let operator = match self.get_token()? {
@@ -1159,11 +1582,13 @@ impl<'a> Parser<'a> {
       lexer::TokenKind::Minus => ast::OperatorKind::SubtractOrNegate,
       lexer::TokenKind::Asterisk => ast::OperatorKind::MultiplyOrDereference,
       lexer::TokenKind::Slash => ast::OperatorKind::Divide,
+      lexer::TokenKind::Percent => ast::OperatorKind::Remainder,
       lexer::TokenKind::LessThan => ast::OperatorKind::LessThan,
       lexer::TokenKind::GreaterThan => ast::OperatorKind::GreaterThan,
       lexer::TokenKind::Ampersand => ast::OperatorKind::AddressOf,
       lexer::TokenKind::Backtick => ast::OperatorKind::Cast,
       lexer::TokenKind::Equality => ast::OperatorKind::Equality,
+      lexer::TokenKind::In => ast::OperatorKind::In,
       // TODO: Implement logic for GTE & LTE.
       _ => return Err(self.expected("operator")),
     };
@@ -1187,21 +1612,23 @@ impl<'a> Parser<'a> {
       return Ok(left);
     };
 
-    let precedence = get_token_precedence(&token_buffer);
     let mut buffer = left;
 
-    while Parser::is_binary_operator(&token_buffer) && (precedence > min_precedence) {
+    while Parser::is_binary_operator(&token_buffer)
+      && get_token_precedence(&token_buffer) > min_precedence
+    {
+      let precedence = get_token_precedence(&token_buffer);
       let operator = self.parse_operator()?;
       let mut right = self.parse_primary_expr()?;
 
       token_buffer = self.get_token()?;
 
+      // Operators that bind tighter apply to the right operand first.
       while Parser::is_binary_operator(&token_buffer)
         && get_token_precedence(&token_buffer) > precedence
       {
-        // REVIEW: Are we adding the correct amount of precedence here? Shouldn't there be a higher difference in precedence?
         // REVISE: This isn't tail-recursive.
-        right = self.parse_binary_expr_or_default(right, precedence + 1)?;
+        right = self.parse_binary_expr_or_default(right, precedence)?;
         token_buffer = self.get_token()?;
       }
 
@@ -1226,7 +1653,14 @@ impl<'a> Parser<'a> {
       return Err(self.expected("unary operator"));
     }
 
-    let operator = self.parse_operator()?;
+    let position = self.get_position();
+    let mut operator = self.parse_operator()?;
+
+    // Mutable references are taken with `&mut`.
+    if operator == ast::OperatorKind::AddressOf && self.is(&lexer::TokenKind::Mut) {
+      self.skip()?;
+      operator = ast::OperatorKind::MutableAddressOf;
+    }
 
     let cast_type = if operator == ast::OperatorKind::Cast {
       Some(self.parse_type()?)
@@ -1245,6 +1679,7 @@ impl<'a> Parser<'a> {
       operator,
       expr,
       cast_type,
+      position,
     })
   }
 
@@ -1284,41 +1719,19 @@ impl<'a> Parser<'a> {
     })
   }
 
-  /// %name '(' (%expr (,))* ')'
-  fn parse_intrinsic_call(&mut self) -> ParserResult<ast::IntrinsicCall> {
-    let kind = match self.parse_name()?.as_str() {
-      "length_of" => ast::IntrinsicKind::LengthOf,
-      // REVISE: This error message won't apply as expected, instead it would compare it with "parentheses" token.
-      _ => return Err(self.expected("a valid intrinsic name")),
-    };
+  /// %pattern
+  fn parse_reference(&mut self) -> ParserResult<ast::Reference> {
+    let position = self.get_position();
+    // REVIEW: Would there be an instance where this method can accept which symbol kind to parse?
+    let pattern = self.parse_pattern(name_resolution::SymbolKind::Definition)?;
 
-    self.skip_past(&lexer::TokenKind::ParenthesesL)?;
+    Ok(ast::Reference { pattern, position })
+  }
 
-    let mut arguments = Vec::new();
+  /// %expr '=' %expr
+  fn parse_assign_stmt(&mut self, assignee_expr: ast::Node) -> ParserResult<ast::AssignStmt> {
+    let position = self.get_position();
 
-    while self.until(&lexer::TokenKind::ParenthesesR)? {
-      arguments.push(self.parse_expr()?);
-
-      if !self.is(&lexer::TokenKind::ParenthesesR) {
-        self.skip_past(&lexer::TokenKind::Comma)?;
-      }
-    }
-
-    self.skip_past(&lexer::TokenKind::ParenthesesR)?;
-
-    Ok(ast::IntrinsicCall { kind, arguments })
-  }
-
-  /// %pattern
-  fn parse_reference(&mut self) -> ParserResult<ast::Reference> {
-    // REVIEW: Would there be an instance where this method can accept which symbol kind to parse?
-    let pattern = self.parse_pattern(name_resolution::SymbolKind::Definition)?;
-
-    Ok(ast::Reference { pattern })
-  }
-
-  /// %expr '=' %expr
-  fn parse_assign_stmt(&mut self, assignee_expr: ast::Node) -> ParserResult<ast::AssignStmt> {
     self.skip_past(&lexer::TokenKind::Equal)?;
 
     let value = Box::new(self.parse_expr()?);
@@ -1326,6 +1739,7 @@ impl<'a> Parser<'a> {
     Ok(ast::AssignStmt {
       assignee_expr: Box::new(assignee_expr),
       value,
+      position,
     })
   }
 
@@ -1364,7 +1778,7 @@ impl<'a> Parser<'a> {
     })
   }
 
-  /// struct %name ':' %indent (%name ':' %type ',')+ %dedent
+  /// struct %name ':' %indent (%name ':' %type ('=' %literal) ',')+ %dedent
   fn parse_struct_type(&mut self) -> ParserResult<ast::StructType> {
     self.skip_past(&lexer::TokenKind::Struct)?;
 
@@ -1373,6 +1787,7 @@ impl<'a> Parser<'a> {
     self.skip_past(&lexer::TokenKind::Colon)?;
 
     let mut fields = Vec::new();
+    let mut field_defaults = Vec::new();
 
     self.parse_indent()?;
 
@@ -1383,8 +1798,17 @@ impl<'a> Parser<'a> {
 
       let field_type = self.parse_type()?;
 
+      let field_default = if self.is(&lexer::TokenKind::Equal) {
+        self.skip()?;
+
+        Some(self.parse_literal()?)
+      } else {
+        None
+      };
+
       self.skip_past(&lexer::TokenKind::Comma)?;
       fields.push((field_name, field_type));
+      field_defaults.push(field_default);
 
       if self.is(&lexer::TokenKind::Dedent) {
         break;
@@ -1396,6 +1820,7 @@ impl<'a> Parser<'a> {
     Ok(ast::StructType {
       name,
       fields,
+      field_defaults,
       cache_id: self.cache.create_id(),
     })
   }
@@ -1434,6 +1859,8 @@ impl<'a> Parser<'a> {
 
   /// func '[' (%name (','))* ']' %prototype ':' %block
   fn parse_closure(&mut self) -> ParserResult<ast::Closure> {
+    let position = self.get_position();
+
     self.skip_past(&lexer::TokenKind::Func)?;
 
     let mut captures = Vec::new();
@@ -1442,7 +1869,11 @@ impl<'a> Parser<'a> {
       self.skip()?;
 
       while self.until(&lexer::TokenKind::BracketR)? {
-        captures.push((self.parse_name()?, None));
+        captures.push(ast::Capture {
+          name: self.parse_name()?,
+          target_id: None,
+          mode: ast::CaptureMode::ByValue,
+        });
       }
 
       self.skip()?;
@@ -1459,6 +1890,7 @@ impl<'a> Parser<'a> {
       prototype,
       body,
       id: self.cache.create_id(),
+      position,
     })
   }
 
@@ -1474,6 +1906,17 @@ impl<'a> Parser<'a> {
       target_struct_pattern = self.parse_pattern(name_resolution::SymbolKind::Type)?;
     }
 
+    // Implementations of marker traits (ex. `impl Copy for Point`) have no body.
+    if trait_pattern.is_some() && !self.is(&lexer::TokenKind::Colon) {
+      return Ok(ast::StructImpl {
+        is_default: false,
+        target_struct_pattern,
+        trait_pattern,
+        member_methods: Vec::new(),
+        static_methods: Vec::new(),
+      });
+    }
+
     self.skip_past(&lexer::TokenKind::Colon)?;
     self.parse_indent()?;
 
@@ -1511,6 +1954,7 @@ impl<'a> Parser<'a> {
     })
   }
 
+  /// trait %name ':' %indent (func %name %prototype)+ %dedent
   fn parse_trait(&mut self) -> ParserResult<ast::Trait> {
     self.skip_past(&lexer::TokenKind::Trait)?;
 
@@ -1518,13 +1962,23 @@ impl<'a> Parser<'a> {
 
     self.skip_past(&lexer::TokenKind::Colon)?;
 
+    // NOTE: Like struct and enum bodies, the methods are indented, and the
+    // ... lexer emits an indent token before them. Without consuming it, no
+    // ... trait written in source code could be parsed.
+    self.parse_indent()?;
+
     let mut methods = Vec::new();
 
     loop {
       self.skip_past(&lexer::TokenKind::Func)?;
 
       let method_name = self.parse_name()?;
-      let prototype = self.parse_prototype(false)?;
+      let mut prototype = self.parse_prototype(false)?;
+
+      // Trait methods have no body from which to infer the return type.
+      if matches!(prototype.return_type_annotation, ast::Type::Variable(_)) {
+        prototype.return_type_annotation = ast::Type::Unit;
+      }
 
       methods.push((method_name, prototype));
 
@@ -1616,11 +2070,13 @@ impl<'a> Parser<'a> {
   }
 
   fn parse_unimplemented_expr(&mut self) -> ParserResult<ast::UnimplementedExpr> {
+    let position = self.get_position();
+
     self.skip_past(&lexer::TokenKind::QuestionMark)?;
     self.skip_past(&lexer::TokenKind::QuestionMark)?;
     self.skip_past(&lexer::TokenKind::QuestionMark)?;
 
-    Ok(ast::UnimplementedExpr)
+    Ok(ast::UnimplementedExpr { position })
   }
 }
 
@@ -1713,36 +2169,13 @@ mod tests {
     assert!(parser.is_eof());
   }
 
-  #[test]
-  fn after_pattern_is() {
-    let mut cache = cache::Cache::new();
-    let mut substitution = Vec::new();
-
-    let mut parser = create_parser(
-      vec![lexer::TokenKind::Identifier("test".to_string())],
-      &mut cache,
-      &mut substitution,
-    );
-
-    parser.tokens.push((lexer::TokenKind::BraceL, 0));
-    assert!(parser.after_pattern_is(&lexer::TokenKind::BraceL));
-    parser.tokens.pop();
-    parser.tokens.push((lexer::TokenKind::Dot, 0));
-
-    parser
-      .tokens
-      .push((lexer::TokenKind::Identifier("foo".to_string()), 0));
-
-    parser.tokens.push((lexer::TokenKind::BraceL, 0));
-    assert!(parser.after_pattern_is(&lexer::TokenKind::BraceL));
-  }
-
   #[test]
   fn is_binary_operator() {
     assert!(!Parser::is_binary_operator(&lexer::TokenKind::BraceL));
     assert!(Parser::is_binary_operator(&lexer::TokenKind::Plus));
     assert!(Parser::is_binary_operator(&lexer::TokenKind::Equality));
     assert!(Parser::is_binary_operator(&lexer::TokenKind::And));
+    assert!(Parser::is_binary_operator(&lexer::TokenKind::Percent));
     assert!(!Parser::is_binary_operator(&lexer::TokenKind::EOF));
 
     // TODO: More.
@@ -1761,5 +2194,709 @@ mod tests {
     assert!(parser.peek_is(&lexer::TokenKind::BraceL));
   }
 
+  #[test]
+  fn parse_try_expr() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::Identifier("a".to_string()),
+        lexer::TokenKind::PostfixQuestionMark,
+        lexer::TokenKind::Dedent,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    let node = parser.parse_primary_expr();
+
+    assert!(node.is_ok());
+    assert!(matches!(node.unwrap().kind, ast::NodeKind::TryExpr(_)));
+  }
+
+  #[test]
+  fn parse_nested_indexing_expr() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::Identifier("a".to_string()),
+        lexer::TokenKind::BracketL,
+        lexer::TokenKind::Int(0),
+        lexer::TokenKind::BracketR,
+        lexer::TokenKind::BracketL,
+        lexer::TokenKind::Int(1),
+        lexer::TokenKind::BracketR,
+        lexer::TokenKind::Dedent,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    let node = parser.parse_primary_expr();
+
+    assert!(node.is_ok());
+
+    let indexing_expr = crate::force_match!(node.unwrap().kind, ast::NodeKind::IndexingExpr);
+
+    assert!(matches!(
+      indexing_expr.target_expr.kind,
+      ast::NodeKind::IndexingExpr(_)
+    ));
+  }
+
+  #[test]
+  fn parse_binary_expr_precedence() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    // 1 + 2 * 3 < 4 and true
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::Int(1),
+        lexer::TokenKind::Plus,
+        lexer::TokenKind::Int(2),
+        lexer::TokenKind::Asterisk,
+        lexer::TokenKind::Int(3),
+        lexer::TokenKind::LessThan,
+        lexer::TokenKind::Int(4),
+        lexer::TokenKind::And,
+        lexer::TokenKind::Bool(true),
+        lexer::TokenKind::Dedent,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    let node = parser.parse_expr();
+
+    assert!(node.is_ok());
+
+    let and_expr = crate::force_match!(node.unwrap().kind, ast::NodeKind::BinaryExpr);
+
+    assert_eq!(ast::OperatorKind::And, and_expr.operator);
+
+    let less_than_expr = crate::force_match!(and_expr.left.kind, ast::NodeKind::BinaryExpr);
+
+    assert_eq!(ast::OperatorKind::LessThan, less_than_expr.operator);
+
+    let add_expr = crate::force_match!(less_than_expr.left.kind, ast::NodeKind::BinaryExpr);
+
+    assert_eq!(ast::OperatorKind::Add, add_expr.operator);
+
+    assert!(matches!(
+      add_expr.right.kind,
+      ast::NodeKind::BinaryExpr(ast::BinaryExpr {
+        operator: ast::OperatorKind::MultiplyOrDereference,
+        ..
+      })
+    ));
+  }
+
+  #[test]
+  fn parse_slice_expr() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::Identifier("a".to_string()),
+        lexer::TokenKind::BracketL,
+        lexer::TokenKind::Int(1),
+        lexer::TokenKind::ShortEllipsis,
+        lexer::TokenKind::Int(3),
+        lexer::TokenKind::BracketR,
+        lexer::TokenKind::Dedent,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    let node = parser.parse_primary_expr();
+
+    assert!(node.is_ok());
+
+    let slice_expr = crate::force_match!(node.unwrap().kind, ast::NodeKind::SliceExpr);
+
+    assert!(matches!(
+      slice_expr.start_expr.kind,
+      ast::NodeKind::Literal(ast::Literal::Int(1, _))
+    ));
+
+    assert!(matches!(
+      slice_expr.end_expr.kind,
+      ast::NodeKind::Literal(ast::Literal::Int(3, _))
+    ));
+  }
+
+  #[test]
+  fn parse_for_in_stmt() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::For,
+        lexer::TokenKind::Identifier("element".to_string()),
+        lexer::TokenKind::In,
+        lexer::TokenKind::Identifier("a".to_string()),
+        lexer::TokenKind::Colon,
+        lexer::TokenKind::Indent,
+        lexer::TokenKind::Pass,
+        lexer::TokenKind::Dedent,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    let for_in_stmt = parser.parse_for_in_stmt();
+
+    assert!(for_in_stmt.is_ok());
+
+    let for_in_stmt = for_in_stmt.unwrap();
+
+    assert_eq!(String::from("element"), for_in_stmt.element.name);
+    assert!(matches!(
+      for_in_stmt.iterable.kind,
+      ast::NodeKind::Reference(_)
+    ));
+    assert!(matches!(for_in_stmt.element.ty, ast::Type::Variable(_)));
+  }
+
+  #[test]
+  fn parse_defer_stmt() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::Defer,
+        lexer::TokenKind::Colon,
+        lexer::TokenKind::Identifier("close".to_string()),
+        lexer::TokenKind::ParenthesesL,
+        lexer::TokenKind::Identifier("file".to_string()),
+        lexer::TokenKind::ParenthesesR,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    let statement = parser.parse_statement();
+
+    assert!(statement.is_ok());
+
+    assert!(matches!(
+      statement.unwrap().kind,
+      ast::NodeKind::DeferStmt(ast::DeferStmt { body }) if matches!(body.kind, ast::NodeKind::CallExpr(_))
+    ));
+  }
+
+  #[test]
+  fn parse_interpolation_expr() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::String("hello {name}, {{you}} are {age + 1}".to_string()),
+        lexer::TokenKind::Dedent,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    let node = parser.parse_primary_expr();
+
+    assert!(node.is_ok());
+
+    let interpolation_expr =
+      crate::force_match!(node.unwrap().kind, ast::NodeKind::InterpolationExpr);
+
+    assert_eq!(
+      vec!["hello ", ", {you} are ", ""],
+      interpolation_expr.pieces
+    );
+
+    assert_eq!(2, interpolation_expr.holes.len());
+    assert!(matches!(
+      interpolation_expr.holes[0].kind,
+      ast::NodeKind::Reference(_)
+    ));
+    assert!(matches!(
+      interpolation_expr.holes[1].kind,
+      ast::NodeKind::BinaryExpr(_)
+    ));
+  }
+
+  #[test]
+  fn parse_string_placeholders_as_literal() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::String("value: {} {{}}".to_string()),
+        lexer::TokenKind::Dedent,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    let node = parser.parse_primary_expr();
+
+    assert!(node.is_ok());
+
+    // Escapes are kept verbatim, since the literal may be a format string.
+    assert!(matches!(
+      node.unwrap().kind,
+      ast::NodeKind::Literal(ast::Literal::String(value)) if value == "value: {} {{}}"
+    ));
+  }
+
+  #[test]
+  fn split_interpolation_errors() {
+    assert!(split_interpolation("{name").is_err());
+    assert!(split_interpolation("{name} {}").is_err());
+    assert!(split_interpolation("name}").is_err());
+  }
+
+  #[test]
+  fn parse_alignof_intrinsic() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::QuestionMark,
+        lexer::TokenKind::Alignof,
+        lexer::TokenKind::BracketL,
+        lexer::TokenKind::TypeUsize,
+        lexer::TokenKind::BracketR,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    let intrinsic_call = parser.parse_intrinsic();
+
+    assert!(intrinsic_call.is_ok());
+
+    let intrinsic_call = intrinsic_call.unwrap();
+
+    assert_eq!(ast::IntrinsicKind::Alignof, intrinsic_call.kind);
+
+    assert_eq!(
+      vec![ast::Type::Basic(ast::BasicType::Int(ast::IntSize::Usize))],
+      intrinsic_call.type_arguments
+    );
+
+    assert!(intrinsic_call.arguments.is_empty());
+  }
+
+  #[test]
+  fn parse_offsetof_intrinsic() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::QuestionMark,
+        lexer::TokenKind::Identifier(String::from("offsetof")),
+        lexer::TokenKind::BracketL,
+        lexer::TokenKind::Identifier(String::from("Point")),
+        lexer::TokenKind::Comma,
+        lexer::TokenKind::Identifier(String::from("y")),
+        lexer::TokenKind::BracketR,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    let intrinsic_call = parser.parse_intrinsic();
+
+    assert!(intrinsic_call.is_ok());
+
+    let intrinsic_call = intrinsic_call.unwrap();
+
+    assert_eq!(ast::IntrinsicKind::Offsetof, intrinsic_call.kind);
+    assert_eq!(1, intrinsic_call.type_arguments.len());
+    assert_eq!(Some(String::from("y")), intrinsic_call.field_name);
+  }
+
+  #[test]
+  fn parse_unknown_intrinsic() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::QuestionMark,
+        lexer::TokenKind::Identifier(String::from("unknown")),
+        lexer::TokenKind::ParenthesesL,
+        lexer::TokenKind::ParenthesesR,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    assert!(parser.parse_intrinsic().is_err());
+  }
+
+  #[test]
+  fn parse_trait() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::Trait,
+        lexer::TokenKind::Identifier(String::from("Allocator")),
+        lexer::TokenKind::Colon,
+        lexer::TokenKind::Indent,
+        lexer::TokenKind::Func,
+        lexer::TokenKind::Identifier(String::from("allocate")),
+        lexer::TokenKind::ParenthesesL,
+        lexer::TokenKind::Identifier(String::from("this")),
+        lexer::TokenKind::Comma,
+        lexer::TokenKind::Identifier(String::from("size")),
+        lexer::TokenKind::Colon,
+        lexer::TokenKind::TypeUsize,
+        lexer::TokenKind::ParenthesesR,
+        lexer::TokenKind::Arrow,
+        lexer::TokenKind::Asterisk,
+        lexer::TokenKind::TypeUint8,
+        lexer::TokenKind::Func,
+        lexer::TokenKind::Identifier(String::from("release")),
+        lexer::TokenKind::ParenthesesL,
+        lexer::TokenKind::Identifier(String::from("this")),
+        lexer::TokenKind::ParenthesesR,
+        lexer::TokenKind::Dedent,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    let trait_type = parser.parse_trait();
+
+    assert!(trait_type.is_ok());
+
+    let trait_type = trait_type.unwrap();
+
+    assert_eq!(String::from("Allocator"), trait_type.name);
+    assert_eq!(2, trait_type.methods.len());
+    assert!(trait_type.methods[0].1.accepts_instance);
+    assert_eq!(1, trait_type.methods[0].1.parameters.len());
+    assert_eq!(
+      ast::Type::Unit,
+      trait_type.methods[1].1.return_type_annotation
+    );
+  }
+
+  #[test]
+  fn parse_marker_trait_impl() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::Impl,
+        lexer::TokenKind::Identifier(String::from("Copy")),
+        lexer::TokenKind::For,
+        lexer::TokenKind::Identifier(String::from("Point")),
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    let struct_impl = parser.parse_struct_impl();
+
+    assert!(struct_impl.is_ok());
+
+    let struct_impl = struct_impl.unwrap();
+
+    assert_eq!(
+      String::from("Copy"),
+      struct_impl.trait_pattern.unwrap().base_name
+    );
+
+    assert_eq!(
+      String::from("Point"),
+      struct_impl.target_struct_pattern.base_name
+    );
+
+    assert!(struct_impl.member_methods.is_empty());
+    assert!(struct_impl.static_methods.is_empty());
+  }
+
+  #[test]
+  fn parse_int_literal_minimum_size() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::Int(1),
+        lexer::TokenKind::Int(u64::MAX as u128),
+        lexer::TokenKind::Int(u128::MAX),
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    assert_eq!(
+      parser.parse_int_literal().ok(),
+      Some(ast::Literal::Int(1, ast::IntSize::I32))
+    );
+
+    assert_eq!(
+      parser.parse_int_literal().ok(),
+      Some(ast::Literal::Int(u64::MAX as u128, ast::IntSize::I128))
+    );
+
+    assert_eq!(
+      parser.parse_int_literal().ok(),
+      Some(ast::Literal::Int(u128::MAX, ast::IntSize::U128))
+    );
+  }
+
+  #[test]
+  fn parse_optional_type() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![lexer::TokenKind::QuestionMark, lexer::TokenKind::TypeBool],
+      &mut cache,
+      &mut substitution,
+    );
+
+    assert_eq!(
+      Ok(ast::Type::Optional(Box::new(ast::Type::Basic(
+        ast::BasicType::Bool
+      )))),
+      parser.parse_type()
+    );
+  }
+
+  #[test]
+  fn parse_result_type() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::TypeResult,
+        lexer::TokenKind::BracketL,
+        lexer::TokenKind::TypeBool,
+        lexer::TokenKind::Comma,
+        lexer::TokenKind::TypeInt32,
+        lexer::TokenKind::BracketR,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    assert_eq!(
+      Ok(ast::Type::Result(
+        Box::new(ast::Type::Basic(ast::BasicType::Bool)),
+        Box::new(ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)))
+      )),
+      parser.parse_type()
+    );
+  }
+
+  #[test]
+  fn parse_result_value() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::Err,
+        lexer::TokenKind::BracketL,
+        lexer::TokenKind::TypeBool,
+        lexer::TokenKind::BracketR,
+        lexer::TokenKind::ParenthesesL,
+        lexer::TokenKind::Int(1),
+        lexer::TokenKind::ParenthesesR,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    let node = parser.parse_primary_expr();
+
+    assert!(node.is_ok());
+
+    assert!(matches!(
+      node.unwrap().kind,
+      ast::NodeKind::ResultValue(ast::ResultValue {
+        is_ok: false,
+        explicit_type: ast::Type::Basic(ast::BasicType::Bool),
+        ..
+      })
+    ));
+  }
+
+  #[test]
+  fn parse_slice_type() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::BracketL,
+        lexer::TokenKind::BracketR,
+        lexer::TokenKind::TypeInt32,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    assert_eq!(
+      Ok(ast::Type::Slice(Box::new(ast::Type::Basic(
+        ast::BasicType::Int(ast::IntSize::I32)
+      )))),
+      parser.parse_type()
+    );
+  }
+
+  #[test]
+  fn parse_reference_type() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::Ampersand,
+        lexer::TokenKind::Mut,
+        lexer::TokenKind::TypeInt32,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    assert_eq!(
+      Ok(ast::Type::MutableReference(Box::new(ast::Type::Basic(
+        ast::BasicType::Int(ast::IntSize::I32)
+      )))),
+      parser.parse_type()
+    );
+  }
+
+  #[test]
+  fn parse_mutable_address_of() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::Ampersand,
+        lexer::TokenKind::Mut,
+        lexer::TokenKind::Identifier("a".to_string()),
+        lexer::TokenKind::Dedent,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    let unary_expr = parser.parse_unary_expr();
+
+    assert!(unary_expr.is_ok());
+
+    let unary_expr = unary_expr.unwrap();
+
+    assert_eq!(ast::OperatorKind::MutableAddressOf, unary_expr.operator);
+    assert!(matches!(unary_expr.expr.kind, ast::NodeKind::Reference(_)));
+  }
+
+  #[test]
+  fn parse_function_type() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::TypeBool,
+        lexer::TokenKind::Arrow,
+        lexer::TokenKind::TypeUnit,
+        lexer::TokenKind::Arrow,
+        lexer::TokenKind::TypeBool,
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    assert_eq!(
+      Ok(ast::Type::Function(ast::FunctionType {
+        parameter_types: vec![
+          ast::Type::Basic(ast::BasicType::Bool),
+          ast::Type::Unit
+        ],
+        return_type: Box::new(ast::Type::Basic(ast::BasicType::Bool)),
+        is_variadic: false,
+        is_extern: false,
+      })),
+      parser.parse_type()
+    );
+  }
+
+  #[test]
+  fn parse_static_pattern() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    let mut parser = create_parser(
+      vec![
+        lexer::TokenKind::Identifier("Person".to_string()),
+        lexer::TokenKind::DoubleColon,
+        lexer::TokenKind::Identifier("create".to_string()),
+      ],
+      &mut cache,
+      &mut substitution,
+    );
+
+    assert_eq!(
+      Ok(ast::Pattern {
+        qualifier: None,
+        base_name: "Person".to_string(),
+        sub_name: Some("create".to_string()),
+        symbol_kind: name_resolution::SymbolKind::Definition,
+        target_id: None,
+      }),
+      parser.parse_pattern(name_resolution::SymbolKind::Definition)
+    );
+  }
+
+  #[test]
+  fn parse_pattern_too_many_segments() {
+    let mut cache = cache::Cache::new();
+    let mut substitution = Vec::new();
+
+    // a::b::c::d::e
+    let tokens = vec![
+      (lexer::TokenKind::Identifier("a".to_string()), 0),
+      (lexer::TokenKind::DoubleColon, 1),
+      (lexer::TokenKind::Identifier("b".to_string()), 3),
+      (lexer::TokenKind::DoubleColon, 4),
+      (lexer::TokenKind::Identifier("c".to_string()), 6),
+      (lexer::TokenKind::DoubleColon, 7),
+      (lexer::TokenKind::Identifier("d".to_string()), 9),
+      (lexer::TokenKind::DoubleColon, 10),
+      (lexer::TokenKind::Identifier("e".to_string()), 12),
+    ];
+
+    let mut parser = Parser::new(tokens, &mut cache, &mut substitution);
+    let diagnostic = parser
+      .parse_pattern(name_resolution::SymbolKind::Definition)
+      .unwrap_err();
+
+    assert_eq!("path has too many segments", diagnostic.message);
+    assert_eq!(1, diagnostic.labels.len());
+    assert_eq!(0..13, diagnostic.labels[0].range);
+  }
+
   // TODO: Add more tests.
 }
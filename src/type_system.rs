@@ -1,16 +1,58 @@
-use crate::{ast, cache, dispatch, lowering};
+use crate::{ast, cache, dispatch, lowering, name_resolution, parser};
+use std::convert::TryFrom;
 
 #[derive(Clone)]
 enum TypeConstrainKind {
   Equality,
 }
 
-type TypeConstraint = (ast::Type, ast::Type, TypeConstrainKind);
+/// A constraint between two types, along with a description of where it
+/// originated from (ex. the value of a binding) and the position of its
+/// origin in the source file (if known), which are reported if the types
+/// cannot be unified.
+type TypeConstraint = (
+  ast::Type,
+  ast::Type,
+  TypeConstrainKind,
+  String,
+  Option<usize>,
+);
+
+/// The kind of conversion performed by a cast expression.
+///
+/// Determines both the rules that apply to the cast, and the
+/// instruction it is lowered to.
+#[derive(PartialEq, Debug)]
+pub enum CastKind {
+  /// A conversion between integer-like types. Booleans, characters and
+  /// enums are integers under the hood.
+  Int {
+    is_source_signed: bool,
+  },
+  /// A reinterpretation of a pointer as a pointer to another type.
+  PointerToPointer,
+  PointerToInt,
+  IntToPointer,
+  /// The decay of an array into a pointer to its first element.
+  ArrayToPointer,
+}
+
+/// A value computed during type-checking, from a constant expression.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum ConstantValue {
+  Int(i128),
+  Bool(bool),
+}
 
 pub struct TypeContext {
   diagnostics: Vec<codespan_reporting::diagnostic::Diagnostic<usize>>,
+  /// The file that the positions of diagnostic labels refer to.
+  pub file_id: usize,
   in_loop: bool,
   in_unsafe_block: bool,
+  /// Whether the node being checked is within the body of a `defer`
+  /// statement, which may not return from its enclosing function.
+  in_defer: bool,
   in_impl: bool,
   current_function_id: Option<cache::Id>,
   // REVISE: Make use-of or discard.
@@ -23,30 +65,38 @@ pub struct TypeContext {
   /// populated during parsing phase, when type variables are created, and
   /// it also is scope-less/context-free.
   substitutions: std::collections::HashMap<usize, ast::Type>,
-  bound_checked_arrays: std::collections::HashSet<cache::Id>,
 }
 
 impl TypeContext {
   pub fn run(
-    ast: &Vec<ast::Node>,
-    cache: &cache::Cache,
+    ast: &mut Vec<ast::Node>,
+    cache: &mut cache::Cache,
   ) -> (
     Vec<codespan_reporting::diagnostic::Diagnostic<usize>>,
     Vec<ast::Using>,
   ) {
     let mut type_context = TypeContext::new();
 
-    // TODO: What about constraint reports, and post-unification?
+    // Infer the types of the nodes that were left unannotated by the
+    // parser (and thus hold type variables), before checking them.
+    for node in ast.iter_mut() {
+      visit_mut(&mut node.kind, &mut |inner| {
+        inner.report_constraints(&mut type_context, cache)
+      });
+    }
 
-    // for top_level_node in inner_ast.iter_mut() {
-    //   top_level_node
-    //     .kind
-    //     .post_unification(&mut type_context, &cache);
-    // }
+    type_context.solve_constraints();
 
-    for node in ast {
+    // NOTE: This will also update the cached copies of the nodes, so that
+    // ... their concrete types are visible through references to them.
+    for node in ast.iter_mut() {
+      visit_mut(&mut node.kind, &mut |inner| {
+        inner.post_unification(&mut type_context, cache)
+      });
+    }
+
+    for node in ast.iter() {
       node.kind.check(&mut type_context, cache);
-      // node.report_constraints(&mut semantic_check_context, cache);
     }
 
     (type_context.diagnostics, type_context.usings)
@@ -55,15 +105,16 @@ impl TypeContext {
   pub fn new() -> Self {
     Self {
       diagnostics: Vec::new(),
+      file_id: 0,
       in_loop: false,
       in_unsafe_block: false,
+      in_defer: false,
       in_impl: false,
       current_function_id: None,
       _types_cache: std::collections::HashMap::new(),
       usings: Vec::new(),
       constraints: Vec::new(),
       substitutions: std::collections::HashMap::new(),
-      bound_checked_arrays: std::collections::HashSet::new(),
     }
   }
 
@@ -111,6 +162,44 @@ impl TypeContext {
     }
   }
 
+  /// Compare the prototype of a trait method with that of its implementation.
+  ///
+  /// Returns a description of the first mismatch found, if any.
+  fn compare_prototypes(
+    trait_prototype: &ast::Prototype,
+    impl_prototype: &ast::Prototype,
+    cache: &cache::Cache,
+  ) -> Option<String> {
+    if trait_prototype.accepts_instance != impl_prototype.accepts_instance {
+      return Some(String::from("instance parameter"));
+    } else if trait_prototype.is_instance_mutable != impl_prototype.is_instance_mutable {
+      return Some(String::from("instance parameter mutability"));
+    } else if trait_prototype.is_variadic != impl_prototype.is_variadic {
+      return Some(String::from("variadic parameters"));
+    } else if trait_prototype.parameters.len() != impl_prototype.parameters.len() {
+      return Some(String::from("parameter count"));
+    }
+
+    for (trait_parameter, impl_parameter) in trait_prototype
+      .parameters
+      .iter()
+      .zip(impl_prototype.parameters.iter())
+    {
+      if !trait_parameter.ty.flat_is(&impl_parameter.ty, cache) {
+        return Some(format!("type of parameter `{}`", impl_parameter.name));
+      }
+    }
+
+    if !trait_prototype
+      .return_type_annotation
+      .flat_is(&impl_prototype.return_type_annotation, cache)
+    {
+      return Some(String::from("return type"));
+    }
+
+    None
+  }
+
   pub fn infer_prototype_type(prototype: &ast::Prototype, return_type: ast::Type) -> ast::Type {
     ast::Type::Function(ast::FunctionType {
       return_type: Box::new(return_type),
@@ -152,1561 +241,5029 @@ impl TypeContext {
     ty
   }
 
-  // TODO: Make use-of, or get rid-of.
-  fn _fetch_type(
-    &mut self,
-    node_kind: &ast::NodeKind,
-    unique_key: &cache::Id,
+  /// Determine the type of the values returned by a function. Static arrays
+  /// returned by functions annotated to return slices are coerced into them.
+  pub fn infer_function_return_type(
+    prototype: &ast::Prototype,
+    body: &ast::BlockExpr,
     cache: &cache::Cache,
   ) -> ast::Type {
-    if let Some(cached_type) = self._types_cache.get(unique_key) {
-      return cached_type.clone();
-    }
+    let return_value_type = TypeContext::infer_return_value_type(body, cache).flatten(cache);
 
-    let inferred_type = node_kind.infer_type(cache);
+    match prototype.return_type_annotation.flatten(cache) {
+      return_type @ ast::Type::Slice(_) if matches!(return_value_type, ast::Type::Array(..)) => {
+        return_type
+      }
+      _ => return_value_type,
+    }
+  }
 
-    self
-      ._types_cache
-      // TODO: Cloning type.
-      .insert(unique_key.clone(), inferred_type.clone());
+  /// Report a temporary static array returned as a slice, since the slice
+  /// would point into the stack frame of the returning function. Static arrays
+  /// that are places are left to the lifetime checker instead.
+  fn check_returned_array(
+    &mut self,
+    value: &ast::NodeKind,
+    return_type: &ast::Type,
+    cache: &cache::Cache,
+  ) {
+    if matches!(return_type, ast::Type::Slice(_))
+      && matches!(value.infer_flatten_type(cache), ast::Type::Array(..))
+      && !TypeContext::is_place(value)
+    {
+      self.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("cannot return a temporary static array as a slice")
+          .with_notes(vec![String::from(
+            "the array would no longer exist once the function returns",
+          )]),
+      );
+    }
+  }
 
-    return inferred_type;
+  /// Determine the bit-width and signedness of an integer-like type.
+  fn int_like_layout(ty: &ast::Type, cache: &cache::Cache) -> Option<(u32, bool)> {
+    match ty {
+      ast::Type::Basic(ast::BasicType::Int(size)) => {
+        Some((size.bit_width(cache.pointer_width), size.is_signed()))
+      }
+      ast::Type::Basic(ast::BasicType::Bool) => Some((1, false)),
+      ast::Type::Basic(ast::BasicType::Char) => Some((8, false)),
+      ast::Type::Stub(stub_type) => match cache.force_get(&stub_type.pattern.target_id.unwrap()) {
+        ast::NodeKind::Enum(enum_) => {
+          TypeContext::int_like_layout(&ast::Type::Basic(enum_.ty.clone()), cache)
+        }
+        _ => None,
+      },
+      _ => None,
+    }
   }
 
-  // TODO: Use an enum to specify error type instead of a string.
-  // REVIEW: Consider using `Result` instead of `Option`.
-  // pub fn compare_prototypes(
-  //   prototype_a: &ast::Prototype,
-  //   prototype_b: &ast::Prototype,
-  //   cache: &cache::Cache,
-  // ) -> Option<String> {
-  //   if prototype_a.parameters.len() != prototype_b.parameters.len() {
-  //     return Some("parameter count".to_string());
-  //   }
+  /// Split a format string into the text surrounding each `{}`
+  /// placeholder, resolving `{{` and `}}` escapes. A format string
+  /// with `n` placeholders yields `n + 1` pieces.
+  pub fn split_format_string(template: &str) -> Result<Vec<String>, &'static str> {
+    let mut pieces = vec![String::new()];
+    let mut characters = template.chars().peekable();
+
+    while let Some(character) = characters.next() {
+      match (character, characters.peek().copied()) {
+        ('{', Some('{')) | ('}', Some('}')) => {
+          characters.next();
+          pieces.last_mut().unwrap().push(character);
+        }
+        ('{', Some('}')) => {
+          characters.next();
+          pieces.push(String::new());
+        }
+        ('{', _) => return Err("unmatched `{` (use `{{` to print a brace)"),
+        ('}', _) => return Err("unmatched `}` (use `}}` to print a brace)"),
+        _ => pieces.last_mut().unwrap().push(character),
+      }
+    }
 
-  //   let parameter_types = prototype_a
-  //     .parameters
-  //     .iter()
-  //     .zip(prototype_b.parameters.iter())
-  //     .map(|(param_def_a, param_def_b)| (param_def_a.ty.clone(), param_def_b.ty.clone()));
+    Ok(pieces)
+  }
 
-  //   for (param_type_a, param_type_b) in parameter_types {
-  //     if !Self::compare(&param_type_a, &param_type_b, cache) {
-  //       // TODO: Be more specific.
-  //       return Some("parameter type".to_string());
-  //     }
-  //   }
+  /// Verify the arguments of a print intrinsic: the format string must
+  /// be a valid string literal, with a placeholder for each of the
+  /// following values, which must be of printable types.
+  ///
+  /// An interpolated format string embeds its own values, so it
+  /// cannot be followed by any.
+  fn check_format_arguments(&mut self, arguments: &[ast::Node], cache: &cache::Cache) {
+    let template = match arguments.first().unwrap().kind.flatten() {
+      ast::NodeKind::Literal(ast::Literal::String(template)) => template,
+      ast::NodeKind::InterpolationExpr(_) => {
+        if arguments.len() > 1 {
+          self.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error()
+              .with_message("interpolated format strings cannot be given values"),
+          );
+        }
 
-  //   if !Self::compare(
-  //     &prototype_a.return_type_annotation,
-  //     &prototype_b.return_type_annotation,
-  //     cache,
-  //   ) {
-  //     return Some("return type".to_string());
-  //   }
+        return;
+      }
+      _ => {
+        self.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("format string must be a string literal"),
+        );
 
-  //   None
-  // }
+        return;
+      }
+    };
 
-  // TODO: Create a `finalize` method step to ensure that the main function was defined.
+    let placeholder_count = match TypeContext::split_format_string(template) {
+      Ok(pieces) => pieces.len() - 1,
+      Err(message) => {
+        self.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message(format!("invalid format string: {}", message)),
+        );
 
-  fn create_type_variable(&mut self) -> ast::Type {
-    let id = self.substitutions.len();
-    let result = ast::Type::Variable(id.clone());
+        return;
+      }
+    };
 
-    self.substitutions.insert(id, result.clone());
+    let values = &arguments[1..];
 
-    result
+    if placeholder_count != values.len() {
+      self.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+          "format string has {} placeholder(s), but {} value(s) were given",
+          placeholder_count,
+          values.len()
+        )),
+      );
+    }
+
+    for value in values {
+      self.check_printable(value, cache);
+    }
   }
 
-  /// Recursively check if a type variable index occurs in
-  /// a type.
-  ///
-  /// For this to be `true`, the type in question must be a type variable.
-  /// Any other type will yield `false`.
-  fn occurs_in(&self, index_id: usize, ty: &ast::Type) -> bool {
-    match ty {
-      ast::Type::Variable(id)
-        if self.substitutions.get(id).unwrap() != &ast::Type::Variable(id.to_owned()) =>
-      {
-        self.occurs_in(index_id, &self.substitutions.get(id).unwrap())
-      }
-      // REVIEW: Will this compare the underlying values or the addresses?
-      ast::Type::Variable(id) => id == &index_id,
-      // TODO: Generics / type constructors.
+  /// Verify that a value given to a print intrinsic or embedded in an
+  /// interpolated string can be formatted.
+  fn check_printable(&mut self, value: &ast::Node, cache: &cache::Cache) {
+    let is_printable = match value.kind.infer_flatten_type(cache) {
+      // REVIEW: Printing 128-bit integers requires a runtime conversion routine.
+      ast::Type::Basic(ast::BasicType::Int(size)) => size.bit_width(cache.pointer_width) <= 64,
+      ast::Type::Basic(ast::BasicType::Bool)
+      | ast::Type::Basic(ast::BasicType::Char)
+      | ast::Type::Basic(ast::BasicType::String)
+      | ast::Type::Basic(ast::BasicType::OwnedString)
+      | ast::Type::Pointer(_) => true,
+      ast::Type::Stub(stub_type) => matches!(
+        cache.force_get(&stub_type.pattern.target_id.unwrap()),
+        ast::NodeKind::Enum(_)
+      ),
       _ => false,
+    };
+
+    if !is_printable {
+      self.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error().with_message(
+          "only integers (up to 64 bits), booleans, characters, strings, pointers and enums may be printed",
+        ),
+      );
     }
   }
 
-  // REVISE: Avoid excessive cloning.
-  fn unify(&mut self, type_a: &ast::Type, type_b: &ast::Type) {
-    // TODO: Cleanup code. Perhaps expand it to not be a big match statement?
-    match (type_a, type_b) {
-      // TODO: Missing type constructor support.
-      // If both sides are the same type variable, do nothing.
-      (ast::Type::Variable(id_a), ast::Type::Variable(id_b)) if id_a == id_b => {}
-      // If one of the types is a type variable that’s bound in the substitution,
-      // use unify with that type instead.
-      (ast::Type::Variable(id), _)
-        if {
-          let access = self.substitutions.get(id);
-
-          // REVIEW: Here we manually added the `.is_some()` check. Verify this is as expected.
-          access.is_some() && access != Some(&ast::Type::Variable(*id))
-        } =>
-      {
-        self.unify(&self.substitutions.get(id).unwrap().clone(), type_b)
+  /// Attempt to evaluate a constant expression during type-checking.
+  ///
+  /// Yields `None` if the expression cannot be evaluated, either because it
+  /// isn't constant, or because its value depends on the target (ex. `?sizeof`).
+  pub fn evaluate_constant(node: &ast::NodeKind, cache: &cache::Cache) -> Option<ConstantValue> {
+    Some(match node {
+      ast::NodeKind::Literal(ast::Literal::Int(value, _)) => {
+        ConstantValue::Int(i128::try_from(*value).ok()?)
       }
-      (_, ast::Type::Variable(id))
-        if {
-          let access = self.substitutions.get(id);
-
-          // REVIEW: Here we manually added the `.is_some()` check. Verify this is as expected.
-          access.is_some() && access != Some(&ast::Type::Variable(*id))
-        } =>
+      ast::NodeKind::Literal(ast::Literal::Bool(value)) => ConstantValue::Bool(*value),
+      ast::NodeKind::ParenthesesExpr(parentheses_expr) => {
+        TypeContext::evaluate_constant(&parentheses_expr.expr.kind, cache)?
+      }
+      ast::NodeKind::Reference(reference) => match cache.force_get(&reference.pattern.target_id?) {
+        ast::NodeKind::BindingStmt(binding_stmt)
+          if binding_stmt.modifier == ast::BindingModifier::ConstExpr =>
+        {
+          TypeContext::evaluate_constant(&binding_stmt.value.kind, cache)?
+        }
+        _ => return None,
+      },
+      ast::NodeKind::IntrinsicCall(intrinsic_call)
+        if intrinsic_call.kind == ast::IntrinsicKind::LengthOf =>
       {
-        self.unify(type_a, &self.substitutions.get(id).unwrap().clone())
+        match intrinsic_call
+          .arguments
+          .first()?
+          .kind
+          .infer_flatten_type(cache)
+        {
+          ast::Type::Array(_, length) => ConstantValue::Int(length as i128),
+          _ => return None,
+        }
       }
-      // Otherwise, if one of the types is an unbound type variable, bind it to the
-      // other type. Remember to do an occurs check to avoid constructing infinite types.
-      (ast::Type::Variable(id_a), _) => {
-        // REVISE: Proper error handling.
-        assert!(!self.occurs_in(id_a.to_owned(), &type_b));
-
-        self.substitutions.insert(*id_a, type_b.clone());
+      ast::NodeKind::UnaryExpr(unary_expr) => {
+        match (
+          &unary_expr.operator,
+          TypeContext::evaluate_constant(&unary_expr.expr.kind, cache)?,
+        ) {
+          (ast::OperatorKind::Not, ConstantValue::Bool(value)) => ConstantValue::Bool(!value),
+          (ast::OperatorKind::SubtractOrNegate, ConstantValue::Int(value)) => {
+            ConstantValue::Int(value.checked_neg()?)
+          }
+          (ast::OperatorKind::Cast, ConstantValue::Int(value)) => {
+            match unary_expr.cast_type.as_ref()?.flatten(cache) {
+              ast::Type::Basic(ast::BasicType::Int(size)) => {
+                ConstantValue::Int(TypeContext::wrap_int(value, &size, cache)?)
+              }
+              _ => return None,
+            }
+          }
+          _ => return None,
+        }
       }
-      (_, ast::Type::Variable(id_b)) => {
-        // REVISE: Proper error handling.
-        assert!(!self.occurs_in(id_b.to_owned(), &type_a));
-
-        self.substitutions.insert(*id_b, type_a.clone());
+      ast::NodeKind::BinaryExpr(binary_expr) => {
+        let left = TypeContext::evaluate_constant(&binary_expr.left.kind, cache)?;
+        let right = TypeContext::evaluate_constant(&binary_expr.right.kind, cache)?;
+
+        match (left, right) {
+          (ConstantValue::Int(left), ConstantValue::Int(right)) => match binary_expr.operator {
+            ast::OperatorKind::Add => ConstantValue::Int(left.checked_add(right)?),
+            ast::OperatorKind::SubtractOrNegate => ConstantValue::Int(left.checked_sub(right)?),
+            ast::OperatorKind::MultiplyOrDereference => {
+              ConstantValue::Int(left.checked_mul(right)?)
+            }
+            ast::OperatorKind::Divide => ConstantValue::Int(left.checked_div(right)?),
+            ast::OperatorKind::Remainder => ConstantValue::Int(left.checked_rem(right)?),
+            ast::OperatorKind::LessThan => ConstantValue::Bool(left < right),
+            ast::OperatorKind::GreaterThan => ConstantValue::Bool(left > right),
+            ast::OperatorKind::LessThanOrEqual => ConstantValue::Bool(left <= right),
+            ast::OperatorKind::GreaterThanOrEqual => ConstantValue::Bool(left >= right),
+            ast::OperatorKind::Equality => ConstantValue::Bool(left == right),
+            _ => return None,
+          },
+          (ConstantValue::Bool(left), ConstantValue::Bool(right)) => match binary_expr.operator {
+            ast::OperatorKind::And => ConstantValue::Bool(left && right),
+            ast::OperatorKind::Or => ConstantValue::Bool(left || right),
+            ast::OperatorKind::Nand => ConstantValue::Bool(!(left && right)),
+            ast::OperatorKind::Nor => ConstantValue::Bool(!(left || right)),
+            ast::OperatorKind::Xor => ConstantValue::Bool(left != right),
+            ast::OperatorKind::Equality => ConstantValue::Bool(left == right),
+            _ => return None,
+          },
+          _ => return None,
+        }
       }
-      _ => {}
-    }
+      _ => return None,
+    })
   }
 
-  // TODO: This is the same thing as `node.unification`, but it assumed nodes can be mutated as in object-oriented languages.
-  /// Solves constraints by performing unification.
+  /// Wrap an integer into the range of an integer type, the same way that
+  /// casting it to that type would (by truncating it, and reinterpreting
+  /// its sign).
   ///
-  /// This occurs after all the constraints have been added,
-  /// and is the last step for Hindley-Milner type inference.
-  /// After this process is completed, nodes can proceed to perform
-  /// their post-unification phase, which mostly consists of replacing
-  /// their type variables with concrete types.
-  fn solve_constraints(&mut self) {
-    // REVIEW: Any way to avoid cloning?
-    for constrain in self.constraints.clone() {
-      self.unify(&constrain.0, &constrain.1);
+  /// Yields `None` for negative values cast to `U128`, which cannot be
+  /// represented.
+  fn wrap_int(value: i128, size: &ast::IntSize, cache: &cache::Cache) -> Option<i128> {
+    let bit_width = size.bit_width(cache.pointer_width);
+
+    if bit_width == 128 {
+      return if size.is_signed() || value >= 0 {
+        Some(value)
+      } else {
+        None
+      };
     }
 
-    self.constraints.clear();
+    let bits = (value as u128) & ((1 << bit_width) - 1);
+
+    Some(if size.is_signed() && bits >> (bit_width - 1) == 1 {
+      bits as i128 - (1 << bit_width)
+    } else {
+      bits as i128
+    })
   }
 
-  /// Substitute a type variable with its non-variable type (if defined).
+  /// Classify a cast between two flattened types, or produce the
+  /// reason why the cast is invalid.
   ///
-  /// This function will recursively substitute type variables,
-  /// until a non-variable type is found.
-  pub fn substitute(&self, ty: ast::Type) -> ast::Type {
-    if let ast::Type::Variable(id) = &ty {
-      let substitution = self.substitutions.get(id).unwrap().clone();
+  /// Casting rules:
+  ///
+  /// | From          | To                 | Kind                 | Notes                   |
+  /// | ------------- | ------------------ | -------------------- | ----------------------- |
+  /// | integer-like  | integer, `Char`    | `Int`                | warns if lossy          |
+  /// | pointer, `Str`| pointer, `Str`     | `PointerToPointer`   | unsafe only             |
+  /// | pointer, `Str`| integer            | `PointerToInt`       | unsafe only             |
+  /// | integer       | pointer, `Str`     | `IntToPointer`       | unsafe only             |
+  /// | `[T, N]`      | `*T`               | `ArrayToPointer`     |                         |
+  pub fn classify_cast(
+    from: &ast::Type,
+    to: &ast::Type,
+    cache: &cache::Cache,
+  ) -> Result<CastKind, &'static str> {
+    let is_pointer_like = |ty: &ast::Type| {
+      matches!(
+        ty,
+        ast::Type::Pointer(_) | ast::Type::Basic(ast::BasicType::String)
+      )
+    };
 
-      // case TVariable(i) if substitution(i) != TVariable(i) =>
-      //substitute(substitution(i))
+    let is_int = |ty: &ast::Type| matches!(ty, ast::Type::Basic(ast::BasicType::Int(_)));
+    let from_layout = TypeContext::int_like_layout(from, cache);
 
-      // REVIEW: Is this condition correct?
-      if substitution != ty {
-        return self.substitute(substitution);
-      }
+    if let Some((_, is_source_signed)) = from_layout {
+      return match to {
+        ast::Type::Basic(ast::BasicType::Bool) => {
+          Err("cannot cast to `Bool`; compare against zero instead")
+        }
+        ast::Type::Stub(_) if TypeContext::int_like_layout(to, cache).is_some() => {
+          Err("cannot cast to an enum; its value may not correspond to any variant")
+        }
+        ast::Type::Basic(ast::BasicType::Int(_)) | ast::Type::Basic(ast::BasicType::Char) => {
+          Ok(CastKind::Int { is_source_signed })
+        }
+        _ if is_pointer_like(to) && is_int(from) => Ok(CastKind::IntToPointer),
+        _ if is_pointer_like(to) => Err("only integers may be cast to pointers"),
+        _ => Err("integer-like values may only be cast to integers or pointers"),
+      };
     }
 
-    // TODO: Missing support for constructor types.
+    if is_pointer_like(from) {
+      return if is_pointer_like(to) {
+        Ok(CastKind::PointerToPointer)
+      } else if is_int(to) {
+        Ok(CastKind::PointerToInt)
+      } else {
+        Err("pointers may only be cast to other pointers or to integers")
+      };
+    }
 
-    ty
-  }
-}
+    if let ast::Type::Array(element_type, _) = from {
+      return match to {
+        ast::Type::Pointer(pointee_type) if element_type.is(pointee_type) => {
+          Ok(CastKind::ArrayToPointer)
+        }
+        ast::Type::Pointer(_) => Err("array element type and pointee type do not match"),
+        _ => Err("arrays may only be cast to a pointer to their element type"),
+      };
+    }
 
-pub trait Check {
-  // REVIEW: Consider caching inference results here, if they are indeed costly.
-  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
-    ast::Type::Unit
+    Err("only integer-like, pointer and array values may be cast")
   }
 
-  fn check(&self, _context: &mut TypeContext, _cache: &cache::Cache) {
-    //
-  }
+  /// Find the bounds guards of an `if` expression's condition, in the
+  /// form of `index in ?length_of(array)`, where both the index and the
+  /// array are references to bindings.
+  ///
+  /// Only a guard forming the whole condition, or one of the operands of
+  /// a chain of `and` operations, is known to hold within the guarded
+  /// branch. It is discarded if either binding is assigned within it.
+  ///
+  /// Yields the ids of the array and index bindings of each guard, in that
+  /// order. Indexing the array by the index within the guarded branch is
+  /// always in bounds.
+  pub fn find_bounds_guards(
+    condition: &ast::NodeKind,
+    branch: &ast::NodeKind,
+    cache: &cache::Cache,
+  ) -> Vec<(cache::Id, cache::Id)> {
+    let (left, right) = match condition.flatten() {
+      ast::NodeKind::BinaryExpr(ast::BinaryExpr {
+        left,
+        right,
+        operator: ast::OperatorKind::And,
+      }) => {
+        let mut bounds_guards = TypeContext::find_bounds_guards(&left.kind, branch, cache);
 
-  fn report_constraints(&mut self, _context: &mut TypeContext, _cache: &cache::Cache) {
-    //
-  }
+        bounds_guards.extend(TypeContext::find_bounds_guards(&right.kind, branch, cache));
 
-  fn post_unification(&mut self, _context: &mut TypeContext, _cache: &cache::Cache) {
-    //
-  }
-}
+        return bounds_guards;
+      }
+      ast::NodeKind::BinaryExpr(ast::BinaryExpr {
+        left,
+        right,
+        operator: ast::OperatorKind::In,
+      }) => (left.kind.flatten(), right.kind.flatten()),
+      _ => return Vec::new(),
+    };
 
-impl Check for ast::NodeKind {
-  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
-    dispatch!(&self, Check::infer_type, cache)
-  }
+    let (index_reference, arguments) = match (left, right) {
+      (
+        ast::NodeKind::Reference(index_reference),
+        ast::NodeKind::IntrinsicCall(ast::IntrinsicCall {
+          arguments,
+          kind: ast::IntrinsicKind::LengthOf,
+          ..
+        }),
+      ) if arguments.len() == 1 => (index_reference, arguments),
+      _ => return Vec::new(),
+    };
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    dispatch!(&self, Check::check, context, cache);
-  }
-}
+    let array_argument = arguments.first().unwrap().kind.flatten();
 
-impl Check for ast::UnimplementedExpr {
-  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
-    ast::Type::Never
-  }
-}
+    let array_reference = match array_argument {
+      ast::NodeKind::Reference(array_reference)
+        if matches!(
+          array_argument.infer_flatten_type(cache),
+          ast::Type::Array(..)
+        ) =>
+      {
+        array_reference
+      }
+      _ => return Vec::new(),
+    };
 
-impl Check for ast::Range {
-  fn check(&self, context: &mut TypeContext, _cache: &cache::Cache) {
-    // NOTE: No need to check whether the range's bounds are constant
-    // ... expressions, this is ensured by the parser.
+    let bounds_guard = (
+      array_reference.pattern.target_id.unwrap(),
+      index_reference.pattern.target_id.unwrap(),
+    );
 
-    let start_literal = crate::force_match!(&self.start.kind, ast::NodeKind::Literal);
-    let end_literal = crate::force_match!(&self.end.kind, ast::NodeKind::Literal);
+    if TypeContext::is_assigned_within(branch, bounds_guard.0)
+      || TypeContext::is_assigned_within(branch, bounds_guard.1)
+    {
+      return Vec::new();
+    }
 
-    let start_int = match start_literal {
-      ast::Literal::Int(value, _) => value,
-      _ => unreachable!(),
-    };
+    vec![bounds_guard]
+  }
 
-    let end_int = match end_literal {
-      ast::Literal::Int(value, _) => value,
-      _ => unreachable!(),
+  /// Determine whether a binding is assigned, or mutably borrowed (and thus
+  /// possibly assigned through the reference), anywhere within a node.
+  fn is_assigned_within(node: &ast::NodeKind, binding_id: cache::Id) -> bool {
+    let is_binding = |node: &ast::NodeKind| {
+      matches!(
+        node.flatten(),
+        ast::NodeKind::Reference(reference) if reference.pattern.target_id == Some(binding_id)
+      )
     };
 
-    if start_int > end_int {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error().with_message(String::from(
-          "range start must be less than or equal to end",
-        )),
-      );
-    }
-  }
-}
-
-impl Check for ast::SizeofIntrinsic {
-  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
-    ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I64))
+    node.any(|inner| match inner {
+      ast::NodeKind::AssignStmt(assign_stmt) => is_binding(&assign_stmt.assignee_expr.kind),
+      ast::NodeKind::UnaryExpr(ast::UnaryExpr {
+        operator: ast::OperatorKind::MutableAddressOf,
+        expr,
+        ..
+      }) => is_binding(&expr.kind),
+      _ => false,
+    })
   }
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    if self.ty.flatten(cache).is(&ast::Type::Unit) {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error()
-          .with_message("cannot determine size of unit type"),
-      );
+  /// Determine whether an expression consists solely of integer literals,
+  /// in which case its type may be dictated by its context.
+  fn is_int_literal_expr(node: &ast::NodeKind) -> bool {
+    match node {
+      ast::NodeKind::Literal(ast::Literal::Int(..)) => true,
+      ast::NodeKind::ParenthesesExpr(parentheses_expr) => {
+        TypeContext::is_int_literal_expr(&parentheses_expr.expr.kind)
+      }
+      ast::NodeKind::UnaryExpr(unary_expr) => {
+        unary_expr.operator == ast::OperatorKind::SubtractOrNegate
+          && TypeContext::is_int_literal_expr(&unary_expr.expr.kind)
+      }
+      ast::NodeKind::BinaryExpr(binary_expr) => {
+        matches!(
+          binary_expr.operator,
+          ast::OperatorKind::Add
+            | ast::OperatorKind::SubtractOrNegate
+            | ast::OperatorKind::MultiplyOrDereference
+            | ast::OperatorKind::Divide
+            | ast::OperatorKind::Remainder
+        ) && TypeContext::is_int_literal_expr(&binary_expr.left.kind)
+          && TypeContext::is_int_literal_expr(&binary_expr.right.kind)
+      }
+      _ => false,
     }
   }
-}
 
-impl Check for ast::Using {
-  fn check(&self, context: &mut TypeContext, _cache: &cache::Cache) {
-    // FIXME: Can't just push the import once encountered; only when it's actually used.
-    context.usings.push(self.clone());
-  }
-}
+  /// Give an integer literal expression the type expected by its context,
+  /// provided that it is an integer type.
+  ///
+  /// Integer literals are otherwise typed after their magnitude by the parser,
+  /// which serves as the default when they are unconstrained.
+  fn type_int_literal_expr(
+    &mut self,
+    node: &mut ast::NodeKind,
+    expected_type: &ast::Type,
+    cache: &cache::Cache,
+  ) {
+    // NOTE: Literals held or yielded by other values are typed
+    // ... through them, by their element or yield type.
+    match (node, expected_type.flatten(cache)) {
+      (ast::NodeKind::StaticArrayValue(array_value), ast::Type::Array(element_type, _))
+      | (ast::NodeKind::StaticArrayValue(array_value), ast::Type::Slice(element_type)) => {
+        for element in array_value.elements.iter_mut() {
+          self.type_int_literal_expr(&mut element.kind, &element_type, cache);
+        }
+      }
+      (ast::NodeKind::BlockExpr(block_expr), _) => {
+        if let Some(yields) = &mut block_expr.yields {
+          self.type_int_literal_expr(&mut yields.kind, expected_type, cache);
+        }
+      }
+      (ast::NodeKind::IfExpr(if_expr), _) => {
+        self.type_int_literal_expr(&mut if_expr.then_expr.kind, expected_type, cache);
 
-impl Check for ast::ParenthesesExpr {
-  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
-    self.expr.kind.infer_type(cache)
-  }
+        for (_, alternative_value) in if_expr.alternative_branches.iter_mut() {
+          self.type_int_literal_expr(&mut alternative_value.kind, expected_type, cache);
+        }
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    self.expr.kind.check(context, cache);
+        if let Some(else_expr) = &mut if_expr.else_expr {
+          self.type_int_literal_expr(&mut else_expr.kind, expected_type, cache);
+        }
+      }
+      (node, ast::Type::Basic(ast::BasicType::Int(size)))
+        if TypeContext::is_int_literal_expr(node) =>
+      {
+        self.retype_int_literals(node, &size, false, cache);
+        self.check_folded_int_range(node, &size, cache);
+      }
+      _ => {}
+    }
   }
-}
 
-impl Check for ast::Trait {
-  //
-}
+  /// Type the integer literals of the values returned from a function's
+  /// body, whether by `return` statements or by the body's yield.
+  fn type_returned_int_literals(
+    &mut self,
+    body: &mut ast::BlockExpr,
+    return_type: &ast::Type,
+    cache: &cache::Cache,
+  ) {
+    fn visit_return_values(node: &mut ast::NodeKind, visitor: &mut dyn FnMut(&mut ast::NodeKind)) {
+      match node {
+        ast::NodeKind::ReturnStmt(return_stmt) => {
+          if let Some(value) = &mut return_stmt.value {
+            visitor(&mut value.kind);
+          }
+        }
+        ast::NodeKind::BlockExpr(block_expr) => {
+          for statement in block_expr.statements.iter_mut() {
+            visit_return_values(&mut statement.kind, visitor);
+          }
 
-impl Check for ast::StructImpl {
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    context.in_impl = true;
+          if let Some(yields) = &mut block_expr.yields {
+            visit_return_values(&mut yields.kind, visitor);
+          }
+        }
+        ast::NodeKind::IfExpr(if_expr) => {
+          visit_return_values(&mut if_expr.then_expr.kind, visitor);
 
-    for method in &self.member_methods {
-      if !method.prototype.accepts_instance {
-        context.diagnostics.push(
-          codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
-            "implementation method `{}` is missing the instance parameter `this`",
-            method.name
-          )),
-        )
-      }
+          for (_, alternative_value) in if_expr.alternative_branches.iter_mut() {
+            visit_return_values(&mut alternative_value.kind, visitor);
+          }
 
-      method.check(context, cache);
+          if let Some(else_expr) = &mut if_expr.else_expr {
+            visit_return_values(&mut else_expr.kind, visitor);
+          }
+        }
+        ast::NodeKind::LoopStmt(loop_stmt) => {
+          for statement in loop_stmt.body.statements.iter_mut() {
+            visit_return_values(&mut statement.kind, visitor);
+          }
+        }
+        ast::NodeKind::ForInStmt(for_in_stmt) => {
+          for statement in for_in_stmt.body.statements.iter_mut() {
+            visit_return_values(&mut statement.kind, visitor);
+          }
+        }
+        ast::NodeKind::UnsafeExpr(unsafe_expr) => {
+          visit_return_values(&mut unsafe_expr.0.kind, visitor)
+        }
+        ast::NodeKind::InlineExprStmt(inline_expr_stmt) => {
+          visit_return_values(&mut inline_expr_stmt.expr.kind, visitor)
+        }
+        // NOTE: Nested closures return from themselves, not from the function.
+        _ => {}
+      };
     }
 
-    let target_node = cache.force_get(&self.target_struct_pattern.target_id.unwrap());
+    for statement in body.statements.iter_mut() {
+      visit_return_values(&mut statement.kind, &mut |value| {
+        self.type_int_literal_expr(value, return_type, cache)
+      });
+    }
 
-    // REVISE: Cleanup.
-    if let ast::NodeKind::StructType(_target_struct_type) = &target_node {
-      if let Some(trait_pattern) = &self.trait_pattern {
-        let trait_node = cache.force_get(&trait_pattern.target_id.unwrap());
+    if let Some(yields) = &mut body.yields {
+      self.type_int_literal_expr(&mut yields.kind, return_type, cache);
+    }
+  }
 
-        if let ast::NodeKind::Trait(trait_type) = &trait_node {
-          for trait_method in &trait_type.methods {
-            let impl_method_result = self
-              .member_methods
-              .iter()
-              .find(|impl_method| impl_method.name == trait_method.0);
+  fn retype_int_literals(
+    &mut self,
+    node: &mut ast::NodeKind,
+    size: &ast::IntSize,
+    is_negative: bool,
+    cache: &cache::Cache,
+  ) {
+    match node {
+      ast::NodeKind::Literal(literal) => self.retype_int_literal(literal, size, is_negative, cache),
+      ast::NodeKind::ParenthesesExpr(parentheses_expr) => {
+        self.retype_int_literals(&mut parentheses_expr.expr.kind, size, is_negative, cache)
+      }
+      ast::NodeKind::UnaryExpr(unary_expr) => {
+        self.retype_int_literals(&mut unary_expr.expr.kind, size, !is_negative, cache)
+      }
+      ast::NodeKind::BinaryExpr(binary_expr) => {
+        self.retype_int_literals(&mut binary_expr.left.kind, size, false, cache);
+        self.retype_int_literals(&mut binary_expr.right.kind, size, false, cache);
+      }
+      _ => {}
+    }
+  }
 
-            if let Some(_impl_method) = impl_method_result {
-              // TODO: Finish implementing.
-              let prototype_unification_result =
-                // TypeCheckContext::unify_prototypes(&trait_method.1, impl_method, cache);
-                Some("pending error".to_string());
+  /// Verify that the intermediate results of an arithmetic expression of
+  /// integer literals fit into the type that it was given, since each
+  /// literal fitting on its own does not prevent the folded value from
+  /// overflowing (ex. `100 + 100` as `I8`).
+  ///
+  /// Only the innermost overflowing operation is reported. Yields whether
+  /// a diagnostic was reported.
+  fn check_folded_int_range(
+    &mut self,
+    node: &ast::NodeKind,
+    size: &ast::IntSize,
+    cache: &cache::Cache,
+  ) -> bool {
+    let binary_expr = match node {
+      ast::NodeKind::ParenthesesExpr(parentheses_expr) => {
+        return self.check_folded_int_range(&parentheses_expr.expr.kind, size, cache)
+      }
+      ast::NodeKind::UnaryExpr(unary_expr) => {
+        return self.check_folded_int_range(&unary_expr.expr.kind, size, cache)
+      }
+      ast::NodeKind::BinaryExpr(binary_expr) => binary_expr,
+      _ => return false,
+    };
 
-              if let Some(error) = prototype_unification_result {
-                // REVISE: Use expected/got system.
-                context.diagnostics.push(
-                  codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
-                    "prototype of implementation method `{}` for trait `{}` mismatch in {}",
-                    "pending impl method name", trait_type.name, error
-                  )),
-                )
-              }
-            } else {
-              context.diagnostics.push(
-                codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
-                  "required method `{}` not implemented",
-                  trait_method.0
-                )),
-              );
-            }
-          }
-        } else {
-          context.diagnostics.push(
-            codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
-              "cannot implement non-trait `{}`",
-              &trait_pattern.base_name
-            )),
-          );
-        }
+    if self.check_folded_int_range(&binary_expr.left.kind, size, cache)
+      || self.check_folded_int_range(&binary_expr.right.kind, size, cache)
+    {
+      return true;
+    }
+
+    let fits = match TypeContext::evaluate_constant(node, cache) {
+      Some(ConstantValue::Int(value)) => {
+        size.fits(value.unsigned_abs(), value < 0, cache.pointer_width)
       }
-    } else {
-      context.diagnostics.push(
+      // REVIEW: Values beyond the range of `i128` (or divisions by zero)
+      // ... cannot be folded, and are left unchecked.
+      _ => true,
+    };
+
+    if !fits {
+      self.diagnostics.push(
         codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
-          "cannot implement for a non-struct type `{}`",
-          self.target_struct_pattern.base_name
+          "constant arithmetic expression overflows type `{}`",
+          size.name()
         )),
       );
     }
 
-    context.in_impl = false;
+    !fits
   }
-}
-
-impl Check for ast::MemberAccess {
-  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
-    let base_expr_type = self.base_expr.kind.infer_flatten_type(cache);
 
-    let struct_type = match base_expr_type {
-      ast::Type::Struct(struct_type) => struct_type,
-      // REVIEW: Investigate this strategy. Shouldn't we be using `unreachable!()` instead?
-      // ... But this point may be reachable from the user-side. Need to somehow properly
-      // ... handle this case.
-      _ => return ast::Type::Error,
+  /// Change the type of an integer literal, reporting a diagnostic if
+  /// its value does not fit into the new type.
+  fn retype_int_literal(
+    &mut self,
+    literal: &mut ast::Literal,
+    size: &ast::IntSize,
+    is_negative: bool,
+    cache: &cache::Cache,
+  ) {
+    let (value, literal_size) = match literal {
+      ast::Literal::Int(value, literal_size) => (value, literal_size),
+      _ => return,
     };
 
-    if let Some(struct_field) = struct_type
-      .fields
-      .iter()
-      .find(|field| field.0 == self.member_name)
-    {
-      return struct_field.1.clone();
-    }
-
-    // REVIEW: Why not abstract this to the `Reference` node? We're doing the same thing (or very similar at least), correct?
-    // TODO: Lookup implementation, and attempt to match a method.
-    if let Some(struct_impls) = cache.struct_impls.get(&struct_type.cache_id) {
-      for (method_cache_id, method_name) in struct_impls {
-        if method_name == &self.member_name {
-          return cache.force_get(&method_cache_id).infer_type(cache);
-        }
-      }
+    if is_negative && !size.is_signed() && *value != 0 {
+      self.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+          "negative integer literal `-{}` cannot be of unsigned type `{}`",
+          value,
+          size.name()
+        )),
+      );
+    } else if !size.fits(*value, is_negative, cache.pointer_width) {
+      self.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+          "integer literal `{}{}` does not fit into type `{}`",
+          if is_negative { "-" } else { "" },
+          value,
+          size.name()
+        )),
+      );
     }
 
-    return ast::Type::Error;
+    *literal_size = size.clone();
   }
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    let base_expr_type = self.base_expr.kind.infer_flatten_type(cache);
-
-    let struct_type = match base_expr_type {
-      ast::Type::Struct(struct_type) => struct_type,
-      // TODO: Implement.
-      ast::Type::This(_) => return,
-      // REVIEW: Investigate this strategy. Shouldn't we be using `unreachable!()` instead?
-      _ => {
-        context.diagnostics.push(
-          codespan_reporting::diagnostic::Diagnostic::error()
-            .with_message("expression is not a struct"),
-        );
+  /// Retrieve the return type of the function or closure currently being
+  /// checked, if any, as given by its prototype.
+  ///
+  /// Unannotated return types have already been inferred by this point.
+  fn find_current_function_return_type(&self, cache: &cache::Cache) -> Option<ast::Type> {
+    let current_function_node = cache.force_get(&self.current_function_id?);
 
-        return;
-      }
+    let prototype = match &current_function_node {
+      ast::NodeKind::Function(function) => &function.prototype,
+      ast::NodeKind::Closure(closure) => &closure.prototype,
+      _ => unreachable!(),
     };
 
-    if !struct_type.fields.iter().any(|x| x.0 == self.member_name) {
-      // context.diagnostic_builder.error(format!(
-      //   "struct type `{}` does not contain a field named `{}`",
-      //   struct_type.name, self.member_name
-      // ));
+    Some(prototype.return_type_annotation.flatten(cache))
+  }
+
+  /// Count the pointers (or references) that must be de-referenced to reach the
+  /// struct they point to, since member accesses automatically de-reference
+  /// those. Yields zero for any other type.
+  pub fn auto_deref_depth(ty: &ast::Type, cache: &cache::Cache) -> usize {
+    let mut depth = 0;
+    let mut current_type = ty.flatten(cache);
+
+    loop {
+      current_type = match current_type {
+        ast::Type::Pointer(pointee_type)
+        | ast::Type::Reference(pointee_type)
+        | ast::Type::MutableReference(pointee_type) => pointee_type.flatten(cache),
+        ast::Type::Struct(_) => return depth,
+        _ => return 0,
+      };
+
+      depth += 1;
     }
   }
-}
 
-impl Check for ast::Closure {
-  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
-    TypeContext::infer_prototype_type(
-      &self.prototype,
-      TypeContext::infer_return_value_type(&self.body, cache),
+  /// Determine whether values of the given type implement `Drop`.
+  pub fn implements_drop(ty: &ast::Type, cache: &cache::Cache) -> bool {
+    matches!(
+      ty.flatten(cache),
+      ast::Type::Struct(struct_type) if cache.drop_methods.contains_key(&struct_type.cache_id)
     )
   }
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    // REVIEW: Might need to mirror `Function`'s type check.
-    let previous_function_id = context.current_function_id.clone();
-
-    context.current_function_id = Some(self.id);
+  /// Report a value that implements `Drop` being held by another value.
+  ///
+  /// Only bindings, parameters and discarded temporaries are dropped; no drop
+  /// glue is generated for the values that hold them, so such a value would
+  /// silently never be dropped.
+  fn report_held_drop_value(&mut self, message: String) {
+    self.diagnostics.push(
+      codespan_reporting::diagnostic::Diagnostic::error()
+        .with_message(message)
+        .with_notes(vec![String::from(
+          "values implementing `Drop` cannot be held by struct fields, array elements, optionals or results, since they would never be dropped",
+        )]),
+    );
+  }
 
-    if self.prototype.accepts_instance {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error()
-          .with_message("closures cannot accept instances"),
-      );
+  /// Determine whether reaching the struct behind the given type, as member
+  /// accesses do, de-references a raw pointer. Unlike references, pointers
+  /// may dangle, so doing so is only allowed inside unsafe blocks.
+  pub fn auto_derefs_pointer(ty: &ast::Type, cache: &cache::Cache) -> bool {
+    if TypeContext::auto_deref_depth(ty, cache) == 0 {
+      return false;
     }
 
-    self.prototype.check(context, cache);
-    self.body.check(context, cache);
-    context.current_function_id = previous_function_id;
+    match ty.flatten(cache) {
+      ast::Type::Pointer(_) => true,
+      ast::Type::Reference(pointee_type) | ast::Type::MutableReference(pointee_type) => {
+        TypeContext::auto_derefs_pointer(&pointee_type, cache)
+      }
+      _ => false,
+    }
   }
-}
-
-impl Check for ast::TypeAlias {
-  // REVIEW: Don't we need to implement `infer_type` here? Seems like not. Confirm.
-}
 
-impl Check for ast::Pattern {
-  //
-}
+  /// Flatten the struct type behind any level of pointers to it. Any other type
+  /// is returned as-is.
+  pub fn auto_deref(ty: ast::Type, cache: &cache::Cache) -> ast::Type {
+    if TypeContext::auto_deref_depth(&ty, cache) == 0 {
+      return ty;
+    }
 
-impl Check for ast::IntrinsicCall {
-  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
-    match self.kind {
-      ast::IntrinsicKind::LengthOf => ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
+    match ty {
+      ast::Type::Pointer(pointee_type)
+      | ast::Type::Reference(pointee_type)
+      | ast::Type::MutableReference(pointee_type) => {
+        TypeContext::auto_deref(pointee_type.flatten(cache), cache)
+      }
+      _ => unreachable!(),
     }
   }
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    // TODO: Redundant to have function return types.
-    let target_prototype_sig: (Vec<ast::Type>, ast::Type) = match self.kind {
-      ast::IntrinsicKind::LengthOf => (
-        // Cannot define array type directly. Use the any type for comparison.
-        vec![ast::Type::Any],
-        ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
-      ),
-    };
+  /// Determine whether the given expression refers to an existing place
+  /// (a binding, parameter, field, element, or dereferenced pointer), as
+  /// opposed to a temporary value. Only places may be referenced.
+  pub fn is_place(node: &ast::NodeKind) -> bool {
+    matches!(
+      node.flatten(),
+      ast::NodeKind::Reference(_)
+        | ast::NodeKind::MemberAccess(_)
+        | ast::NodeKind::IndexingExpr(_)
+        | ast::NodeKind::UnaryExpr(ast::UnaryExpr {
+          operator: ast::OperatorKind::MultiplyOrDereference,
+          ..
+        })
+    )
+  }
 
-    let target_function_type = ast::FunctionType {
-      is_extern: false,
-      is_variadic: false,
-      parameter_types: target_prototype_sig.0,
-      return_type: Box::new(target_prototype_sig.1),
-    };
+  /// Determine whether the place that the given expression refers to
+  /// may be mutated.
+  ///
+  /// Places behind pointers and mutable references are always mutable,
+  /// places behind shared references never are, bindings must be declared
+  /// with `var`, and the instance may only be mutated by methods declared
+  /// with `mut this`.
+  fn is_mutable_place(&self, node: &ast::NodeKind, cache: &cache::Cache) -> bool {
+    match node.flatten() {
+      // The struct lives behind the innermost pointer (or reference), if any.
+      ast::NodeKind::MemberAccess(member_access) => {
+        let mut base_type = member_access.base_expr.kind.infer_flatten_type(cache);
+        let mut is_mutable_pointee = None;
+
+        loop {
+          base_type = match base_type {
+            ast::Type::Pointer(pointee_type) | ast::Type::MutableReference(pointee_type) => {
+              is_mutable_pointee = Some(true);
+
+              pointee_type.flatten(cache)
+            }
+            ast::Type::Reference(pointee_type) => {
+              is_mutable_pointee = Some(false);
 
-    context.validate_fn_call(
-      self
-        .arguments
+              pointee_type.flatten(cache)
+            }
+            _ => break,
+          };
+        }
+
+        is_mutable_pointee
+          .unwrap_or_else(|| self.is_mutable_place(&member_access.base_expr.kind, cache))
+      }
+      ast::NodeKind::UnaryExpr(ast::UnaryExpr {
+        expr,
+        operator: ast::OperatorKind::MultiplyOrDereference,
+        ..
+      }) => !matches!(expr.kind.infer_flatten_type(cache), ast::Type::Reference(_)),
+      // The pointee of a pointer is always mutable, while array and slice
+      // elements are only as mutable as the array or slice itself.
+      ast::NodeKind::IndexingExpr(indexing_expr) => {
+        let target_expr_type = indexing_expr.target_expr.kind.infer_flatten_type(cache);
+
+        matches!(target_expr_type, ast::Type::Pointer(_))
+          || self.is_mutable_place(&indexing_expr.target_expr.kind, cache)
+      }
+      ast::NodeKind::Reference(reference) => {
+        match cache.force_get(&reference.pattern.target_id.unwrap()) {
+          ast::NodeKind::BindingStmt(binding_stmt) => {
+            binding_stmt.modifier == ast::BindingModifier::Mutable
+          }
+          ast::NodeKind::Parameter(parameter) if parameter.name == parser::THIS_IDENTIFIER => {
+            match self
+              .current_function_id
+              .map(|current_function_id| cache.force_get(&current_function_id))
+            {
+              Some(ast::NodeKind::Function(function)) => function.prototype.is_instance_mutable,
+              _ => false,
+            }
+          }
+          // REVIEW: Parameters are passed by value, and therefore cannot be mutated in-place.
+          ast::NodeKind::Parameter(_) => false,
+          _ => true,
+        }
+      }
+      // Temporaries, such as the results of calls, may be freely mutated.
+      _ => true,
+    }
+  }
+
+  // TODO: Make use-of, or get rid-of.
+  fn _fetch_type(
+    &mut self,
+    node_kind: &ast::NodeKind,
+    unique_key: &cache::Id,
+    cache: &cache::Cache,
+  ) -> ast::Type {
+    if let Some(cached_type) = self._types_cache.get(unique_key) {
+      return cached_type.clone();
+    }
+
+    let inferred_type = node_kind.infer_type(cache);
+
+    self
+      ._types_cache
+      // TODO: Cloning type.
+      .insert(unique_key.clone(), inferred_type.clone());
+
+    return inferred_type;
+  }
+
+  // TODO: Use an enum to specify error type instead of a string.
+  // REVIEW: Consider using `Result` instead of `Option`.
+  // pub fn compare_prototypes(
+  //   prototype_a: &ast::Prototype,
+  //   prototype_b: &ast::Prototype,
+  //   cache: &cache::Cache,
+  // ) -> Option<String> {
+  //   if prototype_a.parameters.len() != prototype_b.parameters.len() {
+  //     return Some("parameter count".to_string());
+  //   }
+
+  //   let parameter_types = prototype_a
+  //     .parameters
+  //     .iter()
+  //     .zip(prototype_b.parameters.iter())
+  //     .map(|(param_def_a, param_def_b)| (param_def_a.ty.clone(), param_def_b.ty.clone()));
+
+  //   for (param_type_a, param_type_b) in parameter_types {
+  //     if !Self::compare(&param_type_a, &param_type_b, cache) {
+  //       // TODO: Be more specific.
+  //       return Some("parameter type".to_string());
+  //     }
+  //   }
+
+  //   if !Self::compare(
+  //     &prototype_a.return_type_annotation,
+  //     &prototype_b.return_type_annotation,
+  //     cache,
+  //   ) {
+  //     return Some("return type".to_string());
+  //   }
+
+  //   None
+  // }
+
+  // TODO: Create a `finalize` method step to ensure that the main function was defined.
+
+  fn create_type_variable(&mut self) -> ast::Type {
+    let id = self.substitutions.len();
+    let result = ast::Type::Variable(id.clone());
+
+    self.substitutions.insert(id, result.clone());
+
+    result
+  }
+
+  /// Retrieve the type bound to a type variable.
+  ///
+  /// Type variables created by the parser are not registered in the
+  /// substitutions map, so a missing entry implies that the type variable
+  /// is unbound, in which case the type variable itself is returned.
+  fn get_substitution(&self, id: usize) -> ast::Type {
+    self
+      .substitutions
+      .get(&id)
+      .cloned()
+      .unwrap_or(ast::Type::Variable(id))
+  }
+
+  /// Determine whether a type variable is bound to a type other than itself.
+  fn is_bound(&self, id: usize) -> bool {
+    self.get_substitution(id) != ast::Type::Variable(id)
+  }
+
+  /// Recursively check if a type variable index occurs in
+  /// a type.
+  ///
+  /// For this to be `true`, the type in question must be a type variable,
+  /// or a type constructor that contains it.
+  fn occurs_in(&self, index_id: usize, ty: &ast::Type) -> bool {
+    match ty {
+      ast::Type::Variable(id) if self.is_bound(*id) => {
+        self.occurs_in(index_id, &self.get_substitution(*id))
+      }
+      ast::Type::Variable(id) => id == &index_id,
+      ast::Type::Pointer(inner_type)
+      | ast::Type::Reference(inner_type)
+      | ast::Type::MutableReference(inner_type)
+      | ast::Type::Optional(inner_type)
+      | ast::Type::Slice(inner_type)
+      | ast::Type::Array(inner_type, _) => self.occurs_in(index_id, inner_type),
+      ast::Type::Result(value_type, error_type) => {
+        self.occurs_in(index_id, value_type) || self.occurs_in(index_id, error_type)
+      }
+      ast::Type::Function(function_type) => {
+        function_type
+          .parameter_types
+          .iter()
+          .any(|parameter_type| self.occurs_in(index_id, parameter_type))
+          || self.occurs_in(index_id, &function_type.return_type)
+      }
+      // TODO: Generics.
+      _ => false,
+    }
+  }
+
+  // REVISE: Avoid excessive cloning.
+  fn unify(&mut self, type_a: &ast::Type, type_b: &ast::Type) {
+    match (type_a, type_b) {
+      // If both sides are the same type variable, do nothing.
+      (ast::Type::Variable(id_a), ast::Type::Variable(id_b)) if id_a == id_b => {}
+      // If one of the types is a type variable that’s bound in the substitution,
+      // use unify with that type instead.
+      (ast::Type::Variable(id), _) if self.is_bound(*id) => {
+        self.unify(&self.get_substitution(*id), type_b)
+      }
+      (_, ast::Type::Variable(id)) if self.is_bound(*id) => {
+        self.unify(type_a, &self.get_substitution(*id))
+      }
+      // Otherwise, if one of the types is an unbound type variable, bind it to the
+      // other type. Remember to do an occurs check to avoid constructing infinite types.
+      (ast::Type::Variable(id_a), _) => self.bind(*id_a, type_b),
+      (_, ast::Type::Variable(id_b)) => self.bind(*id_b, type_a),
+      // The error type implies that inference already failed elsewhere, so
+      // avoid reporting cascading diagnostics.
+      (ast::Type::Error, _) | (_, ast::Type::Error) => {}
+      // Compatible concrete types unify trivially. This also covers sub-typing
+      // rules, such as the never type and null pointers.
+      _ if type_a.is(type_b) => {}
+      // Type constructors unify if their inner types do, since they may
+      // contain type variables.
+      (ast::Type::Pointer(inner_a), ast::Type::Pointer(inner_b))
+      | (ast::Type::Reference(inner_a), ast::Type::Reference(inner_b))
+      | (ast::Type::MutableReference(inner_a), ast::Type::MutableReference(inner_b))
+      | (ast::Type::Reference(inner_a), ast::Type::MutableReference(inner_b))
+      | (ast::Type::Pointer(inner_a), ast::Type::Reference(inner_b))
+      | (ast::Type::Pointer(inner_a), ast::Type::MutableReference(inner_b))
+      | (ast::Type::Optional(inner_a), ast::Type::Optional(inner_b)) => {
+        self.unify(inner_a, inner_b)
+      }
+      (ast::Type::Result(value_a, error_a), ast::Type::Result(value_b, error_b)) => {
+        self.unify(value_a, value_b);
+        self.unify(error_a, error_b);
+      }
+      (ast::Type::Array(inner_a, length_a), ast::Type::Array(inner_b, length_b))
+        if length_a == length_b =>
+      {
+        self.unify(inner_a, inner_b)
+      }
+      // Static arrays coerce into slices.
+      (ast::Type::Slice(inner_a), ast::Type::Slice(inner_b))
+      | (ast::Type::Slice(inner_a), ast::Type::Array(inner_b, _)) => self.unify(inner_a, inner_b),
+      (ast::Type::Function(function_a), ast::Type::Function(function_b))
+        if function_a.is_variadic == function_b.is_variadic
+          && function_a.parameter_types.len() == function_b.parameter_types.len() =>
+      {
+        for (parameter_a, parameter_b) in function_a
+          .parameter_types
+          .iter()
+          .zip(function_b.parameter_types.iter())
+        {
+          self.unify(parameter_a, parameter_b);
+        }
+
+        self.unify(&function_a.return_type, &function_b.return_type);
+      }
+      _ => self.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+          "mismatched types: expected `{}`, but found `{}`",
+          self.substitute(type_a.clone()).name(),
+          self.substitute(type_b.clone()).name()
+        )),
+      ),
+    }
+  }
+
+  /// Bind an unbound type variable to a type, unless doing so would
+  /// construct an infinite type.
+  fn bind(&mut self, id: usize, ty: &ast::Type) {
+    if self.occurs_in(id, ty) {
+      self.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("cannot construct an infinite type: type variable occurs within itself"),
+      );
+
+      return;
+    }
+
+    self.substitutions.insert(id, ty.clone());
+  }
+
+  // TODO: This is the same thing as `node.unification`, but it assumed nodes can be mutated as in object-oriented languages.
+  /// Solves constraints by performing unification.
+  ///
+  /// This occurs after all the constraints have been added,
+  /// and is the last step for Hindley-Milner type inference.
+  /// After this process is completed, nodes can proceed to perform
+  /// their post-unification phase, which mostly consists of replacing
+  /// their type variables with concrete types.
+  fn solve_constraints(&mut self) {
+    for (type_a, type_b, _, origin, position) in std::mem::take(&mut self.constraints) {
+      let diagnostics_count = self.diagnostics.len();
+
+      self.unify(&type_a, &type_b);
+
+      // NOTE: Only the start of the origin's position is known, so its
+      // ... label spans a single character, and it is also described.
+      let label = position.map(|position| {
+        codespan_reporting::diagnostic::Label::primary(self.file_id, position..position + 1)
+          .with_message("types are constrained here")
+      });
+
+      for diagnostic in self.diagnostics.iter_mut().skip(diagnostics_count) {
+        diagnostic.notes.push(origin.clone());
+        diagnostic.labels.extend(label.clone());
+      }
+    }
+  }
+
+  /// Substitute a type variable with its non-variable type (if defined).
+  ///
+  /// This function will recursively substitute type variables, including
+  /// those within type constructors, until a non-variable type is found.
+  pub fn substitute(&self, ty: ast::Type) -> ast::Type {
+    match ty {
+      ast::Type::Variable(id) if self.is_bound(id) => self.substitute(self.get_substitution(id)),
+      ast::Type::Pointer(inner_type) => ast::Type::Pointer(Box::new(self.substitute(*inner_type))),
+      ast::Type::Reference(inner_type) => {
+        ast::Type::Reference(Box::new(self.substitute(*inner_type)))
+      }
+      ast::Type::MutableReference(inner_type) => {
+        ast::Type::MutableReference(Box::new(self.substitute(*inner_type)))
+      }
+      ast::Type::Optional(inner_type) => {
+        ast::Type::Optional(Box::new(self.substitute(*inner_type)))
+      }
+      ast::Type::Result(value_type, error_type) => ast::Type::Result(
+        Box::new(self.substitute(*value_type)),
+        Box::new(self.substitute(*error_type)),
+      ),
+      ast::Type::Array(inner_type, length) => {
+        ast::Type::Array(Box::new(self.substitute(*inner_type)), length)
+      }
+      ast::Type::Slice(inner_type) => ast::Type::Slice(Box::new(self.substitute(*inner_type))),
+      ast::Type::Function(function_type) => ast::Type::Function(ast::FunctionType {
+        return_type: Box::new(self.substitute(*function_type.return_type)),
+        parameter_types: function_type
+          .parameter_types
+          .into_iter()
+          .map(|parameter_type| self.substitute(parameter_type))
+          .collect(),
+        ..function_type
+      }),
+      _ => ty,
+    }
+  }
+}
+
+/// Visit a node and all of its descendants in post-order, allowing
+/// them to be mutated.
+///
+/// Unlike `ast::NodeKind::traverse`, this also visits the bodies of
+/// functions, closures and implementation methods.
+fn visit_mut(node: &mut ast::NodeKind, visitor: &mut dyn FnMut(&mut dyn Check)) {
+  fn visit_block(block: &mut ast::BlockExpr, visitor: &mut dyn FnMut(&mut dyn Check)) {
+    for statement in block.statements.iter_mut() {
+      visit_mut(&mut statement.kind, visitor);
+    }
+
+    if let Some(yields) = &mut block.yields {
+      visit_mut(&mut yields.kind, visitor);
+    }
+  }
+
+  match node {
+    ast::NodeKind::Function(function) => visit_block(&mut function.body, visitor),
+    ast::NodeKind::Closure(closure) => visit_block(&mut closure.body, visitor),
+    ast::NodeKind::BlockExpr(block_expr) => visit_block(block_expr, visitor),
+    ast::NodeKind::LoopStmt(loop_stmt) => {
+      if let Some(condition) = &mut loop_stmt.condition {
+        visit_mut(&mut condition.kind, visitor);
+      }
+
+      visit_block(&mut loop_stmt.body, visitor);
+    }
+    ast::NodeKind::ForInStmt(for_in_stmt) => {
+      visit_mut(&mut for_in_stmt.iterable.kind, visitor);
+      visit_block(&mut for_in_stmt.body, visitor);
+    }
+    ast::NodeKind::StructImpl(struct_impl) => {
+      for method in struct_impl
+        .member_methods
+        .iter_mut()
+        .chain(struct_impl.static_methods.iter_mut())
+      {
+        visit_block(&mut method.body, visitor);
+        visitor(method);
+      }
+    }
+    ast::NodeKind::IfExpr(if_expr) => {
+      visit_mut(&mut if_expr.condition.kind, visitor);
+      visit_mut(&mut if_expr.then_expr.kind, visitor);
+
+      for (condition, value) in if_expr.alternative_branches.iter_mut() {
+        visit_mut(&mut condition.kind, visitor);
+        visit_mut(&mut value.kind, visitor);
+      }
+
+      if let Some(else_expr) = &mut if_expr.else_expr {
+        visit_mut(&mut else_expr.kind, visitor);
+      }
+    }
+    ast::NodeKind::BindingStmt(binding_stmt) => visit_mut(&mut binding_stmt.value.kind, visitor),
+    ast::NodeKind::ReturnStmt(ast::ReturnStmt { value: Some(value) })
+    | ast::NodeKind::OptionalValue(ast::OptionalValue {
+      value: Some(value), ..
+    })
+    | ast::NodeKind::ResultValue(ast::ResultValue { value, .. }) => {
+      visit_mut(&mut value.kind, visitor)
+    }
+    ast::NodeKind::InlineExprStmt(ast::InlineExprStmt { expr })
+    | ast::NodeKind::UnaryExpr(ast::UnaryExpr { expr, .. })
+    | ast::NodeKind::ParenthesesExpr(ast::ParenthesesExpr { expr })
+    | ast::NodeKind::TryExpr(ast::TryExpr { expr })
+    | ast::NodeKind::UnsafeExpr(ast::UnsafeExpr(expr))
+    | ast::NodeKind::DeferStmt(ast::DeferStmt { body: expr })
+    | ast::NodeKind::MemberAccess(ast::MemberAccess {
+      base_expr: expr, ..
+    }) => visit_mut(&mut expr.kind, visitor),
+    ast::NodeKind::AssignStmt(assign_stmt) => {
+      visit_mut(&mut assign_stmt.assignee_expr.kind, visitor);
+      visit_mut(&mut assign_stmt.value.kind, visitor);
+    }
+    ast::NodeKind::IndexingExpr(indexing_expr) => {
+      visit_mut(&mut indexing_expr.target_expr.kind, visitor);
+      visit_mut(&mut indexing_expr.index_expr.kind, visitor);
+    }
+    ast::NodeKind::SliceExpr(slice_expr) => {
+      visit_mut(&mut slice_expr.target_expr.kind, visitor);
+      visit_mut(&mut slice_expr.start_expr.kind, visitor);
+      visit_mut(&mut slice_expr.end_expr.kind, visitor);
+    }
+    ast::NodeKind::InterpolationExpr(interpolation_expr) => {
+      for hole in interpolation_expr.holes.iter_mut() {
+        visit_mut(&mut hole.kind, visitor);
+      }
+    }
+    ast::NodeKind::BinaryExpr(binary_expr) => {
+      visit_mut(&mut binary_expr.left.kind, visitor);
+      visit_mut(&mut binary_expr.right.kind, visitor);
+    }
+    ast::NodeKind::CallExpr(call_expr) => {
+      visit_mut(&mut call_expr.callee_expr.kind, visitor);
+
+      for argument in call_expr.arguments.iter_mut() {
+        visit_mut(&mut argument.kind, visitor);
+      }
+    }
+    ast::NodeKind::IntrinsicCall(ast::IntrinsicCall {
+      arguments: children,
+      ..
+    })
+    | ast::NodeKind::StaticArrayValue(ast::StaticArrayValue {
+      elements: children, ..
+    })
+    | ast::NodeKind::StructValue(ast::StructValue {
+      fields: children, ..
+    }) => {
+      for child in children.iter_mut() {
+        visit_mut(&mut child.kind, visitor);
+      }
+    }
+    _ => {}
+  };
+
+  visitor(node);
+}
+
+pub trait Check {
+  // REVIEW: Consider caching inference results here, if they are indeed costly.
+  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
+    ast::Type::Unit
+  }
+
+  fn check(&self, _context: &mut TypeContext, _cache: &cache::Cache) {
+    //
+  }
+
+  fn report_constraints(&mut self, _context: &mut TypeContext, _cache: &cache::Cache) {
+    //
+  }
+
+  fn post_unification(&mut self, _context: &mut TypeContext, _cache: &mut cache::Cache) {
+    //
+  }
+}
+
+impl Check for ast::NodeKind {
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    dispatch!(&self, Check::infer_type, cache)
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    dispatch!(&self, Check::check, context, cache);
+  }
+
+  fn report_constraints(&mut self, context: &mut TypeContext, cache: &cache::Cache) {
+    dispatch!(self, Check::report_constraints, context, cache);
+  }
+
+  fn post_unification(&mut self, context: &mut TypeContext, cache: &mut cache::Cache) {
+    dispatch!(self, Check::post_unification, context, cache);
+  }
+}
+
+impl Check for ast::UnimplementedExpr {
+  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
+    ast::Type::Never
+  }
+}
+
+impl Check for ast::OptionalValue {
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    let value_type = if let Some(explicit_type) = &self.explicit_type {
+      explicit_type.clone()
+    } else {
+      self.value.as_ref().unwrap().kind.infer_type(cache)
+    };
+
+    ast::Type::Optional(Box::new(value_type))
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    if let ast::Type::Optional(value_type) = self.infer_type(cache) {
+      if TypeContext::implements_drop(&value_type, cache) {
+        context.report_held_drop_value(format!(
+          "optional value cannot hold a value of type `{}`, which implements `Drop`",
+          value_type.name()
+        ));
+      }
+    }
+
+    if let Some(value) = &self.value {
+      if value.kind.infer_flatten_type(cache).is_a_meta() {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("optional value cannot be of a meta type"),
+        );
+      }
+
+      value.kind.check(context, cache);
+    }
+  }
+}
+
+impl Check for ast::ResultValue {
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    let held_type = self.value.kind.infer_type(cache);
+
+    if self.is_ok {
+      ast::Type::Result(Box::new(held_type), Box::new(self.explicit_type.clone()))
+    } else {
+      ast::Type::Result(Box::new(self.explicit_type.clone()), Box::new(held_type))
+    }
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    let value_type = self.value.kind.infer_flatten_type(cache);
+
+    if value_type.is_a_meta() {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("result value cannot be of a meta type"),
+      );
+    } else if TypeContext::implements_drop(&value_type, cache) {
+      context.report_held_drop_value(format!(
+        "result value cannot hold a value of type `{}`, which implements `Drop`",
+        value_type.name()
+      ));
+    }
+
+    self.value.kind.check(context, cache);
+  }
+}
+
+impl Check for ast::TryExpr {
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    match self.expr.kind.infer_flatten_type(cache) {
+      ast::Type::Optional(value_type) | ast::Type::Result(value_type, _) => {
+        value_type.as_ref().clone()
+      }
+      _ => ast::Type::Error,
+    }
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    self.expr.kind.check(context, cache);
+
+    // Propagating a value returns from the function, which a deferred body
+    // ... may not do, as it already runs on the way out of its scope.
+    if context.in_defer {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("cannot use the `?` operator within the body of a `defer` statement"),
+      );
+
+      return;
+    }
+
+    let return_type = context.find_current_function_return_type(cache);
+
+    match self.expr.kind.infer_flatten_type(cache) {
+      // The `none` value is propagated as-is, so the enclosing function
+      // must be able to return it.
+      ast::Type::Optional(_) => {
+        if !matches!(return_type, Some(ast::Type::Optional(_))) {
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error().with_message(
+              "the `?` operator can only be used on optionals inside functions that return an optional type",
+            ),
+          );
+        }
+      }
+      // The error is propagated as-is, so the enclosing function must
+      // return a result with the same error type.
+      ast::Type::Result(_, error_type) => match return_type {
+        Some(ast::Type::Result(_, return_error_type))
+          if return_error_type.flat_is(&error_type, cache) => {}
+        Some(ast::Type::Result(_, return_error_type)) => context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+            "the `?` operator cannot propagate an error of type `{}` from a function whose error type is `{}`",
+            error_type.name(),
+            return_error_type.name()
+          )),
+        ),
+        _ => context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error().with_message(
+            "the `?` operator can only be used on results inside functions that return a result type",
+          ),
+        ),
+      },
+      _ => context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error().with_message(
+          "the `?` operator can only be applied to values of an optional or result type",
+        ),
+      ),
+    }
+  }
+}
+
+impl Check for ast::Range {
+  fn check(&self, context: &mut TypeContext, _cache: &cache::Cache) {
+    // NOTE: No need to check whether the range's bounds are constant
+    // ... expressions, this is ensured by the parser.
+
+    let start_literal = crate::force_match!(&self.start.kind, ast::NodeKind::Literal);
+    let end_literal = crate::force_match!(&self.end.kind, ast::NodeKind::Literal);
+
+    let start_int = match start_literal {
+      ast::Literal::Int(value, _) => value,
+      _ => unreachable!(),
+    };
+
+    let end_int = match end_literal {
+      ast::Literal::Int(value, _) => value,
+      _ => unreachable!(),
+    };
+
+    if start_int > end_int {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error().with_message(String::from(
+          "range start must be less than or equal to end",
+        )),
+      );
+    }
+  }
+}
+
+impl Check for ast::Using {
+  fn check(&self, context: &mut TypeContext, _cache: &cache::Cache) {
+    // FIXME: Can't just push the import once encountered; only when it's actually used.
+    context.usings.push(self.clone());
+  }
+}
+
+impl Check for ast::ParenthesesExpr {
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    self.expr.kind.infer_type(cache)
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    self.expr.kind.check(context, cache);
+  }
+}
+
+impl Check for ast::Trait {
+  //
+}
+
+impl Check for ast::StructImpl {
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    context.in_impl = true;
+
+    for method in &self.member_methods {
+      if !method.prototype.accepts_instance {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+            "implementation method `{}` is missing the instance parameter `this`",
+            method.name
+          )),
+        )
+      }
+
+      // The constructor is implicitly called by struct values, so it
+      // cannot expect any arguments other than the instance.
+      if method.name == lowering::CONSTRUCTOR_NAME {
+        if !method.prototype.parameters.is_empty() {
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+              "constructor `{}` must not accept any parameters other than `this`",
+              method.name
+            )),
+          );
+        }
+
+        if !TypeContext::infer_return_value_type(&method.body, cache).is_a_unit() {
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+              "constructor `{}` must not return a value",
+              method.name
+            )),
+          );
+        }
+
+        // Struct values invoke the constructor, so creating a value of the
+        // same struct within it would recurse indefinitely.
+        // REVIEW: Indirect recursion (ex. through a function call) is not detected.
+        let target_id = self.target_struct_pattern.target_id;
+
+        let creates_instance = ast::NodeKind::BlockExpr(method.body.as_ref().clone()).any(|node| {
+          matches!(
+            node,
+            ast::NodeKind::StructValue(struct_value) if struct_value.target_id == target_id
+          )
+        });
+
+        if creates_instance {
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+              "constructor `{}` of `{}` cannot create a value of its own struct",
+              method.name, self.target_struct_pattern.base_name
+            )),
+          );
+        }
+      }
+
+      method.check(context, cache);
+    }
+
+    for static_method in &self.static_methods {
+      if static_method.prototype.accepts_instance {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+            "static method `{}` cannot accept the instance parameter `this`",
+            static_method.name
+          )),
+        )
+      }
+
+      static_method.check(context, cache);
+    }
+
+    let target_node = cache.force_get(&self.target_struct_pattern.target_id.unwrap());
+
+    // REVISE: Cleanup.
+    if let ast::NodeKind::StructType(target_struct_type) = &target_node {
+      if let Some(trait_pattern) = &self.trait_pattern {
+        if name_resolution::is_builtin_trait(trait_pattern, name_resolution::COPY_TRAIT_NAME) {
+          if !self.member_methods.is_empty() || !self.static_methods.is_empty() {
+            context.diagnostics.push(
+              codespan_reporting::diagnostic::Diagnostic::error()
+                .with_message("the `Copy` marker trait has no methods to implement"),
+            );
+          }
+
+          // Copying a struct copies its fields, so they must all be copyable.
+          for (field_name, field_type) in &target_struct_type.fields {
+            if !field_type.is_copyable(cache) {
+              context.diagnostics.push(
+                codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+                  "cannot implement `Copy` for `{}` because its field `{}` of type `{}` is not copyable",
+                  target_struct_type.name,
+                  field_name,
+                  field_type.flatten(cache).name()
+                )),
+              );
+            }
+          }
+        } else if name_resolution::is_builtin_trait(trait_pattern, name_resolution::DROP_TRAIT_NAME)
+        {
+          if cache.copy_types.contains(&target_struct_type.cache_id) {
+            context.diagnostics.push(
+              codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+                "cannot implement both `Copy` and `Drop` for `{}`",
+                target_struct_type.name
+              )),
+            );
+          }
+
+          let is_destructor = |method: &ast::Function| {
+            method.name == name_resolution::DROP_METHOD_NAME
+              && method.prototype.parameters.is_empty()
+              && TypeContext::infer_return_value_type(&method.body, cache)
+                .flatten(cache)
+                .is_a_unit()
+          };
+
+          // The destructor is called implicitly, so its signature is fixed.
+          if self.member_methods.len() != 1
+            || !self.static_methods.is_empty()
+            || !is_destructor(&self.member_methods[0])
+          {
+            context.diagnostics.push(
+              codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+                "`Drop` implementation for `{}` must consist of a single `{}(this)` method, which returns nothing",
+                target_struct_type.name,
+                name_resolution::DROP_METHOD_NAME
+              )),
+            );
+          }
+        } else if let ast::NodeKind::Trait(trait_type) =
+          cache.force_get(&trait_pattern.target_id.unwrap())
+        {
+          for trait_method in &trait_type.methods {
+            let impl_method_result = self
+              .member_methods
+              .iter()
+              .find(|impl_method| impl_method.name == trait_method.0);
+
+            if let Some(impl_method) = impl_method_result {
+              let prototype_comparison_result =
+                TypeContext::compare_prototypes(&trait_method.1, &impl_method.prototype, cache);
+
+              if let Some(error) = prototype_comparison_result {
+                // REVISE: Use expected/got system.
+                context.diagnostics.push(
+                  codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+                    "prototype of implementation method `{}` for trait `{}` mismatch in {}",
+                    impl_method.name, trait_type.name, error
+                  )),
+                )
+              }
+            } else {
+              context.diagnostics.push(
+                codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+                  "required method `{}` not implemented",
+                  trait_method.0
+                )),
+              );
+            }
+          }
+        } else {
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+              "cannot implement non-trait `{}`",
+              &trait_pattern.base_name
+            )),
+          );
+        }
+      }
+    } else {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+          "cannot implement for a non-struct type `{}`",
+          self.target_struct_pattern.base_name
+        )),
+      );
+    }
+
+    context.in_impl = false;
+  }
+}
+
+impl Check for ast::MemberAccess {
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    let base_expr_type =
+      TypeContext::auto_deref(self.base_expr.kind.infer_flatten_type(cache), cache);
+
+    let struct_type = match base_expr_type {
+      ast::Type::Struct(struct_type) => struct_type,
+      // REVIEW: Investigate this strategy. Shouldn't we be using `unreachable!()` instead?
+      // ... But this point may be reachable from the user-side. Need to somehow properly
+      // ... handle this case.
+      _ => return ast::Type::Error,
+    };
+
+    if let Some(struct_field) = struct_type
+      .fields
+      .iter()
+      .find(|field| field.0 == self.member_name)
+    {
+      return struct_field.1.clone();
+    }
+
+    // REVIEW: Why not abstract this to the `Reference` node? We're doing the same thing (or very similar at least), correct?
+    // TODO: Lookup implementation, and attempt to match a method.
+    if let Some(struct_impls) = cache.struct_impls.get(&struct_type.cache_id) {
+      for (method_cache_id, method_name) in struct_impls {
+        if method_name == &self.member_name {
+          return cache.force_get(&method_cache_id).infer_type(cache);
+        }
+      }
+    }
+
+    return ast::Type::Error;
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    self.base_expr.kind.check(context, cache);
+
+    // Temporaries are only dropped when discarded by an expression statement,
+    // ... so those accessed in place would never be.
+    if let ast::Type::Struct(struct_type) = self.base_expr.kind.infer_flatten_type(cache) {
+      if cache.drop_methods.contains_key(&struct_type.cache_id)
+        && !TypeContext::is_place(&self.base_expr.kind)
+      {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message(format!(
+              "cannot access `{}` of a temporary `{}` value, which implements `Drop`",
+              self.member_name, struct_type.name
+            ))
+            .with_notes(vec![String::from(
+              "bind the value first, so that it is dropped",
+            )]),
+        );
+      }
+    }
+
+    if !context.in_unsafe_block
+      && TypeContext::auto_derefs_pointer(&self.base_expr.kind.infer_flatten_type(cache), cache)
+    {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("can only dereference pointers inside an unsafe block")
+          .with_notes(vec![format!(
+            "accessing `{}` de-references a pointer to reach its struct",
+            self.member_name
+          )]),
+      );
+    }
+
+    let base_expr_type =
+      TypeContext::auto_deref(self.base_expr.kind.infer_flatten_type(cache), cache);
+
+    let struct_type = match base_expr_type {
+      ast::Type::Struct(struct_type) => struct_type,
+      // TODO: Implement.
+      ast::Type::This(_) => return,
+      // REVIEW: Investigate this strategy. Shouldn't we be using `unreachable!()` instead?
+      _ => {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("expression is not a struct"),
+        );
+
+        return;
+      }
+    };
+
+    let is_method = cache
+      .struct_impls
+      .get(&struct_type.cache_id)
+      .map(|methods| methods.iter().any(|method| method.1 == self.member_name))
+      .unwrap_or(false);
+
+    if !is_method && !struct_type.fields.iter().any(|x| x.0 == self.member_name) {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+          "struct type `{}` does not contain a field or method named `{}`",
+          struct_type.name, self.member_name
+        )),
+      );
+    }
+  }
+}
+
+impl Check for ast::Closure {
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    TypeContext::infer_prototype_type(
+      &self.prototype,
+      TypeContext::infer_function_return_type(&self.prototype, &self.body, cache),
+    )
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    // REVIEW: Might need to mirror `Function`'s type check.
+    let previous_function_id = context.current_function_id.clone();
+
+    context.current_function_id = Some(self.id);
+
+    if self.prototype.accepts_instance {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("closures cannot accept instances"),
+      );
+    }
+
+    if let Some(yields) = &self.body.yields {
+      context.check_returned_array(
+        &yields.kind,
+        &self.prototype.return_type_annotation.flatten(cache),
+        cache,
+      );
+    }
+
+    self.prototype.check(context, cache);
+    self.body.check(context, cache);
+    context.current_function_id = previous_function_id;
+  }
+
+  fn report_constraints(&mut self, context: &mut TypeContext, cache: &cache::Cache) {
+    context.type_returned_int_literals(
+      &mut self.body,
+      &self.prototype.return_type_annotation,
+      cache,
+    );
+
+    context.constraints.push((
+      self.prototype.return_type_annotation.flatten(cache),
+      TypeContext::infer_return_value_type(&self.body, cache),
+      TypeConstrainKind::Equality,
+      String::from("in the return value of a closure"),
+      Some(self.position),
+    ));
+  }
+
+  fn post_unification(&mut self, context: &mut TypeContext, cache: &mut cache::Cache) {
+    self.prototype.return_type_annotation =
+      context.substitute(self.prototype.return_type_annotation.clone());
+
+    if let Some(ast::NodeKind::Closure(cached_closure)) = cache.symbols.get_mut(&self.id) {
+      cached_closure.prototype.return_type_annotation =
+        self.prototype.return_type_annotation.clone();
+
+      // The body's literals may have been re-typed while reporting constraints.
+      cached_closure.body.clone_from(&self.body);
+    }
+  }
+}
+
+impl Check for ast::TypeAlias {
+  // REVIEW: Don't we need to implement `infer_type` here? Seems like not. Confirm.
+}
+
+impl Check for ast::Pattern {
+  //
+}
+
+impl Check for ast::IntrinsicCall {
+  fn report_constraints(&mut self, context: &mut TypeContext, cache: &cache::Cache) {
+    let parameter_types = match self.kind.signature(&self.type_arguments).parameter_types {
+      Some(parameter_types) => parameter_types,
+      None => return,
+    };
+
+    // NOTE: Variadic arguments are left unconstrained.
+    for (argument, parameter_type) in self.arguments.iter_mut().zip(parameter_types.iter()) {
+      context.type_int_literal_expr(&mut argument.kind, parameter_type, cache);
+    }
+  }
+
+  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
+    self.kind.signature(&self.type_arguments).return_type
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    let signature = self.kind.signature(&self.type_arguments);
+
+    for argument in &self.arguments {
+      argument.kind.check(context, cache);
+    }
+
+    if let Some(parameter_types) = signature.parameter_types {
+      let target_function_type = ast::FunctionType {
+        is_extern: false,
+        is_variadic: signature.is_variadic,
+        parameter_types,
+        return_type: Box::new(signature.return_type),
+      };
+
+      context.validate_fn_call(
+        self
+          .arguments
+          .iter()
+          // No need to flatten.
+          .map(|argument| argument.kind.infer_type(cache))
+          .collect(),
+        target_function_type,
+        cache,
+      );
+    }
+
+    match self.kind {
+      // Special case because of the static array type.
+      ast::IntrinsicKind::LengthOf if self.arguments.len() == 1 => {
+        let target_array = self.arguments.first().unwrap();
+        let target_array_type = target_array.kind.infer_flatten_type(cache);
+
+        if !matches!(
+          target_array_type,
+          ast::Type::Array(..)
+            | ast::Type::Slice(_)
+            | ast::Type::Basic(ast::BasicType::OwnedString)
+        ) {
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error()
+              .with_message("can only determine the length of arrays, slices or strings"),
+          );
+        }
+      }
+      ast::IntrinsicKind::Print | ast::IntrinsicKind::Println if !self.arguments.is_empty() => {
+        context.check_format_arguments(&self.arguments, cache);
+      }
+      ast::IntrinsicKind::Sizeof | ast::IntrinsicKind::Alignof => {
+        if self.type_arguments[0].flatten(cache).is(&ast::Type::Unit) {
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error()
+              .with_message("cannot determine size of unit type"),
+          );
+        }
+      }
+      ast::IntrinsicKind::Offsetof => {
+        let field_name = self.field_name.as_ref().unwrap();
+
+        let message = match self.type_arguments[0].flatten(cache) {
+          ast::Type::Struct(struct_type) => {
+            if struct_type
+              .fields
+              .iter()
+              .any(|field| &field.0 == field_name)
+            {
+              return;
+            }
+
+            format!(
+              "struct `{}` has no field named `{}`",
+              struct_type.name, field_name
+            )
+          }
+          _ => String::from("can only determine the offset of fields of struct types"),
+        };
+
+        context
+          .diagnostics
+          .push(codespan_reporting::diagnostic::Diagnostic::error().with_message(message));
+      }
+      ast::IntrinsicKind::Alloc | ast::IntrinsicKind::AllocArray => {
+        if self.type_arguments[0].flatten(cache).is(&ast::Type::Unit) {
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error()
+              .with_message("cannot allocate values of unit type"),
+          );
+        }
+      }
+      // NOTE: Allocating is safe, but the remaining memory intrinsics
+      // ... operate on pointers which may be dangling or invalid.
+      ast::IntrinsicKind::Free
+      | ast::IntrinsicKind::Resize
+      | ast::IntrinsicKind::Copy
+      | ast::IntrinsicKind::Move
+      | ast::IntrinsicKind::FreeString
+        if !context.in_unsafe_block =>
+      {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+            "intrinsic `?{}` may only be called inside an unsafe block",
+            self.kind.name()
+          )),
+        );
+      }
+      ast::IntrinsicKind::StaticAssert if self.arguments.len() == 2 => {
+        let message = match self.arguments[1].kind.flatten() {
+          ast::NodeKind::Literal(ast::Literal::String(message)) => message,
+          _ => {
+            context.diagnostics.push(
+              codespan_reporting::diagnostic::Diagnostic::error()
+                .with_message("static assertion message must be a string literal"),
+            );
+
+            return;
+          }
+        };
+
+        match TypeContext::evaluate_constant(&self.arguments[0].kind, cache) {
+          Some(ConstantValue::Bool(true)) => {}
+          Some(ConstantValue::Bool(false)) => context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error()
+              .with_message(format!("static assertion failed: {}", message)),
+          ),
+          _ => context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error()
+              .with_message("static assertion condition must be a constant expression"),
+          ),
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+impl Check for ast::ExternStatic {
+  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
+    self.ty.clone()
+  }
+}
+
+impl Check for ast::StructValue {
+  fn report_constraints(&mut self, context: &mut TypeContext, cache: &cache::Cache) {
+    let struct_type = match cache.force_get(&self.target_id.unwrap()) {
+      ast::NodeKind::StructType(struct_type) => struct_type,
+      _ => unreachable!(),
+    };
+
+    for (field, struct_field) in self.fields.iter_mut().zip(struct_type.fields.iter()) {
+      context.type_int_literal_expr(&mut field.kind, &struct_field.1, cache);
+    }
+  }
+
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    let struct_type = match cache.force_get(&self.target_id.unwrap()) {
+      ast::NodeKind::StructType(struct_type) => struct_type,
+      _ => unreachable!(),
+    };
+
+    // REVIEW: Is this the correct type? We might need this one in order to unify with the original struct type.
+    ast::Type::Struct(struct_type.clone())
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    let struct_type_node = cache.force_get(&self.target_id.unwrap());
+
+    let struct_type = match struct_type_node {
+      ast::NodeKind::StructType(struct_type) => struct_type,
+      _ => unreachable!(),
+    };
+
+    if self.fields.len() > struct_type.fields.len() {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("invalid amount of fields in struct value"),
+      );
+
+      return;
+    }
+
+    // Omitted trailing fields take their default values.
+    for (field, field_default) in struct_type
+      .fields
+      .iter()
+      .zip(struct_type.field_defaults.iter())
+      .skip(self.fields.len())
+    {
+      if field_default.is_none() {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+            "missing value for field `{}` of struct `{}`, which has no default value",
+            field.0, struct_type.name
+          )),
+        );
+      }
+    }
+
+    for (index, (value_field, struct_field)) in self
+      .fields
+      .iter()
+      .zip(struct_type.fields.iter())
+      .enumerate()
+    {
+      value_field.kind.check(context, cache);
+
+      let value_field_type = value_field.kind.infer_type(cache);
+
+      if !struct_field.1.flat_is(&value_field_type, cache) {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+            "field and value at position `{}` type for struct `{}` mismatch",
+            index, struct_type.name
+          )),
+        );
+      }
+    }
+  }
+}
+
+impl Check for ast::Prototype {
+  fn check(&self, _context: &mut TypeContext, _cache: &cache::Cache) {
+    // TODO: Implement?
+  }
+}
+
+impl Check for ast::StructType {
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    for field in &self.fields {
+      if TypeContext::implements_drop(&field.1, cache) {
+        context.report_held_drop_value(format!(
+          "field `{}` of struct `{}` cannot be of type `{}`, which implements `Drop`",
+          field.0,
+          self.name,
+          field.1.name()
+        ));
+      }
+    }
+
+    for (field, field_default) in self.fields.iter().zip(self.field_defaults.iter()) {
+      if let Some(field_default) = field_default {
+        if !field.1.flat_is(&field_default.infer_type(cache), cache) {
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+              "default value of field `{}` of struct `{}` type mismatch",
+              field.0, self.name
+            )),
+          );
+        }
+      }
+    }
+  }
+
+  fn report_constraints(&mut self, context: &mut TypeContext, cache: &cache::Cache) {
+    for (field, field_default) in self.fields.iter().zip(self.field_defaults.iter_mut()) {
+      if let (ast::Type::Basic(ast::BasicType::Int(size)), Some(field_default)) =
+        (field.1.flatten(cache), field_default)
+      {
+        context.retype_int_literal(field_default, &size, false, cache);
+      }
+    }
+  }
+
+  fn post_unification(&mut self, _context: &mut TypeContext, cache: &mut cache::Cache) {
+    // The field defaults may have been re-typed while reporting constraints.
+    if let Some(ast::NodeKind::StructType(cached_struct_type)) =
+      cache.symbols.get_mut(&self.cache_id)
+    {
+      cached_struct_type
+        .field_defaults
+        .clone_from(&self.field_defaults);
+    }
+  }
+}
+
+impl Check for ast::UnaryExpr {
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    let expr_type = self.expr.kind.infer_type(cache);
+
+    // Short-circuit if the expression's type is unit.
+    if expr_type.is_a_unit() {
+      return ast::Type::Unit;
+    }
+
+    return match self.operator {
+      ast::OperatorKind::AddressOf => ast::Type::Reference(Box::new(expr_type)),
+      ast::OperatorKind::MutableAddressOf => ast::Type::MutableReference(Box::new(expr_type)),
+      ast::OperatorKind::Cast => self.cast_type.as_ref().unwrap().clone(),
+      ast::OperatorKind::Not => ast::Type::Basic(ast::BasicType::Bool),
+      ast::OperatorKind::SubtractOrNegate => expr_type,
+      ast::OperatorKind::MultiplyOrDereference => match expr_type.flatten(cache) {
+        ast::Type::Pointer(inner_type)
+        | ast::Type::Reference(inner_type)
+        | ast::Type::MutableReference(inner_type) => *inner_type,
+        // NOTE: Non-dereferenceable types are reported during checking.
+        _ => expr_type,
+      },
+      _ => unreachable!(),
+    };
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    let expr_type = &self.expr.kind.infer_flatten_type(cache);
+
+    match self.operator {
+      // References always point to valid places, so reading through them
+      // is safe, unlike pointers.
+      ast::OperatorKind::MultiplyOrDereference => match expr_type {
+        ast::Type::Reference(_) | ast::Type::MutableReference(_) => {}
+        ast::Type::Pointer(_) if !context.in_unsafe_block => {
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error()
+              .with_message("can only dereference pointers inside an unsafe block"),
+          );
+        }
+        ast::Type::Pointer(_) => {}
+        _ => context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("can only dereference pointers or references"),
+        ),
+      },
+      ast::OperatorKind::Not => {
+        if !expr_type.is(&ast::Type::Basic(ast::BasicType::Bool)) {
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error()
+              .with_message("can only negate boolean expressions"),
+          );
+        }
+      }
+      ast::OperatorKind::SubtractOrNegate => {
+        // TODO: Include floats.
+        if !matches!(expr_type, ast::Type::Basic(ast::BasicType::Int(_))) {
+          // REVISE: Error message too similar to the boolean negation case.
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error()
+              .with_message("can only negate integer or float expressions"),
+          );
+        }
+      }
+      ast::OperatorKind::AddressOf | ast::OperatorKind::MutableAddressOf => {
+        // TODO: Lifetimes of references are not yet verified.
+        if !TypeContext::is_place(&self.expr.kind) {
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error()
+              .with_message("can only take references to bindings, parameters, fields or elements"),
+          );
+        } else if self.operator == ast::OperatorKind::MutableAddressOf
+          && !context.is_mutable_place(&self.expr.kind, cache)
+        {
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error()
+              .with_message("cannot take a mutable reference to an immutable place"),
+          );
+        }
+      }
+      ast::OperatorKind::Cast => {
+        let cast_type = self.cast_type.as_ref().unwrap().flatten(cache);
+
+        if expr_type.is(&cast_type) {
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::warning()
+              .with_message("redundant cast to the same type"),
+          );
+
+          return;
+        }
+
+        let cast_kind = match TypeContext::classify_cast(expr_type, &cast_type, cache) {
+          Ok(cast_kind) => cast_kind,
+          Err(message) => {
+            context.diagnostics.push(
+              codespan_reporting::diagnostic::Diagnostic::error()
+                .with_message(format!("invalid cast: {}", message)),
+            );
+
+            return;
+          }
+        };
+
+        match cast_kind {
+          CastKind::PointerToPointer if !context.in_unsafe_block => {
+            context.diagnostics.push(
+              codespan_reporting::diagnostic::Diagnostic::error()
+                .with_message("pointer casts may only occur inside an unsafe block"),
+            );
+          }
+          CastKind::PointerToInt | CastKind::IntToPointer if !context.in_unsafe_block => {
+            context.diagnostics.push(
+              codespan_reporting::diagnostic::Diagnostic::error().with_message(
+                "casts between integers and pointers may only occur inside an unsafe block",
+              ),
+            );
+          }
+          CastKind::Int { is_source_signed } => {
+            let (from_bit_width, _) = TypeContext::int_like_layout(expr_type, cache).unwrap();
+            let (to_bit_width, is_target_signed) =
+              TypeContext::int_like_layout(&cast_type, cache).unwrap();
+
+            let is_lossy = to_bit_width < from_bit_width
+              || (is_source_signed && !is_target_signed)
+              || (!is_source_signed && is_target_signed && to_bit_width == from_bit_width);
+
+            if is_lossy {
+              context.diagnostics.push(
+                codespan_reporting::diagnostic::Diagnostic::warning()
+                  .with_message("lossy cast: the value may be truncated or change its sign"),
+              );
+            }
+          }
+          CastKind::PointerToInt => {
+            let (to_bit_width, _) = TypeContext::int_like_layout(&cast_type, cache).unwrap();
+
+            if to_bit_width < cache.pointer_width {
+              context.diagnostics.push(
+                codespan_reporting::diagnostic::Diagnostic::warning().with_message(
+                  "lossy cast: integer type is narrower than a pointer; consider using `Usize`",
+                ),
+              );
+            }
+          }
+          _ => {}
+        };
+      }
+      _ => unreachable!(),
+    };
+  }
+}
+
+impl Check for ast::Enum {
+  // REVIEW: Isn't there a need for its variants to be considered integer types?
+}
+
+impl Check for ast::AssignStmt {
+  fn report_constraints(&mut self, context: &mut TypeContext, cache: &cache::Cache) {
+    let assignee_type = self.assignee_expr.kind.infer_type(cache);
+
+    context.type_int_literal_expr(&mut self.value.kind, &assignee_type, cache);
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    // TODO: Need to unify the value and the target's type.
+
+    let assignee_type = self.assignee_expr.kind.infer_flatten_type(cache);
+
+    if matches!(
+      assignee_type,
+      ast::Type::Reference(_) | ast::Type::MutableReference(_)
+    ) {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("can't assign to a reference; references cannot be reseated"),
+      );
+
+      // REVIEW: We should continue gathering other diagnostics (ex. immutable)?
+      return;
+    }
+
+    // NOTE: References cannot be reseated/assigned-to, only pointers.
+    let is_pointer = matches!(assignee_type, ast::Type::Pointer(_));
+
+    // REVISE: This checks are superficial. They do not consider
+    // ... that expressions may be nested (ie. parentheses expr.).
+    let is_array_indexing = matches!(self.assignee_expr.kind, ast::NodeKind::IndexingExpr(_));
+    let is_variable_ref = matches!(self.assignee_expr.kind, ast::NodeKind::Reference(_));
+
+    // FIXME: What if the member accessed is a method? Is that even possible?
+    // ... Maybe to disambiguate that specific case we'd need to add a check below.
+    let is_member_access = matches!(self.assignee_expr.kind, ast::NodeKind::MemberAccess(_));
+
+    let is_dereference = matches!(
+      self.assignee_expr.kind,
+      ast::NodeKind::UnaryExpr(ast::UnaryExpr {
+        operator: ast::OperatorKind::MultiplyOrDereference,
+        ..
+      })
+    );
+
+    // TODO: Missing member access (struct fields) support.
+    // NOTE: The assignee expression may only be an expression of type `Pointer`
+    // or `Reference`, a variable reference, or an array indexing.
+    if !is_pointer && !is_variable_ref && !is_array_indexing && !is_member_access && !is_dereference
+    {
+      context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("assignee must be an expression of pointer or reference type, a member access expression, a variable reference, or an array indexing expression"),
+        );
+    } else if is_variable_ref {
+      // If the assignee is a variable reference, ensure that the variable is mutable.
+      match &self.assignee_expr.kind {
+        ast::NodeKind::Reference(variable_ref) => {
+          let binding_node = cache.force_get(&variable_ref.pattern.target_id.unwrap());
+
+          match binding_node {
+            ast::NodeKind::BindingStmt(binding)
+              if binding.modifier != ast::BindingModifier::Mutable =>
+            {
+              context.diagnostics.push(
+                codespan_reporting::diagnostic::Diagnostic::error()
+                  .with_message("assignee is immutable"),
+              );
+            }
+            // TODO: Parameters should be immutable by default.
+            _ => {}
+          };
+        }
+        _ => unreachable!(),
+      };
+    } else if is_dereference && !context.is_mutable_place(&self.assignee_expr.kind, cache) {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error().with_message(
+          "cannot assign through a shared reference; take a `&mut` reference instead",
+        ),
+      );
+    } else if (is_member_access || is_array_indexing)
+      && !context.is_mutable_place(&self.assignee_expr.kind, cache)
+    {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error().with_message("assignee is immutable"),
+      );
+    }
+
+    // REVIEW: should this checks be placed before or after?
+    self.assignee_expr.kind.check(context, cache);
+    self.value.kind.check(context, cache);
+  }
+}
+
+impl Check for ast::ContinueStmt {
+  fn check(&self, context: &mut TypeContext, _cache: &cache::Cache) {
+    if !context.in_loop {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("continue statement may only occur inside loops"),
+      );
+    }
+  }
+}
+
+impl Check for ast::IndexingExpr {
+  fn report_constraints(&mut self, context: &mut TypeContext, cache: &cache::Cache) {
+    // Integer literals used as array indices take the index type.
+    if matches!(
+      self.target_expr.kind.infer_flatten_type(cache),
+      ast::Type::Array(..) | ast::Type::Slice(_) | ast::Type::Basic(ast::BasicType::OwnedString)
+    ) {
+      context.type_int_literal_expr(
+        &mut self.index_expr.kind,
+        &ast::Type::Basic(ast::BasicType::Int(ast::IntSize::U32)),
+        cache,
+      );
+    }
+  }
+
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    match self.target_expr.kind.infer_flatten_type(cache) {
+      ast::Type::Array(element_type, _)
+      | ast::Type::Slice(element_type)
+      | ast::Type::Pointer(element_type) => element_type.as_ref().clone(),
+      // Strings are indexed by bytes.
+      ast::Type::Basic(ast::BasicType::OwnedString) => {
+        ast::Type::Basic(ast::BasicType::Int(ast::IntSize::U8))
+      }
+      // NOTE: Non-indexable targets are reported during checking.
+      _ => ast::Type::Error,
+    }
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    self.target_expr.kind.check(context, cache);
+    self.index_expr.kind.check(context, cache);
+
+    let index_expr_type = self.index_expr.kind.infer_flatten_type(cache);
+    let target_expr_type = self.target_expr.kind.infer_flatten_type(cache);
+
+    // Pointers are indexed by element offsets, which may be negative. Whether the
+    // offset is valid cannot be known, thus it is only allowed inside unsafe blocks.
+    if matches!(target_expr_type, ast::Type::Pointer(_)) {
+      if !context.in_unsafe_block {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("pointer indexing may only occur inside an unsafe block"),
+        );
+      }
+
+      if !matches!(index_expr_type, ast::Type::Basic(ast::BasicType::Int(_))) {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("pointer index expression must be an integer"),
+        );
+      }
+
+      return;
+    }
+
+    let is_index_proper_type =
+      index_expr_type.is(&ast::Type::Basic(ast::BasicType::Int(ast::IntSize::U32)));
+
+    if !is_index_proper_type {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("array index expression must be of type `U32`"),
+      );
+
+      // REVIEW: Should we actually not continue?
+      // Can't continue if the index expression is not of the proper type.
+      return;
+    }
+
+    // REVIEW: Any way of avoiding nesting?
+    if let ast::Type::Array(_, length) = target_expr_type {
+      // Dynamic indices are checked at runtime instead, unless they
+      // are guarded by a bounds check (see `find_bounds_guards`).
+      let index_value = TypeContext::evaluate_constant(&self.index_expr.kind, cache);
+
+      if let Some(ConstantValue::Int(index_value)) = index_value {
+        if index_value < 0 || index_value >= length as i128 {
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error()
+              .with_message("array index expression must be within the bounds of the array"),
+          );
+        }
+      }
+    }
+    // The indices of slices and strings are always checked at runtime.
+    else if !matches!(
+      target_expr_type,
+      ast::Type::Slice(_) | ast::Type::Basic(ast::BasicType::OwnedString)
+    ) {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("can only index into arrays, slices, strings or pointers"),
+      );
+    }
+  }
+}
+
+impl Check for ast::SliceExpr {
+  fn report_constraints(&mut self, context: &mut TypeContext, cache: &cache::Cache) {
+    let index_type = ast::Type::Basic(ast::BasicType::Int(ast::IntSize::U32));
+
+    context.type_int_literal_expr(&mut self.start_expr.kind, &index_type, cache);
+    context.type_int_literal_expr(&mut self.end_expr.kind, &index_type, cache);
+  }
+
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    match self.target_expr.kind.infer_flatten_type(cache) {
+      ast::Type::Array(element_type, _) | ast::Type::Slice(element_type) => {
+        ast::Type::Slice(element_type)
+      }
+      // Slicing a string copies the bytes within bounds into a new string.
+      ast::Type::Basic(ast::BasicType::OwnedString) => {
+        ast::Type::Basic(ast::BasicType::OwnedString)
+      }
+      // NOTE: Non-sliceable targets are reported during checking.
+      _ => ast::Type::Error,
+    }
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    self.target_expr.kind.check(context, cache);
+    self.start_expr.kind.check(context, cache);
+    self.end_expr.kind.check(context, cache);
+
+    let index_type = ast::Type::Basic(ast::BasicType::Int(ast::IntSize::U32));
+
+    if !self
+      .start_expr
+      .kind
+      .infer_flatten_type(cache)
+      .is(&index_type)
+      || !self.end_expr.kind.infer_flatten_type(cache).is(&index_type)
+    {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("slice bounds must be of type `U32`"),
+      );
+
+      return;
+    }
+
+    let length = match self.target_expr.kind.infer_flatten_type(cache) {
+      ast::Type::Array(_, length) => Some(length),
+      ast::Type::Slice(_) | ast::Type::Basic(ast::BasicType::OwnedString) => None,
+      _ => {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("can only slice arrays, slices or strings"),
+        );
+
+        return;
+      }
+    };
+
+    // Constant bounds are verified here; all others are checked at runtime.
+    let start = TypeContext::evaluate_constant(&self.start_expr.kind, cache);
+    let end = TypeContext::evaluate_constant(&self.end_expr.kind, cache);
+
+    if let (Some(ConstantValue::Int(start)), Some(ConstantValue::Int(end))) = (start, end) {
+      if start > end {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("slice start must be less than or equal to its end"),
+        );
+      }
+    }
+
+    if let (Some(length), Some(ConstantValue::Int(end))) = (length, end) {
+      if end > length as i128 {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("slice end must be within the bounds of the array"),
+        );
+      }
+    }
+  }
+}
+
+impl Check for ast::InterpolationExpr {
+  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
+    ast::Type::Basic(ast::BasicType::OwnedString)
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    for hole in &self.holes {
+      hole.kind.check(context, cache);
+      context.check_printable(hole, cache);
+    }
+  }
+}
+
+impl Check for ast::StaticArrayValue {
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    // TODO: Temporary, until type-inference is implemented.
+    // We assume that the length is `0` if the explicit type is provided, otherwise
+    // the array type is determined by the first element.
+    let array_element_type = if let Some(explicit_type) = &self.explicit_type {
+      explicit_type.clone()
+    } else {
+      self.elements.first().unwrap().kind.infer_type(cache)
+    };
+
+    // REVIEW: Is the length conversion safe?
+    ast::Type::Array(Box::new(array_element_type), self.elements.len() as u32)
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    let mut mixed_elements_flag = false;
+
+    let expected_element_type = if let Some(explicit_type) = &self.explicit_type {
+      explicit_type.clone()
+    } else {
+      self.elements.first().unwrap().kind.infer_type(cache)
+    };
+
+    if TypeContext::implements_drop(&expected_element_type, cache) {
+      context.report_held_drop_value(format!(
+        "array elements cannot be of type `{}`, which implements `Drop`",
+        expected_element_type.name()
+      ));
+    }
+
+    // TODO: Skip the first element during iteration, as it is redundant.
+    for element in &self.elements {
+      // Report this error only once.
+      if !mixed_elements_flag && element.kind.infer_type(cache) != expected_element_type {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("array elements must all be of the same type"),
+        );
+
+        mixed_elements_flag = true;
+      }
+
+      element.kind.check(context, cache);
+    }
+  }
+}
+
+impl Check for ast::UnsafeExpr {
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    self.0.kind.infer_type(cache)
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    // REVIEW: To avoid problems with nested cases, save a buffer here, then restore?
+    context.in_unsafe_block = true;
+    self.0.kind.check(context, cache);
+    context.in_unsafe_block = false;
+  }
+}
+
+impl Check for ast::DeferStmt {
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    // Deferred bodies run when their block is exited, which may be from
+    // within a loop, so they may not break out of (or continue) it.
+    let was_in_loop = std::mem::replace(&mut context.in_loop, false);
+    let was_in_defer = std::mem::replace(&mut context.in_defer, true);
+
+    self.body.kind.check(context, cache);
+    context.in_loop = was_in_loop;
+    context.in_defer = was_in_defer;
+  }
+}
+
+impl Check for ast::ExternFunction {
+  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
+    TypeContext::infer_prototype_type(
+      &self.prototype,
+      self.prototype.return_type_annotation.clone(),
+    )
+  }
+
+  fn check(&self, context: &mut TypeContext, _cache: &cache::Cache) {
+    if self.prototype.accepts_instance {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("extern functions cannot accept instances"),
+      );
+    }
+  }
+
+  fn report_constraints(&mut self, context: &mut TypeContext, _cache: &cache::Cache) {
+    // Extern functions have no body to infer from, so they return
+    // unit unless their return type is annotated.
+    if matches!(
+      self.prototype.return_type_annotation,
+      ast::Type::Variable(_)
+    ) {
+      context.constraints.push((
+        self.prototype.return_type_annotation.clone(),
+        ast::Type::Unit,
+        TypeConstrainKind::Equality,
+        format!("in the return type of extern `{}`", self.name),
+        Some(self.position),
+      ));
+    }
+  }
+
+  fn post_unification(&mut self, context: &mut TypeContext, cache: &mut cache::Cache) {
+    self.prototype.return_type_annotation =
+      context.substitute(self.prototype.return_type_annotation.clone());
+
+    if let Some(ast::NodeKind::ExternFunction(cached_extern)) =
+      cache.symbols.get_mut(&self.cache_id)
+    {
+      cached_extern.prototype.return_type_annotation =
+        self.prototype.return_type_annotation.clone();
+    }
+  }
+}
+
+impl Check for ast::Parameter {
+  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
+    self.ty.clone()
+  }
+}
+
+impl Check for ast::BlockExpr {
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    if let Some(yields_value) = &self.yields {
+      return yields_value.kind.infer_type(cache);
+    }
+    // FIXME: Ensure this logic is correct, and that it will work as expected.
+    // BUG: The function to infer return values uses this infer method, so function types CAN be never!
+    // If at least one statement evaluates to type never, the type of this
+    // block is also never.
+    else if self
+      .statements
+      .iter()
+      .any(|statement| statement.kind.infer_flatten_type(cache).is_a_never())
+    {
+      return ast::Type::Never;
+    }
+
+    ast::Type::Unit
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    for statement in &self.statements {
+      statement.kind.check(context, cache);
+    }
+  }
+}
+
+impl Check for ast::Reference {
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    // REVIEW: We should have some sort of caching specifically applied to this construct.
+    cache
+      .force_get(&self.pattern.target_id.unwrap())
+      .infer_type(cache)
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    let target_type = cache
+      .force_get(&self.pattern.target_id.unwrap())
+      .infer_type(cache);
+
+    // FIXME: Investigate how this affects.
+    if target_type.is_a_meta() {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("cannot reference a binding that has a meta type; it cannot be evaluated"),
+      );
+    }
+  }
+}
+
+impl Check for ast::Literal {
+  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
+    ast::Type::Basic(match self {
+      ast::Literal::Bool(_) => ast::BasicType::Bool,
+      ast::Literal::Char(_) => ast::BasicType::Char,
+      ast::Literal::Int(_, size) => ast::BasicType::Int(size.clone()),
+      ast::Literal::String(_) => ast::BasicType::String,
+      ast::Literal::Nullptr(ty) => return ast::Type::Pointer(Box::new(ty.clone())),
+    })
+  }
+}
+
+impl Check for ast::IfExpr {
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    let then_expr_type = self.then_expr.kind.infer_flatten_type(cache);
+
+    // At least the two main branches must be present in order for a value
+    // to possibly evaluate.
+    if self.else_expr.is_none() {
+      return ast::Type::Unit;
+    }
+
+    // TODO: Take into consideration newly-added alternative branches.
+
+    let else_expr = self.else_expr.as_ref().unwrap();
+    let else_expr_type = else_expr.kind.infer_flatten_type(cache);
+
+    // Default to type unit if both branches are of incompatible types.
+    then_expr_type
+      .coercion(&else_expr_type)
+      .unwrap_or(ast::Type::Unit)
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    let condition_type = self.condition.kind.infer_flatten_type(cache);
+
+    if !condition_type.is(&ast::Type::Basic(ast::BasicType::Bool)) {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("if statement condition must evaluate to a boolean"),
+      );
+    }
+
+    // TODO: Check for type-mismatch between branches (they may yield).
+
+    // REVIEW: Should the children be checked first?
+    self.condition.kind.check(context, cache);
+
+    self.then_expr.kind.check(context, cache);
+
+    if let Some(else_block) = &self.else_expr {
+      else_block.kind.check(context, cache);
+    }
+  }
+}
+
+impl Check for ast::BinaryExpr {
+  fn report_constraints(&mut self, context: &mut TypeContext, cache: &cache::Cache) {
+    let is_left_literal = TypeContext::is_int_literal_expr(&self.left.kind);
+    let is_right_literal = TypeContext::is_int_literal_expr(&self.right.kind);
+
+    // An integer literal operand takes the type of the other operand. If both
+    // are literals, the binary expression's context will determine their type.
+    if is_left_literal && !is_right_literal {
+      let right_type = self.right.kind.infer_type(cache);
+
+      context.type_int_literal_expr(&mut self.left.kind, &right_type, cache);
+    } else if is_right_literal && !is_left_literal {
+      let left_type = self.left.kind.infer_type(cache);
+
+      context.type_int_literal_expr(&mut self.right.kind, &left_type, cache);
+    }
+  }
+
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    match self.operator {
+      ast::OperatorKind::LessThan
+      | ast::OperatorKind::GreaterThan
+      | ast::OperatorKind::LessThanOrEqual
+      | ast::OperatorKind::GreaterThanOrEqual
+      | ast::OperatorKind::Equality
+      | ast::OperatorKind::And
+      | ast::OperatorKind::Or
+      | ast::OperatorKind::Nand
+      | ast::OperatorKind::Nor
+      | ast::OperatorKind::Xor
+      | ast::OperatorKind::In => ast::Type::Basic(ast::BasicType::Bool),
+      // The difference between two pointers is the amount of elements between them.
+      ast::OperatorKind::SubtractOrNegate
+        if matches!(
+          self.right.kind.infer_flatten_type(cache),
+          ast::Type::Pointer(_)
+        ) =>
+      {
+        ast::Type::Basic(ast::BasicType::Int(ast::IntSize::Isize))
+      }
+      _ => self.left.kind.infer_type(cache),
+    }
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    let left_type = self.left.kind.infer_flatten_type(cache);
+    let right_type = self.right.kind.infer_flatten_type(cache);
+
+    let is_pointer_arithmetic = matches!(left_type, ast::Type::Pointer(_))
+      && matches!(
+        self.operator,
+        ast::OperatorKind::Add | ast::OperatorKind::SubtractOrNegate
+      );
+
+    if is_pointer_arithmetic {
+      if !context.in_unsafe_block {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("pointer arithmetic may only occur inside an unsafe block"),
+        );
+      }
+
+      let is_pointer_difference = self.operator == ast::OperatorKind::SubtractOrNegate
+        && matches!(right_type, ast::Type::Pointer(_));
+
+      if is_pointer_difference && !left_type.is(&right_type) {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("pointer difference operands must point to the same type"),
+        );
+      } else if !is_pointer_difference
+        && !matches!(right_type, ast::Type::Basic(ast::BasicType::Int(_)))
+      {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("pointer offset must be an integer"),
+        );
+      }
+
+      self.left.kind.check(context, cache);
+      self.right.kind.check(context, cache);
+
+      return;
+    }
+
+    // TODO: Also add checks for when using operators with wrong values (ex. less-than or greater-than comparison of booleans).
+
+    // The range check `index in length` may be given integers of different
+    // types (ex. a `U32` index and the `I32` length of an array).
+    if self.operator == ast::OperatorKind::In {
+      let is_int = |ty: &ast::Type| matches!(ty, ast::Type::Basic(ast::BasicType::Int(_)));
+
+      if !is_int(&left_type) || !is_int(&right_type) {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("operands of the `in` operator must be integers"),
+        );
+      }
+
+      self.left.kind.check(context, cache);
+      self.right.kind.check(context, cache);
+
+      return;
+    }
+
+    if !left_type.is(&right_type) {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("binary expression operands must be the same type"),
+      );
+
+      return;
+    }
+
+    // REVIEW: Check for mixed operators that don't make sense (ex. addition, then a comparison operator)?
+
+    // Strings are concatenated and compared by the string runtime.
+    if matches!(left_type, ast::Type::Basic(ast::BasicType::OwnedString)) {
+      if !matches!(
+        self.operator,
+        ast::OperatorKind::Add
+          | ast::OperatorKind::LessThan
+          | ast::OperatorKind::GreaterThan
+          | ast::OperatorKind::LessThanOrEqual
+          | ast::OperatorKind::GreaterThanOrEqual
+          | ast::OperatorKind::Equality
+      ) {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("strings may only be concatenated or compared"),
+        );
+      }
+
+      self.left.kind.check(context, cache);
+      self.right.kind.check(context, cache);
+
+      return;
+    }
+
+    // NOTE: By this point, it is assumed that both operands are of the same type.
+    match self.operator {
+      ast::OperatorKind::Add
+      | ast::OperatorKind::SubtractOrNegate
+      | ast::OperatorKind::MultiplyOrDereference
+      | ast::OperatorKind::Divide
+      | ast::OperatorKind::Remainder
+      | ast::OperatorKind::LessThan
+      | ast::OperatorKind::GreaterThan => {
+        // REVIEW: What about floats?
+        if !matches!(left_type, ast::Type::Basic(ast::BasicType::Int(_))) {
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error()
+              .with_message("binary expression operands must be both integers"),
+          );
+        }
+      }
+      // TODO: Equality operator, and others? Implement.
+      _ => {}
+    };
+
+    self.left.kind.check(context, cache);
+    self.right.kind.check(context, cache);
+  }
+}
+
+impl Check for ast::BreakStmt {
+  fn check(&self, context: &mut TypeContext, _cache: &cache::Cache) {
+    if !context.in_loop {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("break statement may only occur inside loops"),
+      );
+    }
+  }
+}
+
+impl Check for ast::InlineExprStmt {
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    self.expr.kind.infer_type(cache)
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    self.expr.kind.check(context, cache);
+  }
+}
+
+impl Check for ast::BindingStmt {
+  // BUG: This causes a bug where the string literal is not accessed (left as `i8**`). The let-statement didn't have a type before.
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    // Static arrays bound to slice-annotated bindings are coerced.
+    if matches!(self.ty.flatten(cache), ast::Type::Slice(_)) {
+      return self.ty.clone();
+    }
+
+    self.value.kind.infer_type(cache)
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    // Deferred bodies are lowered once per exit path of their scope, which
+    // ... would declare the same binding more than once.
+    if context.in_defer {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+          "cannot declare binding `{}` within the body of a `defer` statement",
+          self.name
+        )),
+      );
+    }
+
+    let value_type = self.value.kind.infer_flatten_type(cache);
+
+    // Unannotated bindings were given the type of their value, so this only
+    // affects annotated ones. Values that failed to type are reported elsewhere.
+    let is_mismatch = !matches!(value_type, ast::Type::Error)
+      && !matches!(self.ty, ast::Type::Variable(_))
+      && !self.ty.flat_is(&value_type, cache);
+
+    if is_mismatch {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+          "binding `{}` is declared as `{}`, but its value is of type `{}`",
+          self.name,
+          self.ty.flatten(cache).name(),
+          value_type.name()
+        )),
+      );
+    }
+
+    self.value.kind.check(context, cache);
+  }
+
+  fn report_constraints(&mut self, context: &mut TypeContext, cache: &cache::Cache) {
+    context.type_int_literal_expr(&mut self.value.kind, &self.ty, cache);
+
+    // Mismatches between annotations and values are reported during checking.
+    if matches!(self.ty, ast::Type::Variable(_)) {
+      context.constraints.push((
+        self.ty.clone(),
+        self.value.kind.infer_flatten_type(cache),
+        TypeConstrainKind::Equality,
+        format!("in the value of binding `{}`", self.name),
+        Some(self.position),
+      ));
+    }
+  }
+
+  fn post_unification(&mut self, context: &mut TypeContext, cache: &mut cache::Cache) {
+    self.ty = context.substitute(self.ty.clone());
+
+    if let Some(ast::NodeKind::BindingStmt(cached_binding_stmt)) =
+      cache.symbols.get_mut(&self.cache_id)
+    {
+      cached_binding_stmt.ty = self.ty.clone();
+      // The value's literals may have been re-typed while reporting constraints.
+      cached_binding_stmt.value.clone_from(&self.value);
+    }
+  }
+}
+
+impl Check for ast::ReturnStmt {
+  // FIXME: This implies that the return statement may be used as an expression,
+  // ... but that's currently not possible. Instead, this type is currently solely
+  // ... used to determine if all paths return a value?
+  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
+    ast::Type::Never
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    if context.in_defer {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("cannot return from within the body of a `defer` statement"),
+      );
+
+      return;
+    }
+
+    let current_function_node = cache.force_get(&context.current_function_id.unwrap());
+    let mut name = None;
+
+    let (prototype, body) = match &current_function_node {
+      ast::NodeKind::Function(function) => {
+        name = Some(function.name.clone());
+
+        (&function.prototype, &function.body)
+      }
+      ast::NodeKind::Closure(closure) => (&closure.prototype, &closure.body),
+      _ => unreachable!(),
+    };
+
+    let return_type = TypeContext::infer_function_return_type(prototype, body, cache);
+
+    // REVISE: Whether a function returns is already checked. Limit this to comparing the types only.
+    if !return_type.is_a_unit() && self.value.is_none() {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("return statement must return a value"),
+      );
+    } else if return_type.is_a_unit() && self.value.is_some() {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("return statement must not return a value"),
+      );
+
+      // REVIEW: Returning at this point. Is this okay?
+      return;
+    }
+
+    if let Some(value) = &self.value {
+      let value_type = value.kind.infer_flatten_type(cache);
+
+      context.check_returned_array(&value.kind, &return_type, cache);
+
+      if !return_type.is(&value_type) {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+            "return statement value and prototype return type mismatch for {}",
+            // REVISE: Change the actual name to this on its initialization.
+            if let Some(name) = name {
+              format!("function `{}`", name)
+            } else {
+              "closure".to_string()
+            }
+          )),
+        );
+      }
+
+      value.kind.check(context, cache);
+    }
+  }
+}
+
+impl Check for ast::Function {
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    // REVIEW: Why not use annotated return type if defined?
+    TypeContext::infer_prototype_type(
+      &self.prototype,
+      TypeContext::infer_function_return_type(&self.prototype, &self.body, cache),
+    )
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    let previous_function_key = context.current_function_id.clone();
+
+    context.current_function_id = Some(self.cache_id);
+
+    if self.prototype.accepts_instance && !context.in_impl {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("cannot accept instance in a non-impl function"),
+      );
+    }
+
+    // let return_type = self.body.infer_type(cache);
+
+    // if !TypeContext::compare(&return_type, &self.body.infer_type(cache), cache) {
+    //   context.diagnostics.push(
+    //     codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+    //       "function body and prototype return type mismatch for function `{}`",
+    //       self.name
+    //     )),
+    //   );
+    // }
+
+    if self.prototype.is_variadic {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+          "function `{}` cannot be variadic; only externs are allowed to be variadic",
+          self.name
+        )),
+      );
+    }
+
+    if self.name == lowering::MAIN_FUNCTION_NAME {
+      let main_function_type = ast::Type::Function(ast::FunctionType {
+        parameter_types: vec![
+          ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
+          ast::Type::Pointer(Box::new(ast::Type::Basic(ast::BasicType::String))),
+        ],
+        return_type: Box::new(ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32))),
+        is_variadic: false,
+        is_extern: false,
+      });
+
+      if self.infer_type(cache) != main_function_type {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("the `main` function has an invalid signature")
+            .with_notes(vec![String::from("should accept a first parameter of type `Int`, a second one of type `*Str`, and the return type should be `Int`"), String::from("cannot be marked as variadic or extern")]),
+        );
+      }
+    }
+
+    let return_type = TypeContext::infer_return_value_type(&self.body, cache);
+
+    if !return_type.is_a_unit() {
+      // If at least one statement's type evaluates to never, it
+      // means that all paths are covered, because code execution will
+      // always return at one point before reaching (or at) the end of the function.
+      let all_paths_covered = self
+        .body
+        .statements
+        .iter()
+        .any(|statement| statement.kind.infer_flatten_type(cache).is_a_never());
+
+      if !all_paths_covered {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            // TODO: Function name.
+            .with_message("not all paths return a value"),
+        );
+      }
+    }
+
+    if let Some(yields) = &self.body.yields {
+      context.check_returned_array(
+        &yields.kind,
+        &self.prototype.return_type_annotation.flatten(cache),
+        cache,
+      );
+    }
+
+    self.prototype.check(context, cache);
+    self.body.check(context, cache);
+    context.current_function_id = previous_function_key;
+  }
+
+  fn report_constraints(&mut self, context: &mut TypeContext, cache: &cache::Cache) {
+    context.type_returned_int_literals(
+      &mut self.body,
+      &self.prototype.return_type_annotation,
+      cache,
+    );
+
+    context.constraints.push((
+      self.prototype.return_type_annotation.flatten(cache),
+      TypeContext::infer_return_value_type(&self.body, cache),
+      TypeConstrainKind::Equality,
+      format!("in the return value of function `{}`", self.name),
+      Some(self.position),
+    ));
+  }
+
+  fn post_unification(&mut self, context: &mut TypeContext, cache: &mut cache::Cache) {
+    // TODO: Parameters, etc.
+
+    self.prototype.return_type_annotation =
+      context.substitute(self.prototype.return_type_annotation.clone());
+
+    if let Some(ast::NodeKind::Function(cached_function)) = cache.symbols.get_mut(&self.cache_id) {
+      cached_function.prototype.return_type_annotation =
+        self.prototype.return_type_annotation.clone();
+
+      // The body's literals may have been re-typed while reporting constraints.
+      cached_function.body.clone_from(&self.body);
+    }
+  }
+}
+
+impl Check for ast::CallExpr {
+  fn report_constraints(&mut self, context: &mut TypeContext, cache: &cache::Cache) {
+    let callee_type = match self.callee_expr.kind.infer_flatten_type(cache) {
+      ast::Type::Function(callee_type) => callee_type,
+      _ => return,
+    };
+
+    // NOTE: Variadic arguments are left unconstrained.
+    for (argument, parameter_type) in self
+      .arguments
+      .iter_mut()
+      .zip(callee_type.parameter_types.iter())
+    {
+      context.type_int_literal_expr(&mut argument.kind, parameter_type, cache);
+    }
+  }
+
+  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
+    let callee_expr_type = self.callee_expr.kind.infer_flatten_type(cache);
+
+    match callee_expr_type {
+      ast::Type::Function(callable_type) => callable_type.return_type.as_ref().clone(),
+      _ => ast::Type::Error,
+    }
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    self.callee_expr.kind.check(context, cache);
+
+    // REVIEW: Consider adopting a `expected` and `actual` API for diagnostics, when applicable.
+    // REVIEW: Need access to the current function?
+
+    // Function-typed values may be referred to through type aliases.
+    let callee_expr_type = self.callee_expr.kind.infer_flatten_type(cache);
+
+    if !matches!(callee_expr_type, ast::Type::Function(_)) {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("call expression's callee is not actually callable"),
+      );
+
+      // Cannot continue.
+      return;
+    }
+
+    let callee_type = match callee_expr_type {
+      ast::Type::Function(callable_type) => callable_type,
+      _ => unreachable!(),
+    };
+
+    // REVISE: Better, simpler way of doing this?
+    // let attributes;
+
+    if callee_type.is_extern && !context.in_unsafe_block {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+          "extern function call to `{}` may only occur inside an unsafe block",
+          // TODO: Need name.
+          "<pending>"
+        )),
+      );
+    }
+
+    // for attribute in attributes {
+    //   // TODO: Keep it simple for now, but later, we can improve the attribute system.
+    //   match attribute.name.as_str() {
+    //     "deprecated" => context
+    //       .diagnostic_builder
+    //       .warning(format!("function `{}` is deprecated", name)),
+    //     _ => context.diagnostic_builder.warning(format!(
+    //       "use of unrecognized attribute `{}`",
+    //       attribute.name
+    //     )),
+    //   };
+    // }
+
+    context.validate_fn_call(
+      self
+        .arguments
         .iter()
-        // No need to flatten.
+        // No need to flatten here. Whether function-typed arguments may be
+        // ... passed as callbacks is checked below, with a more specific error.
         .map(|argument| argument.kind.infer_type(cache))
         .collect(),
-      target_function_type,
+      callee_type.clone(),
       cache,
     );
 
-    // Special case because of the static array type.
-    if matches!(self.kind, ast::IntrinsicKind::LengthOf) && self.arguments.len() == 1 {
-      let target_array = self.arguments.first().unwrap();
-      let target_array_type = target_array.kind.infer_flatten_type(cache);
+    // Methods declared with `mut this` may only be called on mutable instances.
+    if let ast::NodeKind::MemberAccess(member_access) = &self.callee_expr.kind {
+      let base_expr_type = member_access.base_expr.kind.infer_flatten_type(cache);
+
+      let method_node = match TypeContext::auto_deref(base_expr_type, cache) {
+        ast::Type::Struct(struct_type) => Some(struct_type),
+        _ => None,
+      }
+      .and_then(|struct_type| cache.struct_impls.get(&struct_type.cache_id))
+      .and_then(|methods| {
+        methods
+          .iter()
+          .find(|method| method.1 == member_access.member_name)
+      })
+      .map(|method| cache.force_get(&method.0));
+
+      if let Some(ast::NodeKind::Function(method)) = method_node {
+        if method.prototype.is_instance_mutable
+          && !context.is_mutable_place(&self.callee_expr.kind, cache)
+        {
+          context.diagnostics.push(
+            codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
+              "cannot call mutating method `{}` on an immutable instance",
+              method.name
+            )),
+          );
+        }
+      }
+    }
+
+    // Callbacks handed over to foreign code are plain function pointers,
+    // which cannot carry a closure's environment.
+    for (parameter_type, argument) in callee_type
+      .parameter_types
+      .iter()
+      .zip(self.arguments.iter())
+    {
+      let is_callback = matches!(
+        parameter_type.flatten(cache),
+        ast::Type::Function(ast::FunctionType {
+          is_extern: true,
+          ..
+        })
+      );
+
+      if !is_callback {
+        continue;
+      }
+
+      let is_named_function = match argument.kind.flatten() {
+        ast::NodeKind::Reference(reference) => matches!(
+          cache.force_get(&reference.pattern.target_id.unwrap()),
+          ast::NodeKind::Function(_) | ast::NodeKind::ExternFunction(_)
+        ),
+        _ => false,
+      };
 
-      if !matches!(target_array_type, ast::Type::Array(..)) {
+      if !is_named_function {
         context.diagnostics.push(
           codespan_reporting::diagnostic::Diagnostic::error()
-            .with_message("cannot determine static length of non-array type"),
+            .with_message("only named functions may be passed as callbacks to extern functions"),
         );
       }
     }
+
+    for argument in &self.arguments {
+      argument.kind.check(context, cache);
+    }
   }
 }
 
-impl Check for ast::ExternStatic {
-  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
-    self.ty.clone()
+impl Check for ast::ForInStmt {
+  fn report_constraints(&mut self, context: &mut TypeContext, cache: &cache::Cache) {
+    match self.iterable.kind.infer_flatten_type(cache) {
+      ast::Type::Array(element_type, _) | ast::Type::Slice(element_type) => {
+        context.constraints.push((
+          self.element.ty.clone(),
+          *element_type,
+          TypeConstrainKind::Equality,
+          format!("in the element `{}` of a for-in loop", self.element.name),
+          Some(self.position),
+        ));
+      }
+      // Strings are iterated by bytes.
+      ast::Type::Basic(ast::BasicType::OwnedString) => {
+        context.constraints.push((
+          self.element.ty.clone(),
+          ast::Type::Basic(ast::BasicType::Int(ast::IntSize::U8)),
+          TypeConstrainKind::Equality,
+          format!("in the element `{}` of a for-in loop", self.element.name),
+          Some(self.position),
+        ));
+      }
+      // NOTE: Non-iterable values are reported during checking.
+      _ => {}
+    };
+  }
+
+  fn post_unification(&mut self, context: &mut TypeContext, cache: &mut cache::Cache) {
+    self.element.ty = context.substitute(self.element.ty.clone());
+
+    if let Some(ast::NodeKind::Parameter(cached_element)) =
+      cache.symbols.get_mut(&self.element.cache_id)
+    {
+      cached_element.ty = self.element.ty.clone();
+    }
+  }
+
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    if !matches!(
+      self.iterable.kind.infer_flatten_type(cache),
+      ast::Type::Array(..) | ast::Type::Slice(_) | ast::Type::Basic(ast::BasicType::OwnedString)
+    ) {
+      context.diagnostics.push(
+        codespan_reporting::diagnostic::Diagnostic::error()
+          .with_message("can only iterate over arrays, slices or strings"),
+      );
+    }
+
+    self.iterable.kind.check(context, cache);
+
+    // Nested loops must not end the enclosing loop once they are checked.
+    let was_in_loop = std::mem::replace(&mut context.in_loop, true);
+
+    self.body.check(context, cache);
+    context.in_loop = was_in_loop;
+  }
+}
+
+impl Check for ast::LoopStmt {
+  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
+    if let Some(condition) = &self.condition {
+      let condition_type = condition.kind.infer_flatten_type(cache);
+
+      if !condition_type.is(&ast::Type::Basic(ast::BasicType::Bool)) {
+        context.diagnostics.push(
+          codespan_reporting::diagnostic::Diagnostic::error()
+            .with_message("loop condition must evaluate to a boolean"),
+        );
+      }
+
+      condition.kind.check(context, cache);
+    }
+
+    // Nested loops must not end the enclosing loop once they are checked.
+    let was_in_loop = std::mem::replace(&mut context.in_loop, true);
+
+    self.body.check(context, cache);
+    context.in_loop = was_in_loop;
   }
 }
 
-impl Check for ast::StructValue {
-  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
-    let struct_type = match cache.force_get(&self.target_id.unwrap()) {
-      ast::NodeKind::StructType(struct_type) => struct_type,
-      _ => unreachable!(),
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::name_resolution;
+
+  // TODO: Move this test to the `ast` file.
+  // #[test]
+  // fn is_null_pointer_type() {
+  //   let null_ptr_type = ast::Type::Pointer(Box::new(ast::Type::Basic(ast::BasicType::Null)));
+
+  //   assert!(TypeContext::is_null_pointer_type(&null_ptr_type));
+  //   assert!(!TypeContext::is_null_pointer_type(&ast::Type::Unit));
+  // }
+
+  #[test]
+  fn proper_initial_values() {
+    let type_context = TypeContext::new();
+
+    assert!(type_context.constraints.is_empty());
+    assert!(type_context.substitutions.is_empty());
+    assert!(type_context.diagnostics.is_empty());
+    assert!(type_context.usings.is_empty());
+    assert!(type_context.current_function_id.is_none());
+    assert!(!type_context.in_impl);
+    assert!(!type_context.in_loop);
+    assert!(!type_context.in_unsafe_block);
+    assert!(!type_context.in_defer);
+  }
+
+  #[test]
+  fn constructor_creating_own_struct() {
+    let (diagnostics, _, _) = crate::mock::tests::check_source(
+      "struct Point:\n  x: Int,\n\nimpl Point:\n  func init(this):\n    let origin = new Point{0}\n",
+    );
+
+    assert_eq!(1, diagnostics.len());
+
+    assert_eq!(
+      "constructor `init` of `Point` cannot create a value of its own struct",
+      diagnostics[0].message
+    );
+  }
+
+  #[test]
+  fn member_access_through_nested_pointers() {
+    let (diagnostics, _, _) = crate::mock::tests::check_source(
+      "struct Point:\n  x: Int,\n\nfunc get_x(point: **Point) -> Int:\n  return point.x\n",
+    );
+
+    assert_eq!(1, diagnostics.len());
+
+    assert_eq!(
+      "can only dereference pointers inside an unsafe block",
+      diagnostics[0].message
+    );
+
+    let (diagnostics, _, _) = crate::mock::tests::check_source(
+      "struct Point:\n  x: Int,\n\nfunc get_x(point: **Point) -> Int:\n  return unsafe: point.x\n",
+    );
+
+    assert!(diagnostics.is_empty());
+  }
+
+  #[test]
+  fn member_access_through_references() {
+    let (diagnostics, _, _) = crate::mock::tests::check_source(
+      "struct Point:\n  x: Int,\n\nfunc get_x(point: &Point) -> Int:\n  return point.x\n",
+    );
+
+    assert!(diagnostics.is_empty());
+  }
+
+  #[test]
+  fn member_access_base_diagnostics_once() {
+    let (diagnostics, _, _) = crate::mock::tests::check_source(
+      "struct Point:\n  x: Int,\n\nfunc origin() -> Point:\n  return new Point{0}\n\nfunc get_x() -> Int:\n  return origin(1).x\n",
+    );
+
+    assert_eq!(1, diagnostics.len());
+
+    assert_eq!(
+      "call expression has an invalid amount of arguments",
+      diagnostics[0].message
+    );
+  }
+
+  #[test]
+  fn try_expr_on_non_optional() {
+    let mut type_context = TypeContext::new();
+    let cache = cache::Cache::new();
+
+    let try_expr = ast::TryExpr {
+      expr: Box::new(ast::Node {
+        kind: ast::NodeKind::Literal(ast::Literal::Bool(true)),
+        cached_type: None,
+      }),
+    };
+
+    assert_eq!(ast::Type::Error, try_expr.infer_type(&cache));
+    try_expr.check(&mut type_context, &cache);
+    assert_eq!(1, type_context.diagnostics.len());
+  }
+
+  #[test]
+  fn immutable_member_assignment() {
+    let mut type_context = TypeContext::new();
+    let mut cache = cache::Cache::new();
+    let struct_type_id = 0;
+    let binding_id = 1;
+
+    let bool_node = || ast::Node {
+      kind: ast::NodeKind::Literal(ast::Literal::Bool(true)),
+      cached_type: None,
+    };
+
+    cache.symbols.insert(
+      struct_type_id,
+      ast::NodeKind::StructType(ast::StructType {
+        cache_id: struct_type_id,
+        name: String::from("A"),
+        fields: vec![(String::from("b"), ast::Type::Basic(ast::BasicType::Bool))],
+        field_defaults: vec![None],
+      }),
+    );
+
+    cache.symbols.insert(
+      binding_id,
+      ast::NodeKind::BindingStmt(ast::BindingStmt {
+        name: String::from("a"),
+        value: Box::new(ast::Node {
+          kind: ast::NodeKind::StructValue(ast::StructValue {
+            struct_name: String::from("A"),
+            fields: vec![bool_node()],
+            target_id: Some(struct_type_id),
+            ty: None,
+          }),
+          cached_type: None,
+        }),
+        modifier: ast::BindingModifier::Immutable,
+        cache_id: binding_id,
+        ty: ast::Type::Unit,
+        position: 0,
+      }),
+    );
+
+    let assign_stmt = ast::AssignStmt {
+      assignee_expr: Box::new(ast::Node {
+        kind: ast::NodeKind::MemberAccess(ast::MemberAccess {
+          base_expr: Box::new(ast::Node {
+            kind: ast::NodeKind::Reference(ast::Reference {
+              pattern: ast::Pattern {
+                qualifier: None,
+                base_name: String::from("a"),
+                sub_name: None,
+                symbol_kind: name_resolution::SymbolKind::Definition,
+                target_id: Some(binding_id),
+              },
+              position: 0,
+            }),
+            cached_type: None,
+          }),
+          member_name: String::from("b"),
+        }),
+        cached_type: None,
+      }),
+      value: Box::new(bool_node()),
+      position: 0,
+    };
+
+    assign_stmt.check(&mut type_context, &cache);
+
+    assert!(type_context
+      .diagnostics
+      .iter()
+      .any(|diagnostic| diagnostic.message == "assignee is immutable"));
+  }
+
+  #[test]
+  fn classify_cast() {
+    let cache = cache::Cache::new();
+    let int_type = ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32));
+    let int_pointer_type = ast::Type::Pointer(Box::new(int_type.clone()));
+
+    let bool_pointer_type = ast::Type::Pointer(Box::new(ast::Type::Basic(ast::BasicType::Bool)));
+
+    assert_eq!(
+      Ok(CastKind::Int {
+        is_source_signed: false
+      }),
+      TypeContext::classify_cast(&ast::Type::Basic(ast::BasicType::Bool), &int_type, &cache)
+    );
+
+    assert_eq!(
+      Ok(CastKind::PointerToPointer),
+      TypeContext::classify_cast(&int_pointer_type, &bool_pointer_type, &cache)
+    );
+
+    assert_eq!(
+      Ok(CastKind::PointerToInt),
+      TypeContext::classify_cast(&int_pointer_type, &int_type, &cache)
+    );
+
+    assert_eq!(
+      Ok(CastKind::IntToPointer),
+      TypeContext::classify_cast(&int_type, &int_pointer_type, &cache)
+    );
+
+    assert_eq!(
+      Ok(CastKind::ArrayToPointer),
+      TypeContext::classify_cast(
+        &ast::Type::Array(Box::new(int_type.clone()), 3),
+        &int_pointer_type,
+        &cache
+      )
+    );
+
+    assert!(TypeContext::classify_cast(
+      &ast::Type::Array(Box::new(int_type.clone()), 3),
+      &bool_pointer_type,
+      &cache
+    )
+    .is_err());
+
+    assert!(
+      TypeContext::classify_cast(&int_type, &ast::Type::Basic(ast::BasicType::Bool), &cache)
+        .is_err()
+    );
+
+    assert!(TypeContext::classify_cast(&ast::Type::Unit, &int_type, &cache).is_err());
+  }
+
+  #[test]
+  fn pointer_cast_outside_unsafe() {
+    let mut type_context = TypeContext::new();
+    let cache = cache::Cache::new();
+    let int_type = ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32));
+
+    let cast_expr = ast::UnaryExpr {
+      operator: ast::OperatorKind::Cast,
+      expr: Box::new(ast::Node {
+        kind: ast::NodeKind::Literal(ast::Literal::Nullptr(int_type.clone())),
+        cached_type: None,
+      }),
+      cast_type: Some(ast::Type::Basic(ast::BasicType::Int(ast::IntSize::Usize))),
+      position: 0,
+    };
+
+    cast_expr.check(&mut type_context, &cache);
+    assert_eq!(1, type_context.diagnostics.len());
+
+    type_context.diagnostics.clear();
+    type_context.in_unsafe_block = true;
+    cast_expr.check(&mut type_context, &cache);
+    assert!(type_context.diagnostics.is_empty());
+  }
+
+  #[test]
+  fn pointer_arithmetic() {
+    let mut type_context = TypeContext::new();
+    let cache = cache::Cache::new();
+
+    let nullptr_node = |ty: ast::Type| {
+      Box::new(ast::Node {
+        kind: ast::NodeKind::Literal(ast::Literal::Nullptr(ty)),
+        cached_type: None,
+      })
+    };
+
+    let int_type = ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32));
+
+    let offset_expr = ast::BinaryExpr {
+      left: nullptr_node(int_type.clone()),
+      right: Box::new(ast::Node {
+        kind: ast::NodeKind::Literal(ast::Literal::Int(1, ast::IntSize::I32)),
+        cached_type: None,
+      }),
+      operator: ast::OperatorKind::Add,
+    };
+
+    offset_expr.check(&mut type_context, &cache);
+    assert_eq!(1, type_context.diagnostics.len());
+
+    type_context.diagnostics.clear();
+    type_context.in_unsafe_block = true;
+    offset_expr.check(&mut type_context, &cache);
+    assert!(type_context.diagnostics.is_empty());
+
+    let difference_expr = ast::BinaryExpr {
+      left: nullptr_node(int_type.clone()),
+      right: nullptr_node(ast::Type::Basic(ast::BasicType::Bool)),
+      operator: ast::OperatorKind::SubtractOrNegate,
+    };
+
+    assert_eq!(
+      ast::Type::Basic(ast::BasicType::Int(ast::IntSize::Isize)),
+      difference_expr.infer_type(&cache)
+    );
+
+    difference_expr.check(&mut type_context, &cache);
+    assert_eq!(1, type_context.diagnostics.len());
+  }
+
+  #[test]
+  fn nested_indexing_expr() {
+    let mut cache = cache::Cache::new();
+    let binding_id = 0;
+
+    let int_node = |value: u128| ast::Node {
+      kind: ast::NodeKind::Literal(ast::Literal::Int(value, ast::IntSize::U32)),
+      cached_type: None,
+    };
+
+    let row_node = || ast::Node {
+      kind: ast::NodeKind::StaticArrayValue(ast::StaticArrayValue {
+        elements: vec![int_node(1), int_node(2)],
+        explicit_type: None,
+      }),
+      cached_type: None,
+    };
+
+    cache.symbols.insert(
+      binding_id,
+      ast::NodeKind::BindingStmt(ast::BindingStmt {
+        name: String::from("matrix"),
+        value: Box::new(ast::Node {
+          kind: ast::NodeKind::StaticArrayValue(ast::StaticArrayValue {
+            elements: vec![row_node(), row_node()],
+            explicit_type: None,
+          }),
+          cached_type: None,
+        }),
+        modifier: ast::BindingModifier::Immutable,
+        cache_id: binding_id,
+        ty: ast::Type::Unit,
+        position: 0,
+      }),
+    );
+
+    let reference_node = ast::Node {
+      kind: ast::NodeKind::Reference(ast::Reference {
+        pattern: ast::Pattern {
+          qualifier: None,
+          base_name: String::from("matrix"),
+          sub_name: None,
+          symbol_kind: name_resolution::SymbolKind::Definition,
+          target_id: Some(binding_id),
+        },
+        position: 0,
+      }),
+      cached_type: None,
+    };
+
+    let indexing_expr = ast::IndexingExpr {
+      target_expr: Box::new(ast::Node {
+        kind: ast::NodeKind::IndexingExpr(ast::IndexingExpr {
+          target_expr: Box::new(reference_node),
+          index_expr: Box::new(int_node(1)),
+          position: 0,
+        }),
+        cached_type: None,
+      }),
+      index_expr: Box::new(int_node(0)),
+      position: 0,
+    };
+
+    let mut type_context = TypeContext::new();
+
+    assert_eq!(
+      ast::Type::Basic(ast::BasicType::Int(ast::IntSize::U32)),
+      indexing_expr.infer_type(&cache)
+    );
+
+    indexing_expr.check(&mut type_context, &cache);
+    assert!(type_context.diagnostics.is_empty());
+  }
+
+  #[test]
+  fn slice_expr() {
+    let mut cache = cache::Cache::new();
+    let binding_id = 0;
+
+    let int_node = |value: u128| ast::Node {
+      kind: ast::NodeKind::Literal(ast::Literal::Int(value, ast::IntSize::U32)),
+      cached_type: None,
     };
 
-    // REVIEW: Is this the correct type? We might need this one in order to unify with the original struct type.
-    ast::Type::Struct(struct_type.clone())
-  }
+    cache.symbols.insert(
+      binding_id,
+      ast::NodeKind::BindingStmt(ast::BindingStmt {
+        name: String::from("array"),
+        value: Box::new(ast::Node {
+          kind: ast::NodeKind::StaticArrayValue(ast::StaticArrayValue {
+            elements: vec![int_node(1), int_node(2), int_node(3)],
+            explicit_type: None,
+          }),
+          cached_type: None,
+        }),
+        modifier: ast::BindingModifier::Immutable,
+        cache_id: binding_id,
+        ty: ast::Type::Unit,
+        position: 0,
+      }),
+    );
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    let struct_type_node = cache.force_get(&self.target_id.unwrap());
+    let reference_node = || ast::Node {
+      kind: ast::NodeKind::Reference(ast::Reference {
+        pattern: ast::Pattern {
+          qualifier: None,
+          base_name: String::from("array"),
+          sub_name: None,
+          symbol_kind: name_resolution::SymbolKind::Definition,
+          target_id: Some(binding_id),
+        },
+        position: 0,
+      }),
+      cached_type: None,
+    };
 
-    let struct_type = match struct_type_node {
-      ast::NodeKind::StructType(struct_type) => struct_type,
-      _ => unreachable!(),
+    let slice_expr = ast::SliceExpr {
+      target_expr: Box::new(reference_node()),
+      start_expr: Box::new(int_node(1)),
+      end_expr: Box::new(int_node(3)),
+      position: 0,
     };
 
-    if self.fields.len() != struct_type.fields.len() {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error()
-          .with_message("invalid amount of fields in struct value"),
-      );
+    let mut type_context = TypeContext::new();
 
-      return;
-    }
+    assert_eq!(
+      ast::Type::Slice(Box::new(ast::Type::Basic(ast::BasicType::Int(
+        ast::IntSize::U32
+      )))),
+      slice_expr.infer_type(&cache)
+    );
 
-    for (index, (value_field, struct_field)) in self
-      .fields
-      .iter()
-      .zip(struct_type.fields.iter())
-      .enumerate()
-    {
-      value_field.kind.check(context, cache);
+    slice_expr.check(&mut type_context, &cache);
+    assert!(type_context.diagnostics.is_empty());
 
-      let value_field_type = value_field.kind.infer_type(cache);
+    let out_of_bounds_slice_expr = ast::SliceExpr {
+      target_expr: Box::new(reference_node()),
+      start_expr: Box::new(int_node(2)),
+      end_expr: Box::new(int_node(4)),
+      position: 0,
+    };
 
-      // FIXME: Uncomment and translate to current codebase.
-      // if !unify_option(value_field_type, Some(struct_field.1), cache) {
-      //   context.diagnostics.error(format!(
-      //     "field and value at position `{}` type for struct `{}` mismatch",
-      //     index, struct_type.name
-      //   ));
-      // }
-    }
+    out_of_bounds_slice_expr.check(&mut type_context, &cache);
+    assert_eq!(1, type_context.diagnostics.len());
   }
-}
 
-impl Check for ast::Prototype {
-  fn check(&self, _context: &mut TypeContext, _cache: &cache::Cache) {
-    // TODO: Implement?
-  }
-}
+  #[test]
+  fn find_bounds_guards() {
+    let (_, ast, cache) = crate::mock::tests::check_source(
+      "func f(i: U32, j: U32):\n  let a = [1]\n  var k: U32 = 0\n\n  if i in ?length_of(a) and j in ?length_of(a):\n    pass\n\n  if (i in ?length_of(a)) or true:\n    pass\n\n  if k in ?length_of(a):\n    k = 1\n",
+    );
 
-impl Check for ast::StructType {
-  // REVIEW: Implement? This is already a type on itself.
-}
+    let mut if_exprs = Vec::new();
 
-impl Check for ast::UnaryExpr {
-  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
-    let expr_type = self.expr.kind.infer_type(cache);
+    ast.first().unwrap().kind.traverse(|node| {
+      if let ast::NodeKind::IfExpr(if_expr) = node {
+        if_exprs.push(if_expr);
+      }
 
-    // Short-circuit if the expression's type is unit.
-    if expr_type.is_a_unit() {
-      return ast::Type::Unit;
-    }
+      true
+    });
 
-    return match self.operator {
-      ast::OperatorKind::AddressOf => ast::Type::Pointer(Box::new(expr_type)),
-      ast::OperatorKind::Cast => self.cast_type.as_ref().unwrap().clone(),
-      ast::OperatorKind::Not => ast::Type::Basic(ast::BasicType::Bool),
-      ast::OperatorKind::SubtractOrNegate => expr_type,
-      // BUG: The type must be whatever was inside the pointer; otherwise assume type error.
-      ast::OperatorKind::MultiplyOrDereference => expr_type,
-      _ => unreachable!(),
-    };
-  }
+    let bounds_guard_counts = if_exprs
+      .iter()
+      .map(|if_expr| {
+        TypeContext::find_bounds_guards(&if_expr.condition.kind, &if_expr.then_expr.kind, &cache)
+          .len()
+      })
+      .collect::<Vec<_>>();
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    let expr_type = &self.expr.kind.infer_flatten_type(cache);
+    assert_eq!(vec![2, 0, 0], bounds_guard_counts);
+  }
 
-    match self.operator {
-      ast::OperatorKind::MultiplyOrDereference => {
-        if !context.in_unsafe_block {
-          context.diagnostics.push(
-            codespan_reporting::diagnostic::Diagnostic::error()
-              .with_message("can only dereference inside an unsafe block"),
-          );
-        }
+  #[test]
+  fn lossy_int_cast() {
+    let mut type_context = TypeContext::new();
+    let cache = cache::Cache::new();
 
-        if !matches!(expr_type, ast::Type::Pointer(_)) {
-          context.diagnostics.push(
-            codespan_reporting::diagnostic::Diagnostic::error()
-              .with_message("can only dereference pointers"),
-          );
-        }
-      }
-      ast::OperatorKind::Not => {
-        if !expr_type.is(&ast::Type::Basic(ast::BasicType::Bool)) {
-          context.diagnostics.push(
-            codespan_reporting::diagnostic::Diagnostic::error()
-              .with_message("can only negate boolean expressions"),
-          );
-        }
-      }
-      ast::OperatorKind::SubtractOrNegate => {
-        // TODO: Include floats.
-        if !matches!(expr_type, ast::Type::Basic(ast::BasicType::Int(_))) {
-          // REVISE: Error message too similar to the boolean negation case.
-          context.diagnostics.push(
-            codespan_reporting::diagnostic::Diagnostic::error()
-              .with_message("can only negate integer or float expressions"),
-          );
-        }
-      }
-      ast::OperatorKind::AddressOf => {
-        // TODO: Implement.
-        return;
-      }
-      ast::OperatorKind::Cast => {
-        // REVIEW: What if it's an alias? This could be solved by flattening above.
-        if !matches!(expr_type, ast::Type::Basic(_))
-          || !matches!(self.cast_type.as_ref().unwrap(), ast::Type::Basic(_))
-        {
-          context.diagnostics.push(
-            codespan_reporting::diagnostic::Diagnostic::error()
-              .with_message("can only cast between primitive types"),
-          );
-        } else if expr_type.is(self.cast_type.as_ref().unwrap()) {
-          context.diagnostics.push(
-            codespan_reporting::diagnostic::Diagnostic::warning()
-              .with_message("redundant cast to the same type"),
-          );
-        }
-      }
-      _ => unreachable!(),
+    let cast_int = |from_size: ast::IntSize, to_size: ast::IntSize| ast::UnaryExpr {
+      operator: ast::OperatorKind::Cast,
+      expr: Box::new(ast::Node {
+        kind: ast::NodeKind::Literal(ast::Literal::Int(1, from_size)),
+        cached_type: None,
+      }),
+      cast_type: Some(ast::Type::Basic(ast::BasicType::Int(to_size))),
+      position: 0,
     };
+
+    cast_int(ast::IntSize::I8, ast::IntSize::I64).check(&mut type_context, &cache);
+    cast_int(ast::IntSize::U8, ast::IntSize::I16).check(&mut type_context, &cache);
+    assert!(type_context.diagnostics.is_empty());
+
+    cast_int(ast::IntSize::I64, ast::IntSize::I8).check(&mut type_context, &cache);
+    cast_int(ast::IntSize::I32, ast::IntSize::U64).check(&mut type_context, &cache);
+    cast_int(ast::IntSize::U32, ast::IntSize::I32).check(&mut type_context, &cache);
+    assert_eq!(3, type_context.diagnostics.len());
   }
-}
 
-impl Check for ast::Enum {
-  // REVIEW: Isn't there a need for its variants to be considered integer types?
-}
+  #[test]
+  fn pointer_sized_int_cast_target() {
+    let source_code = "func f(a: Usize) -> U32:\n  return `U32 a\n";
+    let (diagnostics, _, _) = crate::mock::tests::check_source(source_code);
 
-impl Check for ast::AssignStmt {
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    // TODO: Need to unify the value and the target's type.
+    assert_eq!(1, diagnostics.len());
 
-    let assignee_type = self.assignee_expr.kind.infer_flatten_type(cache);
+    let (mut ast, mut cache) = crate::mock::tests::resolve_source(source_code);
 
-    if matches!(assignee_type, ast::Type::Reference(_)) {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error()
-          .with_message("can't assign to a reference; references cannot be reseated"),
-      );
+    cache.pointer_width = 32;
 
-      // REVIEW: We should continue gathering other diagnostics (ex. immutable)?
-      return;
-    }
+    let (diagnostics, _) = TypeContext::run(&mut ast, &mut cache);
 
-    // NOTE: References cannot be reseated/assigned-to, only pointers.
-    let is_pointer = matches!(assignee_type, ast::Type::Pointer(_));
+    assert!(diagnostics.is_empty());
+  }
 
-    // REVISE: This checks are superficial. They do not consider
-    // ... that expressions may be nested (ie. parentheses expr.).
-    let is_array_indexing = matches!(self.assignee_expr.kind, ast::NodeKind::IndexingExpr(_));
-    let is_variable_ref = matches!(self.assignee_expr.kind, ast::NodeKind::Reference(_));
+  #[test]
+  fn occurs_in() {
+    let mut type_context = TypeContext::new();
+    let first_index_id = 0;
+    let second_index_id = first_index_id + 1;
 
-    // FIXME: What if the member accessed is a method? Is that even possible?
-    // ... Maybe to disambiguate that specific case we'd need to add a check below.
-    let is_member_access = matches!(self.assignee_expr.kind, ast::NodeKind::MemberAccess(_));
+    type_context
+      .substitutions
+      .insert(first_index_id, ast::Type::Variable(first_index_id));
 
-    // TODO: Missing member access (struct fields) support.
-    // NOTE: The assignee expression may only be an expression of type `Pointer`
-    // or `Reference`, a variable reference, or an array indexing.
-    if !is_pointer && !is_variable_ref && !is_array_indexing && !is_member_access {
-      context.diagnostics.push(
-          codespan_reporting::diagnostic::Diagnostic::error()
-            .with_message("assignee must be an expression of pointer or reference type, a member access expression, a variable reference, or an array indexing expression"),
-        );
-    } else if is_variable_ref {
-      // If the assignee is a variable reference, ensure that the variable is mutable.
-      match &self.assignee_expr.kind {
-        ast::NodeKind::Reference(variable_ref) => {
-          let binding_node = cache.force_get(&variable_ref.pattern.target_id.unwrap());
+    type_context
+      .substitutions
+      .insert(second_index_id, ast::Type::Unit);
 
-          match binding_node {
-            ast::NodeKind::BindingStmt(binding)
-              if binding.modifier != ast::BindingModifier::Mutable =>
-            {
-              context.diagnostics.push(
-                codespan_reporting::diagnostic::Diagnostic::error()
-                  .with_message("assignee is immutable"),
-              );
-            }
-            // TODO: Parameters should be immutable by default.
-            _ => {}
-          };
-        }
-        _ => unreachable!(),
-      };
-    }
+    let subject_type_variable = ast::Type::Variable(first_index_id);
 
-    // REVIEW: should this checks be placed before or after?
-    self.assignee_expr.kind.check(context, cache);
-    self.value.kind.check(context, cache);
+    assert!(type_context.occurs_in(first_index_id, &subject_type_variable));
+    assert!(!type_context.occurs_in(second_index_id, &subject_type_variable));
+    assert!(!type_context.occurs_in(first_index_id, &ast::Type::Unit));
   }
-}
 
-impl Check for ast::ContinueStmt {
-  fn check(&self, context: &mut TypeContext, _cache: &cache::Cache) {
-    if !context.in_loop {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error()
-          .with_message("continue statement may only occur inside loops"),
-      );
-    }
+  #[test]
+  fn create_type_variable() {
+    let mut type_context = TypeContext::new();
+
+    assert_eq!(type_context.create_type_variable(), ast::Type::Variable(0));
+    assert_eq!(1, type_context.substitutions.len());
   }
-}
 
-impl Check for ast::IndexingExpr {
-  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
-    let target_array = cache.force_get(&self.target_id.unwrap());
-    let array_type = target_array.infer_flatten_type(cache);
+  #[test]
+  fn solve_constraints() {
+    let mut type_context = TypeContext::new();
+    let bool_type = ast::Type::Basic(ast::BasicType::Bool);
 
-    // TODO: In the future, add support for when indexing strings.
-    let element_type = match array_type {
-      ast::Type::Array(element_type, _) => element_type.as_ref().clone(),
-      _ => unreachable!(),
-    };
+    type_context.constraints.push((
+      ast::Type::Variable(0),
+      ast::Type::Variable(1),
+      TypeConstrainKind::Equality,
+      String::from("first"),
+      None,
+    ));
+
+    type_context.constraints.push((
+      ast::Type::Variable(1),
+      bool_type.clone(),
+      TypeConstrainKind::Equality,
+      String::from("second"),
+      None,
+    ));
 
-    element_type
+    type_context.solve_constraints();
+    assert!(type_context.constraints.is_empty());
+    assert!(type_context.diagnostics.is_empty());
+    assert_eq!(bool_type, type_context.substitute(ast::Type::Variable(0)));
   }
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    self.index_expr.kind.check(context, cache);
+  #[test]
+  fn unify_mismatch() {
+    let mut type_context = TypeContext::new();
 
-    let index_expr_type = self.index_expr.kind.infer_flatten_type(cache);
+    type_context.unify(
+      &ast::Type::Basic(ast::BasicType::Bool),
+      &ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
+    );
 
-    let is_index_proper_type =
-      index_expr_type.is(&ast::Type::Basic(ast::BasicType::Int(ast::IntSize::U32)));
+    assert_eq!(1, type_context.diagnostics.len());
+  }
 
-    if !is_index_proper_type {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error()
-          .with_message("array index expression must be of type `U32`"),
-      );
+  #[test]
+  fn unify_type_constructors() {
+    let mut type_context = TypeContext::new();
+    let bool_type = ast::Type::Basic(ast::BasicType::Bool);
 
-      // REVIEW: Should we actually not continue?
-      // Can't continue if the index expression is not of the proper type.
-      return;
-    }
+    type_context.unify(
+      &ast::Type::Pointer(Box::new(ast::Type::Variable(0))),
+      &ast::Type::Pointer(Box::new(bool_type.clone())),
+    );
 
-    let target_array = cache.force_get(&self.target_id.unwrap());
-    let target_expr_type = target_array.infer_flatten_type(cache);
+    assert!(type_context.diagnostics.is_empty());
 
-    // REVIEW: Any way of avoiding nesting?
-    if let ast::Type::Array(_, length) = target_expr_type {
-      // If the index expression is not a constant expression, then
-      // this scope must fall under a bounds check for that index, and
-      // the length of the array.
-      if !self.index_expr.kind.is_constant_expr() {
-        // TODO: Support for dynamic index, but require a bounds check.
+    assert_eq!(
+      ast::Type::Optional(Box::new(bool_type)),
+      type_context.substitute(ast::Type::Optional(Box::new(ast::Type::Variable(0))))
+    );
+  }
 
-        context.diagnostics.push(
-          codespan_reporting::diagnostic::Diagnostic::error()
-            .with_message("array index expression must be a constant expression"),
-        );
-      } else {
-        // FIXME: Why we allow unary expressions on const expressions if we extract their value intact?
-        let index_expr_literal = crate::force_match!(
-          self
-            .index_expr
-            .kind
-            .find_node(|node| matches!(node, ast::NodeKind::Literal(..)))
-            .unwrap(),
-          ast::NodeKind::Literal
-        );
+  #[test]
+  fn unify_infinite_type() {
+    let mut type_context = TypeContext::new();
 
-        let index_expr_value = match index_expr_literal {
-          // NOTE: Safe cast because we know that the literal is of type `U32` at this point.
-          ast::Literal::Int(value, _) => *value as u32,
-          _ => unreachable!(),
-        };
+    type_context.unify(
+      &ast::Type::Variable(0),
+      &ast::Type::Pointer(Box::new(ast::Type::Variable(0))),
+    );
 
-        // NOTE: Because of its type, the index expression will never be lower than 0.
-        if index_expr_value >= length {
-          context.diagnostics.push(
-            codespan_reporting::diagnostic::Diagnostic::error()
-              .with_message("array index expression must be within the bounds of the array"),
-          );
-        }
-      }
-    } else {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error()
-          .with_message("can only index into arrays"),
-      );
-    }
+    assert_eq!(1, type_context.diagnostics.len());
+    assert!(!type_context.is_bound(0));
   }
-}
 
-impl Check for ast::StaticArrayValue {
-  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
-    // TODO: Temporary, until type-inference is implemented.
-    // We assume that the length is `0` if the explicit type is provided, otherwise
-    // the array type is determined by the first element.
-    let array_element_type = if let Some(explicit_type) = &self.explicit_type {
-      explicit_type.clone()
-    } else {
-      self.elements.first().unwrap().kind.infer_type(cache)
-    };
+  #[test]
+  fn substitute() {
+    let mut type_context = TypeContext::new();
 
-    // REVIEW: Is the length conversion safe?
-    ast::Type::Array(Box::new(array_element_type), self.elements.len() as u32)
+    assert_eq!(ast::Type::Unit, type_context.substitute(ast::Type::Unit));
+
+    let type_variable_id = 0;
+    let non_type_variable = ast::Type::Basic(ast::BasicType::Bool);
+
+    type_context
+      .substitutions
+      .insert(type_variable_id, non_type_variable.clone());
+
+    assert_eq!(
+      non_type_variable,
+      type_context.substitute(ast::Type::Variable(type_variable_id))
+    );
   }
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    let mut mixed_elements_flag = false;
+  #[test]
+  fn hindley_milner_type_inference() {
+    let mut type_context = TypeContext::new();
+    let mut cache = cache::Cache::new();
+    let type_variable_id = 0;
 
-    let expected_element_type = if let Some(explicit_type) = &self.explicit_type {
-      explicit_type.clone()
-    } else {
-      self.elements.first().unwrap().kind.infer_type(cache)
+    let mut binding_stmt = ast::BindingStmt {
+      name: String::from("a"),
+      ty: ast::Type::Variable(type_variable_id),
+      // TODO: Use `Mock` scaffolding.
+      value: Box::new(ast::Node {
+        kind: ast::NodeKind::Literal(ast::Literal::Bool(true)),
+        cached_type: None,
+      }),
+      cache_id: 0,
+      modifier: ast::BindingModifier::Immutable,
+      position: 0,
     };
 
-    // TODO: Skip the first element during iteration, as it is redundant.
-    for element in &self.elements {
-      // Report this error only once.
-      if !mixed_elements_flag && element.kind.infer_type(cache) != expected_element_type {
-        context.diagnostics.push(
-          codespan_reporting::diagnostic::Diagnostic::error()
-            .with_message("array elements must all be of the same type"),
-        );
+    // TODO: Use the empty array type test.
+    // TODO: Also, create a second test for inferring of parameter types.
 
-        mixed_elements_flag = true;
-      }
+    cache.symbols.insert(
+      binding_stmt.cache_id,
+      ast::NodeKind::BindingStmt(binding_stmt.clone()),
+    );
 
-      element.kind.check(context, cache);
-    }
-  }
-}
+    binding_stmt.report_constraints(&mut type_context, &cache);
+    type_context.solve_constraints();
+    binding_stmt.post_unification(&mut type_context, &mut cache);
+    assert_eq!(binding_stmt.ty, ast::Type::Basic(ast::BasicType::Bool));
 
-impl Check for ast::UnsafeExpr {
-  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
-    self.0.kind.infer_type(cache)
+    // The cached binding is updated in place.
+    assert!(matches!(
+      cache.force_get(&binding_stmt.cache_id),
+      ast::NodeKind::BindingStmt(ast::BindingStmt {
+        ty: ast::Type::Basic(ast::BasicType::Bool),
+        ..
+        position: 0,
+      })
+    ));
   }
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    // REVIEW: To avoid problems with nested cases, save a buffer here, then restore?
-    context.in_unsafe_block = true;
-    self.0.kind.check(context, cache);
-    context.in_unsafe_block = false;
-  }
-}
+  #[test]
+  fn annotated_binding_mismatch() {
+    let (diagnostics, _, _) = crate::mock::tests::check_source(
+      "func main(argc: Int, argv: *Str) -> Int:\n  let a: Bool = 1\n  return 0\n",
+    );
 
-impl Check for ast::ExternFunction {
-  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
-    TypeContext::infer_prototype_type(
-      &self.prototype,
-      self.prototype.return_type_annotation.clone(),
-    )
-  }
+    assert_eq!(1, diagnostics.len());
 
-  fn check(&self, context: &mut TypeContext, _cache: &cache::Cache) {
-    if self.prototype.accepts_instance {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error()
-          .with_message("extern functions cannot accept instances"),
-      );
-    }
+    assert_eq!(
+      "binding `a` is declared as `Bool`, but its value is of type `Int`",
+      diagnostics[0].message
+    );
   }
-}
 
-impl Check for ast::Parameter {
-  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
-    self.ty.clone()
-  }
-}
+  #[test]
+  fn function_return_mismatch() {
+    let (diagnostics, _, _) = crate::mock::tests::check_source("func f() -> Bool:\n  return 1\n");
 
-impl Check for ast::BlockExpr {
-  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
-    if let Some(yields_value) = &self.yields {
-      return yields_value.kind.infer_type(cache);
-    }
-    // FIXME: Ensure this logic is correct, and that it will work as expected.
-    // BUG: The function to infer return values uses this infer method, so function types CAN be never!
-    // If at least one statement evaluates to type never, the type of this
-    // block is also never.
-    else if self
-      .statements
-      .iter()
-      .any(|statement| statement.kind.infer_flatten_type(cache).is_a_never())
-    {
-      return ast::Type::Never;
-    }
+    assert_eq!(1, diagnostics.len());
 
-    ast::Type::Unit
-  }
+    assert_eq!(
+      "mismatched types: expected `Bool`, but found `Int`",
+      diagnostics[0].message
+    );
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    for statement in &self.statements {
-      statement.kind.check(context, cache);
-    }
-  }
-}
+    assert_eq!(
+      vec![String::from("in the return value of function `f`")],
+      diagnostics[0].notes
+    );
 
-impl Check for ast::Reference {
-  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
-    // REVIEW: We should have some sort of caching specifically applied to this construct.
-    cache
-      .force_get(&self.pattern.target_id.unwrap())
-      .infer_type(cache)
+    // The label points at the function's name.
+    assert_eq!(1, diagnostics[0].labels.len());
+    assert_eq!(5..6, diagnostics[0].labels[0].range);
   }
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    let target_type = cache
-      .force_get(&self.pattern.target_id.unwrap())
-      .infer_type(cache);
+  #[test]
+  fn folded_int_literal_overflow() {
+    let (diagnostics, _, _) = crate::mock::tests::check_source(
+      "func f():\n  let a: I8 = 100 + 27\n  let b: I8 = (100 + 100) - 100\n",
+    );
 
-    // FIXME: Investigate how this affects.
-    if target_type.is_a_meta() {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error()
-          .with_message("cannot reference a binding that has a meta type; it cannot be evaluated"),
-      );
-    }
-  }
-}
+    assert_eq!(1, diagnostics.len());
 
-impl Check for ast::Literal {
-  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
-    ast::Type::Basic(match self {
-      ast::Literal::Bool(_) => ast::BasicType::Bool,
-      ast::Literal::Char(_) => ast::BasicType::Char,
-      ast::Literal::Int(_, size) => ast::BasicType::Int(size.clone()),
-      ast::Literal::String(_) => ast::BasicType::String,
-      ast::Literal::Nullptr(ty) => return ast::Type::Pointer(Box::new(ty.clone())),
-    })
+    assert_eq!(
+      "constant arithmetic expression overflows type `I8`",
+      diagnostics[0].message
+    );
   }
-}
 
-impl Check for ast::IfExpr {
-  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
-    let then_expr_type = self.then_expr.kind.infer_flatten_type(cache);
+  #[test]
+  fn pointer_sized_int_literal_target() {
+    let source_code = "func f():\n  let a: Usize = 4294967296\n";
+    let (diagnostics, _, _) = crate::mock::tests::check_source(source_code);
 
-    // At least the two main branches must be present in order for a value
-    // to possibly evaluate.
-    if self.else_expr.is_none() {
-      return ast::Type::Unit;
-    }
+    assert!(diagnostics.is_empty());
 
-    // TODO: Take into consideration newly-added alternative branches.
+    let (mut ast, mut cache) = crate::mock::tests::resolve_source(source_code);
 
-    let else_expr = self.else_expr.as_ref().unwrap();
-    let else_expr_type = else_expr.kind.infer_flatten_type(cache);
+    cache.pointer_width = 32;
 
-    // Default to type unit if both branches are of incompatible types.
-    then_expr_type
-      .coercion(&else_expr_type)
-      .unwrap_or(ast::Type::Unit)
+    let (diagnostics, _) = TypeContext::run(&mut ast, &mut cache);
+
+    assert_eq!(1, diagnostics.len());
+
+    assert_eq!(
+      "integer literal `4294967296` does not fit into type `Usize`",
+      diagnostics[0].message
+    );
   }
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    let mut bounded_array_buffer = None;
-
-    self.condition.kind.traverse(|node| {
-      // if let ast::NodeKind::BinaryExpr(ast::BinaryExpr {
-      //   left: _,
-      //   right:
-      //     ast::Node {kind: ast::NodeKind::IntrinsicCall(ast::IntrinsicCall {
-      //       arguments,
-      //       kind: ast::IntrinsicKind::LengthOf,
-      //     })), cached_type: _},
-      //   operator: ast::OperatorKind::In,
-      // }) = &node.flatten()
-      // {
-      //   //
-      // }
-
-      if let ast::NodeKind::BinaryExpr(ast::BinaryExpr {
-        left: _,
-        right,
-        operator: ast::OperatorKind::In,
-      }) = &node.flatten()
-      {
-        if let ast::NodeKind::IntrinsicCall(ast::IntrinsicCall {
-          arguments,
-          kind: ast::IntrinsicKind::LengthOf,
-        }) = &right.kind.flatten()
-        {
-          if arguments.len() == 1 {
-            let first_argument = arguments[0].kind.flatten();
-
-            if let ast::NodeKind::Reference(ast::Reference {
-              pattern:
-                ast::Pattern {
-                  target_id: Some(target_id),
-                  ..
-                },
-              ..
-            }) = &first_argument
-            {
-              if matches!(
-                first_argument.infer_flatten_type(cache),
-                ast::Type::Array(..)
-              ) {
-                bounded_array_buffer = Some(target_id);
+  #[test]
+  fn int_literal_context() {
+    let mut type_context = TypeContext::new();
+    let cache = cache::Cache::new();
+    let u8_type = ast::Type::Basic(ast::BasicType::Int(ast::IntSize::U8));
 
-                return true;
-              }
-            }
-          }
-        }
-      }
+    let int_literal =
+      |value: u128| ast::NodeKind::Literal(ast::Literal::Int(value, ast::IntSize::I32));
 
-      false
-    });
+    let mut fitting_literal = int_literal(255);
 
-    let condition_type = self.condition.kind.infer_flatten_type(cache);
+    type_context.type_int_literal_expr(&mut fitting_literal, &u8_type, &cache);
+    assert!(type_context.diagnostics.is_empty());
+    assert_eq!(u8_type, fitting_literal.infer_type(&cache));
 
-    if !condition_type.is(&ast::Type::Basic(ast::BasicType::Bool)) {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error()
-          .with_message("if statement condition must evaluate to a boolean"),
-      );
-    }
+    let mut overflowing_literal = int_literal(256);
 
-    // TODO: Check for type-mismatch between branches (they may yield).
+    type_context.type_int_literal_expr(&mut overflowing_literal, &u8_type, &cache);
+    assert_eq!(1, type_context.diagnostics.len());
 
-    // REVIEW: Should the children be checked first?
-    self.condition.kind.check(context, cache);
+    let mut negative_literal = ast::NodeKind::UnaryExpr(ast::UnaryExpr {
+      operator: ast::OperatorKind::SubtractOrNegate,
+      expr: Box::new(ast::Node {
+        kind: int_literal(1),
+        cached_type: None,
+      }),
+      cast_type: None,
+      position: 0,
+    });
 
-    // TODO: Simplify.
-    // If the condition provides bound checks for a static array,
-    // mark the subject array as checked within the `then` expression
-    // only.
-    if let Some(bounded_array_id) = bounded_array_buffer {
-      context
-        .bound_checked_arrays
-        .insert(bounded_array_id.to_owned());
-    }
+    type_context.type_int_literal_expr(&mut negative_literal, &u8_type, &cache);
+    assert_eq!(2, type_context.diagnostics.len());
+  }
 
-    self.then_expr.kind.check(context, cache);
+  #[test]
+  fn return_int_literal_context() {
+    let (diagnostics, _, _) = crate::mock::tests::check_source("func f() -> U8:\n  return 5\n");
 
-    // If an array bound check was provided, remove it after the `then`
-    // expression has been checked.
-    if let Some(bounded_array_id) = bounded_array_buffer {
-      context.bound_checked_arrays.remove(bounded_array_id);
-    }
+    assert!(diagnostics.is_empty());
 
-    if let Some(else_block) = &self.else_expr {
-      else_block.kind.check(context, cache);
-    }
-  }
-}
+    let (diagnostics, _, _) = crate::mock::tests::check_source("func f() -> U8:\n  return 256\n");
 
-impl Check for ast::BinaryExpr {
-  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
-    match self.operator {
-      ast::OperatorKind::LessThan
-      | ast::OperatorKind::GreaterThan
-      | ast::OperatorKind::Equality
-      | ast::OperatorKind::And
-      | ast::OperatorKind::Or
-      | ast::OperatorKind::Nand
-      | ast::OperatorKind::Nor
-      | ast::OperatorKind::Xor
-      | ast::OperatorKind::In => ast::Type::Basic(ast::BasicType::Bool),
-      _ => self.left.kind.infer_type(cache),
-    }
+    assert_eq!(1, diagnostics.len());
+
+    assert_eq!(
+      "integer literal `256` does not fit into type `U8`",
+      diagnostics[0].message
+    );
   }
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    let left_type = self.left.kind.infer_flatten_type(cache);
-    let right_type = self.right.kind.infer_flatten_type(cache);
+  #[test]
+  fn yield_int_literal_context() {
+    let (diagnostics, _, _) =
+      crate::mock::tests::check_source("func f(a: Bool) -> U8:\n  yield if a: 1 else: 2\n");
 
-    // TODO: Also add checks for when using operators with wrong values (ex. less-than or greater-than comparison of booleans).
+    assert!(diagnostics.is_empty());
 
-    if !left_type.is(&right_type) {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error()
-          .with_message("binary expression operands must be the same type"),
-      );
+    let (diagnostics, _, _) =
+      crate::mock::tests::check_source("func f(a: Bool) -> U8:\n  yield if a: 1 else: 256\n");
 
-      return;
-    }
+    assert_eq!(1, diagnostics.len());
 
-    // REVIEW: Check for mixed operators that don't make sense (ex. addition, then a comparison operator)?
+    assert_eq!(
+      "integer literal `256` does not fit into type `U8`",
+      diagnostics[0].message
+    );
+  }
 
-    // NOTE: By this point, it is assumed that both operands are of the same type.
-    match self.operator {
-      ast::OperatorKind::Add
-      | ast::OperatorKind::SubtractOrNegate
-      | ast::OperatorKind::MultiplyOrDereference
-      | ast::OperatorKind::Divide
-      | ast::OperatorKind::LessThan
-      | ast::OperatorKind::GreaterThan => {
-        // REVIEW: What about floats?
-        if !matches!(left_type, ast::Type::Basic(ast::BasicType::Int(_))) {
-          context.diagnostics.push(
-            codespan_reporting::diagnostic::Diagnostic::error()
-              .with_message("binary expression operands must be both integers"),
-          );
-        }
-      }
-      // TODO: Equality operator, and others? Implement.
-      _ => {}
-    };
+  #[test]
+  fn array_element_int_literal_context() {
+    let (diagnostics, _, _) =
+      crate::mock::tests::check_source("func f():\n  let a: [U8, 2] = [1, 2]\n");
 
-    self.left.kind.check(context, cache);
-    self.right.kind.check(context, cache);
-  }
-}
+    assert!(diagnostics.is_empty());
 
-impl Check for ast::BreakStmt {
-  fn check(&self, context: &mut TypeContext, _cache: &cache::Cache) {
-    if !context.in_loop {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error()
-          .with_message("break statement may only occur inside loops"),
-      );
-    }
-  }
-}
+    let (diagnostics, _, _) =
+      crate::mock::tests::check_source("func f():\n  let a: [U8, 2] = [1, 256]\n");
 
-impl Check for ast::InlineExprStmt {
-  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
-    self.expr.kind.infer_type(cache)
-  }
+    assert_eq!(1, diagnostics.len());
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    self.expr.kind.check(context, cache);
+    assert_eq!(
+      "integer literal `256` does not fit into type `U8`",
+      diagnostics[0].message
+    );
   }
-}
 
-impl Check for ast::BindingStmt {
-  // BUG: This causes a bug where the string literal is not accessed (left as `i8**`). The let-statement didn't have a type before.
-  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
-    self.value.kind.infer_type(cache)
-  }
+  #[test]
+  fn panic_intrinsic() {
+    let cache = cache::Cache::new();
+    let mut type_context = TypeContext::new();
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    let value_type = self.value.kind.infer_type(cache);
-    let ty = self.infer_type(cache);
+    let panic_call = |argument: ast::Literal| ast::IntrinsicCall {
+      kind: ast::IntrinsicKind::Panic,
+      type_arguments: Vec::new(),
+      field_name: None,
+      arguments: vec![ast::Node {
+        kind: ast::NodeKind::Literal(argument),
+        cached_type: None,
+      }],
+      position: 0,
+    };
 
-    // FIXME: This is redundant. The same type is being compared!
-    if !ty.flat_is(&value_type, cache) {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
-          "variable declaration of `{}` value and type mismatch",
-          self.name
-        )),
-      );
-    }
+    let valid_panic_call = panic_call(ast::Literal::String(String::from("oops")));
 
-    self.value.kind.check(context, cache);
+    assert_eq!(ast::Type::Never, valid_panic_call.infer_type(&cache));
+    valid_panic_call.check(&mut type_context, &cache);
+    assert!(type_context.diagnostics.is_empty());
+
+    panic_call(ast::Literal::Bool(true)).check(&mut type_context, &cache);
+    assert_eq!(1, type_context.diagnostics.len());
   }
 
-  fn report_constraints(&mut self, context: &mut TypeContext, cache: &cache::Cache) {
-    // TODO: Abstract this to a method that accepts a type and a list of constraints.
-    // ... This way it can be reused for other implementations.
-    if !matches!(self.ty, ast::Type::Variable(_)) {
-      return;
-    }
+  #[test]
+  fn split_format_string() {
+    assert_eq!(
+      Ok(vec![String::from("a = "), String::from(", {b}")]),
+      TypeContext::split_format_string("a = {}, {{b}}")
+    );
 
-    context.constraints.push((
-      self.ty.clone(),
-      self.value.kind.infer_type(cache),
-      TypeConstrainKind::Equality,
-    ));
+    assert_eq!(
+      Ok(vec![String::from("none")]),
+      TypeContext::split_format_string("none")
+    );
+
+    assert!(TypeContext::split_format_string("{").is_err());
+    assert!(TypeContext::split_format_string("}").is_err());
+    assert!(TypeContext::split_format_string("{x}").is_err());
   }
 
-  fn post_unification(&mut self, context: &mut TypeContext, _cache: &cache::Cache) {
-    let variable_type_id = match self.ty {
-      ast::Type::Variable(ref id) => id,
-      _ => return,
+  #[test]
+  fn print_intrinsic() {
+    let cache = cache::Cache::new();
+
+    let literal_node = |literal: ast::Literal| ast::Node {
+      kind: ast::NodeKind::Literal(literal),
+      cached_type: None,
     };
 
-    // REVIEW: What if there was no substitution defined? Unsafe unwrap?
-    // ... Or maybe this is the part where we report diagnostics in this implementation?
-    // ... Bingo! The `unify` method is where the diagnostics are reported.
-    self.ty = context.substitutions.get(variable_type_id).unwrap().clone();
-  }
-}
+    let print_call = |arguments: Vec<ast::Node>| ast::IntrinsicCall {
+      kind: ast::IntrinsicKind::Println,
+      type_arguments: Vec::new(),
+      field_name: None,
+      arguments,
+      position: 0,
+    };
 
-impl Check for ast::ReturnStmt {
-  // FIXME: This implies that the return statement may be used as an expression,
-  // ... but that's currently not possible. Instead, this type is currently solely
-  // ... used to determine if all paths return a value?
-  fn infer_type(&self, _cache: &cache::Cache) -> ast::Type {
-    ast::Type::Never
-  }
+    let format_node = |template: &str| literal_node(ast::Literal::String(String::from(template)));
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    let current_function_node = cache.force_get(&context.current_function_id.unwrap());
-    let mut name = None;
+    let mut type_context = TypeContext::new();
 
-    let return_type = TypeContext::infer_return_value_type(
-      match &current_function_node {
-        ast::NodeKind::Function(function) => {
-          name = Some(function.name.clone());
+    print_call(vec![
+      format_node("{} and {}"),
+      literal_node(ast::Literal::Bool(true)),
+      literal_node(ast::Literal::Int(1, ast::IntSize::U8)),
+    ])
+    .check(&mut type_context, &cache);
 
-          &function.body
-        }
-        ast::NodeKind::Closure(closure) => &closure.body,
-        _ => unreachable!(),
-      },
-      cache,
-    )
-    .flatten(cache);
+    assert!(type_context.diagnostics.is_empty());
 
-    // REVISE: Whether a function returns is already checked. Limit this to comparing the types only.
-    if !return_type.is_a_unit() && self.value.is_none() {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error()
-          .with_message("return statement must return a value"),
-      );
-    } else if return_type.is_a_unit() && self.value.is_some() {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error()
-          .with_message("return statement must not return a value"),
-      );
+    // Mismatched placeholder count.
+    print_call(vec![
+      format_node("{} and {}"),
+      literal_node(ast::Literal::Bool(true)),
+    ])
+    .check(&mut type_context, &cache);
 
-      // REVIEW: Returning at this point. Is this okay?
-      return;
-    }
+    assert_eq!(1, type_context.diagnostics.len());
 
-    if let Some(value) = &self.value {
-      let value_type = value.kind.infer_flatten_type(cache);
+    // Unsupported type.
+    print_call(vec![
+      format_node("{}"),
+      literal_node(ast::Literal::Int(1, ast::IntSize::I128)),
+    ])
+    .check(&mut type_context, &cache);
 
-      if !return_type.is(&value_type) {
-        context.diagnostics.push(
-          codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
-            "return statement value and prototype return type mismatch for {}",
-            // REVISE: Change the actual name to this on its initialization.
-            if let Some(name) = name {
-              format!("function `{}`", name)
-            } else {
-              "closure".to_string()
-            }
-          )),
-        );
+    assert_eq!(2, type_context.diagnostics.len());
+  }
+
+  #[test]
+  fn constant_intrinsics() {
+    let (_, ast, cache) = crate::mock::tests::check_source(
+      "func f():\n  let a = [1, 2]\n  let length = ?length_of(a)\n  let size = ?sizeof[Int]\n",
+    );
+
+    let mut constants = Vec::new();
+
+    ast.first().unwrap().kind.traverse(|node| {
+      if let ast::NodeKind::BindingStmt(binding_stmt) = node {
+        constants.push((
+          binding_stmt.name.as_str(),
+          binding_stmt.value.kind.is_constant_expr(&cache),
+          TypeContext::evaluate_constant(&binding_stmt.value.kind, &cache),
+        ));
       }
 
-      value.kind.check(context, cache);
-    }
-  }
-}
+      true
+    });
 
-impl Check for ast::Function {
-  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
-    // REVIEW: Why not use annotated return type if defined?
-    TypeContext::infer_prototype_type(
-      &self.prototype,
-      TypeContext::infer_return_value_type(&self.body, cache),
-    )
+    // The size of types depends on the target, thus it is not folded.
+    assert_eq!(
+      vec![
+        ("a", false, None),
+        ("length", true, Some(ConstantValue::Int(2))),
+        ("size", false, None),
+      ],
+      constants
+    );
   }
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    let previous_function_key = context.current_function_id.clone();
+  #[test]
+  fn evaluate_constant() {
+    let cache = cache::Cache::new();
 
-    context.current_function_id = Some(self.cache_id);
+    let node = |kind: ast::NodeKind| {
+      Box::new(ast::Node {
+        kind,
+        cached_type: None,
+      })
+    };
 
-    if self.prototype.accepts_instance && !context.in_impl {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error()
-          .with_message("cannot accept instance in a non-impl function"),
-      );
-    }
+    let int_literal =
+      |value: u128| ast::NodeKind::Literal(ast::Literal::Int(value, ast::IntSize::I32));
 
-    // let return_type = self.body.infer_type(cache);
+    let binary_expr = |left: ast::NodeKind, operator: ast::OperatorKind, right: ast::NodeKind| {
+      ast::NodeKind::BinaryExpr(ast::BinaryExpr {
+        left: node(left),
+        right: node(right),
+        operator,
+      })
+    };
 
-    // if !TypeContext::compare(&return_type, &self.body.infer_type(cache), cache) {
-    //   context.diagnostics.push(
-    //     codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
-    //       "function body and prototype return type mismatch for function `{}`",
-    //       self.name
-    //     )),
-    //   );
-    // }
+    let sum = binary_expr(int_literal(2), ast::OperatorKind::Add, int_literal(3));
 
-    if self.prototype.is_variadic {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
-          "function `{}` cannot be variadic; only externs are allowed to be variadic",
-          self.name
-        )),
-      );
-    }
+    assert_eq!(
+      Some(ConstantValue::Int(5)),
+      TypeContext::evaluate_constant(&sum, &cache)
+    );
 
-    if self.name == lowering::MAIN_FUNCTION_NAME {
-      let main_function_type = ast::Type::Function(ast::FunctionType {
-        parameter_types: vec![
-          ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
-          ast::Type::Pointer(Box::new(ast::Type::Basic(ast::BasicType::String))),
-        ],
-        return_type: Box::new(ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32))),
-        is_variadic: false,
-        is_extern: false,
-      });
+    assert_eq!(
+      Some(ConstantValue::Bool(true)),
+      TypeContext::evaluate_constant(
+        &binary_expr(sum, ast::OperatorKind::Equality, int_literal(5)),
+        &cache
+      )
+    );
 
-      if self.infer_type(cache) != main_function_type {
-        context.diagnostics.push(
-          codespan_reporting::diagnostic::Diagnostic::error()
-            .with_message("the `main` function has an invalid signature")
-            .with_notes(vec![String::from("should accept a first parameter of type `Int`, a second one of type `*Str`, and the return type should be `Int`"), String::from("cannot be marked as variadic or extern")]),
-        );
-      }
-    }
+    assert_eq!(
+      None,
+      TypeContext::evaluate_constant(
+        &binary_expr(int_literal(1), ast::OperatorKind::Divide, int_literal(0)),
+        &cache
+      )
+    );
+  }
 
-    let return_type = TypeContext::infer_return_value_type(&self.body, cache);
+  #[test]
+  fn static_assert_intrinsic() {
+    let cache = cache::Cache::new();
+    let mut type_context = TypeContext::new();
 
-    if !return_type.is_a_unit() {
-      // If at least one statement's type evaluates to never, it
-      // means that all paths are covered, because code execution will
-      // always return at one point before reaching (or at) the end of the function.
-      let all_paths_covered = self
-        .body
-        .statements
-        .iter()
-        .any(|statement| statement.kind.infer_flatten_type(cache).is_a_never());
+    let static_assert_call = |condition: bool| ast::IntrinsicCall {
+      kind: ast::IntrinsicKind::StaticAssert,
+      type_arguments: Vec::new(),
+      field_name: None,
+      arguments: vec![
+        ast::Node {
+          kind: ast::NodeKind::Literal(ast::Literal::Bool(condition)),
+          cached_type: None,
+        },
+        ast::Node {
+          kind: ast::NodeKind::Literal(ast::Literal::String(String::from("oops"))),
+          cached_type: None,
+        },
+      ],
+      position: 0,
+    };
 
-      if !all_paths_covered {
-        context.diagnostics.push(
-          codespan_reporting::diagnostic::Diagnostic::error()
-            // TODO: Function name.
-            .with_message("not all paths return a value"),
-        );
-      }
-    }
+    static_assert_call(true).check(&mut type_context, &cache);
+    assert!(type_context.diagnostics.is_empty());
+    static_assert_call(false).check(&mut type_context, &cache);
+    assert_eq!(1, type_context.diagnostics.len());
+  }
+
+  #[test]
+  fn offsetof_intrinsic() {
+    let cache = cache::Cache::new();
+    let mut type_context = TypeContext::new();
+
+    let offsetof_call = |field_name: &str| ast::IntrinsicCall {
+      kind: ast::IntrinsicKind::Offsetof,
+      type_arguments: vec![ast::Type::Struct(ast::StructType {
+        cache_id: 0,
+        name: String::from("Point"),
+        fields: vec![(
+          String::from("x"),
+          ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
+        )],
+        field_defaults: vec![None],
+      })],
+      field_name: Some(String::from(field_name)),
+      arguments: Vec::new(),
+      position: 0,
+    };
+
+    assert_eq!(
+      ast::Type::Basic(ast::BasicType::Int(ast::IntSize::Usize)),
+      offsetof_call("x").infer_type(&cache)
+    );
 
-    self.prototype.check(context, cache);
-    self.body.check(context, cache);
-    context.current_function_id = previous_function_key;
+    offsetof_call("x").check(&mut type_context, &cache);
+    assert!(type_context.diagnostics.is_empty());
+    offsetof_call("y").check(&mut type_context, &cache);
+    assert_eq!(1, type_context.diagnostics.len());
   }
 
-  fn post_unification(&mut self, context: &mut TypeContext, _cache: &cache::Cache) {
-    // TODO: Parameters, etc.
+  #[test]
+  fn string_operations() {
+    let cache = cache::Cache::new();
+    let mut type_context = TypeContext::new();
+    let owned_string_type = ast::Type::Basic(ast::BasicType::OwnedString);
+
+    let string_node = |value: &str| ast::Node {
+      kind: ast::NodeKind::IntrinsicCall(ast::IntrinsicCall {
+        kind: ast::IntrinsicKind::ToString,
+        type_arguments: Vec::new(),
+        field_name: None,
+        arguments: vec![ast::Node {
+          kind: ast::NodeKind::Literal(ast::Literal::String(value.to_string())),
+          cached_type: None,
+        }],
+        position: 0,
+      }),
+      cached_type: None,
+    };
 
-    self.prototype.return_type_annotation =
-      context.substitute(self.prototype.return_type_annotation.clone());
-  }
-}
+    let binary_expr = |operator: ast::OperatorKind| ast::BinaryExpr {
+      left: Box::new(string_node("hello")),
+      right: Box::new(string_node("world")),
+      operator,
+    };
 
-impl Check for ast::CallExpr {
-  fn infer_type(&self, cache: &cache::Cache) -> ast::Type {
-    let callee_expr_type = self.callee_expr.kind.infer_type(cache);
+    let concat_expr = binary_expr(ast::OperatorKind::Add);
+    let compare_expr = binary_expr(ast::OperatorKind::LessThan);
 
-    match callee_expr_type {
-      ast::Type::Function(callable_type) => callable_type.return_type.as_ref().clone(),
-      _ => ast::Type::Error,
-    }
-  }
+    assert_eq!(owned_string_type, concat_expr.infer_type(&cache));
 
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    self.callee_expr.kind.check(context, cache);
+    assert_eq!(
+      ast::Type::Basic(ast::BasicType::Bool),
+      compare_expr.infer_type(&cache)
+    );
 
-    // REVIEW: Consider adopting a `expected` and `actual` API for diagnostics, when applicable.
-    // REVIEW: Need access to the current function?
+    concat_expr.check(&mut type_context, &cache);
+    compare_expr.check(&mut type_context, &cache);
+    assert!(type_context.diagnostics.is_empty());
 
-    // TODO: Isn't there a need to flatten this type?
-    let callee_expr_type = self.callee_expr.kind.infer_type(cache);
+    // Strings are not numbers.
+    binary_expr(ast::OperatorKind::MultiplyOrDereference).check(&mut type_context, &cache);
+    assert_eq!(1, type_context.diagnostics.len());
 
-    if !matches!(callee_expr_type, ast::Type::Function(_)) {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error()
-          .with_message("call expression's callee is not actually callable"),
-      );
+    // Strings are indexed by bytes.
+    let indexing_expr = ast::IndexingExpr {
+      target_expr: Box::new(string_node("hello")),
+      index_expr: Box::new(ast::Node {
+        kind: ast::NodeKind::Literal(ast::Literal::Int(0, ast::IntSize::U32)),
+        cached_type: None,
+      }),
+      position: 0,
+    };
 
-      // Cannot continue.
-      return;
-    }
+    assert_eq!(
+      ast::Type::Basic(ast::BasicType::Int(ast::IntSize::U8)),
+      indexing_expr.infer_type(&cache)
+    );
 
-    let callee_type = match callee_expr_type {
-      ast::Type::Function(callable_type) => callable_type,
-      _ => unreachable!(),
+    indexing_expr.check(&mut type_context, &cache);
+    assert_eq!(1, type_context.diagnostics.len());
+  }
+
+  #[test]
+  fn references() {
+    let mut type_context = TypeContext::new();
+    let mut cache = cache::Cache::new();
+    let binding_id = 0;
+    let int_type = ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32));
+
+    cache.symbols.insert(
+      binding_id,
+      ast::NodeKind::BindingStmt(ast::BindingStmt {
+        name: String::from("a"),
+        value: Box::new(ast::Node {
+          kind: ast::NodeKind::Literal(ast::Literal::Int(1, ast::IntSize::I32)),
+          cached_type: None,
+        }),
+        modifier: ast::BindingModifier::Immutable,
+        cache_id: binding_id,
+        ty: int_type.clone(),
+        position: 0,
+      }),
+    );
+
+    let unary_expr = |operator: ast::OperatorKind, expr: ast::NodeKind| ast::UnaryExpr {
+      operator,
+      expr: Box::new(ast::Node {
+        kind: expr,
+        cached_type: None,
+      }),
+      cast_type: None,
+      position: 0,
     };
 
-    // REVISE: Better, simpler way of doing this?
-    // let attributes;
+    let reference = ast::NodeKind::Reference(ast::Reference {
+      pattern: ast::Pattern {
+        qualifier: None,
+        base_name: String::from("a"),
+        sub_name: None,
+        symbol_kind: name_resolution::SymbolKind::Definition,
+        target_id: Some(binding_id),
+      },
+      position: 0,
+    });
 
-    if callee_type.is_extern && !context.in_unsafe_block {
-      context.diagnostics.push(
-        codespan_reporting::diagnostic::Diagnostic::error().with_message(format!(
-          "extern function call to `{}` may only occur inside an unsafe block",
-          // TODO: Need name.
-          "<pending>"
-        )),
-      );
-    }
+    let shared_reference = unary_expr(ast::OperatorKind::AddressOf, reference.clone());
 
-    // for attribute in attributes {
-    //   // TODO: Keep it simple for now, but later, we can improve the attribute system.
-    //   match attribute.name.as_str() {
-    //     "deprecated" => context
-    //       .diagnostic_builder
-    //       .warning(format!("function `{}` is deprecated", name)),
-    //     _ => context.diagnostic_builder.warning(format!(
-    //       "use of unrecognized attribute `{}`",
-    //       attribute.name
-    //     )),
-    //   };
-    // }
+    assert_eq!(
+      ast::Type::Reference(Box::new(int_type.clone())),
+      shared_reference.infer_type(&cache)
+    );
 
-    context.validate_fn_call(
-      self
-        .arguments
-        .iter()
-        // No need to flatten here.
-        .map(|argument| argument.kind.infer_type(cache))
-        .collect(),
-      callee_type,
-      cache,
+    shared_reference.check(&mut type_context, &cache);
+    assert!(type_context.diagnostics.is_empty());
+
+    // Reading through a reference doesn't require an unsafe block.
+    let dereference = unary_expr(
+      ast::OperatorKind::MultiplyOrDereference,
+      ast::NodeKind::UnaryExpr(shared_reference),
     );
 
-    for argument in &self.arguments {
-      argument.kind.check(context, cache);
-    }
-  }
-}
+    assert_eq!(int_type, dereference.infer_type(&cache));
+    dereference.check(&mut type_context, &cache);
+    assert!(type_context.diagnostics.is_empty());
 
-impl Check for ast::LoopStmt {
-  fn check(&self, context: &mut TypeContext, cache: &cache::Cache) {
-    if let Some(condition) = &self.condition {
-      let condition_type = condition.kind.infer_flatten_type(cache);
+    // Immutable bindings cannot be mutably referenced.
+    unary_expr(ast::OperatorKind::MutableAddressOf, reference).check(&mut type_context, &cache);
+    assert_eq!(1, type_context.diagnostics.len());
 
-      if !condition_type.is(&ast::Type::Basic(ast::BasicType::Bool)) {
-        context.diagnostics.push(
-          codespan_reporting::diagnostic::Diagnostic::error()
-            .with_message("loop condition must evaluate to a boolean"),
-        );
-      }
+    // Temporaries have no place to be referenced.
+    unary_expr(
+      ast::OperatorKind::AddressOf,
+      ast::NodeKind::Literal(ast::Literal::Int(1, ast::IntSize::I32)),
+    )
+    .check(&mut type_context, &cache);
 
-      condition.kind.check(context, cache);
-    }
+    assert_eq!(2, type_context.diagnostics.len());
 
-    // REVIEW: To avoid problems with nested cases, save a buffer here, then restore?
-    context.in_loop = true;
-    self.body.check(context, cache);
-    context.in_loop = false;
+    // Mutable references coerce into shared references and pointers, but not
+    // the other way around.
+    let mutable_reference_type = ast::Type::MutableReference(Box::new(int_type.clone()));
+
+    assert!(ast::Type::Reference(Box::new(int_type.clone())).is(&mutable_reference_type));
+    assert!(ast::Type::Pointer(Box::new(int_type.clone())).is(&mutable_reference_type));
+    assert!(!mutable_reference_type.is(&ast::Type::Reference(Box::new(int_type.clone()))));
+    assert!(
+      !ast::Type::Reference(Box::new(int_type.clone())).is(&ast::Type::Pointer(Box::new(int_type)))
+    );
   }
-}
 
-#[cfg(test)]
-mod tests {
-  use super::*;
+  #[test]
+  fn interpolation_expr() {
+    let cache = cache::Cache::new();
+    let mut type_context = TypeContext::new();
 
-  // TODO: Move this test to the `ast` file.
-  // #[test]
-  // fn is_null_pointer_type() {
-  //   let null_ptr_type = ast::Type::Pointer(Box::new(ast::Type::Basic(ast::BasicType::Null)));
+    let interpolation_expr = |hole: ast::Literal| ast::InterpolationExpr {
+      pieces: vec!["value: ".to_string(), String::new()],
+      holes: vec![ast::Node {
+        kind: ast::NodeKind::Literal(hole),
+        cached_type: None,
+      }],
+      position: 0,
+    };
 
-  //   assert!(TypeContext::is_null_pointer_type(&null_ptr_type));
-  //   assert!(!TypeContext::is_null_pointer_type(&ast::Type::Unit));
-  // }
+    let printable_expr = interpolation_expr(ast::Literal::Int(1, ast::IntSize::I32));
 
-  #[test]
-  fn proper_initial_values() {
-    let type_context = TypeContext::new();
+    assert_eq!(
+      ast::Type::Basic(ast::BasicType::OwnedString),
+      printable_expr.infer_type(&cache)
+    );
 
-    assert!(type_context.constraints.is_empty());
-    assert!(type_context.substitutions.is_empty());
+    printable_expr.check(&mut type_context, &cache);
     assert!(type_context.diagnostics.is_empty());
-    assert!(type_context.usings.is_empty());
-    assert!(type_context.current_function_id.is_none());
-    assert!(!type_context.in_impl);
-    assert!(!type_context.in_loop);
-    assert!(!type_context.in_unsafe_block);
+
+    // Arrays cannot be formatted.
+    let array_hole = ast::InterpolationExpr {
+      pieces: vec![String::new(), String::new()],
+      holes: vec![ast::Node {
+        kind: ast::NodeKind::StaticArrayValue(ast::StaticArrayValue {
+          elements: vec![ast::Node {
+            kind: ast::NodeKind::Literal(ast::Literal::Bool(true)),
+            cached_type: None,
+          }],
+          explicit_type: None,
+        }),
+        cached_type: None,
+      }],
+      position: 0,
+    };
+
+    array_hole.check(&mut type_context, &cache);
+    assert_eq!(1, type_context.diagnostics.len());
   }
 
   #[test]
-  fn occurs_in() {
+  fn allocation_intrinsics() {
+    let cache = cache::Cache::new();
     let mut type_context = TypeContext::new();
-    let first_index_id = 0;
-    let second_index_id = first_index_id + 1;
+    let int_type = ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32));
+
+    let mut alloc_array_call = ast::IntrinsicCall {
+      kind: ast::IntrinsicKind::AllocArray,
+      type_arguments: vec![int_type.clone()],
+      field_name: None,
+      arguments: vec![ast::Node {
+        kind: ast::NodeKind::Literal(ast::Literal::Int(4, ast::IntSize::I32)),
+        cached_type: None,
+      }],
+      position: 0,
+    };
 
-    type_context
-      .substitutions
-      .insert(first_index_id, ast::Type::Variable(first_index_id));
+    assert_eq!(
+      ast::Type::Pointer(Box::new(int_type.clone())),
+      alloc_array_call.infer_type(&cache)
+    );
 
-    type_context
-      .substitutions
-      .insert(second_index_id, ast::Type::Unit);
+    // The count is constrained to be of the size type.
+    alloc_array_call.report_constraints(&mut type_context, &cache);
+    alloc_array_call.check(&mut type_context, &cache);
+    assert!(type_context.diagnostics.is_empty());
 
-    let subject_type_variable = ast::Type::Variable(first_index_id);
+    let free_call = ast::IntrinsicCall {
+      kind: ast::IntrinsicKind::Free,
+      type_arguments: vec![int_type.clone()],
+      field_name: None,
+      arguments: vec![ast::Node {
+        kind: ast::NodeKind::Literal(ast::Literal::Nullptr(int_type)),
+        cached_type: None,
+      }],
+      position: 0,
+    };
 
-    assert!(type_context.occurs_in(first_index_id, &subject_type_variable));
-    assert!(!type_context.occurs_in(second_index_id, &subject_type_variable));
-    assert!(!type_context.occurs_in(first_index_id, &ast::Type::Unit));
+    free_call.check(&mut type_context, &cache);
+    assert_eq!(1, type_context.diagnostics.len());
+    type_context.in_unsafe_block = true;
+    free_call.check(&mut type_context, &cache);
+    assert_eq!(1, type_context.diagnostics.len());
   }
 
   #[test]
-  fn create_type_variable() {
+  fn copy_impl_requires_copyable_fields() {
     let mut type_context = TypeContext::new();
+    let mut cache = cache::Cache::new();
+    let struct_type_id = 0;
+
+    cache.symbols.insert(
+      struct_type_id,
+      ast::NodeKind::StructType(ast::StructType {
+        cache_id: struct_type_id,
+        name: String::from("Person"),
+        fields: vec![
+          (
+            String::from("age"),
+            ast::Type::Basic(ast::BasicType::Int(ast::IntSize::I32)),
+          ),
+          (
+            String::from("name"),
+            ast::Type::Basic(ast::BasicType::OwnedString),
+          ),
+        ],
+        field_defaults: vec![None, None],
+      }),
+    );
 
-    assert_eq!(type_context.create_type_variable(), ast::Type::Variable(0));
-    assert_eq!(1, type_context.substitutions.len());
+    cache.copy_types.insert(struct_type_id);
+
+    let pattern = |base_name: &str, target_id| ast::Pattern {
+      qualifier: None,
+      base_name: base_name.to_string(),
+      sub_name: None,
+      symbol_kind: name_resolution::SymbolKind::Type,
+      target_id,
+    };
+
+    let struct_impl = ast::StructImpl {
+      is_default: false,
+      target_struct_pattern: pattern("Person", Some(struct_type_id)),
+      trait_pattern: Some(pattern(name_resolution::COPY_TRAIT_NAME, None)),
+      member_methods: Vec::new(),
+      static_methods: Vec::new(),
+    };
+
+    struct_impl.check(&mut type_context, &cache);
+    assert_eq!(1, type_context.diagnostics.len());
+
+    assert_eq!(
+      "cannot implement `Copy` for `Person` because its field `name` of type `String` is not copyable",
+      type_context.diagnostics[0].message
+    );
   }
 
   #[test]
-  fn solve_constraints() {
-    let mut type_context = TypeContext::new();
+  fn user_trait_shadows_copy() {
+    let (diagnostics, _, cache) = crate::mock::tests::check_source(
+      "trait Copy:\n  func copied(this)\n\nstruct Point:\n  x: Int,\n\nimpl Copy for Point:\n  func copied(this):\n    pass\n",
+    );
+
+    // The implementation is checked against the user trait, and doesn't
+    // make the struct copyable.
+    assert!(diagnostics.is_empty());
+    assert!(cache.copy_types.is_empty());
+  }
 
-    // TODO: Add actual constraints to complete this test.
+  #[test]
+  fn binding_within_defer() {
+    let (diagnostics, _, _) =
+      crate::mock::tests::check_source("func f():\n  defer:\n    let a = 1\n");
 
-    type_context.solve_constraints();
-    assert!(type_context.constraints.is_empty());
+    assert_eq!(1, diagnostics.len());
+
+    assert_eq!(
+      "cannot declare binding `a` within the body of a `defer` statement",
+      diagnostics[0].message
+    );
   }
 
   #[test]
-  fn substitute() {
-    let mut type_context = TypeContext::new();
+  fn drop_value_held_by_another_value() {
+    for (source_code, message) in [
+      (
+        "struct Holder:\n  r: R,\n",
+        "field `r` of struct `Holder` cannot be of type `R`, which implements `Drop`",
+      ),
+      (
+        "func f():\n  let a = [new R { 1 }]\n",
+        "array elements cannot be of type `R`, which implements `Drop`",
+      ),
+      (
+        "func f():\n  let a = some(new R { 1 })\n",
+        "optional value cannot hold a value of type `R`, which implements `Drop`",
+      ),
+    ] {
+      let (diagnostics, _, _) = crate::mock::tests::check_source(&format!(
+        "struct R:\n  x: Int,\n\nimpl Drop for R:\n  func drop(this):\n    pass\n\n{}",
+        source_code
+      ));
+
+      assert_eq!(1, diagnostics.len());
+      assert_eq!(message, diagnostics[0].message);
+    }
+  }
 
-    assert_eq!(ast::Type::Unit, type_context.substitute(ast::Type::Unit));
+  #[test]
+  fn return_temporary_array_as_slice() {
+    let (diagnostics, _, _) =
+      crate::mock::tests::check_source("func f() -> []Int:\n  return [1, 2]\n");
 
-    let type_variable_id = 0;
-    let non_type_variable = ast::Type::Basic(ast::BasicType::Bool);
+    assert_eq!(1, diagnostics.len());
 
-    type_context
-      .substitutions
-      .insert(type_variable_id, non_type_variable.clone());
+    assert_eq!(
+      "cannot return a temporary static array as a slice",
+      diagnostics[0].message
+    );
+  }
+
+  #[test]
+  fn try_within_defer() {
+    let (diagnostics, _, _) = crate::mock::tests::check_source(
+      "func g() -> ?Int:\n  return none[Int]\n\nfunc f() -> ?Int:\n  defer: g()?\n\n  return some(1)\n",
+    );
+
+    assert_eq!(1, diagnostics.len());
 
     assert_eq!(
-      non_type_variable,
-      type_context.substitute(ast::Type::Variable(type_variable_id))
+      "cannot use the `?` operator within the body of a `defer` statement",
+      diagnostics[0].message
     );
   }
 
   #[test]
-  fn hindley_milner_type_inference() {
+  fn member_of_drop_temporary() {
+    let (diagnostics, _, _) = crate::mock::tests::check_source(
+      "struct R:\n  x: Int,\n\nimpl Drop for R:\n  func drop(this):\n    pass\n\nfunc f():\n  let x = new R { 1 }.x\n",
+    );
+
+    assert_eq!(1, diagnostics.len());
+
+    assert_eq!(
+      "cannot access `x` of a temporary `R` value, which implements `Drop`",
+      diagnostics[0].message
+    );
+  }
+
+  #[test]
+  fn return_within_defer() {
     let mut type_context = TypeContext::new();
     let cache = cache::Cache::new();
-    let type_variable_id = 0;
 
-    let mut binding_stmt = ast::BindingStmt {
-      name: String::from("a"),
-      ty: ast::Type::Variable(type_variable_id),
-      // TODO: Use `Mock` scaffolding.
-      value: Box::new(ast::Node {
-        kind: ast::NodeKind::Literal(ast::Literal::Bool(true)),
+    let defer_stmt = ast::DeferStmt {
+      body: Box::new(ast::Node {
+        kind: ast::NodeKind::ReturnStmt(ast::ReturnStmt { value: None }),
         cached_type: None,
       }),
-      cache_id: 0,
-      modifier: ast::BindingModifier::Immutable,
     };
 
-    // TODO: Use the empty array type test.
-    // TODO: Also, create a second test for inferring of parameter types.
+    defer_stmt.check(&mut type_context, &cache);
+    assert!(!type_context.in_defer);
+    assert_eq!(1, type_context.diagnostics.len());
 
-    binding_stmt.report_constraints(&mut type_context, &cache);
-    type_context.solve_constraints();
-    binding_stmt.post_unification(&mut type_context, &cache);
-    assert_eq!(binding_stmt.ty, ast::Type::Basic(ast::BasicType::Bool));
+    assert_eq!(
+      "cannot return from within the body of a `defer` statement",
+      type_context.diagnostics[0].message
+    );
+  }
+
+  #[test]
+  fn break_after_nested_loop() {
+    let (diagnostics, _, _) = crate::mock::tests::check_source(
+      "func f(numbers: []Int):\n  loop:\n    for number in numbers:\n      continue\n\n    break\n",
+    );
+
+    assert!(diagnostics.is_empty());
+  }
+
+  #[test]
+  fn allocator_example() {
+    let (diagnostics, _, _) =
+      crate::mock::tests::check_source(include_str!("../examples/allocator.ko"));
+
+    assert!(diagnostics.is_empty());
+  }
+
+  #[test]
+  fn trait_method_mismatch() {
+    let (diagnostics, _, _) = crate::mock::tests::check_source(
+      "trait Allocator:\n  func allocate(this, size: Usize) -> *U8\n\nstruct Heap:\n  allocations: Int,\n\nimpl Allocator for Heap:\n  func allocate(this, size: I32) -> *U8:\n    return nullptr[U8]\n",
+    );
+
+    assert_eq!(1, diagnostics.len());
+
+    assert_eq!(
+      "prototype of implementation method `allocate` for trait `Allocator` mismatch in type of parameter `size`",
+      diagnostics[0].message
+    );
   }
 
   // TODO: Add tests for `compare()`, `infer_and_flatten_type()`, `flatten_type()`, and others.
@@ -60,15 +60,20 @@ mod tests {
     assert!(name_resolver.run(&mut ast_map, &mut cache).is_empty());
 
     // Once symbols are resolved, we can proceed to the other phases.
-    for inner_ast in ast_map.values() {
+    for inner_ast in ast_map.values_mut() {
       // REVIEW: Can we mix linting with type-checking without any problems?
-      for top_level_node in inner_ast {
+      for top_level_node in inner_ast.iter() {
         top_level_node.lint(&cache, &mut lint_context);
       }
 
-      let check_result = gecko::type_system::TypeContext::run(inner_ast, &cache);
+      let check_result = gecko::type_system::TypeContext::run(inner_ast, &mut cache);
 
       assert!(check_result.0.is_empty());
+
+      let lifetime_check_diagnostics =
+        gecko::lifetime_check::LifetimeCheckContext::run(inner_ast, &cache);
+
+      assert!(lifetime_check_diagnostics.is_empty());
     }
 
     // FIXME: Temporarily commented out.
@@ -79,6 +84,7 @@ mod tests {
     for (qualifier, inner_ast) in &mut ast_map {
       // REVIEW: Must join package and module name for uniqueness?
       llvm_generator.module_name = qualifier.module_name.clone();
+      llvm_generator.set_source(source_file_contents);
 
       for top_level_node in inner_ast {
         top_level_node.lower(&mut llvm_generator, &mut cache, false);
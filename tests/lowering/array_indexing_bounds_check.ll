@@ -0,0 +1,22 @@
+define void @test() {
+entry:
+  %array.value = alloca [1 x i32], align 4
+  %array.init = getelementptr [1 x i32], [1 x i32]* %array.value, i32 0, i32 0
+  store i32 1, i32* %array.init, align 4
+  %access = load [1 x i32], [1 x i32]* %array.value, align 4
+  %var.a = alloca [1 x i32], align 4
+  store [1 x i32] %access, [1 x i32]* %var.a, align 4
+  %var.i = alloca i32, align 4
+  store i32 0, i32* %var.i, align 4
+  %access1 = load i32, i32* %var.i, align 4
+  %bounds_check.is_out_of_bounds = icmp uge i32 %access1, 1
+  br i1 %bounds_check.is_out_of_bounds, label %bounds_check.fail, label %bounds_check.ok
+
+bounds_check.fail:
+  call void @.0.panic(i8* getelementptr inbounds ([20 x i8], [20 x i8]* @panic.message, i32 0, i32 0), i8* getelementptr inbounds ([12 x i8], [12 x i8]* @panic.location, i32 0, i32 0))
+  unreachable
+
+bounds_check.ok:
+  %array.index.gep = getelementptr inbounds [1 x i32], [1 x i32]* %var.a, i32 0, i32 %access1
+  %access2 = load i32, i32* %array.index.gep, align 4
+}
@@ -0,0 +1,10 @@
+define void @test() {
+entry:
+  br i1 true, label %assert.ok, label %assert.fail
+
+assert.fail:
+  call void @.0.panic(i8* getelementptr inbounds ([5 x i8], [5 x i8]* @string_literal, i32 0, i32 0), i8* getelementptr inbounds ([12 x i8], [12 x i8]* @panic.location, i32 0, i32 0))
+  unreachable
+
+assert.ok:
+}
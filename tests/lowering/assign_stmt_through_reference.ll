@@ -0,0 +1,9 @@
+define void @test() {
+entry:
+  %var.a = alloca i32, align 4
+  store i32 1, i32* %var.a, align 4
+  %var.b = alloca i32*, align 8
+  store i32* %var.a, i32** %var.b, align 8
+  %access = load i32*, i32** %var.b, align 8
+  store i32 2, i32* %access, align 4
+}
@@ -1,4 +1,8 @@
 define void @test() {
 entry:
-  br label %entry
+  br label %loop.after
+
+loop.next:                                        ; No predecessors!
+
+loop.after:                                       ; preds = %entry
 }
@@ -0,0 +1,23 @@
+; ModuleID = 'test'
+source_filename = "test"
+
+define private void @.0.f() {
+fn.entry:
+  %var.a = alloca i32, align 4
+  store i32 1, i32* %var.a, align 4
+  %access = load i32, i32* %var.a, align 4
+  %closure.env = alloca { i32 }, align 8
+  %closure.env.capture = getelementptr inbounds { i32 }, { i32 }* %closure.env, i32 0, i32 0
+  store i32 %access, i32* %closure.env.capture, align 4
+  %closure.env.opaque = bitcast { i32 }* %closure.env to i8*
+  %closure = insertvalue { i32 (i8*)*, i8* } { i32 (i8*)* @.1.closure, i8* undef }, i8* %closure.env.opaque, 1
+  ret void
+}
+
+define private i32 @.1.closure(i8* %env) {
+closure.entry:
+  %env.cast = bitcast i8* %env to { i32 }*
+  %env.capture = getelementptr inbounds { i32 }, { i32 }* %env.cast, i32 0, i32 0
+  %access = load i32, i32* %env.capture, align 4
+  ret i32 %access
+}
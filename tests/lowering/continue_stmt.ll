@@ -1,4 +1,8 @@
 define void @test() {
 entry:
-  br label %entry
+  br label %loop.next
+
+loop.next:                                        ; preds = %entry
+
+loop.after:                                       ; No predecessors!
 }
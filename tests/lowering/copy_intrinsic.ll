@@ -0,0 +1,4 @@
+define void @test() {
+entry:
+  call void @llvm.memcpy.p0i8.p0i8.i64(i8* align 4 null, i8* align 4 null, i64 16, i1 false)
+}
@@ -0,0 +1,14 @@
+define void @test() {
+entry:
+  %access = load i64, i64* null, align 8
+  %block_size.fits = icmp ule i64 %access, 4611686018427387903
+  br i1 %block_size.fits, label %assert.ok, label %assert.fail
+
+assert.fail:
+  call void @.0.panic(i8* getelementptr inbounds ([26 x i8], [26 x i8]* @panic.message, i32 0, i32 0), i8* getelementptr inbounds ([12 x i8], [12 x i8]* @panic.location, i32 0, i32 0))
+  unreachable
+
+assert.ok:
+  %block.size = mul i64 %access, 4
+  call void @llvm.memcpy.p0i8.p0i8.i64(i8* align 4 null, i8* align 4 null, i64 %block.size, i1 false)
+}
@@ -0,0 +1,13 @@
+define void @test() {
+entry:
+  br label %loop.condition
+
+loop.condition:                                   ; preds = %loop.then, %entry
+  br i1 true, label %loop.then, label %loop.after
+
+loop.then:                                        ; preds = %loop.condition
+  call void @.0.g()
+  br label %loop.condition
+
+loop.after:                                       ; preds = %loop.condition
+}
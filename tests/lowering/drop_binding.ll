@@ -0,0 +1,33 @@
+define void @test() {
+entry:
+  %struct.R.alloca = alloca %.0.struct.R, align 8
+  %struct.alloca.field.gep = getelementptr inbounds %.0.struct.R, %.0.struct.R* %struct.R.alloca, i32 0, i32 0
+  store i32 1, i32* %struct.alloca.field.gep, align 4
+  %access = load %.0.struct.R, %.0.struct.R* %struct.R.alloca, align 4
+  %var.a = alloca %.0.struct.R, align 8
+  store %.0.struct.R %access, %.0.struct.R* %var.a, align 4
+  %drop.flag = alloca i1, align 1
+  store i1 true, i1* %drop.flag, align 1
+  %access1 = load %.0.struct.R, %.0.struct.R* %var.a, align 4
+  store i1 false, i1* %drop.flag, align 1
+  %var.b = alloca %.0.struct.R, align 8
+  store %.0.struct.R %access1, %.0.struct.R* %var.b, align 4
+  %drop.flag2 = alloca i1, align 1
+  store i1 true, i1* %drop.flag2, align 1
+  %access3 = load i1, i1* %drop.flag2, align 1
+  br i1 %access3, label %drop.then, label %drop.after
+
+drop.then:                                        ; preds = %entry
+  call void @.1.drop(%.0.struct.R* %var.b)
+  br label %drop.after
+
+drop.after:                                       ; preds = %drop.then, %entry
+  %access4 = load i1, i1* %drop.flag, align 1
+  br i1 %access4, label %drop.then5, label %drop.after6
+
+drop.then5:                                       ; preds = %drop.after
+  call void @.1.drop(%.0.struct.R* %var.a)
+  br label %drop.after6
+
+drop.after6:                                      ; preds = %drop.then5, %drop.after
+}
@@ -0,0 +1,5 @@
+define void @test() {
+entry:
+  %0 = bitcast i32* null to i8*
+  tail call void @free(i8* %0)
+}
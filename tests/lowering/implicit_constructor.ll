@@ -0,0 +1,21 @@
+; ModuleID = 'test'
+source_filename = "test"
+
+%.1.struct.Point = type { i32 }
+
+define private void @.0.f() {
+fn.entry:
+  %struct.Point.alloca = alloca %.1.struct.Point, align 8
+  %struct.alloca.field.gep = getelementptr inbounds %.1.struct.Point, %.1.struct.Point* %struct.Point.alloca, i32 0, i32 0
+  store i32 0, i32* %struct.alloca.field.gep, align 4
+  call void @.2.init(%.1.struct.Point* %struct.Point.alloca)
+  %access = load %.1.struct.Point, %.1.struct.Point* %struct.Point.alloca, align 4
+  %var.a = alloca %.1.struct.Point, align 8
+  store %.1.struct.Point %access, %.1.struct.Point* %var.a, align 4
+  ret void
+}
+
+define private void @.2.init(%.1.struct.Point* %0) {
+fn.entry:
+  ret void
+}
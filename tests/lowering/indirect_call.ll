@@ -0,0 +1,10 @@
+; ModuleID = 'test'
+source_filename = "test"
+
+define private i32 @.0.apply({ i32 (i8*, i32)*, i8* } %param.callback) {
+fn.entry:
+  %closure.env = extractvalue { i32 (i8*, i32)*, i8* } %param.callback, 1
+  %closure.function = extractvalue { i32 (i8*, i32)*, i8* } %param.callback, 0
+  %call = call i32 %closure.function(i8* %closure.env, i32 1)
+  ret i32 %call
+}
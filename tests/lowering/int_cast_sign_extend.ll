@@ -0,0 +1,7 @@
+define void @test() {
+entry:
+  %var.a = alloca i8, align 1
+  store i8 1, i8* %var.a, align 1
+  %access = load i8, i8* %var.a, align 1
+  %cast_op = sext i8 %access to i32
+}
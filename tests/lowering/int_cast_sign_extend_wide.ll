@@ -0,0 +1,7 @@
+define void @test() {
+entry:
+  %var.a = alloca i64, align 8
+  store i64 1, i64* %var.a, align 4
+  %access = load i64, i64* %var.a, align 4
+  %cast_op = sext i64 %access to i128
+}
@@ -0,0 +1,7 @@
+define void @test() {
+entry:
+  %var.a = alloca i32, align 4
+  store i32 1, i32* %var.a, align 4
+  %access = load i32, i32* %var.a, align 4
+  %cast_op = trunc i32 %access to i8
+}
@@ -0,0 +1,10 @@
+define void @test() {
+entry:
+  %var.a = alloca i8, align 1
+  store i8 1, i8* %var.a, align 1
+  %var.b = alloca i8, align 1
+  store i8 1, i8* %var.b, align 1
+  %access = load i8, i8* %var.a, align 1
+  %access1 = load i8, i8* %var.b, align 1
+  %int.divide_op = sdiv i8 %access, %access1
+}
@@ -0,0 +1,24 @@
+define void @test() {
+entry:
+  %call = call { i8*, i32, i32 } @.0.name()
+  %print.string.data = extractvalue { i8*, i32, i32 } %call, 0
+  %interpolation.length = call i32 (i8*, i64, i8*, ...) @snprintf(i8* null, i64 0, i8* getelementptr inbounds ([9 x i8], [9 x i8]* @interpolation.format, i32 0, i32 0), i8* %print.string.data)
+  %interpolation.is_formatted = icmp sge i32 %interpolation.length, 0
+  br i1 %interpolation.is_formatted, label %assert.ok, label %assert.fail
+
+assert.fail:                                      ; preds = %entry
+  call void @.1.panic(i8* getelementptr inbounds ([25 x i8], [25 x i8]* @panic.message, i32 0, i32 0), i8* getelementptr inbounds ([12 x i8], [12 x i8]* @panic.location, i32 0, i32 0))
+  unreachable
+
+assert.ok:                                        ; preds = %entry
+  %string.capacity = add i32 %interpolation.length, 1
+  %string.size = zext i32 %string.capacity to i64
+  %string.buffer = call i8* @malloc(i64 %string.size)
+  %string.capacity1 = add i32 %interpolation.length, 1
+  %interpolation.size = zext i32 %string.capacity1 to i64
+  %0 = call i32 (i8*, i64, i8*, ...) @snprintf(i8* %string.buffer, i64 %interpolation.size, i8* getelementptr inbounds ([9 x i8], [9 x i8]* @interpolation.format, i32 0, i32 0), i8* %print.string.data)
+  tail call void @free(i8* %print.string.data)
+  %string.data = insertvalue { i8*, i32, i32 } undef, i8* %string.buffer, 0
+  %string.length = insertvalue { i8*, i32, i32 } %string.data, i32 %interpolation.length, 1
+  %string.capacity2 = insertvalue { i8*, i32, i32 } %string.length, i32 %string.capacity1, 2
+}
@@ -0,0 +1,16 @@
+define void @test() {
+entry:
+  %array.value = alloca [1 x i32], align 4
+  %array.init = getelementptr [1 x i32], [1 x i32]* %array.value, i32 0, i32 0
+  store i32 1, i32* %array.init, align 4
+  %access = load [1 x i32], [1 x i32]* %array.value, align 4
+  %array.value1 = alloca [1 x [1 x i32]], align 4
+  %array.init2 = getelementptr [1 x [1 x i32]], [1 x [1 x i32]]* %array.value1, i32 0, i32 0
+  store [1 x i32] %access, [1 x i32]* %array.init2, align 4
+  %access3 = load [1 x [1 x i32]], [1 x [1 x i32]]* %array.value1, align 4
+  %var.a = alloca [1 x [1 x i32]], align 4
+  store [1 x [1 x i32]] %access3, [1 x [1 x i32]]* %var.a, align 4
+  %array.index.gep = getelementptr inbounds [1 x [1 x i32]], [1 x [1 x i32]]* %var.a, i32 0, i32 0
+  %array.index.gep4 = getelementptr inbounds [1 x i32], [1 x i32]* %array.index.gep, i32 0, i32 0
+  %access5 = load i32, i32* %array.index.gep4, align 4
+}
@@ -0,0 +1,11 @@
+define void @test() {
+entry:
+  %var.a = alloca i32*, align 8
+  store i32* null, i32** %var.a, align 8
+  %access = load i32*, i32** %var.a, align 8
+  %access1 = load i32*, i32** %var.a, align 8
+  %0 = ptrtoint i32* %access to i64
+  %1 = ptrtoint i32* %access1 to i64
+  %2 = sub i64 %0, %1
+  %pointer.difference_op = sdiv exact i64 %2, ptrtoint (i32* getelementptr (i32, i32* null, i32 1) to i64)
+}
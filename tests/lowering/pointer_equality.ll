@@ -0,0 +1,10 @@
+define void @test() {
+entry:
+  %var.a = alloca i32*, align 8
+  store i32* null, i32** %var.a, align 8
+  %access = load i32*, i32** %var.a, align 8
+  %pointer.address = ptrtoint i32* %access to i64
+  %access1 = load i32*, i32** %var.a, align 8
+  %pointer.address2 = ptrtoint i32* %access1 to i64
+  %int.eq_op = icmp eq i64 %pointer.address, %pointer.address2
+}
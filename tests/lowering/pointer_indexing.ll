@@ -0,0 +1,8 @@
+define void @test() {
+entry:
+  %var.a = alloca i32*, align 8
+  store i32* null, i32** %var.a, align 8
+  %access = load i32*, i32** %var.a, align 8
+  %pointer.index.gep = getelementptr inbounds i32, i32* %access, i32 1
+  %access1 = load i32, i32* %pointer.index.gep, align 4
+}
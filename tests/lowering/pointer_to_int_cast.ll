@@ -0,0 +1,7 @@
+define void @test() {
+entry:
+  %var.a = alloca i32*, align 8
+  store i32* null, i32** %var.a, align 8
+  %access = load i32*, i32** %var.a, align 8
+  %cast_op = ptrtoint i32* %access to i64
+}
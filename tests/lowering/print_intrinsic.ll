@@ -0,0 +1,4 @@
+define void @test() {
+entry:
+  %0 = call i32 (i8*, ...) @printf(i8* getelementptr inbounds ([11 x i8], [11 x i8]* @print.format, i32 0, i32 0), i8* getelementptr inbounds ([5 x i8], [5 x i8]* @print.true, i32 0, i32 0), i32 42)
+}
@@ -0,0 +1,9 @@
+; ModuleID = 'test'
+source_filename = "test"
+
+@table = external global [2 x i32]
+
+define private { i32*, i32 } @.0.f() {
+fn.entry:
+  ret { i32*, i32 } { i32* getelementptr inbounds ([2 x i32], [2 x i32]* @table, i32 0, i32 0), i32 2 }
+}
@@ -0,0 +1,13 @@
+define void @test() {
+entry:
+  %array.value = alloca [1 x i32], align 4
+  %array.init = getelementptr [1 x i32], [1 x i32]* %array.value, i32 0, i32 0
+  store i32 1, i32* %array.init, align 4
+  %access = load [1 x i32], [1 x i32]* %array.value, align 4
+  %array.value1 = alloca [1 x i32], align 4
+  store [1 x i32] %access, [1 x i32]* %array.value1, align 4
+  %array.decay = getelementptr inbounds [1 x i32], [1 x i32]* %array.value1, i32 0, i32 0
+  %slice.start = getelementptr inbounds i32, i32* %array.decay, i32 0
+  %slice.pointer = insertvalue { i32*, i32 } undef, i32* %slice.start, 0
+  %slice.length = insertvalue { i32*, i32 } %slice.pointer, i32 1, 1
+}
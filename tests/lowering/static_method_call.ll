@@ -0,0 +1,21 @@
+; ModuleID = 'test'
+source_filename = "test"
+
+%.1.struct.Point = type { i32 }
+
+define private void @.0.f() {
+fn.entry:
+  %call = call %.1.struct.Point @.2.origin()
+  %var.a = alloca %.1.struct.Point, align 8
+  store %.1.struct.Point %call, %.1.struct.Point* %var.a, align 4
+  ret void
+}
+
+define private %.1.struct.Point @.2.origin() {
+fn.entry:
+  %struct.Point.alloca = alloca %.1.struct.Point, align 8
+  %struct.alloca.field.gep = getelementptr inbounds %.1.struct.Point, %.1.struct.Point* %struct.Point.alloca, i32 0, i32 0
+  store i32 0, i32* %struct.alloca.field.gep, align 4
+  %access = load %.1.struct.Point, %.1.struct.Point* %struct.Point.alloca, align 4
+  ret %.1.struct.Point %access
+}
@@ -0,0 +1,99 @@
+; ModuleID = 'test'
+source_filename = "test"
+
+@string_literal = private unnamed_addr constant [2 x i8] c"a\00", align 1
+@string_literal.1 = private unnamed_addr constant [2 x i8] c"b\00", align 1
+
+define void @test() {
+entry:
+  %string.length = call i32 @string.length_of_str(i8* getelementptr inbounds ([2 x i8], [2 x i8]* @string_literal, i32 0, i32 0))
+  %string.from_str = call { i8*, i32, i32 } @string.from_bytes(i8* getelementptr inbounds ([2 x i8], [2 x i8]* @string_literal, i32 0, i32 0), i32 %string.length)
+  %string.length1 = call i32 @string.length_of_str(i8* getelementptr inbounds ([2 x i8], [2 x i8]* @string_literal.1, i32 0, i32 0))
+  %string.from_str2 = call { i8*, i32, i32 } @string.from_bytes(i8* getelementptr inbounds ([2 x i8], [2 x i8]* @string_literal.1, i32 0, i32 0), i32 %string.length1)
+  %string.order = call i32 @string.compare({ i8*, i32, i32 } %string.from_str, { i8*, i32, i32 } %string.from_str2)
+  %string.compare_op = icmp slt i32 %string.order, 0
+  %string.data = extractvalue { i8*, i32, i32 } %string.from_str, 0
+  tail call void @free(i8* %string.data)
+  %string.data3 = extractvalue { i8*, i32, i32 } %string.from_str2, 0
+  tail call void @free(i8* %string.data3)
+}
+
+define private i32 @string.length_of_str(i8* %0) {
+string.entry:
+  br label %string.scan
+
+string.scan:                                      ; preds = %string.scan, %string.entry
+  %string.index = phi i32 [ 0, %string.entry ], [ %string.next_index, %string.scan ]
+  %string.byte.gep = getelementptr inbounds i8, i8* %0, i32 %string.index
+  %string.byte = load i8, i8* %string.byte.gep, align 1
+  %string.is_terminator = icmp eq i8 %string.byte, 0
+  %string.next_index = add i32 %string.index, 1
+  br i1 %string.is_terminator, label %string.scan_done, label %string.scan
+
+string.scan_done:                                 ; preds = %string.scan
+  ret i32 %string.index
+}
+
+define private { i8*, i32, i32 } @string.from_bytes(i8* %0, i32 %1) {
+string.entry:
+  %string.capacity = add i32 %1, 1
+  %string.size = zext i32 %string.capacity to i64
+  %string.buffer = call i8* @malloc(i64 %string.size)
+  call void @llvm.memcpy.p0i8.p0i8.i32(i8* align 1 %string.buffer, i8* align 1 %0, i32 %1, i1 false)
+  %string.terminator = getelementptr inbounds i8, i8* %string.buffer, i32 %1
+  store i8 0, i8* %string.terminator, align 1
+  %string.capacity1 = add i32 %1, 1
+  %string.data = insertvalue { i8*, i32, i32 } undef, i8* %string.buffer, 0
+  %string.length = insertvalue { i8*, i32, i32 } %string.data, i32 %1, 1
+  %string.capacity2 = insertvalue { i8*, i32, i32 } %string.length, i32 %string.capacity1, 2
+  ret { i8*, i32, i32 } %string.capacity2
+}
+
+declare i8* @malloc(i64)
+
+; Function Attrs: argmemonly nofree nounwind willreturn
+declare void @llvm.memcpy.p0i8.p0i8.i32(i8* noalias nocapture writeonly, i8* noalias nocapture readonly, i32, i1 immarg) #0
+
+define private i32 @string.compare({ i8*, i32, i32 } %0, { i8*, i32, i32 } %1) {
+string.entry:
+  %string.data = extractvalue { i8*, i32, i32 } %0, 0
+  %string.length = extractvalue { i8*, i32, i32 } %0, 1
+  %string.capacity = extractvalue { i8*, i32, i32 } %0, 2
+  %string.data1 = extractvalue { i8*, i32, i32 } %1, 0
+  %string.length2 = extractvalue { i8*, i32, i32 } %1, 1
+  %string.capacity3 = extractvalue { i8*, i32, i32 } %1, 2
+  %string.is_shorter = icmp ult i32 %string.length, %string.length2
+  %string.is_longer = icmp ugt i32 %string.length, %string.length2
+  %string.common_length = select i1 %string.is_shorter, i32 %string.length, i32 %string.length2
+  %2 = zext i1 %string.is_longer to i32
+  %3 = zext i1 %string.is_shorter to i32
+  %string.length_order = sub i32 %2, %3
+  br label %string.compare
+
+string.compare:                                   ; preds = %string.compare_bytes, %string.entry
+  %string.index = phi i32 [ 0, %string.entry ], [ %string.next_index, %string.compare_bytes ]
+  %string.is_done = icmp eq i32 %string.index, %string.common_length
+  br i1 %string.is_done, label %string.common_equal, label %string.compare_bytes
+
+string.compare_bytes:                             ; preds = %string.compare
+  %string.left_byte.gep = getelementptr inbounds i8, i8* %string.data, i32 %string.index
+  %string.left_byte = load i8, i8* %string.left_byte.gep, align 1
+  %string.right_byte.gep = getelementptr inbounds i8, i8* %string.data1, i32 %string.index
+  %string.right_byte = load i8, i8* %string.right_byte.gep, align 1
+  %string.is_byte_equal = icmp eq i8 %string.left_byte, %string.right_byte
+  %string.next_index = add i32 %string.index, 1
+  br i1 %string.is_byte_equal, label %string.compare, label %string.bytes_differ
+
+string.bytes_differ:                              ; preds = %string.compare_bytes
+  %4 = zext i8 %string.left_byte to i32
+  %5 = zext i8 %string.right_byte to i32
+  %string.bytes_order = sub i32 %4, %5
+  ret i32 %string.bytes_order
+
+string.common_equal:                              ; preds = %string.compare
+  ret i32 %string.length_order
+}
+
+declare void @free(i8*)
+
+attributes #0 = { argmemonly nofree nounwind willreturn }
@@ -0,0 +1,82 @@
+; ModuleID = 'test'
+source_filename = "test"
+
+@string_literal = private unnamed_addr constant [2 x i8] c"a\00", align 1
+@string_literal.1 = private unnamed_addr constant [2 x i8] c"b\00", align 1
+
+define void @test() {
+entry:
+  %string.length = call i32 @string.length_of_str(i8* getelementptr inbounds ([2 x i8], [2 x i8]* @string_literal, i32 0, i32 0))
+  %string.from_str = call { i8*, i32, i32 } @string.from_bytes(i8* getelementptr inbounds ([2 x i8], [2 x i8]* @string_literal, i32 0, i32 0), i32 %string.length)
+  %string.length1 = call i32 @string.length_of_str(i8* getelementptr inbounds ([2 x i8], [2 x i8]* @string_literal.1, i32 0, i32 0))
+  %string.from_str2 = call { i8*, i32, i32 } @string.from_bytes(i8* getelementptr inbounds ([2 x i8], [2 x i8]* @string_literal.1, i32 0, i32 0), i32 %string.length1)
+  %string.concat_op = call { i8*, i32, i32 } @string.concat({ i8*, i32, i32 } %string.from_str, { i8*, i32, i32 } %string.from_str2)
+  %string.data = extractvalue { i8*, i32, i32 } %string.from_str, 0
+  tail call void @free(i8* %string.data)
+  %string.data3 = extractvalue { i8*, i32, i32 } %string.from_str2, 0
+  tail call void @free(i8* %string.data3)
+}
+
+define private i32 @string.length_of_str(i8* %0) {
+string.entry:
+  br label %string.scan
+
+string.scan:                                      ; preds = %string.scan, %string.entry
+  %string.index = phi i32 [ 0, %string.entry ], [ %string.next_index, %string.scan ]
+  %string.byte.gep = getelementptr inbounds i8, i8* %0, i32 %string.index
+  %string.byte = load i8, i8* %string.byte.gep, align 1
+  %string.is_terminator = icmp eq i8 %string.byte, 0
+  %string.next_index = add i32 %string.index, 1
+  br i1 %string.is_terminator, label %string.scan_done, label %string.scan
+
+string.scan_done:                                 ; preds = %string.scan
+  ret i32 %string.index
+}
+
+define private { i8*, i32, i32 } @string.from_bytes(i8* %0, i32 %1) {
+string.entry:
+  %string.capacity = add i32 %1, 1
+  %string.size = zext i32 %string.capacity to i64
+  %string.buffer = call i8* @malloc(i64 %string.size)
+  call void @llvm.memcpy.p0i8.p0i8.i32(i8* align 1 %string.buffer, i8* align 1 %0, i32 %1, i1 false)
+  %string.terminator = getelementptr inbounds i8, i8* %string.buffer, i32 %1
+  store i8 0, i8* %string.terminator, align 1
+  %string.capacity1 = add i32 %1, 1
+  %string.data = insertvalue { i8*, i32, i32 } undef, i8* %string.buffer, 0
+  %string.length = insertvalue { i8*, i32, i32 } %string.data, i32 %1, 1
+  %string.capacity2 = insertvalue { i8*, i32, i32 } %string.length, i32 %string.capacity1, 2
+  ret { i8*, i32, i32 } %string.capacity2
+}
+
+declare i8* @malloc(i64)
+
+; Function Attrs: argmemonly nofree nounwind willreturn
+declare void @llvm.memcpy.p0i8.p0i8.i32(i8* noalias nocapture writeonly, i8* noalias nocapture readonly, i32, i1 immarg) #0
+
+define private { i8*, i32, i32 } @string.concat({ i8*, i32, i32 } %0, { i8*, i32, i32 } %1) {
+string.entry:
+  %string.data = extractvalue { i8*, i32, i32 } %0, 0
+  %string.length = extractvalue { i8*, i32, i32 } %0, 1
+  %string.capacity = extractvalue { i8*, i32, i32 } %0, 2
+  %string.data1 = extractvalue { i8*, i32, i32 } %1, 0
+  %string.length2 = extractvalue { i8*, i32, i32 } %1, 1
+  %string.capacity3 = extractvalue { i8*, i32, i32 } %1, 2
+  %string.length4 = add i32 %string.length, %string.length2
+  %string.capacity5 = add i32 %string.length4, 1
+  %string.size = zext i32 %string.capacity5 to i64
+  %string.buffer = call i8* @malloc(i64 %string.size)
+  call void @llvm.memcpy.p0i8.p0i8.i32(i8* align 1 %string.buffer, i8* align 1 %string.data, i32 %string.length, i1 false)
+  %string.tail = getelementptr inbounds i8, i8* %string.buffer, i32 %string.length
+  call void @llvm.memcpy.p0i8.p0i8.i32(i8* align 1 %string.tail, i8* align 1 %string.data1, i32 %string.length2, i1 false)
+  %string.terminator = getelementptr inbounds i8, i8* %string.buffer, i32 %string.length4
+  store i8 0, i8* %string.terminator, align 1
+  %string.capacity6 = add i32 %string.length4, 1
+  %string.data7 = insertvalue { i8*, i32, i32 } undef, i8* %string.buffer, 0
+  %string.length8 = insertvalue { i8*, i32, i32 } %string.data7, i32 %string.length4, 1
+  %string.capacity9 = insertvalue { i8*, i32, i32 } %string.length8, i32 %string.capacity6, 2
+  ret { i8*, i32, i32 } %string.capacity9
+}
+
+declare void @free(i8*)
+
+attributes #0 = { argmemonly nofree nounwind willreturn }
@@ -0,0 +1,5 @@
+define void @test() {
+entry:
+  call void @.0.panic(i8* getelementptr inbounds ([16 x i8], [16 x i8]* @panic.message, i32 0, i32 0), i8* getelementptr inbounds ([12 x i8], [12 x i8]* @panic.location, i32 0, i32 0))
+  unreachable
+}
@@ -1,6 +1,10 @@
 ; ModuleID = 'binding'
 source_filename = "binding"
 
+@panic.message = private unnamed_addr constant [16 x i8] c"not implemented\00", align 1
+@panic.format = private unnamed_addr constant [17 x i8] c"panic at %s: %s\0A\00", align 1
+@panic.location = private unnamed_addr constant [14 x i8] c"binding:14:11\00", align 1
+
 define private void @.0.binding() {
 fn.entry:
   %var.a = alloca i32, align 4
@@ -24,11 +28,7 @@ if.after:                                         ; preds = %if.then, %if.else
   %access4 = load i32, i32* %if.value, align 4
   %var.d = alloca i32, align 4
   store i32 %access4, i32* %var.d, align 4
-  %var.g = alloca i32, align 4
-  store i32 1, i32* %var.g, align 4
-  %var.h = alloca i32, align 4
-  store i32 2, i32* %var.h, align 4
-  store i32 3, i32* %var.h, align 4
+  call void @.2.panic(i8* getelementptr inbounds ([16 x i8], [16 x i8]* @panic.message, i32 0, i32 0), i8* getelementptr inbounds ([14 x i8], [14 x i8]* @panic.location, i32 0, i32 0))
   unreachable
 
 if.else:                                          ; preds = %fn.entry
@@ -37,7 +37,22 @@ if.else:                                          ; preds = %fn.entry
   br label %if.after
 }
 
-define private i32 @.1.closure() {
+define private i32 @.1.closure(i8* %env) {
 closure.entry:
   ret i32 0
 }
+
+; Function Attrs: noreturn
+declare void @abort() #0
+
+declare i32 @dprintf(i32, i8*, ...)
+
+; Function Attrs: noreturn
+define private void @.2.panic(i8* %0, i8* %1) #0 {
+panic.entry:
+  %2 = call i32 (i32, i8*, ...) @dprintf(i32 2, i8* getelementptr inbounds ([17 x i8], [17 x i8]* @panic.format, i32 0, i32 0), i8* %1, i8* %0)
+  call void @abort()
+  unreachable
+}
+
+attributes #0 = { noreturn }
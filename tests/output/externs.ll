@@ -7,8 +7,6 @@ declare i32 @printf(i8*, ...)
 
 define private void @.0.calling_externs() {
 fn.entry:
-  %call = call i32 (i8*, ...) @printf.1(i8* getelementptr inbounds ([12 x i8], [12 x i8]* @string_literal, i32 0, i32 0))
+  %call = call i32 (i8*, ...) @printf(i8* getelementptr inbounds ([12 x i8], [12 x i8]* @string_literal, i32 0, i32 0))
   ret void
 }
-
-declare i32 @printf.1(i8*, ...)
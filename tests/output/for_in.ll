@@ -0,0 +1,29 @@
+; ModuleID = 'for_in'
+source_filename = "for_in"
+
+define private void @.0.for_in_continue({ i32*, i32 } %param.numbers) {
+fn.entry:
+  %slice.pointer = extractvalue { i32*, i32 } %param.numbers, 0
+  %slice.length = extractvalue { i32*, i32 } %param.numbers, 1
+  %for.index = alloca i32, align 4
+  store i32 0, i32* %for.index, align 4
+  br label %for.condition
+
+for.condition:                                    ; preds = %for.next, %fn.entry
+  %access = load i32, i32* %for.index, align 4
+  %for.is_within_bounds = icmp ult i32 %access, %slice.length
+  br i1 %for.is_within_bounds, label %for.body, label %for.after
+
+for.body:                                         ; preds = %for.condition
+  %for.element.gep = getelementptr inbounds i32, i32* %slice.pointer, i32 %access
+  %access1 = load i32, i32* %for.element.gep, align 4
+  br label %for.next
+
+for.next:                                         ; preds = %for.body
+  %for.index.next = add i32 %access, 1
+  store i32 %for.index.next, i32* %for.index, align 4
+  br label %for.condition
+
+for.after:                                        ; preds = %for.condition
+  ret void
+}
@@ -0,0 +1,29 @@
+; ModuleID = 'in_operator'
+source_filename = "in_operator"
+
+define private i32 @.0.in_operator() {
+fn.entry:
+  %array.value = alloca [2 x i32], align 4
+  %array.init = getelementptr [2 x i32], [2 x i32]* %array.value, i32 0, i32 0
+  store i32 1, i32* %array.init, align 4
+  %array.init1 = getelementptr [2 x i32], [2 x i32]* %array.value, i32 0, i32 1
+  store i32 2, i32* %array.init1, align 4
+  %access = load [2 x i32], [2 x i32]* %array.value, align 4
+  %var.arr = alloca [2 x i32], align 4
+  store [2 x i32] %access, [2 x i32]* %var.arr, align 4
+  %var.i = alloca i32, align 4
+  store i32 1, i32* %var.i, align 4
+  %access2 = load [2 x i32], [2 x i32]* %var.arr, align 4
+  %access3 = load i32, i32* %var.i, align 4
+  %in_op = icmp ult i32 %access3, 2
+  br i1 %in_op, label %if.then, label %if.after
+
+if.then:                                          ; preds = %fn.entry
+  %access4 = load i32, i32* %var.i, align 4
+  %array.index.gep = getelementptr inbounds [2 x i32], [2 x i32]* %var.arr, i32 0, i32 %access4
+  %access5 = load i32, i32* %array.index.gep, align 4
+  ret i32 %access5
+
+if.after:                                         ; preds = %fn.entry
+  ret i32 0
+}
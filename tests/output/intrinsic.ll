@@ -3,12 +3,20 @@ source_filename = "intrinsic"
 
 %.1.struct.Complex = type { i8*, i32 }
 
+@type_name = private unnamed_addr constant [8 x i8] c"Complex\00", align 1
+
 define private void @.0.size_of() {
 fn.entry:
   %var.int_size = alloca i64, align 8
-  store i64 ptrtoint (i32* getelementptr (i32, i32* null, i32 1) to i64), i64* %var.int_size, align 4
+  store i64 4, i64* %var.int_size, align 4
   %var.person_size = alloca i64, align 8
-  store i64 ptrtoint (%.1.struct.Complex* getelementptr (%.1.struct.Complex, %.1.struct.Complex* null, i32 1) to i64), i64* %var.person_size, align 4
+  store i64 16, i64* %var.person_size, align 4
+  %var.int_alignment = alloca i64, align 8
+  store i64 4, i64* %var.int_alignment, align 4
+  %var.second_offset = alloca i64, align 8
+  store i64 8, i64* %var.second_offset, align 4
+  %var.complex_name = alloca i8*, align 8
+  store i8* getelementptr inbounds ([8 x i8], [8 x i8]* @type_name, i32 0, i32 0), i8** %var.complex_name, align 8
   ret void
 }
 